@@ -0,0 +1,189 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Segmented address resolution.
+//!
+//! Addresses in display lists are usually segmented: the top byte selects
+//! one of the 16 entries of the RSP segment table, and the low 24 bits are an
+//! offset from the base address in that entry. Like the RSP, only bits 24 to
+//! 27 are used to pick the segment, so unsegmented addresses like
+//! `0x80123456` belong to segment `0`.
+//!
+//! An [`AddressResolver`] turns those addresses into offsets into a buffer,
+//! such as the file being extracted. The caller provides where each known
+//! segment starts in the buffer, see [`AddressResolver::map`], and the
+//! resolver records the `gsSPSegment` updates met while executing a display
+//! list. Addresses that end up in a segment with no known location are
+//! reported as [`ResolveError::Unmapped`].
+
+use core::fmt;
+
+use crate::command::Command;
+#[cfg(feature = "alloc")]
+use crate::decode::Macro;
+#[cfg(feature = "alloc")]
+use crate::macro_id::MacroId;
+
+/// The number of entries of the segment table.
+pub const NUM_SEGMENTS: usize = 16;
+
+/// How many segment table entries a single address can go through before
+/// [`ResolveError::TooDeep`] is reported.
+const MAX_DEPTH: usize = NUM_SEGMENTS;
+
+/// The segment of `addr`, as the RSP computes it.
+#[must_use]
+pub fn segment_of(addr: u32) -> u32 {
+    (addr >> 24) & 0xF
+}
+
+/// The offset of `addr` from the start of its segment.
+#[must_use]
+pub fn segment_offset(addr: u32) -> u32 {
+    addr & 0x00FF_FFFF
+}
+
+/// Builds the segmented address of `offset` in segment `seg`, like the
+/// `SEGMENT_ADDR` macro of many decompilation projects.
+#[must_use]
+pub fn segmented(seg: u32, offset: u32) -> u32 {
+    ((seg & 0xF) << 24) | segment_offset(offset)
+}
+
+/// Resolves segmented addresses into buffer offsets.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct AddressResolver {
+    /// The offset in the buffer where each segment starts, as supplied by
+    /// the caller. `None` for segments that are not in the buffer.
+    pub map: [Option<u32>; NUM_SEGMENTS],
+    /// The base address set for each segment by `gsSPSegment`, `None` for
+    /// segments that have not been set.
+    pub segments: [Option<u32>; NUM_SEGMENTS],
+}
+
+impl AddressResolver {
+    /// A resolver with no segment mapped and no segment set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maps segment `seg` to the buffer offset `offset`.
+    #[must_use]
+    pub fn with_segment(mut self, seg: u32, offset: u32) -> Self {
+        self.map[seg as usize & 0xF] = Some(offset);
+        self
+    }
+
+    /// Records a `gsSPSegment` that sets segment `seg` to `base`.
+    ///
+    /// A [`gfxd_seg_callback`] receives the base address first, as `seg`,
+    /// and the segment number second, as `num`, so it records them with
+    /// `set_segment(num as u32, seg)`.
+    ///
+    /// [`gfxd_seg_callback`]: crate::argument_callbacks::gfxd_seg_callback
+    pub fn set_segment(&mut self, seg: u32, base: u32) {
+        self.segments[seg as usize & 0xF] = Some(base);
+    }
+
+    /// Forgets every segment set by [`AddressResolver::set_segment`], as
+    /// when starting another display list.
+    pub fn reset(&mut self) {
+        self.segments = [None; NUM_SEGMENTS];
+    }
+
+    /// Records `command` if it is a [`Command::SPSegment`]. Returns whether
+    /// it was.
+    pub fn apply(&mut self, command: &Command) -> bool {
+        match *command {
+            Command::SPSegment { seg, base } => {
+                self.set_segment(seg, base);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Records `m` if it is a `gsSPSegment`. Returns whether it was.
+    #[cfg(feature = "alloc")]
+    pub fn apply_macro(&mut self, m: &Macro) -> bool {
+        if m.id != MacroId::gfxd_SPSegment {
+            return false;
+        }
+        match Command::from_macro(m) {
+            Some(command) => self.apply(&command),
+            None => false,
+        }
+    }
+
+    /// Resolves `addr` into an offset in the buffer.
+    ///
+    /// A segment set by `gsSPSegment` takes precedence over the location
+    /// given for it in [`AddressResolver::map`]. Its base address is
+    /// resolved in turn when it points into another segment, so segments
+    /// can be set to addresses in other segments, while a base in the same
+    /// segment, like the usual `gsSPSegment(0, 0)`, is looked up in the map.
+    pub fn resolve(&self, addr: u32) -> Result<u32, ResolveError> {
+        let current = self.translate(addr)?;
+        let seg = segment_of(current);
+        match self.map[seg as usize] {
+            Some(start) => Ok(start.wrapping_add(segment_offset(current))),
+            None => Err(ResolveError::Unmapped { addr, segment: seg }),
+        }
+    }
+
+    /// Applies the segments set by `gsSPSegment` to `addr`, without looking
+    /// up [`AddressResolver::map`].
+    ///
+    /// Segments are followed in turn like in [`AddressResolver::resolve`],
+    /// and an address in a segment that was not set is returned as is.
+    pub fn translate(&self, addr: u32) -> Result<u32, ResolveError> {
+        let mut current = addr;
+
+        for _ in 0..MAX_DEPTH {
+            let seg = segment_of(current);
+            if let Some(base) = self.segments[seg as usize] {
+                current = base.wrapping_add(segment_offset(current));
+                if segment_of(current) != seg {
+                    continue;
+                }
+            }
+            return Ok(current);
+        }
+        Err(ResolveError::TooDeep { addr })
+    }
+
+    /// Whether `addr` can be resolved.
+    #[must_use]
+    pub fn is_resolvable(&self, addr: u32) -> bool {
+        self.resolve(addr).is_ok()
+    }
+}
+
+/// The reasons [`AddressResolver::resolve`] can fail.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum ResolveError {
+    /// `addr` led to `segment`, which was neither set nor mapped.
+    Unmapped { addr: u32, segment: u32 },
+    /// `addr` went through too many segments, likely because some of them
+    /// point to each other.
+    TooDeep { addr: u32 },
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ResolveError::Unmapped { addr, segment } => write!(
+                f,
+                "segment {:#X} of address {:#010X} is not mapped",
+                segment, addr
+            ),
+            ResolveError::TooDeep { addr } => {
+                write!(f, "address {:#010X} goes through too many segments", addr)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ResolveError {}
@@ -392,3 +392,25 @@ pub enum ArgType {
     /// dma io flag
     gfxd_Dmaflag = 95,
 }
+
+impl ArgType {
+    /// The highest valid argument type.
+    pub const MAX: u32 = ArgType::gfxd_Dmaflag as u32;
+
+    /// Converts a raw type, like the one returned by [`gfxd_arg_type`], into
+    /// an [`ArgType`].
+    ///
+    /// Returns [`None`] if `raw` is not a known type.
+    ///
+    /// [`gfxd_arg_type`]: crate::macro_info::gfxd_arg_type
+    #[must_use]
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        if raw <= Self::MAX {
+            // SAFETY: `ArgType` is `repr(u32)` and its values are contiguous
+            // from zero up to `MAX`.
+            Some(unsafe { core::mem::transmute::<u32, ArgType>(raw) })
+        } else {
+            None
+        }
+    }
+}
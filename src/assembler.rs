@@ -0,0 +1,804 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Assemble display lists written in the syntax `libgfxd` prints back into
+//! [`Gfx`] packets.
+//!
+//! The input is a sequence of macros, like the ones printed by
+//! [`gfxd_macro_dflt`], separated by commas, semicolons or whitespace. Both
+//! the static (`gsSPVertex(...)`) and the dynamic (`gSPVertex(glistp++, ...)`)
+//! forms are accepted, as are raw packets written as `{0xE7000000, 0x0}`.
+//! Braces wrapping the whole list and C comments are ignored.
+//!
+//! Arguments are C expressions, which may use:
+//! - Integer and float literals.
+//! - The `|`, `^`, `&`, `<<`, `>>`, `+`, `-`, `*`, `/`, `%`, `~` and `!`
+//!   operators, with C precedence.
+//! - Casts, like `(u8)` or `*(Lightsn *)`. Pointer casts, dereferences and
+//!   address-of operators are ignored.
+//! - Every constant in [`gbi`], like `G_IM_FMT_RGBA` or `G_RM_AA_ZB_OPA_SURF`,
+//!   with the value it has for the target microcode.
+//! - The fixed point macros, like `qu102(3.25)`, and `GBL_c1`/`GBL_c2`.
+//! - Combiner inputs, like `TEXEL0`, in the arguments of
+//!   `gsDPSetCombineLERP` and the `G_CC_*` presets in the arguments of
+//!   `gsDPSetCombineMode`.
+//!
+//! Any other name is passed to the symbol resolver set with
+//! [`Assembler::symbols`].
+//!
+//! [`gfxd_macro_dflt`]: crate::handlers::gfxd_macro_dflt
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::arg_type::ArgType;
+use crate::encode::{self, EncodeError};
+use crate::gbi;
+use crate::gfx::Gfx;
+use crate::macro_id::MacroId;
+use crate::settings::{Endian, Ucode};
+
+/// What went wrong while assembling, see [`AsmError`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum AsmErrorKind {
+    /// A character that can not start any token.
+    UnexpectedChar(char),
+    /// The input ended in the middle of a macro.
+    UnexpectedEnd,
+    /// A token other than the one described was found.
+    Expected(&'static str),
+    /// A malformed number.
+    InvalidNumber(String),
+    /// A name that is not a known macro.
+    UnknownMacro(String),
+    /// A name that is neither a known constant nor resolved by the symbol
+    /// resolver.
+    UnknownSymbol(String),
+    /// A division or remainder by zero.
+    DivisionByZero,
+    /// An operator that can not be applied to a float.
+    FloatOperand,
+    /// The macro could not be encoded.
+    Encode(EncodeError),
+}
+
+/// An error found while assembling, with the position where it was found.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AsmError {
+    /// The line, starting at 1.
+    pub line: usize,
+    /// The column, in characters, starting at 1.
+    pub column: usize,
+    /// What went wrong.
+    pub kind: AsmErrorKind,
+}
+
+impl fmt::Display for AsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.line, self.column)?;
+        match &self.kind {
+            AsmErrorKind::UnexpectedChar(c) => write!(f, "unexpected character {:?}", c),
+            AsmErrorKind::UnexpectedEnd => write!(f, "unexpected end of input"),
+            AsmErrorKind::Expected(x) => write!(f, "expected {}", x),
+            AsmErrorKind::InvalidNumber(x) => write!(f, "invalid number `{}`", x),
+            AsmErrorKind::UnknownMacro(x) => write!(f, "unknown macro `{}`", x),
+            AsmErrorKind::UnknownSymbol(x) => write!(f, "unknown symbol `{}`", x),
+            AsmErrorKind::DivisionByZero => write!(f, "division by zero"),
+            AsmErrorKind::FloatOperand => write!(f, "invalid operand of type float"),
+            AsmErrorKind::Encode(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AsmError {}
+
+/// Assembles `text` for `ucode`, see the [module documentation](self).
+pub fn assemble(text: &str, ucode: Ucode) -> Result<Vec<Gfx>, AsmError> {
+    Assembler::new(ucode).assemble(text)
+}
+
+/// A configurable assembler.
+#[derive(Copy, Clone)]
+pub struct Assembler<'a> {
+    ucode: Ucode,
+    symbols: Option<Resolver<'a>>,
+}
+
+type Resolver<'a> = &'a dyn Fn(&str) -> Option<u32>;
+
+impl<'a> Assembler<'a> {
+    /// An assembler targeting `ucode`, with no symbol resolver.
+    #[must_use]
+    pub fn new(ucode: Ucode) -> Self {
+        Self {
+            ucode,
+            symbols: None,
+        }
+    }
+
+    /// Uses `resolver` to get the value of names that are not `gbi.h`
+    /// constants, like the names of other display lists or textures.
+    #[must_use]
+    pub fn symbols(mut self, resolver: Resolver<'a>) -> Self {
+        self.symbols = Some(resolver);
+        self
+    }
+
+    /// Assembles `text` into packets.
+    pub fn assemble(&self, text: &str) -> Result<Vec<Gfx>, AsmError> {
+        let tokens = lex(text)?;
+        let mut parser = Parser {
+            asm: self,
+            tokens: &tokens,
+            pos: 0,
+            end: end_position(text),
+        };
+        parser.parse_list()
+    }
+
+    /// Assembles `text` into bytes laid out as described by `endian` and
+    /// `wordsize`, see [`Gfx::to_bytes`].
+    pub fn assemble_bytes(
+        &self,
+        text: &str,
+        endian: Endian,
+        wordsize: u32,
+    ) -> Result<Vec<u8>, AsmError> {
+        let packets = self.assemble(text)?;
+        Ok(crate::gfx::to_byte_vec(&packets, endian, wordsize))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tok {
+    Ident(String),
+    Int(i64),
+    Float(f64),
+    Punct(&'static str),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    tok: Tok,
+    line: usize,
+    column: usize,
+}
+
+static PUNCTS: [&str; 23] = [
+    "<<", ">>", "++", "--", "->", "(", ")", "{", "}", "[", "]", ",", ";", "|", "^", "&", "+", "-",
+    "*", "/", "%", "~", "!",
+];
+
+fn end_position(text: &str) -> (usize, usize) {
+    let line = text.lines().count().max(1);
+    let column = text.lines().last().map_or(0, |x| x.chars().count()) + 1;
+    (line, column)
+}
+
+fn lex(text: &str) -> Result<Vec<Token>, AsmError> {
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut column = 1;
+    let mut rest = text;
+
+    while let Some(c) = rest.chars().next() {
+        let err = |kind| AsmError { line, column, kind };
+
+        let len = if c == '\n' {
+            line += 1;
+            column = 1;
+            rest = &rest[1..];
+            continue;
+        } else if c.is_whitespace() {
+            c.len_utf8()
+        } else if rest.starts_with("//") {
+            rest.find('\n').unwrap_or(rest.len())
+        } else if rest.starts_with("/*") {
+            let len = rest[2..]
+                .find("*/")
+                .map(|x| x + 4)
+                .ok_or_else(|| err(AsmErrorKind::UnexpectedEnd))?;
+            let comment = &rest[..len];
+            let newlines = comment.matches('\n').count();
+            if newlines != 0 {
+                line += newlines;
+                column = comment.rsplit('\n').next().map_or(0, |x| x.chars().count()) + 1;
+                rest = &rest[len..];
+                continue;
+            }
+            len
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let len = rest
+                .find(|x: char| !(x.is_ascii_alphanumeric() || x == '_'))
+                .unwrap_or(rest.len());
+            tokens.push(Token {
+                tok: Tok::Ident(String::from(&rest[..len])),
+                line,
+                column,
+            });
+            len
+        } else if c.is_ascii_digit()
+            || (c == '.' && rest[1..].starts_with(|x: char| x.is_ascii_digit()))
+        {
+            let (tok, len) = lex_number(rest).map_err(err)?;
+            tokens.push(Token { tok, line, column });
+            len
+        } else if let Some(p) = PUNCTS.iter().find(|x| rest.starts_with(*x)) {
+            tokens.push(Token {
+                tok: Tok::Punct(p),
+                line,
+                column,
+            });
+            p.len()
+        } else {
+            return Err(err(AsmErrorKind::UnexpectedChar(c)));
+        };
+
+        column += rest[..len].chars().count();
+        rest = &rest[len..];
+    }
+
+    Ok(tokens)
+}
+
+fn lex_number(text: &str) -> Result<(Tok, usize), AsmErrorKind> {
+    let bytes = text.as_bytes();
+    let hex = text.starts_with("0x") || text.starts_with("0X");
+    let mut len = if hex { 2 } else { 0 };
+    let mut float = false;
+
+    while len < bytes.len() {
+        let c = bytes[len] as char;
+        if hex && c.is_ascii_hexdigit() || c.is_ascii_digit() {
+            len += 1;
+        } else if !hex && c == '.' {
+            float = true;
+            len += 1;
+        } else if !hex && (c == 'e' || c == 'E') {
+            float = true;
+            len += 1;
+            if len < bytes.len() && (bytes[len] == b'+' || bytes[len] == b'-') {
+                len += 1;
+            }
+        } else {
+            break;
+        }
+    }
+    let digits = &text[..len];
+
+    let suffix = text[len..]
+        .find(|x: char| !x.is_ascii_alphanumeric() && x != '_')
+        .unwrap_or(text.len() - len);
+    let full = &text[..len + suffix];
+    let suffix = &text[len..len + suffix];
+    let invalid = || AsmErrorKind::InvalidNumber(String::from(full));
+
+    let tok = if float {
+        if !(suffix.is_empty() || suffix == "f" || suffix == "F") {
+            return Err(invalid());
+        }
+        Tok::Float(digits.parse::<f64>().map_err(|_| invalid())?)
+    } else {
+        if !suffix
+            .chars()
+            .all(|x| x == 'u' || x == 'U' || x == 'l' || x == 'L')
+        {
+            return Err(invalid());
+        }
+        let value = if hex {
+            u64::from_str_radix(&digits[2..], 16)
+        } else if digits.len() > 1 && digits.starts_with('0') {
+            u64::from_str_radix(&digits[1..], 8)
+        } else {
+            digits.parse::<u64>()
+        };
+        Tok::Int(value.map_err(|_| invalid())? as i64)
+    };
+
+    Ok((tok, full.len()))
+}
+
+#[derive(Debug, Copy, Clone)]
+enum Num {
+    Int(i64),
+    Float(f64),
+}
+
+impl Num {
+    fn as_f64(self) -> f64 {
+        match self {
+            Num::Int(x) => x as f64,
+            Num::Float(x) => x,
+        }
+    }
+
+    fn as_u32(self) -> u32 {
+        match self {
+            Num::Int(x) => x as u32,
+            Num::Float(x) => x as i64 as u32,
+        }
+    }
+}
+
+/// The fixed point macros of `gbi.h` and `gu.h`, with their number of
+/// integer and fractional bits.
+static FIXED_POINT: [(&str, u32, u32); 12] = [
+    ("qu08", 0, 8),
+    ("qu016", 0, 16),
+    ("qs48", 4, 8),
+    ("qs816", 8, 16),
+    ("qs1616", 16, 16),
+    ("qu102", 10, 2),
+    ("qs102", 10, 2),
+    ("qu105", 10, 5),
+    ("qs105", 10, 5),
+    ("qu510", 5, 10),
+    ("qs510", 5, 10),
+    ("qs1516", 15, 16),
+];
+
+/// Integer types accepted in casts, with their size in bits and signedness.
+static INT_TYPES: [(&str, u32, bool); 16] = [
+    ("u8", 8, false),
+    ("s8", 8, true),
+    ("u16", 16, false),
+    ("s16", 16, true),
+    ("u32", 32, false),
+    ("s32", 32, true),
+    ("u64", 64, false),
+    ("s64", 64, true),
+    ("char", 8, true),
+    ("short", 16, true),
+    ("int", 32, true),
+    ("long", 32, true),
+    ("signed", 32, true),
+    ("unsigned", 32, false),
+    ("uintptr_t", 32, false),
+    ("intptr_t", 32, true),
+];
+
+struct Parser<'a, 'b> {
+    asm: &'b Assembler<'a>,
+    tokens: &'b [Token],
+    pos: usize,
+    end: (usize, usize),
+}
+
+impl<'a, 'b> Parser<'a, 'b> {
+    fn peek(&self) -> Option<&'b Tok> {
+        self.tokens.get(self.pos).map(|x| &x.tok)
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<&'b Tok> {
+        self.tokens.get(self.pos + offset).map(|x| &x.tok)
+    }
+
+    fn is_punct(&self, p: &str) -> bool {
+        self.peek() == Some(&Tok::Punct(static_punct(p)))
+    }
+
+    fn err_at(&self, pos: usize, kind: AsmErrorKind) -> AsmError {
+        let (line, column) = match self.tokens.get(pos) {
+            Some(x) => (x.line, x.column),
+            None => self.end,
+        };
+        AsmError { line, column, kind }
+    }
+
+    fn err(&self, kind: AsmErrorKind) -> AsmError {
+        let kind = if self.pos >= self.tokens.len() {
+            AsmErrorKind::UnexpectedEnd
+        } else {
+            kind
+        };
+        self.err_at(self.pos, kind)
+    }
+
+    fn eat(&mut self, p: &str) -> bool {
+        if self.is_punct(p) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, p: &'static str) -> Result<(), AsmError> {
+        if self.eat(p) {
+            Ok(())
+        } else {
+            Err(self.err(AsmErrorKind::Expected(p)))
+        }
+    }
+
+    fn parse_list(&mut self) -> Result<Vec<Gfx>, AsmError> {
+        let mut out = Vec::new();
+
+        while let Some(tok) = self.peek() {
+            match tok {
+                Tok::Punct(",") | Tok::Punct(";") | Tok::Punct("}") => self.pos += 1,
+                Tok::Punct("{") => match self.peek_at(1) {
+                    Some(Tok::Ident(_)) | Some(Tok::Punct("{")) | Some(Tok::Punct("}")) => {
+                        self.pos += 1;
+                    }
+                    _ => {
+                        self.pos += 1;
+                        let w0 = self.parse_expr()?.as_u32();
+                        self.expect(",")?;
+                        let w1 = self.parse_expr()?.as_u32();
+                        self.expect("}")?;
+                        out.push(Gfx::new(w0, w1));
+                    }
+                },
+                Tok::Ident(_) => self.parse_macro(&mut out)?,
+                _ => return Err(self.err(AsmErrorKind::Expected("a macro"))),
+            }
+        }
+
+        Ok(out)
+    }
+
+    fn parse_macro(&mut self, out: &mut Vec<Gfx>) -> Result<(), AsmError> {
+        let start = self.pos;
+        let name = match self.peek() {
+            Some(Tok::Ident(x)) => x,
+            _ => return Err(self.err(AsmErrorKind::Expected("a macro"))),
+        };
+        let id = MacroId::from_name(name)
+            .ok_or_else(|| self.err(AsmErrorKind::UnknownMacro(name.clone())))?;
+        let dynamic = !name.trim_start_matches('_').starts_with("gs");
+        self.pos += 1;
+        self.expect("(")?;
+
+        if dynamic {
+            self.skip_arg()?;
+            if !id.args().is_empty() {
+                self.expect(",")?;
+            }
+        }
+
+        let mut args = Vec::with_capacity(id.args().len());
+        for (i, info) in id.args().iter().enumerate() {
+            if i != 0 {
+                self.expect(",")?;
+            }
+            args.push(self.parse_arg(info.type_)?);
+        }
+        self.expect(")")?;
+
+        let packets = encode::encode(self.asm.ucode, id, &args)
+            .map_err(|x| self.err_at(start, AsmErrorKind::Encode(x)))?;
+        out.extend_from_slice(&packets);
+        Ok(())
+    }
+
+    /// Skips the display list pointer of dynamic macros.
+    fn skip_arg(&mut self) -> Result<(), AsmError> {
+        let mut depth = 0usize;
+        loop {
+            match self.peek() {
+                None => return Err(self.err(AsmErrorKind::UnexpectedEnd)),
+                Some(Tok::Punct(",")) | Some(Tok::Punct(")")) if depth == 0 => return Ok(()),
+                Some(Tok::Punct("(")) | Some(Tok::Punct("[")) => depth += 1,
+                Some(Tok::Punct(")")) | Some(Tok::Punct("]")) => {
+                    depth = depth
+                        .checked_sub(1)
+                        .ok_or_else(|| self.err(AsmErrorKind::Expected("`,` or `)`")))?;
+                }
+                _ => {}
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// Whether the argument being parsed is a single token.
+    fn single_token(&self) -> bool {
+        match self.peek_at(1) {
+            Some(Tok::Punct(",")) | Some(Tok::Punct(")")) => true,
+            _ => false,
+        }
+    }
+
+    fn parse_arg(&mut self, type_: ArgType) -> Result<u32, AsmError> {
+        if self.single_token() {
+            let table = match type_ {
+                ArgType::gfxd_Ccmuxa => Some(gbi::CCMUX_A),
+                ArgType::gfxd_Ccmuxb => Some(gbi::CCMUX_B),
+                ArgType::gfxd_Ccmuxc => Some(gbi::CCMUX_C),
+                ArgType::gfxd_Ccmuxd => Some(gbi::CCMUX_D),
+                ArgType::gfxd_Acmuxabd => Some(gbi::ACMUX_ABD),
+                ArgType::gfxd_Acmuxc => Some(gbi::ACMUX_C),
+                _ => None,
+            };
+            let found = match (table, self.peek()) {
+                (Some(table), Some(Tok::Ident(name))) => table
+                    .iter()
+                    .find(|(x, _)| *x == name.as_str())
+                    .map(|(_, v)| *v),
+                (Some(table), Some(Tok::Int(n))) if *n == 0 || *n == 1 => {
+                    let name = if *n == 0 { "0" } else { "1" };
+                    table.iter().find(|(x, _)| *x == name).map(|(_, v)| *v)
+                }
+                (None, Some(Tok::Ident(name))) if type_ == ArgType::gfxd_Ccpre => gbi::CC_PRESETS
+                    .iter()
+                    .position(|x| x.name == name.as_str())
+                    .map(|x| x as u32),
+                _ => None,
+            };
+            if let Some(value) = found {
+                self.pos += 1;
+                return Ok(value);
+            }
+        }
+
+        Ok(self.parse_expr()?.as_u32())
+    }
+
+    fn parse_expr(&mut self) -> Result<Num, AsmError> {
+        self.parse_binary(0)
+    }
+
+    /// Parses binary operators, `level` being the index of the lowest
+    /// precedence level allowed in [`BINARY`].
+    fn parse_binary(&mut self, level: usize) -> Result<Num, AsmError> {
+        if level == BINARY.len() {
+            return self.parse_unary();
+        }
+
+        let mut lhs = self.parse_binary(level + 1)?;
+        loop {
+            let op = match self.peek() {
+                Some(Tok::Punct(p)) if BINARY[level].contains(p) => *p,
+                _ => return Ok(lhs),
+            };
+            let pos = self.pos;
+            self.pos += 1;
+            let rhs = self.parse_binary(level + 1)?;
+            lhs = binary(op, lhs, rhs).map_err(|x| self.err_at(pos, x))?;
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Num, AsmError> {
+        let pos = self.pos;
+        let op = match self.peek() {
+            Some(Tok::Punct(p)) => *p,
+            _ => return self.parse_primary(),
+        };
+
+        match op {
+            "-" | "+" | "~" | "!" => {
+                self.pos += 1;
+                let value = self.parse_unary()?;
+                match (op, value) {
+                    ("+", x) => Ok(x),
+                    ("-", Num::Int(x)) => Ok(Num::Int(x.wrapping_neg())),
+                    ("-", Num::Float(x)) => Ok(Num::Float(-x)),
+                    ("~", Num::Int(x)) => Ok(Num::Int(!x)),
+                    ("!", x) => Ok(Num::Int((x.as_f64() == 0.0) as i64)),
+                    _ => Err(self.err_at(pos, AsmErrorKind::FloatOperand)),
+                }
+            }
+            "*" | "&" => {
+                self.pos += 1;
+                self.parse_unary()
+            }
+            "(" => match self.parse_cast()? {
+                Some(cast) => {
+                    let value = self.parse_unary()?;
+                    Ok(cast.apply(value))
+                }
+                None => self.parse_primary(),
+            },
+            _ => self.parse_primary(),
+        }
+    }
+
+    /// Parses a cast if there is one, leaving the position untouched
+    /// otherwise.
+    fn parse_cast(&mut self) -> Result<Option<Cast>, AsmError> {
+        let mut i = 1;
+        let mut names = Vec::new();
+        while let Some(Tok::Ident(name)) = self.peek_at(i) {
+            names.push(name);
+            i += 1;
+        }
+        if names.is_empty() {
+            return Ok(None);
+        }
+        let mut pointer = false;
+        while self.peek_at(i) == Some(&Tok::Punct("*")) {
+            pointer = true;
+            i += 1;
+        }
+        if self.peek_at(i) != Some(&Tok::Punct(")")) {
+            return Ok(None);
+        }
+
+        let cast = if pointer {
+            Cast::None
+        } else if names
+            .iter()
+            .any(|x| *x == "float" || *x == "double" || *x == "f32" || *x == "f64")
+        {
+            Cast::Float
+        } else {
+            let mut found = None;
+            for name in names.iter() {
+                match INT_TYPES.iter().find(|(x, _, _)| *x == name.as_str()) {
+                    Some(&(_, bits, signed)) => {
+                        let bits = if *name == "unsigned" || *name == "signed" {
+                            found.map_or(bits, |(x, _)| x)
+                        } else {
+                            bits
+                        };
+                        let signed = if *name == "unsigned" {
+                            false
+                        } else if *name == "signed" {
+                            true
+                        } else {
+                            found.map_or(signed, |(_, x)| x && signed)
+                        };
+                        found = Some((bits, signed));
+                    }
+                    None => return Ok(None),
+                }
+            }
+            match found {
+                Some((bits, signed)) => Cast::Int(bits, signed),
+                None => return Ok(None),
+            }
+        };
+
+        self.pos += i + 1;
+        Ok(Some(cast))
+    }
+
+    fn parse_primary(&mut self) -> Result<Num, AsmError> {
+        let pos = self.pos;
+        let tok = self
+            .peek()
+            .ok_or_else(|| self.err(AsmErrorKind::UnexpectedEnd))?;
+        self.pos += 1;
+
+        match tok {
+            Tok::Int(x) => Ok(Num::Int(*x)),
+            Tok::Float(x) => Ok(Num::Float(*x)),
+            Tok::Punct("(") => {
+                let value = self.parse_expr()?;
+                self.expect(")")?;
+                Ok(value)
+            }
+            Tok::Ident(name) => {
+                if self.is_punct("(") {
+                    self.parse_call(pos, name)
+                } else {
+                    self.resolve(name)
+                        .map(|x| Num::Int(x.into()))
+                        .ok_or_else(|| self.err_at(pos, AsmErrorKind::UnknownSymbol(name.clone())))
+                }
+            }
+            _ => {
+                self.pos -= 1;
+                Err(self.err(AsmErrorKind::Expected("an expression")))
+            }
+        }
+    }
+
+    fn parse_call(&mut self, pos: usize, name: &str) -> Result<Num, AsmError> {
+        let mut args = Vec::new();
+        self.expect("(")?;
+        loop {
+            args.push(self.parse_expr()?);
+            if !self.eat(",") {
+                break;
+            }
+        }
+        self.expect(")")?;
+        let wrong_count = || self.err_at(pos, AsmErrorKind::Expected("a different argument count"));
+
+        if let Some(&(_, int, frac)) = FIXED_POINT.iter().find(|(x, _, _)| *x == name) {
+            if args.len() != 1 {
+                return Err(wrong_count());
+            }
+            let scaled = (args[0].as_f64() * (1u64 << frac) as f64) as i64;
+            let mask = (1i64 << (int + frac)) - 1;
+            return Ok(Num::Int(scaled & mask));
+        }
+        if name == "GBL_c1" || name == "GBL_c2" {
+            if args.len() != 4 {
+                return Err(wrong_count());
+            }
+            let a = |i: usize| args[i].as_u32();
+            let f = if name == "GBL_c1" {
+                gbi::GBL_c1
+            } else {
+                gbi::GBL_c2
+            };
+            return Ok(Num::Int(f(a(0), a(1), a(2), a(3)).into()));
+        }
+
+        Err(self.err_at(pos, AsmErrorKind::UnknownSymbol(String::from(name))))
+    }
+
+    fn resolve(&self, name: &str) -> Option<u32> {
+        gbi::lookup(self.asm.ucode, name).or_else(|| self.asm.symbols.and_then(|f| f(name)))
+    }
+}
+
+/// Binary operators, from lowest to highest precedence.
+static BINARY: [&[&str]; 6] = [
+    &["|"],
+    &["^"],
+    &["&"],
+    &["<<", ">>"],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+fn static_punct(p: &str) -> &'static str {
+    PUNCTS.iter().find(|x| **x == p).copied().unwrap_or("")
+}
+
+fn binary(op: &str, lhs: Num, rhs: Num) -> Result<Num, AsmErrorKind> {
+    match (lhs, rhs) {
+        (Num::Int(a), Num::Int(b)) => {
+            let value = match op {
+                "|" => a | b,
+                "^" => a ^ b,
+                "&" => a & b,
+                "<<" => a.wrapping_shl(b as u32),
+                ">>" => a.wrapping_shr(b as u32),
+                "+" => a.wrapping_add(b),
+                "-" => a.wrapping_sub(b),
+                "*" => a.wrapping_mul(b),
+                _ if b == 0 => return Err(AsmErrorKind::DivisionByZero),
+                "/" => a.wrapping_div(b),
+                _ => a.wrapping_rem(b),
+            };
+            Ok(Num::Int(value))
+        }
+        (a, b) => {
+            let (a, b) = (a.as_f64(), b.as_f64());
+            let value = match op {
+                "+" => a + b,
+                "-" => a - b,
+                "*" => a * b,
+                "/" => a / b,
+                _ => return Err(AsmErrorKind::FloatOperand),
+            };
+            Ok(Num::Float(value))
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+enum Cast {
+    None,
+    Float,
+    Int(u32, bool),
+}
+
+impl Cast {
+    fn apply(self, value: Num) -> Num {
+        match self {
+            Cast::None => value,
+            Cast::Float => Num::Float(value.as_f64()),
+            Cast::Int(bits, signed) => {
+                let x = match value {
+                    Num::Int(x) => x,
+                    Num::Float(x) => x as i64,
+                };
+                if bits >= 64 {
+                    Num::Int(x)
+                } else if signed {
+                    let shift = 64 - bits;
+                    Num::Int((x << shift) >> shift)
+                } else {
+                    Num::Int(x & ((1i64 << bits) - 1))
+                }
+            }
+        }
+    }
+}
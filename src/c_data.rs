@@ -0,0 +1,183 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! C initializers for the data display lists reference.
+//!
+//! `libgfxd` prints the macros of a display list, but not the vertices,
+//! lights, matrices and viewports they point to. The functions of this
+//! module print those as C definitions, so they can be written next to the
+//! display lists that use them:
+//!
+//! ```c
+//! Vtx name[] = {
+//!     VTX(-10, 20, 256, 1024, -1024, 0xFF, 0x00, 0x00, 0xFF),
+//! };
+//! ```
+//!
+//! Vertices use the `VTX` macro common to decompilation projects, defined as
+//! `{ { { x, y, z }, 0, { s, t }, { cr, cg, cb, a } } }`. Every other
+//! structure is printed with the `gbi.h` macros when it can be, and as plain
+//! braces otherwise, so the output compiles with a stock `gbi.h`.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use crate::light::{Light, Lights};
+use crate::mtx::Mtx;
+use crate::settings::Endian;
+use crate::vp::Vp;
+use crate::vtx::Vtx;
+
+/// The most lights the `gdSPDefLights` macros take.
+const MAX_DEF_LIGHTS: usize = 7;
+
+fn write_color(out: &mut String, col: &[u8]) {
+    for (i, x) in col.iter().enumerate() {
+        if i != 0 {
+            out.push_str(", ");
+        }
+        let _ = write!(out, "0x{:02X}", x);
+    }
+}
+
+fn write_dir(out: &mut String, dir: &[i8; 3]) {
+    let _ = write!(out, "{}, {}, {}", dir[0], dir[1], dir[2]);
+}
+
+/// Defines `vertices` as an array named `name`.
+///
+/// When `normals` is set, the last four bytes of each vertex are printed as
+/// a signed normal and an alpha, as used with lighting, rather than as a
+/// color. Vertices with a nonzero `flag`, which `VTX` cannot express, are
+/// printed in full.
+#[must_use]
+pub fn vtx(name: &str, vertices: &[Vtx], normals: bool) -> String {
+    let mut out = String::new();
+    let _ = writeln!(out, "Vtx {}[] = {{", name);
+    for v in vertices.iter() {
+        let [x, y, z] = v.ob;
+        let [s, t] = v.tc;
+        if v.flag == 0 {
+            let _ = write!(out, "    VTX({}, {}, {}, {}, {}, ", x, y, z, s, t);
+        } else {
+            let _ = write!(
+                out,
+                "    {{ {{ {{ {}, {}, {} }}, {}, {{ {}, {} }}, {{ ",
+                x, y, z, v.flag, s, t
+            );
+        }
+        if normals {
+            write_dir(&mut out, &v.normal());
+            let _ = write!(out, ", 0x{:02X}", v.alpha());
+        } else {
+            write_color(&mut out, &v.color());
+        }
+        if v.flag == 0 {
+            out.push_str("),\n");
+        } else {
+            out.push_str(" } } },\n");
+        }
+    }
+    out.push_str("};\n");
+    out
+}
+
+fn write_light(out: &mut String, light: &Light) {
+    out.push_str("{ { { ");
+    write_color(out, &light.col);
+    out.push_str(" }, 0, { ");
+    write_color(out, &light.colc);
+    out.push_str(" }, 0, { ");
+    write_dir(out, &light.dir);
+    out.push_str(" }, 0 } }");
+}
+
+/// Defines `lights` as a `LightsN` named `name`, where `N` is the number of
+/// directional lights.
+///
+/// The `gdSPDefLights` macros are used when every color matches its copy
+/// and there are at most 7 lights.
+#[must_use]
+pub fn lights(name: &str, lights: &Lights) -> String {
+    let mut out = String::new();
+    let n = lights.lights.len();
+    let _ = write!(out, "Lights{} {} = ", n, name);
+
+    let copies_match =
+        lights.ambient.col == lights.ambient.colc && lights.lights.iter().all(|x| x.col == x.colc);
+    if copies_match && n <= MAX_DEF_LIGHTS {
+        let _ = write!(out, "gdSPDefLights{}(", n);
+        write_color(&mut out, &lights.ambient.col);
+        for light in lights.lights.iter() {
+            out.push_str(", ");
+            write_color(&mut out, &light.col);
+            out.push_str(", ");
+            write_dir(&mut out, &light.dir);
+        }
+        out.push_str(");\n");
+        return out;
+    }
+
+    out.push_str("{\n    { { { ");
+    write_color(&mut out, &lights.ambient.col);
+    out.push_str(" }, 0, { ");
+    write_color(&mut out, &lights.ambient.colc);
+    out.push_str(" }, 0 } },\n");
+    if n != 0 {
+        out.push_str("    {\n");
+        for light in lights.lights.iter() {
+            out.push_str("        ");
+            write_light(&mut out, light);
+            out.push_str(",\n");
+        }
+        out.push_str("    },\n");
+    }
+    out.push_str("};\n");
+    out
+}
+
+/// Defines `mtx` as an `Mtx` named `name`.
+///
+/// `Mtx` stores the integer and fractional halves of its elements apart, so
+/// the initializer lists the raw words of the structure, preceded by a
+/// comment with the value of the matrix.
+#[must_use]
+pub fn mtx(name: &str, mtx: &Mtx) -> String {
+    let mut out = String::new();
+    out.push_str("/*\n");
+    for row in mtx.to_f32().iter() {
+        let _ = writeln!(
+            out,
+            " * {{ {}, {}, {}, {} }}",
+            row[0], row[1], row[2], row[3]
+        );
+    }
+    out.push_str(" */\n");
+
+    let bytes = mtx.to_bytes(Endian::gfxd_endian_big, 4);
+    let _ = writeln!(out, "Mtx {} = {{ {{", name);
+    for row in bytes.chunks(16) {
+        out.push_str("    {");
+        for (i, word) in row.chunks(4).enumerate() {
+            let word = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+            let _ = write!(out, "{}0x{:08X}", if i == 0 { " " } else { ", " }, word);
+        }
+        out.push_str(" },\n");
+    }
+    out.push_str("} };\n");
+    out
+}
+
+/// Defines `vp` as a `Vp` named `name`.
+#[must_use]
+pub fn vp(name: &str, vp: &Vp) -> String {
+    let [sx, sy, sz, sw] = vp.vscale;
+    let [tx, ty, tz, tw] = vp.vtrans;
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "Vp {} = {{ {{\n    {{ {}, {}, {}, {} }},\n    {{ {}, {}, {}, {} }},\n}} }};\n",
+        name, sx, sy, sz, sw, tx, ty, tz, tw
+    );
+    out
+}
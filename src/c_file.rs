@@ -0,0 +1,420 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! C source file generation.
+//!
+//! A [`CFile`] gathers decoded display lists and the data they use, and
+//! prints them all as a single C source file:
+//!
+//! ```c
+//! #include "ultra64.h"
+//!
+//! extern u64 gSharedTex[];
+//!
+//! Vtx sVtx[] = {
+//!     VTX(0, 0, 0, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF),
+//!     ...
+//! };
+//!
+//! Gfx sDL[] = {
+//!     gsSPVertex(sVtx, 3, 0),
+//!     ...
+//! };
+//! ```
+//!
+//! Pointer arguments of the display lists are printed as the name of the
+//! definition or extern symbol they point to, `&sVtx[4]` when they point
+//! inside an array, and as the address `libgfxd` printed when neither is
+//! known. Every definition is printed after the ones it refers to, and
+//! display lists that call each other in a cycle are declared before their
+//! definitions.
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::arg_type::ArgType;
+use crate::c_data;
+use crate::decode::{Macro, GFX_SIZE};
+use crate::light::{Ambient, Light, Lights};
+use crate::mtx::Mtx;
+use crate::vp::Vp;
+use crate::vtx::Vtx;
+
+/// What a definition holds.
+#[derive(Debug, Clone)]
+enum Kind {
+    List(Vec<Macro>),
+    Array { type_: &'static str, elem: u32 },
+    Lights,
+    Struct,
+}
+
+#[derive(Debug, Clone)]
+struct Definition {
+    name: String,
+    addr: u32,
+    size: u32,
+    kind: Kind,
+    /// The definition as C source, except for display lists, which are
+    /// printed once every symbol is known.
+    text: String,
+}
+
+/// The C type of the data a pointer argument of type `type_` points to, and
+/// the size of its elements if it is an array.
+fn pointee(type_: ArgType, macro_name: &str) -> Option<(String, Option<u32>)> {
+    let (name, elem) = match type_ {
+        ArgType::gfxd_Dl => ("Gfx", Some(GFX_SIZE as u32)),
+        ArgType::gfxd_Vtxptr => ("Vtx", Some(Vtx::SIZE as u32)),
+        ArgType::gfxd_Timg | ArgType::gfxd_Tlut | ArgType::gfxd_Uctext | ArgType::gfxd_Ucdata => {
+            ("u64", Some(8))
+        }
+        ArgType::gfxd_Cimg | ArgType::gfxd_Zimg => ("u16", Some(2)),
+        ArgType::gfxd_Mtxptr => ("Mtx", None),
+        ArgType::gfxd_Vpptr => ("Vp", None),
+        ArgType::gfxd_Lookatptr => ("LookAt", None),
+        ArgType::gfxd_Lightptr => ("Light", None),
+        ArgType::gfxd_Lightsn => {
+            // gsSPSetLights1 to gsSPSetLights7 take a Lights1 to Lights7.
+            let n = macro_name
+                .chars()
+                .last()
+                .filter(|x| x.is_ascii_digit())
+                .map_or(String::from("n"), |x| x.to_string());
+            return Some((format!("Lights{}", n), None));
+        }
+        _ => return None,
+    };
+    Some((String::from(name), elem))
+}
+
+/// A C source file made of display lists and the data they use.
+#[derive(Debug, Clone, Default)]
+pub struct CFile {
+    headers: Vec<String>,
+    definitions: Vec<Definition>,
+    externs: BTreeMap<u32, String>,
+}
+
+impl CFile {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds an `#include` of `path` at the top of the file.
+    pub fn add_header(&mut self, path: &str) {
+        self.headers.push(path.into());
+    }
+
+    /// Names the symbol at `addr`, which is defined in another file.
+    ///
+    /// An `extern` declaration is printed for it if a display list of this
+    /// file points to it.
+    pub fn add_extern(&mut self, addr: u32, name: &str) {
+        self.externs.insert(addr, name.into());
+    }
+
+    fn add(&mut self, name: &str, addr: u32, size: u32, kind: Kind, text: String) {
+        self.definitions.push(Definition {
+            name: name.into(),
+            addr,
+            size,
+            kind,
+            text,
+        });
+    }
+
+    /// Adds the display list at `addr`, made of `macros`.
+    pub fn add_list(&mut self, name: &str, addr: u32, macros: &[Macro]) {
+        let size = macros.iter().map(|x| x.packets).sum::<u32>() * GFX_SIZE as u32;
+        self.add(name, addr, size, Kind::List(macros.to_vec()), String::new());
+    }
+
+    /// Adds the vertices at `addr`, printed as described by [`c_data::vtx`].
+    pub fn add_vtx(&mut self, name: &str, addr: u32, vertices: &[Vtx], normals: bool) {
+        let text = c_data::vtx(name, vertices, normals);
+        let kind = Kind::Array {
+            type_: "Vtx",
+            elem: Vtx::SIZE as u32,
+        };
+        self.add(name, addr, (vertices.len() * Vtx::SIZE) as u32, kind, text);
+    }
+
+    /// Adds the set of lights at `addr`.
+    pub fn add_lights(&mut self, name: &str, addr: u32, lights: &Lights) {
+        let text = c_data::lights(name, lights);
+        let size = Lights::size(lights.lights.len()) as u32;
+        self.add(name, addr, size, Kind::Lights, text);
+    }
+
+    /// Adds the matrix at `addr`.
+    pub fn add_mtx(&mut self, name: &str, addr: u32, mtx: &Mtx) {
+        let text = c_data::mtx(name, mtx);
+        self.add(name, addr, Mtx::SIZE as u32, Kind::Struct, text);
+    }
+
+    /// Adds the viewport at `addr`.
+    pub fn add_vp(&mut self, name: &str, addr: u32, vp: &Vp) {
+        let text = c_data::vp(name, vp);
+        self.add(name, addr, Vp::SIZE as u32, Kind::Struct, text);
+    }
+
+    /// Adds `size` bytes of data at `addr`, like a texture or a palette,
+    /// defined as a `u64` array that includes the file at `path`:
+    ///
+    /// ```c
+    /// u64 name[] = {
+    /// #include "path"
+    /// };
+    /// ```
+    pub fn add_include(&mut self, name: &str, addr: u32, size: u32, path: &str) {
+        let text = format!("u64 {}[] = {{\n#include \"{}\"\n}};\n", name, path);
+        let kind = Kind::Array {
+            type_: "u64",
+            elem: 8,
+        };
+        self.add(name, addr, size, kind, text);
+    }
+
+    fn definition_at(&self, addr: u32) -> Option<usize> {
+        self.definitions
+            .iter()
+            .position(|x| addr.wrapping_sub(x.addr) < x.size || (addr == x.addr && x.size == 0))
+    }
+
+    /// The expression that refers to `addr` in an argument of type `type_`,
+    /// and the definition it points into, if any.
+    fn reference(
+        &self,
+        type_: ArgType,
+        addr: u32,
+        macro_name: &str,
+    ) -> Option<(String, Option<usize>)> {
+        let (_, pointee_elem) = pointee(type_, macro_name)?;
+        let by_value = type_ == ArgType::gfxd_Lightsn;
+
+        if let Some(index) = self.definition_at(addr) {
+            let def = &self.definitions[index];
+            let offset = addr - def.addr;
+            let name = &def.name;
+
+            let expr = match def.kind {
+                Kind::List(_) => array_ref(name, offset, GFX_SIZE as u32),
+                Kind::Array { elem, .. } => array_ref(name, offset, elem),
+                Kind::Lights if by_value => {
+                    if offset == 0 {
+                        Some(name.clone())
+                    } else {
+                        None
+                    }
+                }
+                Kind::Lights => {
+                    let light = offset.wrapping_sub(Ambient::SIZE as u32);
+                    if offset == 0 {
+                        Some(format!("&{}.a", name))
+                    } else if offset >= Ambient::SIZE as u32 && light % Light::SIZE as u32 == 0 {
+                        Some(format!("&{}.l[{}]", name, light / Light::SIZE as u32))
+                    } else {
+                        None
+                    }
+                }
+                Kind::Struct => {
+                    if offset == 0 {
+                        Some(if by_value {
+                            name.clone()
+                        } else {
+                            format!("&{}", name)
+                        })
+                    } else {
+                        None
+                    }
+                }
+            };
+            return expr.map(|x| (x, Some(index)));
+        }
+
+        let name = self.externs.get(&addr)?;
+        let expr = if by_value || pointee_elem.is_some() {
+            name.clone()
+        } else {
+            format!("&{}", name)
+        };
+        Some((expr, None))
+    }
+
+    /// Prints `m`, with its pointer arguments named where possible, and
+    /// records what they refer to.
+    fn render_macro(
+        &self,
+        m: &Macro,
+        refs: &mut BTreeSet<usize>,
+        externs: &mut BTreeMap<String, (String, bool)>,
+    ) -> String {
+        let name = match &m.name {
+            Some(name) => name,
+            None => return m.text.clone(),
+        };
+
+        let mut replaced = false;
+        let mut args = Vec::with_capacity(m.args.len());
+        for arg in m.args.iter() {
+            match self.reference(arg.type_, arg.value.as_u32(), name) {
+                Some((expr, def)) => {
+                    match def {
+                        Some(index) => {
+                            refs.insert(index);
+                        }
+                        None => {
+                            let (type_, elem) = pointee(arg.type_, name).unwrap_or_default();
+                            let symbol = &self.externs[&arg.value.as_u32()];
+                            externs
+                                .entry(symbol.clone())
+                                .or_insert((type_, elem.is_some()));
+                        }
+                    }
+                    args.push(expr);
+                    replaced = true;
+                }
+                None => args.push(arg.text.clone()),
+            }
+        }
+
+        if replaced {
+            let mut text = format!("{}(", name);
+            for (i, arg) in args.iter().enumerate() {
+                if i != 0 {
+                    text.push_str(", ");
+                }
+                text.push_str(arg);
+            }
+            text.push(')');
+            text
+        } else {
+            m.text.clone()
+        }
+    }
+
+    /// Prints the whole file.
+    #[must_use]
+    pub fn to_source(&self) -> String {
+        let mut bodies = Vec::with_capacity(self.definitions.len());
+        let mut deps = Vec::with_capacity(self.definitions.len());
+        let mut externs = BTreeMap::new();
+
+        for def in self.definitions.iter() {
+            let mut refs = BTreeSet::new();
+            let body = match &def.kind {
+                Kind::List(macros) => {
+                    let mut body = format!("Gfx {}[] = {{\n", def.name);
+                    for m in macros.iter() {
+                        let _ = writeln!(
+                            body,
+                            "    {},",
+                            self.render_macro(m, &mut refs, &mut externs)
+                        );
+                    }
+                    body.push_str("};\n");
+                    body
+                }
+                _ => def.text.clone(),
+            };
+            bodies.push(body);
+            deps.push(refs);
+        }
+
+        // Definitions in address order, each after the ones it refers to.
+        let mut by_addr = (0..self.definitions.len()).collect::<Vec<_>>();
+        by_addr.sort_by_key(|&x| self.definitions[x].addr);
+
+        let mut order = Vec::with_capacity(by_addr.len());
+        let mut visited = BTreeSet::new();
+        for &root in by_addr.iter() {
+            visit(root, &deps, &mut visited, &mut order);
+        }
+
+        // Lists referred to before they are defined, because of cycles.
+        let mut position = BTreeMap::new();
+        for (i, &def) in order.iter().enumerate() {
+            position.insert(def, i);
+        }
+        let mut forward = BTreeSet::new();
+        for (i, &def) in order.iter().enumerate() {
+            for target in deps[def].iter() {
+                if position[target] > i {
+                    forward.insert(position[target]);
+                }
+            }
+        }
+
+        let mut out = String::new();
+        for header in self.headers.iter() {
+            let _ = writeln!(out, "#include \"{}\"", header);
+        }
+        if !self.headers.is_empty() {
+            out.push('\n');
+        }
+
+        let mut declarations = String::new();
+        for (name, (type_, array)) in externs.iter() {
+            let _ = writeln!(
+                declarations,
+                "extern {} {}{};",
+                type_,
+                name,
+                if *array { "[]" } else { "" }
+            );
+        }
+        for &i in forward.iter() {
+            let def = &self.definitions[order[i]];
+            let type_ = match def.kind {
+                Kind::List(_) => "Gfx",
+                Kind::Array { type_, .. } => type_,
+                _ => continue,
+            };
+            let _ = writeln!(declarations, "extern {} {}[];", type_, def.name);
+        }
+        if !declarations.is_empty() {
+            out.push_str(&declarations);
+            out.push('\n');
+        }
+
+        for (i, &def) in order.iter().enumerate() {
+            if i != 0 {
+                out.push('\n');
+            }
+            out.push_str(&bodies[def]);
+        }
+        out
+    }
+}
+
+/// `&name[offset / elem]`, or just `name` at the start of the array.
+fn array_ref(name: &str, offset: u32, elem: u32) -> Option<String> {
+    if offset == 0 {
+        Some(name.into())
+    } else if offset % elem == 0 {
+        Some(format!("&{}[{}]", name, offset / elem))
+    } else {
+        None
+    }
+}
+
+/// Appends `def` to `order` after everything it refers to, directly or not.
+fn visit(
+    def: usize,
+    deps: &[BTreeSet<usize>],
+    visited: &mut BTreeSet<usize>,
+    order: &mut Vec<usize>,
+) {
+    if !visited.insert(def) {
+        return;
+    }
+    for &target in deps[def].iter() {
+        visit(target, deps, visited, order);
+    }
+    order.push(def);
+}
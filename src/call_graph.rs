@@ -0,0 +1,291 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Display list call graphs.
+//!
+//! A [`CallGraph`] records which display lists call or branch to which,
+//! either all at once from a [`Traversal`] or list by list with
+//! [`CallGraph::record`], and exports the result as Graphviz DOT or as
+//! JSON.
+//!
+//! Lists that use `gsSPCullDisplayList` are marked as such, since they may
+//! end before their last macro.
+
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+use crate::command::Command;
+use crate::decode::Macro;
+use crate::json;
+use crate::macro_id::MacroId;
+use crate::traverse::{self, Call, CallKind, Traversal};
+
+/// A display list in a [`CallGraph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    /// The address of the list.
+    pub addr: u32,
+    /// A name for the list, used instead of its address in the exports.
+    pub name: Option<String>,
+    /// If the macros of the list were recorded. Lists that are only known
+    /// as the target of a call are not.
+    pub decoded: bool,
+    /// If the list uses `gsSPCullDisplayList`.
+    pub culls: bool,
+}
+
+/// Every call of a given kind from one list to another.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Edge {
+    pub from: u32,
+    pub to: u32,
+    pub kind: CallKind,
+    /// The number of such calls.
+    pub count: usize,
+}
+
+/// A graph of the calls between display lists.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CallGraph {
+    /// Every list, in the order they were added.
+    pub nodes: Vec<Node>,
+    /// Every edge, in the order they were added.
+    pub edges: Vec<Edge>,
+    index: BTreeMap<u32, usize>,
+}
+
+fn kind_name(kind: CallKind) -> &'static str {
+    match kind {
+        CallKind::Call => "call",
+        CallKind::Branch => "branch",
+        CallKind::BranchZ => "branch_z",
+    }
+}
+
+impl CallGraph {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the graph of every list found by [`traverse`].
+    ///
+    /// The edges come from the [`Call`]s of the traversal, so they point to
+    /// the lists the calls reach once segments are applied, added in the
+    /// order the calls appear in their callers.
+    ///
+    /// [`traverse`]: crate::traverse::traverse
+    #[must_use]
+    pub fn from_traversal(traversal: &Traversal) -> Self {
+        let mut graph = Self::new();
+        for list in traversal.lists.iter() {
+            let node = graph.node_mut(list.addr);
+            node.decoded = true;
+            node.culls = list
+                .macros
+                .iter()
+                .any(|m| m.id == MacroId::gfxd_SPCullDisplayList);
+        }
+
+        let mut calls: Vec<(usize, &Call)> = traversal
+            .lists
+            .iter()
+            .flat_map(|list| list.callers.iter())
+            .chain(traversal.unresolved.iter())
+            .map(|call| {
+                let position = traversal
+                    .lists
+                    .iter()
+                    .position(|x| x.addr == call.caller)
+                    .unwrap_or(traversal.lists.len());
+                (position, call)
+            })
+            .collect();
+        calls.sort_by_key(|&(position, call)| (position, call.addr));
+
+        for (_, call) in calls {
+            graph.add_edge(call.caller, call.target, call.kind);
+        }
+        graph
+    }
+
+    /// The list at `addr`, added if it is not in the graph yet.
+    pub fn node_mut(&mut self, addr: u32) -> &mut Node {
+        let nodes = &mut self.nodes;
+        let index = *self.index.entry(addr).or_insert_with(|| {
+            nodes.push(Node {
+                addr,
+                name: None,
+                decoded: false,
+                culls: false,
+            });
+            nodes.len() - 1
+        });
+        &mut self.nodes[index]
+    }
+
+    /// The list at `addr`, if it is in the graph.
+    #[must_use]
+    pub fn node(&self, addr: u32) -> Option<&Node> {
+        self.index.get(&addr).map(|&x| &self.nodes[x])
+    }
+
+    /// Names the list at `addr`.
+    pub fn set_name(&mut self, addr: u32, name: &str) {
+        self.node_mut(addr).name = Some(name.into());
+    }
+
+    /// Adds a call of kind `kind` from `from` to `to`.
+    pub fn add_edge(&mut self, from: u32, to: u32, kind: CallKind) {
+        self.node_mut(from);
+        self.node_mut(to);
+
+        let edge = self
+            .edges
+            .iter_mut()
+            .find(|x| x.from == from && x.to == to && x.kind == kind);
+        match edge {
+            Some(edge) => edge.count += 1,
+            None => self.edges.push(Edge {
+                from,
+                to,
+                kind,
+                count: 1,
+            }),
+        }
+    }
+
+    /// Records the macros of the list at `addr`, adding an edge for every
+    /// `gsSPDisplayList`, `gsSPBranchList`, `gsSPBranchLessZraw` and
+    /// `gsBranchZ`.
+    ///
+    /// Recording stops at the macro that ends the list.
+    pub fn record(&mut self, addr: u32, macros: &[Macro]) {
+        self.node_mut(addr).decoded = true;
+
+        let mut half_1 = None;
+        for m in macros.iter() {
+            let command = match Command::from_macro(m) {
+                Some(command) => command,
+                None => continue,
+            };
+
+            if let Command::SPCullDisplayList { .. } = command {
+                self.node_mut(addr).culls = true;
+            }
+
+            let (target, ends) = traverse::call_of(&command, half_1);
+            if let Some((target, kind)) = target {
+                self.add_edge(addr, target, kind);
+            }
+            if ends {
+                break;
+            }
+
+            half_1 = match command {
+                Command::DPHalf1 { wordhi } => Some(wordhi),
+                _ => None,
+            };
+        }
+    }
+
+    /// The lists that call `addr`, directly.
+    pub fn callers(&self, addr: u32) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |x| x.to == addr)
+    }
+
+    /// The lists `addr` calls, directly.
+    pub fn callees(&self, addr: u32) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |x| x.from == addr)
+    }
+
+    /// The graph in the Graphviz DOT language.
+    ///
+    /// Lists that were not decoded are drawn dashed, and lists that use
+    /// `gsSPCullDisplayList` are drawn as boxes.
+    #[must_use]
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        out.push_str("digraph {\n");
+
+        for node in self.nodes.iter() {
+            let _ = write!(out, "    dl_{:08X} [label=", node.addr);
+            match &node.name {
+                Some(name) => json::write_str(&mut out, name),
+                None => {
+                    let _ = write!(out, "\"0x{:08X}\"", node.addr);
+                }
+            }
+            if node.culls {
+                out.push_str(", shape=box");
+            }
+            if !node.decoded {
+                out.push_str(", style=dashed");
+            }
+            out.push_str("];\n");
+        }
+
+        for edge in self.edges.iter() {
+            let _ = write!(
+                out,
+                "    dl_{:08X} -> dl_{:08X} [label=\"{}",
+                edge.from,
+                edge.to,
+                kind_name(edge.kind)
+            );
+            if edge.count > 1 {
+                let _ = write!(out, " x{}", edge.count);
+            }
+            out.push_str("\"];\n");
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// The graph as a JSON object, with a `nodes` and an `edges` array.
+    ///
+    /// Addresses are numbers, names are `null` for unnamed lists and edge
+    /// kinds are one of `"call"`, `"branch"` and `"branch_z"`.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("{\"nodes\":[");
+        for (i, node) in self.nodes.iter().enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{{\"addr\":{},\"name\":", node.addr);
+            match &node.name {
+                Some(name) => json::write_str(&mut out, name),
+                None => out.push_str("null"),
+            }
+            let _ = write!(
+                out,
+                ",\"decoded\":{},\"culls\":{}}}",
+                node.decoded, node.culls
+            );
+        }
+
+        out.push_str("],\"edges\":[");
+        for (i, edge) in self.edges.iter().enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            let _ = write!(
+                out,
+                "{{\"from\":{},\"to\":{},\"kind\":\"{}\",\"count\":{}}}",
+                edge.from,
+                edge.to,
+                kind_name(edge.kind),
+                edge.count
+            );
+        }
+        out.push_str("]}");
+
+        out
+    }
+}
@@ -0,0 +1,290 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Color combiner decoding.
+//!
+//! The RDP color combiner computes `(a - b) * c + d` once for the color and
+//! once for the alpha channel of each of its two cycles. A [`Combiner`]
+//! holds the inputs of all four equations as they are passed to
+//! `gsDPSetCombineLERP`, and can be built from a `G_SETCOMBINE` packet or
+//! from either of the `gsDPSetCombineMode` and `gsDPSetCombineLERP`
+//! commands.
+//!
+//! Equations are printed with the input names `gsDPSetCombineLERP` takes,
+//! like `(TEXEL0 - 0) * SHADE + 0`. Input values that have no name, which
+//! the hardware treats as `0`, are printed as numbers.
+
+use core::fmt;
+
+use crate::command::Command;
+use crate::gbi::{self, CombinePreset};
+use crate::gfx::Gfx;
+use crate::static_dl;
+
+fn input_name(table: &'static [(&'static str, u32)], value: u32) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(_, x)| *x == value)
+        .map(|(name, _)| *name)
+}
+
+fn write_equation(
+    f: &mut fmt::Formatter<'_>,
+    names: [Option<&'static str>; 4],
+    values: [u32; 4],
+) -> fmt::Result {
+    let input = |i: usize| Input(names[i], values[i]);
+    write!(
+        f,
+        "({} - {}) * {} + {}",
+        input(0),
+        input(1),
+        input(2),
+        input(3)
+    )
+}
+
+/// A combiner input, printed by name when it has one.
+struct Input(Option<&'static str>, u32);
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "{}", self.1),
+        }
+    }
+}
+
+/// The `(a - b) * c + d` equation of the color channels.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct ColorEquation {
+    pub a: u32,
+    pub b: u32,
+    pub c: u32,
+    pub d: u32,
+}
+
+impl ColorEquation {
+    /// The names of the `a`, `b`, `c` and `d` inputs, from
+    /// [`gbi::CCMUX_A`], [`gbi::CCMUX_B`], [`gbi::CCMUX_C`] and
+    /// [`gbi::CCMUX_D`].
+    #[must_use]
+    pub fn input_names(&self) -> [Option<&'static str>; 4] {
+        [
+            input_name(gbi::CCMUX_A, self.a),
+            input_name(gbi::CCMUX_B, self.b),
+            input_name(gbi::CCMUX_C, self.c),
+            input_name(gbi::CCMUX_D, self.d),
+        ]
+    }
+}
+
+impl fmt::Display for ColorEquation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_equation(f, self.input_names(), [self.a, self.b, self.c, self.d])
+    }
+}
+
+/// The `(a - b) * c + d` equation of the alpha channel.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct AlphaEquation {
+    pub a: u32,
+    pub b: u32,
+    pub c: u32,
+    pub d: u32,
+}
+
+impl AlphaEquation {
+    /// The names of the `a`, `b`, `c` and `d` inputs, from
+    /// [`gbi::ACMUX_ABD`] and [`gbi::ACMUX_C`].
+    #[must_use]
+    pub fn input_names(&self) -> [Option<&'static str>; 4] {
+        [
+            input_name(gbi::ACMUX_ABD, self.a),
+            input_name(gbi::ACMUX_ABD, self.b),
+            input_name(gbi::ACMUX_C, self.c),
+            input_name(gbi::ACMUX_ABD, self.d),
+        ]
+    }
+}
+
+impl fmt::Display for AlphaEquation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_equation(f, self.input_names(), [self.a, self.b, self.c, self.d])
+    }
+}
+
+/// The color and alpha equations of a single combiner cycle.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Cycle {
+    pub color: ColorEquation,
+    pub alpha: AlphaEquation,
+}
+
+impl Cycle {
+    /// The equations of `preset`.
+    #[must_use]
+    pub fn from_preset(preset: &CombinePreset) -> Self {
+        Self {
+            color: ColorEquation {
+                a: preset.a,
+                b: preset.b,
+                c: preset.c,
+                d: preset.d,
+            },
+            alpha: AlphaEquation {
+                a: preset.aa,
+                b: preset.ab,
+                c: preset.ac,
+                d: preset.ad,
+            },
+        }
+    }
+
+    /// The first preset of [`gbi::CC_PRESETS`] with the same equations, if
+    /// any.
+    #[must_use]
+    pub fn preset(&self) -> Option<&'static CombinePreset> {
+        gbi::CC_PRESETS
+            .iter()
+            .find(|x| Self::from_preset(x) == *self)
+    }
+}
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "color = {}, alpha = {}", self.color, self.alpha)
+    }
+}
+
+/// The full state of the color combiner, as set by `G_SETCOMBINE`.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Combiner {
+    /// The first and second cycles.
+    ///
+    /// Only the first one is used in `G_CYC_1CYCLE` mode, where both are
+    /// usually set to the same equations.
+    pub cycles: [Cycle; 2],
+}
+
+impl Combiner {
+    /// Builds a combiner from the arguments of `gsDPSetCombineLERP`, in
+    /// order.
+    #[must_use]
+    pub fn from_lerp(args: [u32; 16]) -> Self {
+        let cycle = |x: &[u32]| Cycle {
+            color: ColorEquation {
+                a: x[0],
+                b: x[1],
+                c: x[2],
+                d: x[3],
+            },
+            alpha: AlphaEquation {
+                a: x[4],
+                b: x[5],
+                c: x[6],
+                d: x[7],
+            },
+        };
+        Self {
+            cycles: [cycle(&args[..8]), cycle(&args[8..])],
+        }
+    }
+
+    /// Builds a combiner from the presets of `gsDPSetCombineMode`.
+    #[must_use]
+    pub fn from_presets(mode1: &CombinePreset, mode2: &CombinePreset) -> Self {
+        Self {
+            cycles: [Cycle::from_preset(mode1), Cycle::from_preset(mode2)],
+        }
+    }
+
+    /// Decodes a `G_SETCOMBINE` packet, or returns `None` if `gfx` is any
+    /// other command.
+    #[must_use]
+    pub fn from_gfx(gfx: Gfx) -> Option<Self> {
+        if gfx.opcode() as u32 != gbi::G_SETCOMBINE {
+            return None;
+        }
+        let (w0, w1) = (gfx.w0, gfx.w1);
+        let field = |w: u32, s: u32, n: u32| (w >> s) & ((1 << n) - 1);
+
+        Some(Self::from_lerp([
+            field(w0, 20, 4),
+            field(w1, 28, 4),
+            field(w0, 15, 5),
+            field(w1, 15, 3),
+            field(w0, 12, 3),
+            field(w1, 12, 3),
+            field(w0, 9, 3),
+            field(w1, 9, 3),
+            field(w0, 5, 4),
+            field(w1, 24, 4),
+            field(w0, 0, 5),
+            field(w1, 6, 3),
+            field(w1, 21, 3),
+            field(w1, 3, 3),
+            field(w1, 18, 3),
+            field(w1, 0, 3),
+        ]))
+    }
+
+    /// Builds a combiner from a [`Command::DPSetCombineLERP`] or a
+    /// [`Command::DPSetCombineMode`], or returns `None` for any other
+    /// command or an unknown preset index.
+    #[must_use]
+    pub fn from_command(command: &Command) -> Option<Self> {
+        match *command {
+            Command::DPSetCombineMode { mode1, mode2 } => {
+                let mode1 = gbi::CC_PRESETS.get(mode1 as usize)?;
+                let mode2 = gbi::CC_PRESETS.get(mode2 as usize)?;
+                Some(Self::from_presets(mode1, mode2))
+            }
+            Command::DPSetCombineLERP { .. } => {
+                let mut args = [0; 16];
+                args.copy_from_slice(&command.args());
+                Some(Self::from_lerp(args))
+            }
+            _ => None,
+        }
+    }
+
+    /// The arguments of the `gsDPSetCombineLERP` that sets this combiner.
+    #[must_use]
+    pub fn to_lerp(&self) -> [u32; 16] {
+        let [c0, c1] = self.cycles;
+        [
+            c0.color.a, c0.color.b, c0.color.c, c0.color.d, c0.alpha.a, c0.alpha.b, c0.alpha.c,
+            c0.alpha.d, c1.color.a, c1.color.b, c1.color.c, c1.color.d, c1.alpha.a, c1.alpha.b,
+            c1.alpha.c, c1.alpha.d,
+        ]
+    }
+
+    /// Encodes this combiner as a `G_SETCOMBINE` packet.
+    #[must_use]
+    pub fn to_gfx(&self) -> Gfx {
+        let a = self.to_lerp();
+        static_dl::gsDPSetCombineLERP(
+            a[0], a[1], a[2], a[3], a[4], a[5], a[6], a[7], a[8], a[9], a[10], a[11], a[12], a[13],
+            a[14], a[15],
+        )
+    }
+
+    /// The `G_CC_*` presets for the first and second cycles, if both cycles
+    /// match one.
+    #[must_use]
+    pub fn presets(&self) -> Option<(&'static CombinePreset, &'static CombinePreset)> {
+        Some((self.cycles[0].preset()?, self.cycles[1].preset()?))
+    }
+}
+
+impl fmt::Display for Combiner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cycle 1: {}; cycle 2: {}",
+            self.cycles[0], self.cycles[1]
+        )
+    }
+}
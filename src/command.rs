@@ -0,0 +1,2768 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Macros as typed commands with named fields.
+//!
+//! A [`Command`] has one variant per [`MacroId`], with one field per
+//! argument named like the argument reported by [`gfxd_arg_name`]. Fields
+//! hold the value of the argument, as it would be returned by
+//! [`gfxd_arg_value`]: arguments of a signed type, like the texture
+//! coordinates of `gsSPTextureRectangle` or the coefficients of
+//! `gsDPSetConvert`, are `i32` and the others are `u32`. [`MacroId::args`]
+//! lists the [`ArgType`] of each field.
+//!
+//! [`Command::from_args`] and [`Command::args`] work with the raw values,
+//! which hold signed values as their two's complement representation.
+//!
+//! [`gfxd_arg_name`]: crate::macro_info::gfxd_arg_name
+//! [`gfxd_arg_value`]: crate::macro_info::gfxd_arg_value
+//! [`ArgType`]: crate::arg_type::ArgType
+
+#![allow(non_snake_case)]
+
+use core::ops::Deref;
+
+#[cfg(feature = "alloc")]
+use crate::decode::Macro;
+use crate::encode::{EncodeError, Packets};
+use crate::macro_id::MacroId;
+use crate::settings::Ucode;
+
+/// The largest number of arguments a single macro takes.
+pub const MAX_ARGS: usize = 18;
+
+/// The argument values of a [`Command`], in order.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Args {
+    buf: [u32; MAX_ARGS],
+    len: usize,
+}
+
+impl Args {
+    fn new(values: &[u32]) -> Self {
+        let mut buf = [0; MAX_ARGS];
+        buf[..values.len()].copy_from_slice(values);
+        Self {
+            buf,
+            len: values.len(),
+        }
+    }
+
+    /// The values as a slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[u32] {
+        &self.buf[..self.len]
+    }
+}
+
+impl Deref for Args {
+    type Target = [u32];
+
+    fn deref(&self) -> &[u32] {
+        self.as_slice()
+    }
+}
+
+/// A macro with its arguments, see the [module documentation](self).
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Command {
+    /// A packet that is not a valid command, kept as its two words.
+    Invalid { hi: u32, lo: u32 },
+    /// `gsDPFillRectangle`.
+    DPFillRectangle {
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+    },
+    /// `gsDPFullSync`.
+    DPFullSync,
+    /// `gsDPLoadSync`.
+    DPLoadSync,
+    /// `gsDPTileSync`.
+    DPTileSync,
+    /// `gsDPPipeSync`.
+    DPPipeSync,
+    /// `gsDPLoadTLUT_pal16`.
+    DPLoadTLUT_pal16 { pal: u32, dram: u32 },
+    /// `gsDPLoadTLUT_pal256`.
+    DPLoadTLUT_pal256 { dram: u32 },
+    /// `gsDPLoadMultiBlockYuvS`.
+    DPLoadMultiBlockYuvS {
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadMultiBlockYuv`.
+    DPLoadMultiBlockYuv {
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadMultiBlock_4bS`.
+    DPLoadMultiBlock_4bS {
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadMultiBlock_4b`.
+    DPLoadMultiBlock_4b {
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadMultiBlockS`.
+    DPLoadMultiBlockS {
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadMultiBlock`.
+    DPLoadMultiBlock {
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `_gsDPLoadTextureBlockYuvS`.
+    _DPLoadTextureBlockYuvS {
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `_gsDPLoadTextureBlockYuv`.
+    _DPLoadTextureBlockYuv {
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `_gsDPLoadTextureBlock_4bS`.
+    _DPLoadTextureBlock_4bS {
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `_gsDPLoadTextureBlock_4b`.
+    _DPLoadTextureBlock_4b {
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `_gsDPLoadTextureBlockS`.
+    _DPLoadTextureBlockS {
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `_gsDPLoadTextureBlock`.
+    _DPLoadTextureBlock {
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadTextureBlockYuvS`.
+    DPLoadTextureBlockYuvS {
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadTextureBlockYuv`.
+    DPLoadTextureBlockYuv {
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadTextureBlock_4bS`.
+    DPLoadTextureBlock_4bS {
+        timg: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadTextureBlock_4b`.
+    DPLoadTextureBlock_4b {
+        timg: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadTextureBlockS`.
+    DPLoadTextureBlockS {
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadTextureBlock`.
+    DPLoadTextureBlock {
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadMultiTileYuv`.
+    DPLoadMultiTileYuv {
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadMultiTile_4b`.
+    DPLoadMultiTile_4b {
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadMultiTile`.
+    DPLoadMultiTile {
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `_gsDPLoadTextureTileYuv`.
+    _DPLoadTextureTileYuv {
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `_gsDPLoadTextureTile_4b`.
+    _DPLoadTextureTile_4b {
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `_gsDPLoadTextureTile`.
+    _DPLoadTextureTile {
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadTextureTileYuv`.
+    DPLoadTextureTileYuv {
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadTextureTile_4b`.
+    DPLoadTextureTile_4b {
+        timg: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadTextureTile`.
+    DPLoadTextureTile {
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    },
+    /// `gsDPLoadBlock`.
+    DPLoadBlock {
+        tile: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        dxt: u32,
+    },
+    /// `gsDPNoOp`.
+    DPNoOp,
+    /// `gsDPNoOpTag`.
+    DPNoOpTag { tag: u32 },
+    /// `gsDPPipelineMode`.
+    DPPipelineMode { mode: u32 },
+    /// `gsDPSetBlendColor`.
+    DPSetBlendColor { r: u32, g: u32, b: u32, a: u32 },
+    /// `gsDPSetEnvColor`.
+    DPSetEnvColor { r: u32, g: u32, b: u32, a: u32 },
+    /// `gsDPSetFillColor`.
+    DPSetFillColor { c: u32 },
+    /// `gsDPSetFogColor`.
+    DPSetFogColor { r: u32, g: u32, b: u32, a: u32 },
+    /// `gsDPSetPrimColor`.
+    DPSetPrimColor {
+        m: u32,
+        l: u32,
+        r: u32,
+        g: u32,
+        b: u32,
+        a: u32,
+    },
+    /// `gsDPSetColorImage`.
+    DPSetColorImage {
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        cimg: u32,
+    },
+    /// `gsDPSetDepthImage`.
+    DPSetDepthImage { zimg: u32 },
+    /// `gsDPSetTextureImage`.
+    DPSetTextureImage {
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        timg: u32,
+    },
+    /// `gsDPSetAlphaCompare`.
+    DPSetAlphaCompare { mode: u32 },
+    /// `gsDPSetAlphaDither`.
+    DPSetAlphaDither { mode: u32 },
+    /// `gsDPSetColorDither`.
+    DPSetColorDither { mode: u32 },
+    /// `gsDPSetCombineMode`.
+    DPSetCombineMode { mode1: u32, mode2: u32 },
+    /// `gsDPSetCombineLERP`.
+    DPSetCombineLERP {
+        a0: u32,
+        b0: u32,
+        c0: u32,
+        d0: u32,
+        Aa0: u32,
+        Ab0: u32,
+        Ac0: u32,
+        Ad0: u32,
+        a1: u32,
+        b1: u32,
+        c1: u32,
+        d1: u32,
+        Aa1: u32,
+        Ab1: u32,
+        Ac1: u32,
+        Ad1: u32,
+    },
+    /// `gsDPSetConvert`.
+    DPSetConvert {
+        k0: i32,
+        k1: i32,
+        k2: i32,
+        k3: i32,
+        k4: i32,
+        k5: i32,
+    },
+    /// `gsDPSetTextureConvert`.
+    DPSetTextureConvert { mode: u32 },
+    /// `gsDPSetCycleType`.
+    DPSetCycleType { mode: u32 },
+    /// `gsDPSetDepthSource`.
+    DPSetDepthSource { mode: u32 },
+    /// `gsDPSetCombineKey`.
+    DPSetCombineKey { mode: u32 },
+    /// `gsDPSetKeyGB`.
+    DPSetKeyGB {
+        cG: u32,
+        sG: u32,
+        wG: u32,
+        cB: u32,
+        sB: u32,
+        wB: u32,
+    },
+    /// `gsDPSetKeyR`.
+    DPSetKeyR { cR: u32, sR: u32, wR: u32 },
+    /// `gsDPSetPrimDepth`.
+    DPSetPrimDepth { z: u32, dz: u32 },
+    /// `gsDPSetRenderMode`.
+    DPSetRenderMode { mode1: u32, mode2: u32 },
+    /// `gsDPSetScissor`.
+    DPSetScissor {
+        mode: u32,
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+    },
+    /// `gsDPSetScissorFrac`.
+    DPSetScissorFrac {
+        mode: u32,
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+    },
+    /// `gsDPSetTextureDetail`.
+    DPSetTextureDetail { mode: u32 },
+    /// `gsDPSetTextureFilter`.
+    DPSetTextureFilter { mode: u32 },
+    /// `gsDPSetTextureLOD`.
+    DPSetTextureLOD { mode: u32 },
+    /// `gsDPSetTextureLUT`.
+    DPSetTextureLUT { mode: u32 },
+    /// `gsDPSetTexturePersp`.
+    DPSetTexturePersp { mode: u32 },
+    /// `gsDPSetTile`.
+    DPSetTile {
+        fmt: u32,
+        siz: u32,
+        line: u32,
+        tmem: u32,
+        tile: u32,
+        pal: u32,
+        cmt: u32,
+        maskt: u32,
+        shiftt: u32,
+        cms: u32,
+        masks: u32,
+        shifts: u32,
+    },
+    /// `gsDPSetTileSize`.
+    DPSetTileSize {
+        tile: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+    },
+    /// `gsSP1Triangle`.
+    SP1Triangle {
+        v0: u32,
+        v1: u32,
+        v2: u32,
+        flag: u32,
+    },
+    /// `gsSP2Triangles`.
+    SP2Triangles {
+        v00: u32,
+        v01: u32,
+        v02: u32,
+        flag0: u32,
+        v10: u32,
+        v11: u32,
+        v12: u32,
+        flag1: u32,
+    },
+    /// `gsSP1Quadrangle`.
+    SP1Quadrangle {
+        v0: u32,
+        v1: u32,
+        v2: u32,
+        v3: u32,
+        flag: u32,
+    },
+    /// `gsSPBranchLessZraw`.
+    SPBranchLessZraw { dl: u32, vtx: u32, zval: u32 },
+    /// `gsSPBranchList`.
+    SPBranchList { dl: u32 },
+    /// `gsSPClipRatio`.
+    SPClipRatio { r: u32 },
+    /// `gsSPCullDisplayList`.
+    SPCullDisplayList { vstart: u32, vend: u32 },
+    /// `gsSPDisplayList`.
+    SPDisplayList { dl: u32 },
+    /// `gsSPEndDisplayList`.
+    SPEndDisplayList,
+    /// `gsSPFogFactor`.
+    SPFogFactor { fm: i32, fo: i32 },
+    /// `gsSPFogPosition`.
+    SPFogPosition { min: i32, max: i32 },
+    /// `gsSPForceMatrix`.
+    SPForceMatrix { mtx: u32 },
+    /// `gsSPSetGeometryMode`.
+    SPSetGeometryMode { mode: u32 },
+    /// `gsSPClearGeometryMode`.
+    SPClearGeometryMode { mode: u32 },
+    /// `gsSPLoadGeometryMode`.
+    SPLoadGeometryMode { mode: u32 },
+    /// `gsSPInsertMatrix`.
+    SPInsertMatrix { r#where: u32, val: u32 },
+    /// `gsSPLine3D`.
+    SPLine3D { v0: u32, v1: u32, flag: u32 },
+    /// `gsSPLineW3D`.
+    SPLineW3D {
+        v0: u32,
+        v1: u32,
+        wd: u32,
+        flag: u32,
+    },
+    /// `gsSPLoadUcode`.
+    SPLoadUcode { uc_start: u32, uc_dstart: u32 },
+    /// `gsSPLookAtX`.
+    SPLookAtX { l: u32 },
+    /// `gsSPLookAtY`.
+    SPLookAtY { l: u32 },
+    /// `gsSPLookAt`.
+    SPLookAt { l: u32 },
+    /// `gsSPMatrix`.
+    SPMatrix { mtx: u32, param: u32 },
+    /// `gsSPModifyVertex`.
+    SPModifyVertex { vtx: u32, r#where: u32, val: u32 },
+    /// `gsSPPerspNormalize`.
+    SPPerspNormalize { scale: u32 },
+    /// `gsSPPopMatrix`.
+    SPPopMatrix { param: u32 },
+    /// `gsSPPopMatrixN`.
+    SPPopMatrixN { param: u32, num: u32 },
+    /// `gsSPSegment`.
+    SPSegment { seg: u32, base: u32 },
+    /// `gsSPSetLights1`.
+    SPSetLights1 { lights: u32 },
+    /// `gsSPSetLights2`.
+    SPSetLights2 { lights: u32 },
+    /// `gsSPSetLights3`.
+    SPSetLights3 { lights: u32 },
+    /// `gsSPSetLights4`.
+    SPSetLights4 { lights: u32 },
+    /// `gsSPSetLights5`.
+    SPSetLights5 { lights: u32 },
+    /// `gsSPSetLights6`.
+    SPSetLights6 { lights: u32 },
+    /// `gsSPSetLights7`.
+    SPSetLights7 { lights: u32 },
+    /// `gsSPNumLights`.
+    SPNumLights { n: u32 },
+    /// `gsSPLight`.
+    SPLight { light: u32, n: u32 },
+    /// `gsSPLightColor`.
+    SPLightColor { n: u32, color: u32 },
+    /// `gsSPTexture`.
+    SPTexture {
+        sc: u32,
+        tc: u32,
+        level: u32,
+        tile: u32,
+        on: u32,
+    },
+    /// `gsSPTextureRectangle`.
+    SPTextureRectangle {
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+        s: i32,
+        t: i32,
+        dsdx: i32,
+        dtdy: i32,
+    },
+    /// `gsSPTextureRectangleFlip`.
+    SPTextureRectangleFlip {
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+        s: i32,
+        t: i32,
+        dsdx: i32,
+        dtdy: i32,
+    },
+    /// `gsSPVertex`.
+    SPVertex { v: u32, n: u32, v0: u32 },
+    /// `gsSPViewport`.
+    SPViewport { v: u32 },
+    /// `gsDPLoadTLUTCmd`.
+    DPLoadTLUTCmd { tile: u32, count: u32 },
+    /// `gsDPLoadTLUT`.
+    DPLoadTLUT { count: u32, tmem: u32, dram: u32 },
+    /// `gsBranchZ`.
+    BranchZ { vtx: u32, zval: u32 },
+    /// `gsDisplayList`.
+    DisplayList { dl: u32, flag: u32 },
+    /// `gsDPHalf1`.
+    DPHalf1 { wordhi: u32 },
+    /// `gsDPHalf2`.
+    DPHalf2 { wordlo: u32 },
+    /// `gsDPWord`.
+    DPWord { wordhi: u32, wordlo: u32 },
+    /// `gsDPLoadTile`.
+    DPLoadTile {
+        tile: u32,
+        uls: u32,
+        ult: u32,
+        lrs: u32,
+        lrt: u32,
+    },
+    /// `gsSPGeometryMode`.
+    SPGeometryMode { clearbits: u32, setbits: u32 },
+    /// `gsSPSetOtherMode`.
+    SPSetOtherMode {
+        opc: u32,
+        sft: u32,
+        len: u32,
+        data: u32,
+    },
+    /// `gsSPSetOtherModeLo`.
+    SPSetOtherModeLo { sft: u32, len: u32, data: u32 },
+    /// `gsSPSetOtherModeHi`.
+    SPSetOtherModeHi { sft: u32, len: u32, data: u32 },
+    /// `gsDPSetOtherMode`.
+    DPSetOtherMode { hi: u32, lo: u32 },
+    /// `gsMoveWd`.
+    MoveWd { index: u32, offset: u32, data: u32 },
+    /// `gsMoveMem`.
+    MoveMem {
+        size: u32,
+        index: u32,
+        offset: u32,
+        dram: u32,
+    },
+    /// `gsSPDma_io`.
+    SPDma_io {
+        flag: u32,
+        dmem: u32,
+        dram: u32,
+        size: u32,
+    },
+    /// `gsSPDmaRead`.
+    SPDmaRead { dmem: u32, dram: u32, size: u32 },
+    /// `gsSPDmaWrite`.
+    SPDmaWrite { dmem: u32, dram: u32, size: u32 },
+    /// `gsLoadUcode`.
+    LoadUcode { uc_start: u32, uc_dsize: u32 },
+    /// `gsSPLoadUcodeEx`.
+    SPLoadUcodeEx {
+        uc_start: u32,
+        uc_dstart: u32,
+        uc_dsize: u32,
+    },
+    /// `gsTexRect`.
+    TexRect {
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+    },
+    /// `gsTexRectFlip`.
+    TexRectFlip {
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+    },
+    /// `gsSPNoOp`.
+    SPNoOp,
+    /// `gsSpecial3`.
+    Special3 { hi: u32, lo: u32 },
+    /// `gsSpecial2`.
+    Special2 { hi: u32, lo: u32 },
+    /// `gsSpecial1`.
+    Special1 { hi: u32, lo: u32 },
+}
+
+impl Command {
+    /// The id of the macro.
+    #[must_use]
+    pub fn id(&self) -> MacroId {
+        match self {
+            Command::Invalid { .. } => MacroId::gfxd_Invalid,
+            Command::DPFillRectangle { .. } => MacroId::gfxd_DPFillRectangle,
+            Command::DPFullSync => MacroId::gfxd_DPFullSync,
+            Command::DPLoadSync => MacroId::gfxd_DPLoadSync,
+            Command::DPTileSync => MacroId::gfxd_DPTileSync,
+            Command::DPPipeSync => MacroId::gfxd_DPPipeSync,
+            Command::DPLoadTLUT_pal16 { .. } => MacroId::gfxd_DPLoadTLUT_pal16,
+            Command::DPLoadTLUT_pal256 { .. } => MacroId::gfxd_DPLoadTLUT_pal256,
+            Command::DPLoadMultiBlockYuvS { .. } => MacroId::gfxd_DPLoadMultiBlockYuvS,
+            Command::DPLoadMultiBlockYuv { .. } => MacroId::gfxd_DPLoadMultiBlockYuv,
+            Command::DPLoadMultiBlock_4bS { .. } => MacroId::gfxd_DPLoadMultiBlock_4bS,
+            Command::DPLoadMultiBlock_4b { .. } => MacroId::gfxd_DPLoadMultiBlock_4b,
+            Command::DPLoadMultiBlockS { .. } => MacroId::gfxd_DPLoadMultiBlockS,
+            Command::DPLoadMultiBlock { .. } => MacroId::gfxd_DPLoadMultiBlock,
+            Command::_DPLoadTextureBlockYuvS { .. } => MacroId::gfxd__DPLoadTextureBlockYuvS,
+            Command::_DPLoadTextureBlockYuv { .. } => MacroId::gfxd__DPLoadTextureBlockYuv,
+            Command::_DPLoadTextureBlock_4bS { .. } => MacroId::gfxd__DPLoadTextureBlock_4bS,
+            Command::_DPLoadTextureBlock_4b { .. } => MacroId::gfxd__DPLoadTextureBlock_4b,
+            Command::_DPLoadTextureBlockS { .. } => MacroId::gfxd__DPLoadTextureBlockS,
+            Command::_DPLoadTextureBlock { .. } => MacroId::gfxd__DPLoadTextureBlock,
+            Command::DPLoadTextureBlockYuvS { .. } => MacroId::gfxd_DPLoadTextureBlockYuvS,
+            Command::DPLoadTextureBlockYuv { .. } => MacroId::gfxd_DPLoadTextureBlockYuv,
+            Command::DPLoadTextureBlock_4bS { .. } => MacroId::gfxd_DPLoadTextureBlock_4bS,
+            Command::DPLoadTextureBlock_4b { .. } => MacroId::gfxd_DPLoadTextureBlock_4b,
+            Command::DPLoadTextureBlockS { .. } => MacroId::gfxd_DPLoadTextureBlockS,
+            Command::DPLoadTextureBlock { .. } => MacroId::gfxd_DPLoadTextureBlock,
+            Command::DPLoadMultiTileYuv { .. } => MacroId::gfxd_DPLoadMultiTileYuv,
+            Command::DPLoadMultiTile_4b { .. } => MacroId::gfxd_DPLoadMultiTile_4b,
+            Command::DPLoadMultiTile { .. } => MacroId::gfxd_DPLoadMultiTile,
+            Command::_DPLoadTextureTileYuv { .. } => MacroId::gfxd__DPLoadTextureTileYuv,
+            Command::_DPLoadTextureTile_4b { .. } => MacroId::gfxd__DPLoadTextureTile_4b,
+            Command::_DPLoadTextureTile { .. } => MacroId::gfxd__DPLoadTextureTile,
+            Command::DPLoadTextureTileYuv { .. } => MacroId::gfxd_DPLoadTextureTileYuv,
+            Command::DPLoadTextureTile_4b { .. } => MacroId::gfxd_DPLoadTextureTile_4b,
+            Command::DPLoadTextureTile { .. } => MacroId::gfxd_DPLoadTextureTile,
+            Command::DPLoadBlock { .. } => MacroId::gfxd_DPLoadBlock,
+            Command::DPNoOp => MacroId::gfxd_DPNoOp,
+            Command::DPNoOpTag { .. } => MacroId::gfxd_DPNoOpTag,
+            Command::DPPipelineMode { .. } => MacroId::gfxd_DPPipelineMode,
+            Command::DPSetBlendColor { .. } => MacroId::gfxd_DPSetBlendColor,
+            Command::DPSetEnvColor { .. } => MacroId::gfxd_DPSetEnvColor,
+            Command::DPSetFillColor { .. } => MacroId::gfxd_DPSetFillColor,
+            Command::DPSetFogColor { .. } => MacroId::gfxd_DPSetFogColor,
+            Command::DPSetPrimColor { .. } => MacroId::gfxd_DPSetPrimColor,
+            Command::DPSetColorImage { .. } => MacroId::gfxd_DPSetColorImage,
+            Command::DPSetDepthImage { .. } => MacroId::gfxd_DPSetDepthImage,
+            Command::DPSetTextureImage { .. } => MacroId::gfxd_DPSetTextureImage,
+            Command::DPSetAlphaCompare { .. } => MacroId::gfxd_DPSetAlphaCompare,
+            Command::DPSetAlphaDither { .. } => MacroId::gfxd_DPSetAlphaDither,
+            Command::DPSetColorDither { .. } => MacroId::gfxd_DPSetColorDither,
+            Command::DPSetCombineMode { .. } => MacroId::gfxd_DPSetCombineMode,
+            Command::DPSetCombineLERP { .. } => MacroId::gfxd_DPSetCombineLERP,
+            Command::DPSetConvert { .. } => MacroId::gfxd_DPSetConvert,
+            Command::DPSetTextureConvert { .. } => MacroId::gfxd_DPSetTextureConvert,
+            Command::DPSetCycleType { .. } => MacroId::gfxd_DPSetCycleType,
+            Command::DPSetDepthSource { .. } => MacroId::gfxd_DPSetDepthSource,
+            Command::DPSetCombineKey { .. } => MacroId::gfxd_DPSetCombineKey,
+            Command::DPSetKeyGB { .. } => MacroId::gfxd_DPSetKeyGB,
+            Command::DPSetKeyR { .. } => MacroId::gfxd_DPSetKeyR,
+            Command::DPSetPrimDepth { .. } => MacroId::gfxd_DPSetPrimDepth,
+            Command::DPSetRenderMode { .. } => MacroId::gfxd_DPSetRenderMode,
+            Command::DPSetScissor { .. } => MacroId::gfxd_DPSetScissor,
+            Command::DPSetScissorFrac { .. } => MacroId::gfxd_DPSetScissorFrac,
+            Command::DPSetTextureDetail { .. } => MacroId::gfxd_DPSetTextureDetail,
+            Command::DPSetTextureFilter { .. } => MacroId::gfxd_DPSetTextureFilter,
+            Command::DPSetTextureLOD { .. } => MacroId::gfxd_DPSetTextureLOD,
+            Command::DPSetTextureLUT { .. } => MacroId::gfxd_DPSetTextureLUT,
+            Command::DPSetTexturePersp { .. } => MacroId::gfxd_DPSetTexturePersp,
+            Command::DPSetTile { .. } => MacroId::gfxd_DPSetTile,
+            Command::DPSetTileSize { .. } => MacroId::gfxd_DPSetTileSize,
+            Command::SP1Triangle { .. } => MacroId::gfxd_SP1Triangle,
+            Command::SP2Triangles { .. } => MacroId::gfxd_SP2Triangles,
+            Command::SP1Quadrangle { .. } => MacroId::gfxd_SP1Quadrangle,
+            Command::SPBranchLessZraw { .. } => MacroId::gfxd_SPBranchLessZraw,
+            Command::SPBranchList { .. } => MacroId::gfxd_SPBranchList,
+            Command::SPClipRatio { .. } => MacroId::gfxd_SPClipRatio,
+            Command::SPCullDisplayList { .. } => MacroId::gfxd_SPCullDisplayList,
+            Command::SPDisplayList { .. } => MacroId::gfxd_SPDisplayList,
+            Command::SPEndDisplayList => MacroId::gfxd_SPEndDisplayList,
+            Command::SPFogFactor { .. } => MacroId::gfxd_SPFogFactor,
+            Command::SPFogPosition { .. } => MacroId::gfxd_SPFogPosition,
+            Command::SPForceMatrix { .. } => MacroId::gfxd_SPForceMatrix,
+            Command::SPSetGeometryMode { .. } => MacroId::gfxd_SPSetGeometryMode,
+            Command::SPClearGeometryMode { .. } => MacroId::gfxd_SPClearGeometryMode,
+            Command::SPLoadGeometryMode { .. } => MacroId::gfxd_SPLoadGeometryMode,
+            Command::SPInsertMatrix { .. } => MacroId::gfxd_SPInsertMatrix,
+            Command::SPLine3D { .. } => MacroId::gfxd_SPLine3D,
+            Command::SPLineW3D { .. } => MacroId::gfxd_SPLineW3D,
+            Command::SPLoadUcode { .. } => MacroId::gfxd_SPLoadUcode,
+            Command::SPLookAtX { .. } => MacroId::gfxd_SPLookAtX,
+            Command::SPLookAtY { .. } => MacroId::gfxd_SPLookAtY,
+            Command::SPLookAt { .. } => MacroId::gfxd_SPLookAt,
+            Command::SPMatrix { .. } => MacroId::gfxd_SPMatrix,
+            Command::SPModifyVertex { .. } => MacroId::gfxd_SPModifyVertex,
+            Command::SPPerspNormalize { .. } => MacroId::gfxd_SPPerspNormalize,
+            Command::SPPopMatrix { .. } => MacroId::gfxd_SPPopMatrix,
+            Command::SPPopMatrixN { .. } => MacroId::gfxd_SPPopMatrixN,
+            Command::SPSegment { .. } => MacroId::gfxd_SPSegment,
+            Command::SPSetLights1 { .. } => MacroId::gfxd_SPSetLights1,
+            Command::SPSetLights2 { .. } => MacroId::gfxd_SPSetLights2,
+            Command::SPSetLights3 { .. } => MacroId::gfxd_SPSetLights3,
+            Command::SPSetLights4 { .. } => MacroId::gfxd_SPSetLights4,
+            Command::SPSetLights5 { .. } => MacroId::gfxd_SPSetLights5,
+            Command::SPSetLights6 { .. } => MacroId::gfxd_SPSetLights6,
+            Command::SPSetLights7 { .. } => MacroId::gfxd_SPSetLights7,
+            Command::SPNumLights { .. } => MacroId::gfxd_SPNumLights,
+            Command::SPLight { .. } => MacroId::gfxd_SPLight,
+            Command::SPLightColor { .. } => MacroId::gfxd_SPLightColor,
+            Command::SPTexture { .. } => MacroId::gfxd_SPTexture,
+            Command::SPTextureRectangle { .. } => MacroId::gfxd_SPTextureRectangle,
+            Command::SPTextureRectangleFlip { .. } => MacroId::gfxd_SPTextureRectangleFlip,
+            Command::SPVertex { .. } => MacroId::gfxd_SPVertex,
+            Command::SPViewport { .. } => MacroId::gfxd_SPViewport,
+            Command::DPLoadTLUTCmd { .. } => MacroId::gfxd_DPLoadTLUTCmd,
+            Command::DPLoadTLUT { .. } => MacroId::gfxd_DPLoadTLUT,
+            Command::BranchZ { .. } => MacroId::gfxd_BranchZ,
+            Command::DisplayList { .. } => MacroId::gfxd_DisplayList,
+            Command::DPHalf1 { .. } => MacroId::gfxd_DPHalf1,
+            Command::DPHalf2 { .. } => MacroId::gfxd_DPHalf2,
+            Command::DPWord { .. } => MacroId::gfxd_DPWord,
+            Command::DPLoadTile { .. } => MacroId::gfxd_DPLoadTile,
+            Command::SPGeometryMode { .. } => MacroId::gfxd_SPGeometryMode,
+            Command::SPSetOtherMode { .. } => MacroId::gfxd_SPSetOtherMode,
+            Command::SPSetOtherModeLo { .. } => MacroId::gfxd_SPSetOtherModeLo,
+            Command::SPSetOtherModeHi { .. } => MacroId::gfxd_SPSetOtherModeHi,
+            Command::DPSetOtherMode { .. } => MacroId::gfxd_DPSetOtherMode,
+            Command::MoveWd { .. } => MacroId::gfxd_MoveWd,
+            Command::MoveMem { .. } => MacroId::gfxd_MoveMem,
+            Command::SPDma_io { .. } => MacroId::gfxd_SPDma_io,
+            Command::SPDmaRead { .. } => MacroId::gfxd_SPDmaRead,
+            Command::SPDmaWrite { .. } => MacroId::gfxd_SPDmaWrite,
+            Command::LoadUcode { .. } => MacroId::gfxd_LoadUcode,
+            Command::SPLoadUcodeEx { .. } => MacroId::gfxd_SPLoadUcodeEx,
+            Command::TexRect { .. } => MacroId::gfxd_TexRect,
+            Command::TexRectFlip { .. } => MacroId::gfxd_TexRectFlip,
+            Command::SPNoOp => MacroId::gfxd_SPNoOp,
+            Command::Special3 { .. } => MacroId::gfxd_Special3,
+            Command::Special2 { .. } => MacroId::gfxd_Special2,
+            Command::Special1 { .. } => MacroId::gfxd_Special1,
+        }
+    }
+
+    /// Builds the command `id` from the raw value of each of its arguments,
+    /// in the order listed by [`MacroId::args`].
+    ///
+    /// [`MacroId::gfxd_Invalid`] takes the high and the low word of the
+    /// packet. Returns [`None`] if `args` has the wrong length.
+    #[must_use]
+    pub fn from_args(id: MacroId, args: &[u32]) -> Option<Self> {
+        let expected = match id {
+            MacroId::gfxd_Invalid => 2,
+            _ => id.args().len(),
+        };
+        if args.len() != expected {
+            return None;
+        }
+        let a = args;
+
+        Some(match id {
+            MacroId::gfxd_Invalid => Command::Invalid { hi: a[0], lo: a[1] },
+            MacroId::gfxd_DPFillRectangle => Command::DPFillRectangle {
+                ulx: a[0],
+                uly: a[1],
+                lrx: a[2],
+                lry: a[3],
+            },
+            MacroId::gfxd_DPFullSync => Command::DPFullSync,
+            MacroId::gfxd_DPLoadSync => Command::DPLoadSync,
+            MacroId::gfxd_DPTileSync => Command::DPTileSync,
+            MacroId::gfxd_DPPipeSync => Command::DPPipeSync,
+            MacroId::gfxd_DPLoadTLUT_pal16 => Command::DPLoadTLUT_pal16 {
+                pal: a[0],
+                dram: a[1],
+            },
+            MacroId::gfxd_DPLoadTLUT_pal256 => Command::DPLoadTLUT_pal256 { dram: a[0] },
+            MacroId::gfxd_DPLoadMultiBlockYuvS => Command::DPLoadMultiBlockYuvS {
+                timg: a[0],
+                tmem: a[1],
+                rtile: a[2],
+                fmt: a[3],
+                siz: a[4],
+                width: a[5],
+                height: a[6],
+                pal: a[7],
+                cms: a[8],
+                cmt: a[9],
+                masks: a[10],
+                maskt: a[11],
+                shifts: a[12],
+                shiftt: a[13],
+            },
+            MacroId::gfxd_DPLoadMultiBlockYuv => Command::DPLoadMultiBlockYuv {
+                timg: a[0],
+                tmem: a[1],
+                rtile: a[2],
+                fmt: a[3],
+                siz: a[4],
+                width: a[5],
+                height: a[6],
+                pal: a[7],
+                cms: a[8],
+                cmt: a[9],
+                masks: a[10],
+                maskt: a[11],
+                shifts: a[12],
+                shiftt: a[13],
+            },
+            MacroId::gfxd_DPLoadMultiBlock_4bS => Command::DPLoadMultiBlock_4bS {
+                timg: a[0],
+                tmem: a[1],
+                rtile: a[2],
+                fmt: a[3],
+                width: a[4],
+                height: a[5],
+                pal: a[6],
+                cms: a[7],
+                cmt: a[8],
+                masks: a[9],
+                maskt: a[10],
+                shifts: a[11],
+                shiftt: a[12],
+            },
+            MacroId::gfxd_DPLoadMultiBlock_4b => Command::DPLoadMultiBlock_4b {
+                timg: a[0],
+                tmem: a[1],
+                rtile: a[2],
+                fmt: a[3],
+                width: a[4],
+                height: a[5],
+                pal: a[6],
+                cms: a[7],
+                cmt: a[8],
+                masks: a[9],
+                maskt: a[10],
+                shifts: a[11],
+                shiftt: a[12],
+            },
+            MacroId::gfxd_DPLoadMultiBlockS => Command::DPLoadMultiBlockS {
+                timg: a[0],
+                tmem: a[1],
+                rtile: a[2],
+                fmt: a[3],
+                siz: a[4],
+                width: a[5],
+                height: a[6],
+                pal: a[7],
+                cms: a[8],
+                cmt: a[9],
+                masks: a[10],
+                maskt: a[11],
+                shifts: a[12],
+                shiftt: a[13],
+            },
+            MacroId::gfxd_DPLoadMultiBlock => Command::DPLoadMultiBlock {
+                timg: a[0],
+                tmem: a[1],
+                rtile: a[2],
+                fmt: a[3],
+                siz: a[4],
+                width: a[5],
+                height: a[6],
+                pal: a[7],
+                cms: a[8],
+                cmt: a[9],
+                masks: a[10],
+                maskt: a[11],
+                shifts: a[12],
+                shiftt: a[13],
+            },
+            MacroId::gfxd__DPLoadTextureBlockYuvS => Command::_DPLoadTextureBlockYuvS {
+                timg: a[0],
+                tmem: a[1],
+                fmt: a[2],
+                siz: a[3],
+                width: a[4],
+                height: a[5],
+                pal: a[6],
+                cms: a[7],
+                cmt: a[8],
+                masks: a[9],
+                maskt: a[10],
+                shifts: a[11],
+                shiftt: a[12],
+            },
+            MacroId::gfxd__DPLoadTextureBlockYuv => Command::_DPLoadTextureBlockYuv {
+                timg: a[0],
+                tmem: a[1],
+                fmt: a[2],
+                siz: a[3],
+                width: a[4],
+                height: a[5],
+                pal: a[6],
+                cms: a[7],
+                cmt: a[8],
+                masks: a[9],
+                maskt: a[10],
+                shifts: a[11],
+                shiftt: a[12],
+            },
+            MacroId::gfxd__DPLoadTextureBlock_4bS => Command::_DPLoadTextureBlock_4bS {
+                timg: a[0],
+                tmem: a[1],
+                fmt: a[2],
+                width: a[3],
+                height: a[4],
+                pal: a[5],
+                cms: a[6],
+                cmt: a[7],
+                masks: a[8],
+                maskt: a[9],
+                shifts: a[10],
+                shiftt: a[11],
+            },
+            MacroId::gfxd__DPLoadTextureBlock_4b => Command::_DPLoadTextureBlock_4b {
+                timg: a[0],
+                tmem: a[1],
+                fmt: a[2],
+                width: a[3],
+                height: a[4],
+                pal: a[5],
+                cms: a[6],
+                cmt: a[7],
+                masks: a[8],
+                maskt: a[9],
+                shifts: a[10],
+                shiftt: a[11],
+            },
+            MacroId::gfxd__DPLoadTextureBlockS => Command::_DPLoadTextureBlockS {
+                timg: a[0],
+                tmem: a[1],
+                fmt: a[2],
+                siz: a[3],
+                width: a[4],
+                height: a[5],
+                pal: a[6],
+                cms: a[7],
+                cmt: a[8],
+                masks: a[9],
+                maskt: a[10],
+                shifts: a[11],
+                shiftt: a[12],
+            },
+            MacroId::gfxd__DPLoadTextureBlock => Command::_DPLoadTextureBlock {
+                timg: a[0],
+                tmem: a[1],
+                fmt: a[2],
+                siz: a[3],
+                width: a[4],
+                height: a[5],
+                pal: a[6],
+                cms: a[7],
+                cmt: a[8],
+                masks: a[9],
+                maskt: a[10],
+                shifts: a[11],
+                shiftt: a[12],
+            },
+            MacroId::gfxd_DPLoadTextureBlockYuvS => Command::DPLoadTextureBlockYuvS {
+                timg: a[0],
+                fmt: a[1],
+                siz: a[2],
+                width: a[3],
+                height: a[4],
+                pal: a[5],
+                cms: a[6],
+                cmt: a[7],
+                masks: a[8],
+                maskt: a[9],
+                shifts: a[10],
+                shiftt: a[11],
+            },
+            MacroId::gfxd_DPLoadTextureBlockYuv => Command::DPLoadTextureBlockYuv {
+                timg: a[0],
+                fmt: a[1],
+                siz: a[2],
+                width: a[3],
+                height: a[4],
+                pal: a[5],
+                cms: a[6],
+                cmt: a[7],
+                masks: a[8],
+                maskt: a[9],
+                shifts: a[10],
+                shiftt: a[11],
+            },
+            MacroId::gfxd_DPLoadTextureBlock_4bS => Command::DPLoadTextureBlock_4bS {
+                timg: a[0],
+                fmt: a[1],
+                width: a[2],
+                height: a[3],
+                pal: a[4],
+                cms: a[5],
+                cmt: a[6],
+                masks: a[7],
+                maskt: a[8],
+                shifts: a[9],
+                shiftt: a[10],
+            },
+            MacroId::gfxd_DPLoadTextureBlock_4b => Command::DPLoadTextureBlock_4b {
+                timg: a[0],
+                fmt: a[1],
+                width: a[2],
+                height: a[3],
+                pal: a[4],
+                cms: a[5],
+                cmt: a[6],
+                masks: a[7],
+                maskt: a[8],
+                shifts: a[9],
+                shiftt: a[10],
+            },
+            MacroId::gfxd_DPLoadTextureBlockS => Command::DPLoadTextureBlockS {
+                timg: a[0],
+                fmt: a[1],
+                siz: a[2],
+                width: a[3],
+                height: a[4],
+                pal: a[5],
+                cms: a[6],
+                cmt: a[7],
+                masks: a[8],
+                maskt: a[9],
+                shifts: a[10],
+                shiftt: a[11],
+            },
+            MacroId::gfxd_DPLoadTextureBlock => Command::DPLoadTextureBlock {
+                timg: a[0],
+                fmt: a[1],
+                siz: a[2],
+                width: a[3],
+                height: a[4],
+                pal: a[5],
+                cms: a[6],
+                cmt: a[7],
+                masks: a[8],
+                maskt: a[9],
+                shifts: a[10],
+                shiftt: a[11],
+            },
+            MacroId::gfxd_DPLoadMultiTileYuv => Command::DPLoadMultiTileYuv {
+                timg: a[0],
+                tmem: a[1],
+                rtile: a[2],
+                fmt: a[3],
+                siz: a[4],
+                width: a[5],
+                height: a[6],
+                uls: a[7],
+                ult: a[8],
+                lrs: a[9],
+                lrt: a[10],
+                pal: a[11],
+                cms: a[12],
+                cmt: a[13],
+                masks: a[14],
+                maskt: a[15],
+                shifts: a[16],
+                shiftt: a[17],
+            },
+            MacroId::gfxd_DPLoadMultiTile_4b => Command::DPLoadMultiTile_4b {
+                timg: a[0],
+                tmem: a[1],
+                rtile: a[2],
+                fmt: a[3],
+                width: a[4],
+                height: a[5],
+                uls: a[6],
+                ult: a[7],
+                lrs: a[8],
+                lrt: a[9],
+                pal: a[10],
+                cms: a[11],
+                cmt: a[12],
+                masks: a[13],
+                maskt: a[14],
+                shifts: a[15],
+                shiftt: a[16],
+            },
+            MacroId::gfxd_DPLoadMultiTile => Command::DPLoadMultiTile {
+                timg: a[0],
+                tmem: a[1],
+                rtile: a[2],
+                fmt: a[3],
+                siz: a[4],
+                width: a[5],
+                height: a[6],
+                uls: a[7],
+                ult: a[8],
+                lrs: a[9],
+                lrt: a[10],
+                pal: a[11],
+                cms: a[12],
+                cmt: a[13],
+                masks: a[14],
+                maskt: a[15],
+                shifts: a[16],
+                shiftt: a[17],
+            },
+            MacroId::gfxd__DPLoadTextureTileYuv => Command::_DPLoadTextureTileYuv {
+                timg: a[0],
+                tmem: a[1],
+                fmt: a[2],
+                siz: a[3],
+                width: a[4],
+                height: a[5],
+                uls: a[6],
+                ult: a[7],
+                lrs: a[8],
+                lrt: a[9],
+                pal: a[10],
+                cms: a[11],
+                cmt: a[12],
+                masks: a[13],
+                maskt: a[14],
+                shifts: a[15],
+                shiftt: a[16],
+            },
+            MacroId::gfxd__DPLoadTextureTile_4b => Command::_DPLoadTextureTile_4b {
+                timg: a[0],
+                tmem: a[1],
+                fmt: a[2],
+                width: a[3],
+                height: a[4],
+                uls: a[5],
+                ult: a[6],
+                lrs: a[7],
+                lrt: a[8],
+                pal: a[9],
+                cms: a[10],
+                cmt: a[11],
+                masks: a[12],
+                maskt: a[13],
+                shifts: a[14],
+                shiftt: a[15],
+            },
+            MacroId::gfxd__DPLoadTextureTile => Command::_DPLoadTextureTile {
+                timg: a[0],
+                tmem: a[1],
+                fmt: a[2],
+                siz: a[3],
+                width: a[4],
+                height: a[5],
+                uls: a[6],
+                ult: a[7],
+                lrs: a[8],
+                lrt: a[9],
+                pal: a[10],
+                cms: a[11],
+                cmt: a[12],
+                masks: a[13],
+                maskt: a[14],
+                shifts: a[15],
+                shiftt: a[16],
+            },
+            MacroId::gfxd_DPLoadTextureTileYuv => Command::DPLoadTextureTileYuv {
+                timg: a[0],
+                fmt: a[1],
+                siz: a[2],
+                width: a[3],
+                height: a[4],
+                uls: a[5],
+                ult: a[6],
+                lrs: a[7],
+                lrt: a[8],
+                pal: a[9],
+                cms: a[10],
+                cmt: a[11],
+                masks: a[12],
+                maskt: a[13],
+                shifts: a[14],
+                shiftt: a[15],
+            },
+            MacroId::gfxd_DPLoadTextureTile_4b => Command::DPLoadTextureTile_4b {
+                timg: a[0],
+                fmt: a[1],
+                width: a[2],
+                height: a[3],
+                uls: a[4],
+                ult: a[5],
+                lrs: a[6],
+                lrt: a[7],
+                pal: a[8],
+                cms: a[9],
+                cmt: a[10],
+                masks: a[11],
+                maskt: a[12],
+                shifts: a[13],
+                shiftt: a[14],
+            },
+            MacroId::gfxd_DPLoadTextureTile => Command::DPLoadTextureTile {
+                timg: a[0],
+                fmt: a[1],
+                siz: a[2],
+                width: a[3],
+                height: a[4],
+                uls: a[5],
+                ult: a[6],
+                lrs: a[7],
+                lrt: a[8],
+                pal: a[9],
+                cms: a[10],
+                cmt: a[11],
+                masks: a[12],
+                maskt: a[13],
+                shifts: a[14],
+                shiftt: a[15],
+            },
+            MacroId::gfxd_DPLoadBlock => Command::DPLoadBlock {
+                tile: a[0],
+                uls: a[1],
+                ult: a[2],
+                lrs: a[3],
+                dxt: a[4],
+            },
+            MacroId::gfxd_DPNoOp => Command::DPNoOp,
+            MacroId::gfxd_DPNoOpTag => Command::DPNoOpTag { tag: a[0] },
+            MacroId::gfxd_DPPipelineMode => Command::DPPipelineMode { mode: a[0] },
+            MacroId::gfxd_DPSetBlendColor => Command::DPSetBlendColor {
+                r: a[0],
+                g: a[1],
+                b: a[2],
+                a: a[3],
+            },
+            MacroId::gfxd_DPSetEnvColor => Command::DPSetEnvColor {
+                r: a[0],
+                g: a[1],
+                b: a[2],
+                a: a[3],
+            },
+            MacroId::gfxd_DPSetFillColor => Command::DPSetFillColor { c: a[0] },
+            MacroId::gfxd_DPSetFogColor => Command::DPSetFogColor {
+                r: a[0],
+                g: a[1],
+                b: a[2],
+                a: a[3],
+            },
+            MacroId::gfxd_DPSetPrimColor => Command::DPSetPrimColor {
+                m: a[0],
+                l: a[1],
+                r: a[2],
+                g: a[3],
+                b: a[4],
+                a: a[5],
+            },
+            MacroId::gfxd_DPSetColorImage => Command::DPSetColorImage {
+                fmt: a[0],
+                siz: a[1],
+                width: a[2],
+                cimg: a[3],
+            },
+            MacroId::gfxd_DPSetDepthImage => Command::DPSetDepthImage { zimg: a[0] },
+            MacroId::gfxd_DPSetTextureImage => Command::DPSetTextureImage {
+                fmt: a[0],
+                siz: a[1],
+                width: a[2],
+                timg: a[3],
+            },
+            MacroId::gfxd_DPSetAlphaCompare => Command::DPSetAlphaCompare { mode: a[0] },
+            MacroId::gfxd_DPSetAlphaDither => Command::DPSetAlphaDither { mode: a[0] },
+            MacroId::gfxd_DPSetColorDither => Command::DPSetColorDither { mode: a[0] },
+            MacroId::gfxd_DPSetCombineMode => Command::DPSetCombineMode {
+                mode1: a[0],
+                mode2: a[1],
+            },
+            MacroId::gfxd_DPSetCombineLERP => Command::DPSetCombineLERP {
+                a0: a[0],
+                b0: a[1],
+                c0: a[2],
+                d0: a[3],
+                Aa0: a[4],
+                Ab0: a[5],
+                Ac0: a[6],
+                Ad0: a[7],
+                a1: a[8],
+                b1: a[9],
+                c1: a[10],
+                d1: a[11],
+                Aa1: a[12],
+                Ab1: a[13],
+                Ac1: a[14],
+                Ad1: a[15],
+            },
+            MacroId::gfxd_DPSetConvert => Command::DPSetConvert {
+                k0: a[0] as i32,
+                k1: a[1] as i32,
+                k2: a[2] as i32,
+                k3: a[3] as i32,
+                k4: a[4] as i32,
+                k5: a[5] as i32,
+            },
+            MacroId::gfxd_DPSetTextureConvert => Command::DPSetTextureConvert { mode: a[0] },
+            MacroId::gfxd_DPSetCycleType => Command::DPSetCycleType { mode: a[0] },
+            MacroId::gfxd_DPSetDepthSource => Command::DPSetDepthSource { mode: a[0] },
+            MacroId::gfxd_DPSetCombineKey => Command::DPSetCombineKey { mode: a[0] },
+            MacroId::gfxd_DPSetKeyGB => Command::DPSetKeyGB {
+                cG: a[0],
+                sG: a[1],
+                wG: a[2],
+                cB: a[3],
+                sB: a[4],
+                wB: a[5],
+            },
+            MacroId::gfxd_DPSetKeyR => Command::DPSetKeyR {
+                cR: a[0],
+                sR: a[1],
+                wR: a[2],
+            },
+            MacroId::gfxd_DPSetPrimDepth => Command::DPSetPrimDepth { z: a[0], dz: a[1] },
+            MacroId::gfxd_DPSetRenderMode => Command::DPSetRenderMode {
+                mode1: a[0],
+                mode2: a[1],
+            },
+            MacroId::gfxd_DPSetScissor => Command::DPSetScissor {
+                mode: a[0],
+                ulx: a[1],
+                uly: a[2],
+                lrx: a[3],
+                lry: a[4],
+            },
+            MacroId::gfxd_DPSetScissorFrac => Command::DPSetScissorFrac {
+                mode: a[0],
+                ulx: a[1],
+                uly: a[2],
+                lrx: a[3],
+                lry: a[4],
+            },
+            MacroId::gfxd_DPSetTextureDetail => Command::DPSetTextureDetail { mode: a[0] },
+            MacroId::gfxd_DPSetTextureFilter => Command::DPSetTextureFilter { mode: a[0] },
+            MacroId::gfxd_DPSetTextureLOD => Command::DPSetTextureLOD { mode: a[0] },
+            MacroId::gfxd_DPSetTextureLUT => Command::DPSetTextureLUT { mode: a[0] },
+            MacroId::gfxd_DPSetTexturePersp => Command::DPSetTexturePersp { mode: a[0] },
+            MacroId::gfxd_DPSetTile => Command::DPSetTile {
+                fmt: a[0],
+                siz: a[1],
+                line: a[2],
+                tmem: a[3],
+                tile: a[4],
+                pal: a[5],
+                cmt: a[6],
+                maskt: a[7],
+                shiftt: a[8],
+                cms: a[9],
+                masks: a[10],
+                shifts: a[11],
+            },
+            MacroId::gfxd_DPSetTileSize => Command::DPSetTileSize {
+                tile: a[0],
+                uls: a[1],
+                ult: a[2],
+                lrs: a[3],
+                lrt: a[4],
+            },
+            MacroId::gfxd_SP1Triangle => Command::SP1Triangle {
+                v0: a[0],
+                v1: a[1],
+                v2: a[2],
+                flag: a[3],
+            },
+            MacroId::gfxd_SP2Triangles => Command::SP2Triangles {
+                v00: a[0],
+                v01: a[1],
+                v02: a[2],
+                flag0: a[3],
+                v10: a[4],
+                v11: a[5],
+                v12: a[6],
+                flag1: a[7],
+            },
+            MacroId::gfxd_SP1Quadrangle => Command::SP1Quadrangle {
+                v0: a[0],
+                v1: a[1],
+                v2: a[2],
+                v3: a[3],
+                flag: a[4],
+            },
+            MacroId::gfxd_SPBranchLessZraw => Command::SPBranchLessZraw {
+                dl: a[0],
+                vtx: a[1],
+                zval: a[2],
+            },
+            MacroId::gfxd_SPBranchList => Command::SPBranchList { dl: a[0] },
+            MacroId::gfxd_SPClipRatio => Command::SPClipRatio { r: a[0] },
+            MacroId::gfxd_SPCullDisplayList => Command::SPCullDisplayList {
+                vstart: a[0],
+                vend: a[1],
+            },
+            MacroId::gfxd_SPDisplayList => Command::SPDisplayList { dl: a[0] },
+            MacroId::gfxd_SPEndDisplayList => Command::SPEndDisplayList,
+            MacroId::gfxd_SPFogFactor => Command::SPFogFactor {
+                fm: a[0] as i32,
+                fo: a[1] as i32,
+            },
+            MacroId::gfxd_SPFogPosition => Command::SPFogPosition {
+                min: a[0] as i32,
+                max: a[1] as i32,
+            },
+            MacroId::gfxd_SPForceMatrix => Command::SPForceMatrix { mtx: a[0] },
+            MacroId::gfxd_SPSetGeometryMode => Command::SPSetGeometryMode { mode: a[0] },
+            MacroId::gfxd_SPClearGeometryMode => Command::SPClearGeometryMode { mode: a[0] },
+            MacroId::gfxd_SPLoadGeometryMode => Command::SPLoadGeometryMode { mode: a[0] },
+            MacroId::gfxd_SPInsertMatrix => Command::SPInsertMatrix {
+                r#where: a[0],
+                val: a[1],
+            },
+            MacroId::gfxd_SPLine3D => Command::SPLine3D {
+                v0: a[0],
+                v1: a[1],
+                flag: a[2],
+            },
+            MacroId::gfxd_SPLineW3D => Command::SPLineW3D {
+                v0: a[0],
+                v1: a[1],
+                wd: a[2],
+                flag: a[3],
+            },
+            MacroId::gfxd_SPLoadUcode => Command::SPLoadUcode {
+                uc_start: a[0],
+                uc_dstart: a[1],
+            },
+            MacroId::gfxd_SPLookAtX => Command::SPLookAtX { l: a[0] },
+            MacroId::gfxd_SPLookAtY => Command::SPLookAtY { l: a[0] },
+            MacroId::gfxd_SPLookAt => Command::SPLookAt { l: a[0] },
+            MacroId::gfxd_SPMatrix => Command::SPMatrix {
+                mtx: a[0],
+                param: a[1],
+            },
+            MacroId::gfxd_SPModifyVertex => Command::SPModifyVertex {
+                vtx: a[0],
+                r#where: a[1],
+                val: a[2],
+            },
+            MacroId::gfxd_SPPerspNormalize => Command::SPPerspNormalize { scale: a[0] },
+            MacroId::gfxd_SPPopMatrix => Command::SPPopMatrix { param: a[0] },
+            MacroId::gfxd_SPPopMatrixN => Command::SPPopMatrixN {
+                param: a[0],
+                num: a[1],
+            },
+            MacroId::gfxd_SPSegment => Command::SPSegment {
+                seg: a[0],
+                base: a[1],
+            },
+            MacroId::gfxd_SPSetLights1 => Command::SPSetLights1 { lights: a[0] },
+            MacroId::gfxd_SPSetLights2 => Command::SPSetLights2 { lights: a[0] },
+            MacroId::gfxd_SPSetLights3 => Command::SPSetLights3 { lights: a[0] },
+            MacroId::gfxd_SPSetLights4 => Command::SPSetLights4 { lights: a[0] },
+            MacroId::gfxd_SPSetLights5 => Command::SPSetLights5 { lights: a[0] },
+            MacroId::gfxd_SPSetLights6 => Command::SPSetLights6 { lights: a[0] },
+            MacroId::gfxd_SPSetLights7 => Command::SPSetLights7 { lights: a[0] },
+            MacroId::gfxd_SPNumLights => Command::SPNumLights { n: a[0] },
+            MacroId::gfxd_SPLight => Command::SPLight {
+                light: a[0],
+                n: a[1],
+            },
+            MacroId::gfxd_SPLightColor => Command::SPLightColor {
+                n: a[0],
+                color: a[1],
+            },
+            MacroId::gfxd_SPTexture => Command::SPTexture {
+                sc: a[0],
+                tc: a[1],
+                level: a[2],
+                tile: a[3],
+                on: a[4],
+            },
+            MacroId::gfxd_SPTextureRectangle => Command::SPTextureRectangle {
+                ulx: a[0],
+                uly: a[1],
+                lrx: a[2],
+                lry: a[3],
+                tile: a[4],
+                s: a[5] as i32,
+                t: a[6] as i32,
+                dsdx: a[7] as i32,
+                dtdy: a[8] as i32,
+            },
+            MacroId::gfxd_SPTextureRectangleFlip => Command::SPTextureRectangleFlip {
+                ulx: a[0],
+                uly: a[1],
+                lrx: a[2],
+                lry: a[3],
+                tile: a[4],
+                s: a[5] as i32,
+                t: a[6] as i32,
+                dsdx: a[7] as i32,
+                dtdy: a[8] as i32,
+            },
+            MacroId::gfxd_SPVertex => Command::SPVertex {
+                v: a[0],
+                n: a[1],
+                v0: a[2],
+            },
+            MacroId::gfxd_SPViewport => Command::SPViewport { v: a[0] },
+            MacroId::gfxd_DPLoadTLUTCmd => Command::DPLoadTLUTCmd {
+                tile: a[0],
+                count: a[1],
+            },
+            MacroId::gfxd_DPLoadTLUT => Command::DPLoadTLUT {
+                count: a[0],
+                tmem: a[1],
+                dram: a[2],
+            },
+            MacroId::gfxd_BranchZ => Command::BranchZ {
+                vtx: a[0],
+                zval: a[1],
+            },
+            MacroId::gfxd_DisplayList => Command::DisplayList {
+                dl: a[0],
+                flag: a[1],
+            },
+            MacroId::gfxd_DPHalf1 => Command::DPHalf1 { wordhi: a[0] },
+            MacroId::gfxd_DPHalf2 => Command::DPHalf2 { wordlo: a[0] },
+            MacroId::gfxd_DPWord => Command::DPWord {
+                wordhi: a[0],
+                wordlo: a[1],
+            },
+            MacroId::gfxd_DPLoadTile => Command::DPLoadTile {
+                tile: a[0],
+                uls: a[1],
+                ult: a[2],
+                lrs: a[3],
+                lrt: a[4],
+            },
+            MacroId::gfxd_SPGeometryMode => Command::SPGeometryMode {
+                clearbits: a[0],
+                setbits: a[1],
+            },
+            MacroId::gfxd_SPSetOtherMode => Command::SPSetOtherMode {
+                opc: a[0],
+                sft: a[1],
+                len: a[2],
+                data: a[3],
+            },
+            MacroId::gfxd_SPSetOtherModeLo => Command::SPSetOtherModeLo {
+                sft: a[0],
+                len: a[1],
+                data: a[2],
+            },
+            MacroId::gfxd_SPSetOtherModeHi => Command::SPSetOtherModeHi {
+                sft: a[0],
+                len: a[1],
+                data: a[2],
+            },
+            MacroId::gfxd_DPSetOtherMode => Command::DPSetOtherMode { hi: a[0], lo: a[1] },
+            MacroId::gfxd_MoveWd => Command::MoveWd {
+                index: a[0],
+                offset: a[1],
+                data: a[2],
+            },
+            MacroId::gfxd_MoveMem => Command::MoveMem {
+                size: a[0],
+                index: a[1],
+                offset: a[2],
+                dram: a[3],
+            },
+            MacroId::gfxd_SPDma_io => Command::SPDma_io {
+                flag: a[0],
+                dmem: a[1],
+                dram: a[2],
+                size: a[3],
+            },
+            MacroId::gfxd_SPDmaRead => Command::SPDmaRead {
+                dmem: a[0],
+                dram: a[1],
+                size: a[2],
+            },
+            MacroId::gfxd_SPDmaWrite => Command::SPDmaWrite {
+                dmem: a[0],
+                dram: a[1],
+                size: a[2],
+            },
+            MacroId::gfxd_LoadUcode => Command::LoadUcode {
+                uc_start: a[0],
+                uc_dsize: a[1],
+            },
+            MacroId::gfxd_SPLoadUcodeEx => Command::SPLoadUcodeEx {
+                uc_start: a[0],
+                uc_dstart: a[1],
+                uc_dsize: a[2],
+            },
+            MacroId::gfxd_TexRect => Command::TexRect {
+                ulx: a[0],
+                uly: a[1],
+                lrx: a[2],
+                lry: a[3],
+                tile: a[4],
+            },
+            MacroId::gfxd_TexRectFlip => Command::TexRectFlip {
+                ulx: a[0],
+                uly: a[1],
+                lrx: a[2],
+                lry: a[3],
+                tile: a[4],
+            },
+            MacroId::gfxd_SPNoOp => Command::SPNoOp,
+            MacroId::gfxd_Special3 => Command::Special3 { hi: a[0], lo: a[1] },
+            MacroId::gfxd_Special2 => Command::Special2 { hi: a[0], lo: a[1] },
+            MacroId::gfxd_Special1 => Command::Special1 { hi: a[0], lo: a[1] },
+        })
+    }
+
+    /// The raw value of each argument, in the order listed by
+    /// [`MacroId::args`], the inverse of [`Command::from_args`].
+    #[must_use]
+    pub fn args(&self) -> Args {
+        match *self {
+            Command::Invalid { hi, lo } => Args::new(&[hi, lo]),
+            Command::DPFillRectangle { ulx, uly, lrx, lry } => Args::new(&[ulx, uly, lrx, lry]),
+            Command::DPFullSync => Args::new(&[]),
+            Command::DPLoadSync => Args::new(&[]),
+            Command::DPTileSync => Args::new(&[]),
+            Command::DPPipeSync => Args::new(&[]),
+            Command::DPLoadTLUT_pal16 { pal, dram } => Args::new(&[pal, dram]),
+            Command::DPLoadTLUT_pal256 { dram } => Args::new(&[dram]),
+            Command::DPLoadMultiBlockYuvS {
+                timg,
+                tmem,
+                rtile,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, rtile, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ]),
+            Command::DPLoadMultiBlockYuv {
+                timg,
+                tmem,
+                rtile,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, rtile, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ]),
+            Command::DPLoadMultiBlock_4bS {
+                timg,
+                tmem,
+                rtile,
+                fmt,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, rtile, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadMultiBlock_4b {
+                timg,
+                tmem,
+                rtile,
+                fmt,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, rtile, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadMultiBlockS {
+                timg,
+                tmem,
+                rtile,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, rtile, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ]),
+            Command::DPLoadMultiBlock {
+                timg,
+                tmem,
+                rtile,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, rtile, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ]),
+            Command::_DPLoadTextureBlockYuvS {
+                timg,
+                tmem,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::_DPLoadTextureBlockYuv {
+                timg,
+                tmem,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::_DPLoadTextureBlock_4bS {
+                timg,
+                tmem,
+                fmt,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::_DPLoadTextureBlock_4b {
+                timg,
+                tmem,
+                fmt,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::_DPLoadTextureBlockS {
+                timg,
+                tmem,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::_DPLoadTextureBlock {
+                timg,
+                tmem,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadTextureBlockYuvS {
+                timg,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadTextureBlockYuv {
+                timg,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadTextureBlock_4bS {
+                timg,
+                fmt,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadTextureBlock_4b {
+                timg,
+                fmt,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadTextureBlockS {
+                timg,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadTextureBlock {
+                timg,
+                fmt,
+                siz,
+                width,
+                height,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadMultiTileYuv {
+                timg,
+                tmem,
+                rtile,
+                fmt,
+                siz,
+                width,
+                height,
+                uls,
+                ult,
+                lrs,
+                lrt,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, rtile, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt,
+                masks, maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadMultiTile_4b {
+                timg,
+                tmem,
+                rtile,
+                fmt,
+                width,
+                height,
+                uls,
+                ult,
+                lrs,
+                lrt,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, rtile, fmt, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks,
+                maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadMultiTile {
+                timg,
+                tmem,
+                rtile,
+                fmt,
+                siz,
+                width,
+                height,
+                uls,
+                ult,
+                lrs,
+                lrt,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, rtile, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt,
+                masks, maskt, shifts, shiftt,
+            ]),
+            Command::_DPLoadTextureTileYuv {
+                timg,
+                tmem,
+                fmt,
+                siz,
+                width,
+                height,
+                uls,
+                ult,
+                lrs,
+                lrt,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks,
+                maskt, shifts, shiftt,
+            ]),
+            Command::_DPLoadTextureTile_4b {
+                timg,
+                tmem,
+                fmt,
+                width,
+                height,
+                uls,
+                ult,
+                lrs,
+                lrt,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, fmt, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt,
+                shifts, shiftt,
+            ]),
+            Command::_DPLoadTextureTile {
+                timg,
+                tmem,
+                fmt,
+                siz,
+                width,
+                height,
+                uls,
+                ult,
+                lrs,
+                lrt,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, tmem, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks,
+                maskt, shifts, shiftt,
+            ]),
+            Command::DPLoadTextureTileYuv {
+                timg,
+                fmt,
+                siz,
+                width,
+                height,
+                uls,
+                ult,
+                lrs,
+                lrt,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt,
+                shifts, shiftt,
+            ]),
+            Command::DPLoadTextureTile_4b {
+                timg,
+                fmt,
+                width,
+                height,
+                uls,
+                ult,
+                lrs,
+                lrt,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, fmt, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ]),
+            Command::DPLoadTextureTile {
+                timg,
+                fmt,
+                siz,
+                width,
+                height,
+                uls,
+                ult,
+                lrs,
+                lrt,
+                pal,
+                cms,
+                cmt,
+                masks,
+                maskt,
+                shifts,
+                shiftt,
+            } => Args::new(&[
+                timg, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt,
+                shifts, shiftt,
+            ]),
+            Command::DPLoadBlock {
+                tile,
+                uls,
+                ult,
+                lrs,
+                dxt,
+            } => Args::new(&[tile, uls, ult, lrs, dxt]),
+            Command::DPNoOp => Args::new(&[]),
+            Command::DPNoOpTag { tag } => Args::new(&[tag]),
+            Command::DPPipelineMode { mode } => Args::new(&[mode]),
+            Command::DPSetBlendColor { r, g, b, a } => Args::new(&[r, g, b, a]),
+            Command::DPSetEnvColor { r, g, b, a } => Args::new(&[r, g, b, a]),
+            Command::DPSetFillColor { c } => Args::new(&[c]),
+            Command::DPSetFogColor { r, g, b, a } => Args::new(&[r, g, b, a]),
+            Command::DPSetPrimColor { m, l, r, g, b, a } => Args::new(&[m, l, r, g, b, a]),
+            Command::DPSetColorImage {
+                fmt,
+                siz,
+                width,
+                cimg,
+            } => Args::new(&[fmt, siz, width, cimg]),
+            Command::DPSetDepthImage { zimg } => Args::new(&[zimg]),
+            Command::DPSetTextureImage {
+                fmt,
+                siz,
+                width,
+                timg,
+            } => Args::new(&[fmt, siz, width, timg]),
+            Command::DPSetAlphaCompare { mode } => Args::new(&[mode]),
+            Command::DPSetAlphaDither { mode } => Args::new(&[mode]),
+            Command::DPSetColorDither { mode } => Args::new(&[mode]),
+            Command::DPSetCombineMode { mode1, mode2 } => Args::new(&[mode1, mode2]),
+            Command::DPSetCombineLERP {
+                a0,
+                b0,
+                c0,
+                d0,
+                Aa0,
+                Ab0,
+                Ac0,
+                Ad0,
+                a1,
+                b1,
+                c1,
+                d1,
+                Aa1,
+                Ab1,
+                Ac1,
+                Ad1,
+            } => Args::new(&[
+                a0, b0, c0, d0, Aa0, Ab0, Ac0, Ad0, a1, b1, c1, d1, Aa1, Ab1, Ac1, Ad1,
+            ]),
+            Command::DPSetConvert {
+                k0,
+                k1,
+                k2,
+                k3,
+                k4,
+                k5,
+            } => Args::new(&[
+                k0 as u32, k1 as u32, k2 as u32, k3 as u32, k4 as u32, k5 as u32,
+            ]),
+            Command::DPSetTextureConvert { mode } => Args::new(&[mode]),
+            Command::DPSetCycleType { mode } => Args::new(&[mode]),
+            Command::DPSetDepthSource { mode } => Args::new(&[mode]),
+            Command::DPSetCombineKey { mode } => Args::new(&[mode]),
+            Command::DPSetKeyGB {
+                cG,
+                sG,
+                wG,
+                cB,
+                sB,
+                wB,
+            } => Args::new(&[cG, sG, wG, cB, sB, wB]),
+            Command::DPSetKeyR { cR, sR, wR } => Args::new(&[cR, sR, wR]),
+            Command::DPSetPrimDepth { z, dz } => Args::new(&[z, dz]),
+            Command::DPSetRenderMode { mode1, mode2 } => Args::new(&[mode1, mode2]),
+            Command::DPSetScissor {
+                mode,
+                ulx,
+                uly,
+                lrx,
+                lry,
+            } => Args::new(&[mode, ulx, uly, lrx, lry]),
+            Command::DPSetScissorFrac {
+                mode,
+                ulx,
+                uly,
+                lrx,
+                lry,
+            } => Args::new(&[mode, ulx, uly, lrx, lry]),
+            Command::DPSetTextureDetail { mode } => Args::new(&[mode]),
+            Command::DPSetTextureFilter { mode } => Args::new(&[mode]),
+            Command::DPSetTextureLOD { mode } => Args::new(&[mode]),
+            Command::DPSetTextureLUT { mode } => Args::new(&[mode]),
+            Command::DPSetTexturePersp { mode } => Args::new(&[mode]),
+            Command::DPSetTile {
+                fmt,
+                siz,
+                line,
+                tmem,
+                tile,
+                pal,
+                cmt,
+                maskt,
+                shiftt,
+                cms,
+                masks,
+                shifts,
+            } => Args::new(&[
+                fmt, siz, line, tmem, tile, pal, cmt, maskt, shiftt, cms, masks, shifts,
+            ]),
+            Command::DPSetTileSize {
+                tile,
+                uls,
+                ult,
+                lrs,
+                lrt,
+            } => Args::new(&[tile, uls, ult, lrs, lrt]),
+            Command::SP1Triangle { v0, v1, v2, flag } => Args::new(&[v0, v1, v2, flag]),
+            Command::SP2Triangles {
+                v00,
+                v01,
+                v02,
+                flag0,
+                v10,
+                v11,
+                v12,
+                flag1,
+            } => Args::new(&[v00, v01, v02, flag0, v10, v11, v12, flag1]),
+            Command::SP1Quadrangle {
+                v0,
+                v1,
+                v2,
+                v3,
+                flag,
+            } => Args::new(&[v0, v1, v2, v3, flag]),
+            Command::SPBranchLessZraw { dl, vtx, zval } => Args::new(&[dl, vtx, zval]),
+            Command::SPBranchList { dl } => Args::new(&[dl]),
+            Command::SPClipRatio { r } => Args::new(&[r]),
+            Command::SPCullDisplayList { vstart, vend } => Args::new(&[vstart, vend]),
+            Command::SPDisplayList { dl } => Args::new(&[dl]),
+            Command::SPEndDisplayList => Args::new(&[]),
+            Command::SPFogFactor { fm, fo } => Args::new(&[fm as u32, fo as u32]),
+            Command::SPFogPosition { min, max } => Args::new(&[min as u32, max as u32]),
+            Command::SPForceMatrix { mtx } => Args::new(&[mtx]),
+            Command::SPSetGeometryMode { mode } => Args::new(&[mode]),
+            Command::SPClearGeometryMode { mode } => Args::new(&[mode]),
+            Command::SPLoadGeometryMode { mode } => Args::new(&[mode]),
+            Command::SPInsertMatrix { r#where, val } => Args::new(&[r#where, val]),
+            Command::SPLine3D { v0, v1, flag } => Args::new(&[v0, v1, flag]),
+            Command::SPLineW3D { v0, v1, wd, flag } => Args::new(&[v0, v1, wd, flag]),
+            Command::SPLoadUcode {
+                uc_start,
+                uc_dstart,
+            } => Args::new(&[uc_start, uc_dstart]),
+            Command::SPLookAtX { l } => Args::new(&[l]),
+            Command::SPLookAtY { l } => Args::new(&[l]),
+            Command::SPLookAt { l } => Args::new(&[l]),
+            Command::SPMatrix { mtx, param } => Args::new(&[mtx, param]),
+            Command::SPModifyVertex { vtx, r#where, val } => Args::new(&[vtx, r#where, val]),
+            Command::SPPerspNormalize { scale } => Args::new(&[scale]),
+            Command::SPPopMatrix { param } => Args::new(&[param]),
+            Command::SPPopMatrixN { param, num } => Args::new(&[param, num]),
+            Command::SPSegment { seg, base } => Args::new(&[seg, base]),
+            Command::SPSetLights1 { lights } => Args::new(&[lights]),
+            Command::SPSetLights2 { lights } => Args::new(&[lights]),
+            Command::SPSetLights3 { lights } => Args::new(&[lights]),
+            Command::SPSetLights4 { lights } => Args::new(&[lights]),
+            Command::SPSetLights5 { lights } => Args::new(&[lights]),
+            Command::SPSetLights6 { lights } => Args::new(&[lights]),
+            Command::SPSetLights7 { lights } => Args::new(&[lights]),
+            Command::SPNumLights { n } => Args::new(&[n]),
+            Command::SPLight { light, n } => Args::new(&[light, n]),
+            Command::SPLightColor { n, color } => Args::new(&[n, color]),
+            Command::SPTexture {
+                sc,
+                tc,
+                level,
+                tile,
+                on,
+            } => Args::new(&[sc, tc, level, tile, on]),
+            Command::SPTextureRectangle {
+                ulx,
+                uly,
+                lrx,
+                lry,
+                tile,
+                s,
+                t,
+                dsdx,
+                dtdy,
+            } => Args::new(&[
+                ulx,
+                uly,
+                lrx,
+                lry,
+                tile,
+                s as u32,
+                t as u32,
+                dsdx as u32,
+                dtdy as u32,
+            ]),
+            Command::SPTextureRectangleFlip {
+                ulx,
+                uly,
+                lrx,
+                lry,
+                tile,
+                s,
+                t,
+                dsdx,
+                dtdy,
+            } => Args::new(&[
+                ulx,
+                uly,
+                lrx,
+                lry,
+                tile,
+                s as u32,
+                t as u32,
+                dsdx as u32,
+                dtdy as u32,
+            ]),
+            Command::SPVertex { v, n, v0 } => Args::new(&[v, n, v0]),
+            Command::SPViewport { v } => Args::new(&[v]),
+            Command::DPLoadTLUTCmd { tile, count } => Args::new(&[tile, count]),
+            Command::DPLoadTLUT { count, tmem, dram } => Args::new(&[count, tmem, dram]),
+            Command::BranchZ { vtx, zval } => Args::new(&[vtx, zval]),
+            Command::DisplayList { dl, flag } => Args::new(&[dl, flag]),
+            Command::DPHalf1 { wordhi } => Args::new(&[wordhi]),
+            Command::DPHalf2 { wordlo } => Args::new(&[wordlo]),
+            Command::DPWord { wordhi, wordlo } => Args::new(&[wordhi, wordlo]),
+            Command::DPLoadTile {
+                tile,
+                uls,
+                ult,
+                lrs,
+                lrt,
+            } => Args::new(&[tile, uls, ult, lrs, lrt]),
+            Command::SPGeometryMode { clearbits, setbits } => Args::new(&[clearbits, setbits]),
+            Command::SPSetOtherMode {
+                opc,
+                sft,
+                len,
+                data,
+            } => Args::new(&[opc, sft, len, data]),
+            Command::SPSetOtherModeLo { sft, len, data } => Args::new(&[sft, len, data]),
+            Command::SPSetOtherModeHi { sft, len, data } => Args::new(&[sft, len, data]),
+            Command::DPSetOtherMode { hi, lo } => Args::new(&[hi, lo]),
+            Command::MoveWd {
+                index,
+                offset,
+                data,
+            } => Args::new(&[index, offset, data]),
+            Command::MoveMem {
+                size,
+                index,
+                offset,
+                dram,
+            } => Args::new(&[size, index, offset, dram]),
+            Command::SPDma_io {
+                flag,
+                dmem,
+                dram,
+                size,
+            } => Args::new(&[flag, dmem, dram, size]),
+            Command::SPDmaRead { dmem, dram, size } => Args::new(&[dmem, dram, size]),
+            Command::SPDmaWrite { dmem, dram, size } => Args::new(&[dmem, dram, size]),
+            Command::LoadUcode { uc_start, uc_dsize } => Args::new(&[uc_start, uc_dsize]),
+            Command::SPLoadUcodeEx {
+                uc_start,
+                uc_dstart,
+                uc_dsize,
+            } => Args::new(&[uc_start, uc_dstart, uc_dsize]),
+            Command::TexRect {
+                ulx,
+                uly,
+                lrx,
+                lry,
+                tile,
+            } => Args::new(&[ulx, uly, lrx, lry, tile]),
+            Command::TexRectFlip {
+                ulx,
+                uly,
+                lrx,
+                lry,
+                tile,
+            } => Args::new(&[ulx, uly, lrx, lry, tile]),
+            Command::SPNoOp => Args::new(&[]),
+            Command::Special3 { hi, lo } => Args::new(&[hi, lo]),
+            Command::Special2 { hi, lo } => Args::new(&[hi, lo]),
+            Command::Special1 { hi, lo } => Args::new(&[hi, lo]),
+        }
+    }
+
+    /// Encodes this command for `ucode`, see [`encode_command`].
+    ///
+    /// [`encode_command`]: crate::encode::encode_command
+    pub fn encode(&self, ucode: Ucode) -> Result<Packets, EncodeError> {
+        crate::encode::encode_command(ucode, self)
+    }
+
+    /// Builds a command from a decoded macro.
+    ///
+    /// Returns [`None`] if the macro does not have the arguments its id
+    /// expects.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    pub fn from_macro(m: &Macro) -> Option<Self> {
+        let mut values = [0; MAX_ARGS];
+        if m.args.len() > MAX_ARGS {
+            return None;
+        }
+        for (value, arg) in values.iter_mut().zip(m.args.iter()) {
+            *value = arg.value.bits();
+        }
+        Self::from_args(m.id, &values[..m.args.len()])
+    }
+}
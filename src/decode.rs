@@ -0,0 +1,606 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Decode a buffer into owned, structured macros.
+//!
+//! [`decode`] runs [`gfxd_execute`] over a byte buffer and records everything
+//! the macro information functions report for each macro, so the results can
+//! be inspected after execution has finished.
+//!
+//! Decoding uses a freshly allocated config, so the settings of the current
+//! config are left untouched.
+//!
+//! [`gfxd_execute`]: crate::execution::gfxd_execute
+
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::slice;
+
+use crate::ffi;
+
+use crate::arg_type::ArgType;
+use crate::gbi;
+use crate::json::{self, Json, JsonError};
+use crate::macro_id::MacroId;
+use crate::macro_info::{self, ArgFmt};
+use crate::names::VariantName;
+use crate::settings::{Endian, FeatureSet, Ucode};
+
+use crate::ptr::{NonNullConst, NonNullMut};
+
+/// The size in bytes of a single `Gfx` packet.
+pub const GFX_SIZE: usize = 8;
+
+/// The settings used to decode a buffer.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DecodeSettings {
+    /// The target microcode.
+    pub ucode: Ucode,
+    /// The endianness of the input.
+    pub endian: Endian,
+    /// The size of each word of the input, in bytes. Can be 1, 2, 4 or 8.
+    pub wordsize: u32,
+    /// The enabled features.
+    pub features: FeatureSet,
+}
+
+impl DecodeSettings {
+    /// Big endian input with a word size of 4 and the default `libgfxd`
+    /// features.
+    #[must_use]
+    pub fn new(ucode: Ucode) -> Self {
+        Self {
+            ucode,
+            endian: Endian::gfxd_endian_big,
+            wordsize: 4,
+            features: FeatureSet::libgfxd_default(),
+        }
+    }
+
+    /// Applies these settings to the current `libgfxd` config.
+    pub fn apply(&self) {
+        // SAFETY: Plain setters on the current config.
+        unsafe {
+            crate::settings::gfxd_target(Some(self.ucode.as_raw()));
+            crate::settings::gfxd_endian(self.endian, self.wordsize as ffi::c_int);
+        }
+        self.features.apply();
+    }
+}
+
+/// The value of a macro argument, tagged by its [`ArgFmt`].
+#[derive(Debug, Copy, Clone)]
+pub enum Value {
+    I(i32),
+    U(u32),
+    F(f32),
+}
+
+impl Value {
+    /// Builds a value from its raw bits, interpreting them as `fmt`.
+    #[must_use]
+    pub fn from_bits(fmt: ArgFmt, bits: u32) -> Self {
+        match fmt {
+            ArgFmt::gfxd_argfmt_i => Value::I(bits as i32),
+            ArgFmt::gfxd_argfmt_u => Value::U(bits),
+            ArgFmt::gfxd_argfmt_f => Value::F(f32::from_bits(bits)),
+        }
+    }
+
+    /// The format of this value.
+    #[must_use]
+    pub fn fmt(self) -> ArgFmt {
+        match self {
+            Value::I(_) => ArgFmt::gfxd_argfmt_i,
+            Value::U(_) => ArgFmt::gfxd_argfmt_u,
+            Value::F(_) => ArgFmt::gfxd_argfmt_f,
+        }
+    }
+
+    /// The raw bits of this value.
+    #[must_use]
+    pub fn bits(self) -> u32 {
+        match self {
+            Value::I(i) => i as u32,
+            Value::U(u) => u,
+            Value::F(f) => f.to_bits(),
+        }
+    }
+
+    /// The value as an unsigned integer.
+    ///
+    /// Signed values are reinterpreted and floats are truncated.
+    #[must_use]
+    pub fn as_u32(self) -> u32 {
+        match self {
+            Value::I(i) => i as u32,
+            Value::U(u) => u,
+            Value::F(f) => f as i32 as u32,
+        }
+    }
+
+    /// The value as a signed integer.
+    ///
+    /// Unsigned values are reinterpreted and floats are truncated.
+    #[must_use]
+    pub fn as_i32(self) -> i32 {
+        match self {
+            Value::I(i) => i,
+            Value::U(u) => u as i32,
+            Value::F(f) => f as i32,
+        }
+    }
+
+    /// The value as a float.
+    #[must_use]
+    pub fn as_f32(self) -> f32 {
+        match self {
+            Value::I(i) => i as f32,
+            Value::U(u) => u as f32,
+            Value::F(f) => f,
+        }
+    }
+}
+
+/// Values are compared by format and raw bits, so `NaN`s compare equal to
+/// themselves.
+impl PartialEq for Value {
+    fn eq(&self, other: &Self) -> bool {
+        self.fmt() == other.fmt() && self.bits() == other.bits()
+    }
+}
+impl Eq for Value {}
+
+/// A decoded macro argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arg {
+    /// The name of the argument, as reported by [`gfxd_arg_name`].
+    ///
+    /// [`gfxd_arg_name`]: crate::macro_info::gfxd_arg_name
+    pub name: String,
+    /// The type of the argument.
+    pub type_: ArgType,
+    /// The value of the argument.
+    pub value: Value,
+    /// If `libgfxd` considers this argument valid, see [`gfxd_arg_valid`].
+    ///
+    /// [`gfxd_arg_valid`]: crate::macro_info::gfxd_arg_valid
+    pub valid: bool,
+    /// The argument as printed by [`gfxd_print_value`].
+    ///
+    /// [`gfxd_print_value`]: crate::custom_output::gfxd_print_value
+    pub text: String,
+}
+
+impl Arg {
+    /// The name of the `gbi.h` constant the value of this argument stands
+    /// for under `ucode`, see [`gbi::symbolic_name`].
+    #[must_use]
+    pub fn symbolic_name(&self, ucode: Ucode) -> Option<&'static str> {
+        gbi::symbolic_name(ucode, self.type_, self.value.bits())
+    }
+}
+
+/// A decoded macro.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Macro {
+    /// The offset of the macro in the input, in bytes.
+    pub offset: u32,
+    /// The number of `Gfx` packets the macro is made of.
+    pub packets: u32,
+    /// The input data of the macro, not byte-swapped.
+    pub data: Vec<u8>,
+    /// The id of the macro.
+    pub id: MacroId,
+    /// The name of the macro, [`None`] for invalid macros.
+    pub name: Option<String>,
+    /// The arguments of the macro, in order.
+    pub args: Vec<Arg>,
+    /// The macro as printed by [`gfxd_macro_dflt`].
+    ///
+    /// [`gfxd_macro_dflt`]: crate::handlers::gfxd_macro_dflt
+    pub text: String,
+}
+
+impl Macro {
+    /// The offset just past the end of this macro.
+    #[must_use]
+    pub fn end(&self) -> u32 {
+        self.offset + self.packets * GFX_SIZE as u32
+    }
+
+    /// Returns the argument of type `type_` that has order `idx` in all the
+    /// arguments of that type, like [`gfxd_value_by_type`].
+    ///
+    /// [`gfxd_value_by_type`]: crate::macro_info::gfxd_value_by_type
+    #[must_use]
+    pub fn arg_by_type(&self, type_: ArgType, idx: usize) -> Option<&Arg> {
+        self.args.iter().filter(|x| x.type_ == type_).nth(idx)
+    }
+}
+
+fn write_value(out: &mut String, value: Value) {
+    let _ = match value {
+        Value::I(i) => write!(out, "{}", i),
+        Value::U(u) => write!(out, "{}", u),
+        Value::F(f) if f.is_nan() => write!(out, "\"NaN\""),
+        Value::F(f) if f.is_infinite() => write!(out, "\"{}\"", f),
+        Value::F(f) => write!(out, "{:?}", f),
+    };
+}
+
+fn read_value(fmt: ArgFmt, json: &Json) -> Option<Value> {
+    match fmt {
+        ArgFmt::gfxd_argfmt_i => json.as_number().map(Value::I),
+        ArgFmt::gfxd_argfmt_u => json.as_number().map(Value::U),
+        ArgFmt::gfxd_argfmt_f => json
+            .as_number()
+            .or_else(|| json.as_str().and_then(|x| x.parse().ok()))
+            .map(Value::F),
+    }
+}
+
+impl Arg {
+    fn write_json(&self, out: &mut String) {
+        out.push_str("{\"name\":");
+        json::write_str(out, &self.name);
+        let _ = write!(
+            out,
+            ",\"type\":\"{}\",\"fmt\":\"{}\",\"value\":",
+            self.type_.variant_name(),
+            self.value.fmt().variant_name()
+        );
+        write_value(out, self.value);
+        let _ = write!(out, ",\"valid\":{},\"text\":", self.valid);
+        json::write_str(out, &self.text);
+        out.push('}');
+    }
+
+    fn from_json_value(json: &Json) -> Result<Self, JsonError> {
+        let member = |key: &'static str| json.get(key).ok_or(JsonError::Invalid(key));
+        let fmt = member("fmt")?
+            .as_str()
+            .and_then(ArgFmt::from_variant_name)
+            .ok_or(JsonError::Invalid("fmt"))?;
+
+        Ok(Self {
+            name: member("name")?
+                .as_str()
+                .ok_or(JsonError::Invalid("name"))?
+                .into(),
+            type_: member("type")?
+                .as_str()
+                .and_then(ArgType::from_variant_name)
+                .ok_or(JsonError::Invalid("type"))?,
+            value: read_value(fmt, member("value")?).ok_or(JsonError::Invalid("value"))?,
+            valid: member("valid")?
+                .as_bool()
+                .ok_or(JsonError::Invalid("valid"))?,
+            text: member("text")?
+                .as_str()
+                .ok_or(JsonError::Invalid("text"))?
+                .into(),
+        })
+    }
+}
+
+impl Macro {
+    /// The macro as a JSON object.
+    ///
+    /// `words` holds [`Macro::data`] as 32-bit words, read in big endian
+    /// order, and `value` holds each argument in its `fmt`, with floats that
+    /// are not finite written as the strings `"NaN"`, `"inf"` and `"-inf"`.
+    /// The id, argument types and formats are written with their `libgfxd`
+    /// names, like `"gfxd_SPVertex"`, `"gfxd_Vtxptr"` and `"gfxd_argfmt_u"`.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        self.write_json(&mut out);
+        out
+    }
+
+    /// Reads a macro written by [`Macro::to_json`].
+    pub fn from_json(text: &str) -> Result<Self, JsonError> {
+        Self::from_json_value(&json::parse(text)?)
+    }
+
+    fn write_json(&self, out: &mut String) {
+        let _ = write!(
+            out,
+            "{{\"offset\":{},\"packets\":{},\"words\":[",
+            self.offset, self.packets
+        );
+        for (i, word) in self.data.chunks_exact(4).enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            let word = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+            let _ = write!(out, "{}", word);
+        }
+        let _ = write!(out, "],\"id\":\"{}\",\"name\":", self.id.variant_name());
+        match &self.name {
+            Some(name) => json::write_str(out, name),
+            None => out.push_str("null"),
+        }
+        out.push_str(",\"args\":[");
+        for (i, arg) in self.args.iter().enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            arg.write_json(out);
+        }
+        out.push_str("],\"text\":");
+        json::write_str(out, &self.text);
+        out.push('}');
+    }
+
+    fn from_json_value(json: &Json) -> Result<Self, JsonError> {
+        let member = |key: &'static str| json.get(key).ok_or(JsonError::Invalid(key));
+
+        let mut data = Vec::new();
+        for word in member("words")?
+            .as_array()
+            .ok_or(JsonError::Invalid("words"))?
+        {
+            let word: u32 = word.as_number().ok_or(JsonError::Invalid("words"))?;
+            data.extend_from_slice(&word.to_be_bytes());
+        }
+        let name = match member("name")? {
+            Json::Null => None,
+            x => Some(x.as_str().ok_or(JsonError::Invalid("name"))?.into()),
+        };
+        let args = member("args")?
+            .as_array()
+            .ok_or(JsonError::Invalid("args"))?
+            .iter()
+            .map(Arg::from_json_value)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            offset: member("offset")?
+                .as_number()
+                .ok_or(JsonError::Invalid("offset"))?,
+            packets: member("packets")?
+                .as_number()
+                .ok_or(JsonError::Invalid("packets"))?,
+            data,
+            id: member("id")?
+                .as_str()
+                .and_then(MacroId::from_variant_name)
+                .ok_or(JsonError::Invalid("id"))?,
+            name,
+            args,
+            text: member("text")?
+                .as_str()
+                .ok_or(JsonError::Invalid("text"))?
+                .into(),
+        })
+    }
+}
+
+/// The outcome of [`decode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decoded {
+    /// Every macro decoded, in order.
+    pub macros: Vec<Macro>,
+    /// The value returned by [`gfxd_execute`].
+    ///
+    /// [`gfxd_execute`]: crate::execution::gfxd_execute
+    pub status: i32,
+}
+
+impl Decoded {
+    /// The result as a JSON object, with the `status` and the `macros` as
+    /// written by [`Macro::to_json`].
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut out = format!("{{\"status\":{},\"macros\":[", self.status);
+        for (i, m) in self.macros.iter().enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            m.write_json(&mut out);
+        }
+        out.push_str("]}");
+        out
+    }
+
+    /// Reads a result written by [`Decoded::to_json`].
+    pub fn from_json(text: &str) -> Result<Self, JsonError> {
+        let json = json::parse(text)?;
+        let status = json
+            .get("status")
+            .and_then(Json::as_number)
+            .ok_or(JsonError::Invalid("status"))?;
+        let macros = json
+            .get("macros")
+            .and_then(Json::as_array)
+            .ok_or(JsonError::Invalid("macros"))?
+            .iter()
+            .map(Macro::from_json_value)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { macros, status })
+    }
+}
+
+struct State {
+    macros: Vec<Macro>,
+    out: Vec<u8>,
+}
+
+/// Decodes every macro in `input` using `settings`.
+#[must_use]
+pub fn decode(input: &[u8], settings: &DecodeSettings) -> Decoded {
+    let mut state = State {
+        macros: Vec::new(),
+        out: Vec::new(),
+    };
+
+    let status = with_config(|| {
+        settings.apply();
+        // SAFETY: `input` and `state` outlive the execution.
+        unsafe {
+            crate::io::gfxd_input_buffer(NonNullConst::new_void(input.as_ptr()), input.len() as _);
+            crate::io::gfxd_output_callback(Some(output_to_state));
+            crate::handlers::gfxd_macro_fn(Some(record_macro));
+            crate::settings::gfxd_udata_set(NonNullMut::new_void(&mut state));
+
+            crate::execution::gfxd_execute()
+        }
+    });
+
+    Decoded {
+        macros: state.macros,
+        status,
+    }
+}
+
+/// Runs `f` with a freshly allocated config selected, restoring the previous
+/// config afterwards.
+pub(crate) fn with_config<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R,
+{
+    // SAFETY: The new config is deselected before being freed.
+    unsafe {
+        let previous = crate::config::gfxd_get_config();
+        let config = crate::config::gfxd_alloc_config();
+        crate::config::gfxd_set_config(Some(config));
+
+        let ret = f();
+
+        crate::config::gfxd_set_config(Some(previous));
+        crate::config::gfxd_free_config(config);
+
+        ret
+    }
+}
+
+/// The `T` given to [`gfxd_udata_set`] for the current execution.
+///
+/// # Safety
+///
+/// The user data must have been set to a live `T`, which is not otherwise
+/// borrowed while the returned reference is used.
+///
+/// [`gfxd_udata_set`]: crate::settings::gfxd_udata_set
+pub(crate) unsafe fn udata<'a, T>() -> &'a mut T {
+    let udata = unsafe { crate::settings::gfxd_udata_get() };
+    let udata = udata.expect("udata was not set");
+
+    unsafe { udata.cast::<T>().as_mut() }
+}
+
+unsafe fn state<'a>() -> &'a mut State {
+    unsafe { udata() }
+}
+
+/// An output callback that discards everything, for executions that only
+/// look at the macros and their arguments.
+pub(crate) unsafe extern "C" fn discard_output(
+    _buf: NonNullConst<ffi::c_char>,
+    count: ffi::c_int,
+) -> ffi::c_int {
+    count
+}
+
+/// Reads a nul-terminated C string into an owned [`String`].
+pub(crate) unsafe fn string_from_c(ptr: NonNullConst<ffi::c_char>) -> String {
+    let start = ptr.as_ptr() as *const u8;
+    let mut len = 0;
+    while unsafe { *start.add(len) } != 0 {
+        len += 1;
+    }
+    let bytes = unsafe { slice::from_raw_parts(start, len) };
+
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+unsafe extern "C" fn output_to_state(
+    buf: NonNullConst<ffi::c_char>,
+    count: ffi::c_int,
+) -> ffi::c_int {
+    let state = unsafe { state() };
+    let bytes = unsafe { slice::from_raw_parts(buf.as_ptr() as *const u8, count as usize) };
+
+    state.out.extend_from_slice(bytes);
+
+    count
+}
+
+/// Runs `f` and returns everything it printed.
+unsafe fn capture<F>(f: F) -> String
+where
+    F: FnOnce(),
+{
+    let start = unsafe { state() }.out.len();
+    f();
+
+    let state = unsafe { state() };
+    let text = String::from_utf8_lossy(&state.out[start..]).into_owned();
+    state.out.truncate(start);
+
+    text
+}
+
+unsafe extern "C" fn record_macro() -> ffi::c_int {
+    let text = unsafe {
+        capture(|| {
+            crate::handlers::gfxd_macro_dflt();
+        })
+    };
+
+    let offset = unsafe { macro_info::gfxd_macro_offset() } as u32;
+    let packets = unsafe { macro_info::gfxd_macro_packets() } as u32;
+    let data = unsafe {
+        let ptr = macro_info::gfxd_macro_data().cast::<u8>();
+        slice::from_raw_parts(ptr.as_ptr(), packets as usize * GFX_SIZE).to_vec()
+    };
+    let id = MacroId::from_raw(unsafe { macro_info::gfxd_macro_id() } as u32)
+        .unwrap_or(MacroId::gfxd_Invalid);
+    let name = unsafe { macro_info::gfxd_macro_name() }.map(|x| unsafe { string_from_c(x) });
+
+    let count = unsafe { macro_info::gfxd_arg_count() };
+    let args = (0..count)
+        .map(|i| unsafe { record_arg(i) })
+        .collect::<Vec<_>>();
+
+    unsafe { state() }.macros.push(Macro {
+        offset,
+        packets,
+        data,
+        id,
+        name,
+        args,
+        text,
+    });
+
+    0
+}
+
+unsafe fn record_arg(arg_num: ffi::c_int) -> Arg {
+    let name = unsafe { string_from_c(macro_info::gfxd_arg_name(arg_num)) };
+    let type_ = ArgType::from_raw(unsafe { macro_info::gfxd_arg_type(arg_num) } as u32)
+        .unwrap_or(ArgType::gfxd_Word);
+    let fmt = ArgFmt::from_raw(unsafe { macro_info::gfxd_arg_fmt(arg_num) } as u32)
+        .unwrap_or(ArgFmt::gfxd_argfmt_u);
+    let raw = unsafe { macro_info::gfxd_arg_value(arg_num) };
+    let value = Value::from_bits(fmt, unsafe { raw.as_ref().u });
+    let valid = unsafe { macro_info::gfxd_arg_valid(arg_num) } != 0;
+    let text = unsafe {
+        capture(|| {
+            crate::custom_output::gfxd_print_value(type_, raw);
+        })
+    };
+
+    Arg {
+        name,
+        type_,
+        value,
+        valid,
+        text,
+    }
+}
@@ -0,0 +1,429 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Differential testing of decoders.
+//!
+//! The same input is decoded under every combination of microcode,
+//! endianness, word size and feature set, and the structured results of each
+//! combination are compared macro by macro against either a second
+//! [`Backend`] or a stored [`GoldenCorpus`].
+//!
+//! Only the first divergence of each combination is reported, since every
+//! macro after it is likely to be misaligned anyway.
+
+use alloc::borrow::ToOwned;
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+use crate::arg_type::ArgType;
+use crate::decode::{self, Arg, DecodeSettings, Macro, Value};
+use crate::macro_id::MacroId;
+use crate::macro_info::ArgFmt;
+use crate::settings::{Endian, FeatureSet, Ucode};
+
+/// A display list decoder that can be compared against others.
+pub trait Backend {
+    /// A short name used to identify this backend.
+    fn name(&self) -> &str;
+
+    /// Decodes `input` using `settings`.
+    fn decode(&mut self, input: &[u8], settings: &DecodeSettings) -> Vec<Macro>;
+}
+
+/// The `libgfxd` backend, using [`decode::decode`].
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Libgfxd;
+
+impl Backend for Libgfxd {
+    fn name(&self) -> &str {
+        "libgfxd"
+    }
+
+    fn decode(&mut self, input: &[u8], settings: &DecodeSettings) -> Vec<Macro> {
+        decode::decode(input, settings).macros
+    }
+}
+
+/// Every endianness that can be tested, excluding [`Endian::gfxd_endian_host`]
+/// since it aliases one of the others.
+pub const ENDIANS: [Endian; 2] = [Endian::gfxd_endian_big, Endian::gfxd_endian_little];
+
+/// Every word size supported by [`gfxd_endian`].
+///
+/// [`gfxd_endian`]: crate::settings::gfxd_endian
+pub const WORDSIZES: [u32; 4] = [1, 2, 4, 8];
+
+/// Iterates over every combination of microcode, endianness, word size and
+/// feature set.
+pub fn combinations() -> impl Iterator<Item = DecodeSettings> {
+    Ucode::ALL.iter().flat_map(|&ucode| {
+        ENDIANS.iter().flat_map(move |&endian| {
+            WORDSIZES.iter().flat_map(move |&wordsize| {
+                FeatureSet::every().map(move |features| DecodeSettings {
+                    ucode,
+                    endian,
+                    wordsize,
+                    features,
+                })
+            })
+        })
+    })
+}
+
+/// The first point where two decodings of the same input disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Divergence {
+    /// The settings used for both decodings.
+    pub settings: DecodeSettings,
+    /// The index of the first differing macro.
+    pub index: usize,
+    /// The input offset of the first differing macro.
+    ///
+    /// If one side ran out of macros, this is the offset of the macro from
+    /// the other side.
+    pub offset: u32,
+    /// The macro from the reference side, if any.
+    pub expected: Option<Macro>,
+    /// The macro from the side under test, if any.
+    pub actual: Option<Macro>,
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: macro {} at offset 0x{:X} differs: expected `{}`, got `{}`",
+            settings_key(&self.settings),
+            self.index,
+            self.offset,
+            self.expected.as_ref().map_or("<none>", |x| &x.text),
+            self.actual.as_ref().map_or("<none>", |x| &x.text),
+        )
+    }
+}
+
+/// Compares two decodings of the same input, returning the first macro
+/// where they differ.
+#[must_use]
+pub fn first_divergence(
+    settings: &DecodeSettings,
+    expected: &[Macro],
+    actual: &[Macro],
+) -> Option<Divergence> {
+    let len = expected.len().max(actual.len());
+
+    (0..len).find_map(|index| {
+        let e = expected.get(index);
+        let a = actual.get(index);
+        if e == a {
+            return None;
+        }
+
+        let offset = e.or(a).map_or(0, |x| x.offset);
+        Some(Divergence {
+            settings: *settings,
+            index,
+            offset,
+            expected: e.cloned(),
+            actual: a.cloned(),
+        })
+    })
+}
+
+/// Decodes `input` with both backends under every combination from
+/// [`combinations`], returning the first divergence of each combination.
+pub fn compare_backends(
+    input: &[u8],
+    reference: &mut dyn Backend,
+    tested: &mut dyn Backend,
+) -> Vec<Divergence> {
+    combinations()
+        .filter_map(|settings| {
+            let expected = reference.decode(input, &settings);
+            let actual = tested.decode(input, &settings);
+
+            first_divergence(&settings, &expected, &actual)
+        })
+        .collect()
+}
+
+/// Decodes `input` with `tested` under every combination stored in `corpus`,
+/// returning the first divergence of each combination.
+pub fn compare_golden(
+    input: &[u8],
+    corpus: &GoldenCorpus,
+    tested: &mut dyn Backend,
+) -> Vec<Divergence> {
+    corpus
+        .entries
+        .iter()
+        .filter_map(|(settings, expected)| {
+            let actual = tested.decode(input, settings);
+
+            first_divergence(settings, expected, &actual)
+        })
+        .collect()
+}
+
+/// The stored results of decoding a single input under several settings.
+///
+/// A corpus is serialized as plain text, so it can be kept next to the tests
+/// and reviewed in diffs. Fields are separated by tabs, shown here as
+/// spaces:
+///
+/// ```text
+/// [f3dex2 big 4 0x03]
+/// 0x00000000  1  78  DF00000000000000  gsSPEndDisplayList  gsSPEndDisplayList()
+/// ```
+///
+/// Each section starts with the microcode, endianness, word size and feature
+/// bitmask. Each macro line holds the offset, packet count, raw id, data,
+/// name and text, separated by tabs. Argument lines follow their macro and
+/// start with a tab, holding the name, raw type, raw format, raw bits,
+/// validity and text.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GoldenCorpus {
+    /// The stored macros, keyed by the settings that produced them.
+    pub entries: BTreeMap<DecodeSettings, Vec<Macro>>,
+}
+
+impl GoldenCorpus {
+    /// Creates an empty corpus.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the output of `backend` for `input` under every combination
+    /// from [`combinations`].
+    #[must_use]
+    pub fn record(input: &[u8], backend: &mut dyn Backend) -> Self {
+        Self::record_with(input, backend, combinations())
+    }
+
+    /// Records the output of `backend` for `input` under the given settings.
+    #[must_use]
+    pub fn record_with<I>(input: &[u8], backend: &mut dyn Backend, settings: I) -> Self
+    where
+        I: IntoIterator<Item = DecodeSettings>,
+    {
+        let entries = settings
+            .into_iter()
+            .map(|x| {
+                let macros = backend.decode(input, &x);
+                (x, macros)
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Serializes the corpus into its text form.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut out = String::new();
+
+        for (settings, macros) in &self.entries {
+            let _ = writeln!(out, "[{}]", settings_key(settings));
+            for m in macros {
+                let _ = write!(out, "0x{:08X}\t{}\t{}\t", m.offset, m.packets, m.id as u32);
+                for b in &m.data {
+                    let _ = write!(out, "{:02X}", b);
+                }
+                let _ = writeln!(out, "\t{}\t{}", m.name.as_ref().map_or("", |x| x), m.text);
+                for a in &m.args {
+                    let _ = writeln!(
+                        out,
+                        "\t{}\t{}\t{}\t0x{:08X}\t{}\t{}",
+                        a.name,
+                        a.type_ as u32,
+                        a.value.fmt() as u32,
+                        a.value.bits(),
+                        a.valid as u32,
+                        a.text
+                    );
+                }
+            }
+        }
+
+        out
+    }
+
+    /// Parses a corpus from its text form.
+    pub fn from_text(text: &str) -> Result<Self, GoldenParseError> {
+        let mut entries = BTreeMap::new();
+        let mut current: Option<(DecodeSettings, Vec<Macro>)> = None;
+
+        for (i, line) in text.lines().enumerate() {
+            let err = |msg: &'static str| GoldenParseError { line: i + 1, msg };
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if line.starts_with('[') {
+                if let Some((settings, macros)) = current.take() {
+                    entries.insert(settings, macros);
+                }
+                let key = line.trim().trim_start_matches('[').trim_end_matches(']');
+                let settings = parse_settings_key(key).ok_or_else(|| err("bad section header"))?;
+                current = Some((settings, Vec::new()));
+            } else if line.starts_with('\t') {
+                let (_, macros) = current
+                    .as_mut()
+                    .ok_or_else(|| err("argument outside section"))?;
+                let m = macros
+                    .last_mut()
+                    .ok_or_else(|| err("argument without macro"))?;
+                m.args
+                    .push(parse_arg(&line[1..]).ok_or_else(|| err("bad argument"))?);
+            } else {
+                let (_, macros) = current
+                    .as_mut()
+                    .ok_or_else(|| err("macro outside section"))?;
+                macros.push(parse_macro(line).ok_or_else(|| err("bad macro"))?);
+            }
+        }
+
+        if let Some((settings, macros)) = current.take() {
+            entries.insert(settings, macros);
+        }
+
+        Ok(Self { entries })
+    }
+}
+
+/// An error produced by [`GoldenCorpus::from_text`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoldenParseError {
+    /// The 1-based line where the error was found.
+    pub line: usize,
+    /// A description of the error.
+    pub msg: &'static str,
+}
+
+impl fmt::Display for GoldenParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.msg)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GoldenParseError {}
+
+/// A short human readable key for `settings`, in the form used by the
+/// section headers of a [`GoldenCorpus`].
+#[must_use]
+pub fn settings_key(settings: &DecodeSettings) -> String {
+    let endian = match settings.endian {
+        Endian::gfxd_endian_big => "big",
+        Endian::gfxd_endian_little => "little",
+        Endian::gfxd_endian_host => "host",
+    };
+
+    let mut out = String::new();
+    let _ = write!(
+        out,
+        "{} {} {} 0x{:02X}",
+        settings.ucode.name(),
+        endian,
+        settings.wordsize,
+        settings.features.bits()
+    );
+    out
+}
+
+fn parse_settings_key(key: &str) -> Option<DecodeSettings> {
+    let mut parts = key.split_whitespace();
+
+    let ucode = Ucode::from_name(parts.next()?)?;
+    let endian = match parts.next()? {
+        "big" => Endian::gfxd_endian_big,
+        "little" => Endian::gfxd_endian_little,
+        "host" => Endian::gfxd_endian_host,
+        _ => return None,
+    };
+    let wordsize = parts.next()?.parse().ok()?;
+    let features = FeatureSet::from_bits(parse_hex(parts.next()?)?);
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(DecodeSettings {
+        ucode,
+        endian,
+        wordsize,
+        features,
+    })
+}
+
+fn parse_hex(s: &str) -> Option<u32> {
+    if s.starts_with("0x") {
+        u32::from_str_radix(&s[2..], 16).ok()
+    } else {
+        None
+    }
+}
+
+fn parse_macro(line: &str) -> Option<Macro> {
+    let mut fields = line.splitn(6, '\t');
+
+    let offset = parse_hex(fields.next()?)?;
+    let packets = fields.next()?.parse().ok()?;
+    let id = MacroId::from_raw(fields.next()?.parse().ok()?)?;
+    let data_hex = fields.next()?;
+    let name = fields.next()?;
+    let text = fields.next()?;
+
+    if data_hex.len() % 2 != 0 {
+        return None;
+    }
+    // Works on bytes, so text that is not ASCII is rejected instead of being
+    // sliced in the middle of a character.
+    let digit = |c: u8| char::from(c).to_digit(16);
+    let data = data_hex
+        .as_bytes()
+        .chunks(2)
+        .map(|pair| Some((digit(pair[0])? << 4 | digit(pair[1])?) as u8))
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(Macro {
+        offset,
+        packets,
+        data,
+        id,
+        name: if name.is_empty() {
+            None
+        } else {
+            Some(name.to_owned())
+        },
+        args: Vec::new(),
+        text: text.to_owned(),
+    })
+}
+
+fn parse_arg(line: &str) -> Option<Arg> {
+    let mut fields = line.splitn(6, '\t');
+
+    let name = fields.next()?.to_owned();
+    let type_ = ArgType::from_raw(fields.next()?.parse().ok()?)?;
+    let fmt = ArgFmt::from_raw(fields.next()?.parse().ok()?)?;
+    let bits = parse_hex(fields.next()?)?;
+    let valid = match fields.next()? {
+        "0" => false,
+        "1" => true,
+        _ => return None,
+    };
+    let text = fields.next()?.to_owned();
+
+    Some(Arg {
+        name,
+        type_,
+        value: Value::from_bits(fmt, bits),
+        valid,
+        text,
+    })
+}
@@ -0,0 +1,579 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Encode macros back into [`Gfx`] packets.
+//!
+//! [`encode`] does the opposite of what `libgfxd` does: given a [`MacroId`]
+//! and the values of its arguments, in the order listed by
+//! [`MacroId::args`], it produces the packets the macro expands to in
+//! `gbi.h` for the target microcode. The packets themselves are built by
+//! the [`static_dl`] builders, after the arguments have been checked.
+//!
+//! Arguments of type [`gfxd_Ccpre`] are indices into
+//! [`gbi::CC_PRESETS`].
+//!
+//! [`encode_command`] does the same for a typed [`Command`]. With the
+//! `alloc` feature, `DisplayListBuilder` collects the packets of many
+//! commands into a whole display list.
+//!
+//! [`gfxd_Ccpre`]: crate::arg_type::ArgType::gfxd_Ccpre
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::Deref;
+
+use crate::command::Command;
+use crate::gbi;
+use crate::gfx::Gfx;
+use crate::macro_id::MacroId;
+#[cfg(feature = "alloc")]
+use crate::settings::Endian;
+use crate::settings::Ucode;
+use crate::static_dl;
+
+/// The largest number of packets a single macro can expand to.
+pub const MAX_PACKETS: usize = 9;
+
+/// The packets a single macro expands to.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Packets {
+    buf: [Gfx; MAX_PACKETS],
+    len: usize,
+}
+
+impl Packets {
+    fn new() -> Self {
+        Self {
+            buf: [Gfx::default(); MAX_PACKETS],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, gfx: Gfx) {
+        self.buf[self.len] = gfx;
+        self.len += 1;
+    }
+
+    fn extend(&mut self, expansion: &impl Expansion) {
+        for &gfx in expansion.packets() {
+            self.push(gfx);
+        }
+    }
+
+    /// The packets as a slice.
+    #[must_use]
+    pub fn as_slice(&self) -> &[Gfx] {
+        &self.buf[..self.len]
+    }
+}
+
+impl Deref for Packets {
+    type Target = [Gfx];
+
+    fn deref(&self) -> &[Gfx] {
+        self.as_slice()
+    }
+}
+
+/// What a [`static_dl`] builder returns: a single packet, or an array of
+/// them.
+trait Expansion {
+    fn packets(&self) -> &[Gfx];
+}
+
+impl Expansion for Gfx {
+    fn packets(&self) -> &[Gfx] {
+        core::slice::from_ref(self)
+    }
+}
+
+macro_rules! impl_expansion {
+    ($($len:expr),*) => {
+        $(
+            impl Expansion for [Gfx; $len] {
+                fn packets(&self) -> &[Gfx] {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_expansion!(2, 3, 4, 5, 6, 7, 8, 9);
+
+/// The reasons [`encode`] can fail.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The macro does not exist for the target microcode.
+    Unsupported { id: MacroId, ucode: Ucode },
+    /// The macro was given the wrong number of arguments.
+    ArgCount {
+        id: MacroId,
+        expected: usize,
+        found: usize,
+    },
+    /// The argument at `index` has a value the macro can not encode.
+    InvalidArg { id: MacroId, index: usize },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            EncodeError::Unsupported { id, ucode } => {
+                write!(f, "{:?} is not supported by {}", id, ucode.name())
+            }
+            EncodeError::ArgCount {
+                id,
+                expected,
+                found,
+            } => write!(
+                f,
+                "{:?} takes {} arguments but {} were given",
+                id, expected, found
+            ),
+            EncodeError::InvalidArg { id, index } => {
+                write!(f, "argument {} of {:?} can not be encoded", index, id)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodeError {}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Family {
+    F3d,
+    F3dex,
+    F3dex2,
+}
+
+impl Family {
+    pub(crate) fn of(ucode: Ucode) -> Self {
+        match ucode {
+            Ucode::gfxd_f3d | Ucode::gfxd_f3db => Family::F3d,
+            Ucode::gfxd_f3dex | Ucode::gfxd_f3dexb => Family::F3dex,
+            Ucode::gfxd_f3dex2 => Family::F3dex2,
+        }
+    }
+}
+
+/// Pushes to `$out` the packets of the [`static_dl`] builder of `$id`, for
+/// `$family`, called with the values in `$args`.
+///
+/// Every macro is listed along with its builder, which takes the arguments
+/// in the same order. The builders of the `all` macros exist for every
+/// family, the ones of the `f3dex` macros for `f3dex` and `f3dex2`, and the
+/// ones of the `f3dex2` macros only for `f3dex2`.
+macro_rules! build {
+    (
+        $out:ident, $id:expr, $family:expr, $args:expr;
+        all { $($a_id:ident => $a_name:ident($($a_arg:ident),* $(,)?),)* }
+        f3dex { $($x_id:ident => $x_name:ident($($x_arg:ident),* $(,)?),)* }
+        f3dex2 { $($y_id:ident => $y_name:ident($($y_arg:ident),* $(,)?),)* }
+    ) => {
+        match ($id, $family, $args) {
+            $(
+                (MacroId::$a_id, Family::F3d, &[$($a_arg),*]) => {
+                    $out.extend(&static_dl::f3d::$a_name($($a_arg),*));
+                }
+                (MacroId::$a_id, Family::F3dex, &[$($a_arg),*]) => {
+                    $out.extend(&static_dl::f3dex::$a_name($($a_arg),*));
+                }
+                (MacroId::$a_id, Family::F3dex2, &[$($a_arg),*]) => {
+                    $out.extend(&static_dl::f3dex2::$a_name($($a_arg),*));
+                }
+            )*
+            $(
+                (MacroId::$x_id, Family::F3dex, &[$($x_arg),*]) => {
+                    $out.extend(&static_dl::f3dex::$x_name($($x_arg),*));
+                }
+                (MacroId::$x_id, Family::F3dex2, &[$($x_arg),*]) => {
+                    $out.extend(&static_dl::f3dex2::$x_name($($x_arg),*));
+                }
+            )*
+            $(
+                (MacroId::$y_id, Family::F3dex2, &[$($y_arg),*]) => {
+                    $out.extend(&static_dl::f3dex2::$y_name($($y_arg),*));
+                }
+            )*
+            _ => unreachable!("the macro, family and argument count were checked"),
+        }
+    };
+}
+
+/// Whether `id` exists for `ucode`.
+#[must_use]
+pub fn is_supported(ucode: Ucode, id: MacroId) -> bool {
+    let family = Family::of(ucode);
+    match id {
+        MacroId::gfxd_SP2Triangles
+        | MacroId::gfxd_SP1Quadrangle
+        | MacroId::gfxd_SPBranchLessZraw
+        | MacroId::gfxd_BranchZ
+        | MacroId::gfxd_SPLoadUcode
+        | MacroId::gfxd_SPLoadUcodeEx
+        | MacroId::gfxd_LoadUcode => family != Family::F3d,
+        MacroId::gfxd_SPLoadGeometryMode
+        | MacroId::gfxd_SPGeometryMode
+        | MacroId::gfxd_SPPopMatrixN
+        | MacroId::gfxd_SPDma_io
+        | MacroId::gfxd_SPDmaRead
+        | MacroId::gfxd_SPDmaWrite
+        | MacroId::gfxd_Special3
+        | MacroId::gfxd_Special2
+        | MacroId::gfxd_Special1 => family == Family::F3dex2,
+        MacroId::gfxd_Invalid => false,
+        _ => true,
+    }
+}
+
+/// Encodes the macro `id` for `ucode`.
+///
+/// `args` holds the raw value of every argument, in the order listed by
+/// [`MacroId::args`]. Signed values are passed as their two's complement
+/// representation.
+pub fn encode(ucode: Ucode, id: MacroId, args: &[u32]) -> Result<Packets, EncodeError> {
+    if !is_supported(ucode, id) {
+        return Err(EncodeError::Unsupported { id, ucode });
+    }
+    let expected = id.args().len();
+    if args.len() != expected {
+        return Err(EncodeError::ArgCount {
+            id,
+            expected,
+            found: args.len(),
+        });
+    }
+
+    let family = Family::of(ucode);
+    let invalid = |index| EncodeError::InvalidArg { id, index };
+    let mut out = Packets::new();
+
+    match id {
+        MacroId::gfxd_DPSetCombineMode => {
+            let mode1 = gbi::CC_PRESETS
+                .get(args[0] as usize)
+                .ok_or_else(|| invalid(0))?;
+            let mode2 = gbi::CC_PRESETS
+                .get(args[1] as usize)
+                .ok_or_else(|| invalid(1))?;
+            out.push(static_dl::gsDPSetCombineMode(*mode1, *mode2));
+            return Ok(out);
+        }
+        MacroId::gfxd_SPFogPosition if args[0] as i32 == args[1] as i32 => {
+            return Err(invalid(1));
+        }
+        _ => {}
+    }
+
+    // The texture loading builders look the texel size up in tables.
+    let loads_texture = id.static_name().map_or(false, |x| {
+        x.contains("LoadTexture") || x.contains("LoadMulti")
+    });
+    if loads_texture {
+        if let Some(i) = id.args().iter().position(|x| x.name == "siz") {
+            if args[i] > gbi::G_IM_SIZ_32b {
+                return Err(invalid(i));
+            }
+        }
+    }
+
+    build! {
+        out, id, family, args;
+        all {
+            gfxd_DPFillRectangle => gsDPFillRectangle(ulx, uly, lrx, lry),
+            gfxd_DPFullSync => gsDPFullSync(),
+            gfxd_DPLoadSync => gsDPLoadSync(),
+            gfxd_DPTileSync => gsDPTileSync(),
+            gfxd_DPPipeSync => gsDPPipeSync(),
+            gfxd_DPLoadTLUT_pal16 => gsDPLoadTLUT_pal16(pal, dram),
+            gfxd_DPLoadTLUT_pal256 => gsDPLoadTLUT_pal256(dram),
+            gfxd_DPLoadMultiTileYuv => gsDPLoadMultiTileYuv(
+                timg, tmem, rtile, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt,
+                masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadMultiTile_4b => gsDPLoadMultiTile_4b(
+                timg, tmem, rtile, fmt, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks,
+                maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadMultiTile => gsDPLoadMultiTile(
+                timg, tmem, rtile, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt,
+                masks, maskt, shifts, shiftt,
+            ),
+            gfxd__DPLoadTextureTileYuv => _gsDPLoadTextureTileYuv(
+                timg, tmem, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks,
+                maskt, shifts, shiftt,
+            ),
+            gfxd__DPLoadTextureTile_4b => _gsDPLoadTextureTile_4b(
+                timg, tmem, fmt, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt,
+                shifts, shiftt,
+            ),
+            gfxd__DPLoadTextureTile => _gsDPLoadTextureTile(
+                timg, tmem, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks,
+                maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadTextureTileYuv => gsDPLoadTextureTileYuv(
+                timg, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt,
+                shifts, shiftt,
+            ),
+            gfxd_DPLoadTextureTile_4b => gsDPLoadTextureTile_4b(
+                timg, fmt, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ),
+            gfxd_DPLoadTextureTile => gsDPLoadTextureTile(
+                timg, fmt, siz, width, height, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt,
+                shifts, shiftt,
+            ),
+            gfxd_DPLoadBlock => gsDPLoadBlock(tile, uls, ult, lrs, dxt),
+            gfxd_DPSetBlendColor => gsDPSetBlendColor(r, g, b, a),
+            gfxd_DPSetEnvColor => gsDPSetEnvColor(r, g, b, a),
+            gfxd_DPSetFillColor => gsDPSetFillColor(c),
+            gfxd_DPSetFogColor => gsDPSetFogColor(r, g, b, a),
+            gfxd_DPSetPrimColor => gsDPSetPrimColor(m, l, r, g, b, a),
+            gfxd_DPSetColorImage => gsDPSetColorImage(fmt, siz, width, cimg),
+            gfxd_DPSetDepthImage => gsDPSetDepthImage(zimg),
+            gfxd_DPSetTextureImage => gsDPSetTextureImage(fmt, siz, width, timg),
+            gfxd_DPSetCombineLERP => gsDPSetCombineLERP(
+                a0, b0, c0, d0, aa0, ab0, ac0, ad0, a1, b1, c1, d1, aa1, ab1, ac1, ad1,
+            ),
+            gfxd_DPSetConvert => gsDPSetConvert(k0, k1, k2, k3, k4, k5),
+            gfxd_DPSetKeyGB => gsDPSetKeyGB(cg, sg, wg, cb, sb, wb),
+            gfxd_DPSetKeyR => gsDPSetKeyR(cr, sr, wr),
+            gfxd_DPSetPrimDepth => gsDPSetPrimDepth(z, dz),
+            gfxd_DPSetScissor => gsDPSetScissor(mode, ulx, uly, lrx, lry),
+            gfxd_DPSetScissorFrac => gsDPSetScissorFrac(mode, ulx, uly, lrx, lry),
+            gfxd_DPSetTile => gsDPSetTile(
+                fmt, siz, line, tmem, tile, pal, cmt, maskt, shiftt, cms, masks, shifts,
+            ),
+            gfxd_DPSetTileSize => gsDPSetTileSize(tile, uls, ult, lrs, lrt),
+            gfxd_DPLoadTLUTCmd => gsDPLoadTLUTCmd(tile, count),
+            gfxd_DPLoadTLUT => gsDPLoadTLUT(count, tmem, dram),
+            gfxd_DPLoadTile => gsDPLoadTile(tile, uls, ult, lrs, lrt),
+            gfxd_DPSetOtherMode => gsDPSetOtherMode(hi, lo),
+            gfxd_TexRect => gsTexRect(ulx, uly, lrx, lry, tile),
+            gfxd_TexRectFlip => gsTexRectFlip(ulx, uly, lrx, lry, tile),
+            gfxd_DPLoadMultiBlockYuvS => gsDPLoadMultiBlockYuvS(
+                timg, tmem, rtile, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ),
+            gfxd_DPLoadMultiBlockYuv => gsDPLoadMultiBlockYuv(
+                timg, tmem, rtile, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ),
+            gfxd_DPLoadMultiBlock_4bS => gsDPLoadMultiBlock_4bS(
+                timg, tmem, rtile, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadMultiBlock_4b => gsDPLoadMultiBlock_4b(
+                timg, tmem, rtile, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadMultiBlockS => gsDPLoadMultiBlockS(
+                timg, tmem, rtile, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ),
+            gfxd_DPLoadMultiBlock => gsDPLoadMultiBlock(
+                timg, tmem, rtile, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts,
+                shiftt,
+            ),
+            gfxd__DPLoadTextureBlockYuvS => _gsDPLoadTextureBlockYuvS(
+                timg, tmem, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd__DPLoadTextureBlockYuv => _gsDPLoadTextureBlockYuv(
+                timg, tmem, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd__DPLoadTextureBlock_4bS => _gsDPLoadTextureBlock_4bS(
+                timg, tmem, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd__DPLoadTextureBlock_4b => _gsDPLoadTextureBlock_4b(
+                timg, tmem, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd__DPLoadTextureBlockS => _gsDPLoadTextureBlockS(
+                timg, tmem, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd__DPLoadTextureBlock => _gsDPLoadTextureBlock(
+                timg, tmem, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadTextureBlockYuvS => gsDPLoadTextureBlockYuvS(
+                timg, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadTextureBlockYuv => gsDPLoadTextureBlockYuv(
+                timg, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadTextureBlock_4bS => gsDPLoadTextureBlock_4bS(
+                timg, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadTextureBlock_4b => gsDPLoadTextureBlock_4b(
+                timg, fmt, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadTextureBlockS => gsDPLoadTextureBlockS(
+                timg, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPLoadTextureBlock => gsDPLoadTextureBlock(
+                timg, fmt, siz, width, height, pal, cms, cmt, masks, maskt, shifts, shiftt,
+            ),
+            gfxd_DPNoOp => gsDPNoOp(),
+            gfxd_DPNoOpTag => gsDPNoOpTag(tag),
+            gfxd_DPPipelineMode => gsDPPipelineMode(mode),
+            gfxd_DPSetAlphaCompare => gsDPSetAlphaCompare(mode),
+            gfxd_DPSetAlphaDither => gsDPSetAlphaDither(mode),
+            gfxd_DPSetColorDither => gsDPSetColorDither(mode),
+            gfxd_DPSetTextureConvert => gsDPSetTextureConvert(mode),
+            gfxd_DPSetCycleType => gsDPSetCycleType(mode),
+            gfxd_DPSetDepthSource => gsDPSetDepthSource(mode),
+            gfxd_DPSetCombineKey => gsDPSetCombineKey(mode),
+            gfxd_DPSetRenderMode => gsDPSetRenderMode(mode1, mode2),
+            gfxd_DPSetTextureDetail => gsDPSetTextureDetail(mode),
+            gfxd_DPSetTextureFilter => gsDPSetTextureFilter(mode),
+            gfxd_DPSetTextureLOD => gsDPSetTextureLOD(mode),
+            gfxd_DPSetTextureLUT => gsDPSetTextureLUT(mode),
+            gfxd_DPSetTexturePersp => gsDPSetTexturePersp(mode),
+            gfxd_SP1Triangle => gsSP1Triangle(v0, v1, v2, flag),
+            gfxd_SPBranchList => gsSPBranchList(dl),
+            gfxd_SPClipRatio => gsSPClipRatio(r),
+            gfxd_SPCullDisplayList => gsSPCullDisplayList(vstart, vend),
+            gfxd_SPDisplayList => gsSPDisplayList(dl),
+            gfxd_SPEndDisplayList => gsSPEndDisplayList(),
+            gfxd_SPFogFactor => gsSPFogFactor(fm, fo),
+            gfxd_SPFogPosition => gsSPFogPosition(min, max),
+            gfxd_SPForceMatrix => gsSPForceMatrix(mtx),
+            gfxd_SPSetGeometryMode => gsSPSetGeometryMode(mode),
+            gfxd_SPClearGeometryMode => gsSPClearGeometryMode(mode),
+            gfxd_SPInsertMatrix => gsSPInsertMatrix(r#where, val),
+            gfxd_SPLine3D => gsSPLine3D(v0, v1, flag),
+            gfxd_SPLineW3D => gsSPLineW3D(v0, v1, wd, flag),
+            gfxd_SPLookAtX => gsSPLookAtX(l),
+            gfxd_SPLookAtY => gsSPLookAtY(l),
+            gfxd_SPLookAt => gsSPLookAt(l),
+            gfxd_SPMatrix => gsSPMatrix(mtx, param),
+            gfxd_SPModifyVertex => gsSPModifyVertex(vtx, r#where, val),
+            gfxd_SPPerspNormalize => gsSPPerspNormalize(scale),
+            gfxd_SPPopMatrix => gsSPPopMatrix(param),
+            gfxd_SPSegment => gsSPSegment(seg, base),
+            gfxd_SPSetLights1 => gsSPSetLights1(lights),
+            gfxd_SPSetLights2 => gsSPSetLights2(lights),
+            gfxd_SPSetLights3 => gsSPSetLights3(lights),
+            gfxd_SPSetLights4 => gsSPSetLights4(lights),
+            gfxd_SPSetLights5 => gsSPSetLights5(lights),
+            gfxd_SPSetLights6 => gsSPSetLights6(lights),
+            gfxd_SPSetLights7 => gsSPSetLights7(lights),
+            gfxd_SPNumLights => gsSPNumLights(n),
+            gfxd_SPLight => gsSPLight(light, n),
+            gfxd_SPLightColor => gsSPLightColor(n, color),
+            gfxd_SPTexture => gsSPTexture(sc, tc, level, tile, on),
+            gfxd_SPTextureRectangle => gsSPTextureRectangle(
+                ulx, uly, lrx, lry, tile, s, t, dsdx, dtdy,
+            ),
+            gfxd_SPTextureRectangleFlip => gsSPTextureRectangleFlip(
+                ulx, uly, lrx, lry, tile, s, t, dsdx, dtdy,
+            ),
+            gfxd_SPVertex => gsSPVertex(v, n, v0),
+            gfxd_SPViewport => gsSPViewport(v),
+            gfxd_DisplayList => gsDisplayList(dl, flag),
+            gfxd_DPHalf1 => gsDPHalf1(wordhi),
+            gfxd_DPHalf2 => gsDPHalf2(wordlo),
+            gfxd_DPWord => gsDPWord(wordhi, wordlo),
+            gfxd_SPSetOtherMode => gsSPSetOtherMode(opc, sft, len, data),
+            gfxd_SPSetOtherModeLo => gsSPSetOtherModeLo(sft, len, data),
+            gfxd_SPSetOtherModeHi => gsSPSetOtherModeHi(sft, len, data),
+            gfxd_MoveWd => gsMoveWd(index, offset, data),
+            gfxd_MoveMem => gsMoveMem(size, index, offset, dram),
+            gfxd_SPNoOp => gsSPNoOp(),
+        }
+        f3dex {
+            gfxd_SP2Triangles => gsSP2Triangles(v00, v01, v02, flag0, v10, v11, v12, flag1),
+            gfxd_SP1Quadrangle => gsSP1Quadrangle(v0, v1, v2, v3, flag),
+            gfxd_SPBranchLessZraw => gsSPBranchLessZraw(dl, vtx, zval),
+            gfxd_SPLoadUcode => gsSPLoadUcode(uc_start, uc_dstart),
+            gfxd_BranchZ => gsBranchZ(vtx, zval),
+            gfxd_LoadUcode => gsLoadUcode(uc_start, uc_dsize),
+            gfxd_SPLoadUcodeEx => gsSPLoadUcodeEx(uc_start, uc_dstart, uc_dsize),
+        }
+        f3dex2 {
+            gfxd_SPLoadGeometryMode => gsSPLoadGeometryMode(mode),
+            gfxd_SPPopMatrixN => gsSPPopMatrixN(param, num),
+            gfxd_SPGeometryMode => gsSPGeometryMode(clearbits, setbits),
+            gfxd_SPDma_io => gsSPDma_io(flag, dmem, dram, size),
+            gfxd_SPDmaRead => gsSPDmaRead(dmem, dram, size),
+            gfxd_SPDmaWrite => gsSPDmaWrite(dmem, dram, size),
+            gfxd_Special3 => gsSpecial3(hi, lo),
+            gfxd_Special2 => gsSpecial2(hi, lo),
+            gfxd_Special1 => gsSpecial1(hi, lo),
+        }
+    }
+
+    Ok(out)
+}
+
+/// Encodes `command` for `ucode`.
+///
+/// Unlike [`encode`], [`Command::Invalid`] is accepted and its two words are
+/// emitted verbatim.
+pub fn encode_command(ucode: Ucode, command: &Command) -> Result<Packets, EncodeError> {
+    if let Command::Invalid { hi, lo } = *command {
+        let mut out = Packets::new();
+        out.push(Gfx::new(hi, lo));
+        return Ok(out);
+    }
+    encode(ucode, command.id(), &command.args())
+}
+
+/// Builds a display list out of commands for a single microcode.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayListBuilder {
+    ucode: Ucode,
+    packets: Vec<Gfx>,
+}
+
+#[cfg(feature = "alloc")]
+impl DisplayListBuilder {
+    /// An empty display list targeting `ucode`.
+    #[must_use]
+    pub fn new(ucode: Ucode) -> Self {
+        Self {
+            ucode,
+            packets: Vec::new(),
+        }
+    }
+
+    /// The target microcode.
+    #[must_use]
+    pub fn ucode(&self) -> Ucode {
+        self.ucode
+    }
+
+    /// Appends the packets of `command`.
+    ///
+    /// Nothing is appended if the command can not be encoded.
+    pub fn push(&mut self, command: &Command) -> Result<&mut Self, EncodeError> {
+        let packets = encode_command(self.ucode, command)?;
+        self.packets.extend_from_slice(&packets);
+        Ok(self)
+    }
+
+    /// Appends a single packet as is.
+    pub fn push_raw(&mut self, gfx: Gfx) -> &mut Self {
+        self.packets.push(gfx);
+        self
+    }
+
+    /// The packets appended so far.
+    #[must_use]
+    pub fn packets(&self) -> &[Gfx] {
+        &self.packets
+    }
+
+    /// The packets laid out as described by `endian` and `wordsize`, see
+    /// [`Gfx::to_bytes`].
+    #[must_use]
+    pub fn to_bytes(&self, endian: Endian, wordsize: u32) -> Vec<u8> {
+        crate::gfx::to_byte_vec(&self.packets, endian, wordsize)
+    }
+
+    /// Consumes the builder, returning its packets.
+    #[must_use]
+    pub fn build(self) -> Vec<Gfx> {
+        self.packets
+    }
+}
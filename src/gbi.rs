@@ -0,0 +1,3012 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Constants from the Graphics Binary Interface (`gbi.h`).
+//!
+//! Constants shared by every microcode live at the top level of this module,
+//! while the ones that differ between microcode families live in the
+//! [`f3d`], [`f3dex`] and [`f3dex2`] submodules. Use [`ucode_constants`] to
+//! get the table of names that applies to a given [`Ucode`].
+//!
+//! Every group of constants also has a table of `(name, value)` pairs, in the
+//! same order `gbi.h` defines them.
+//!
+//! [`symbolic_name`] uses those tables to name the raw value of a macro
+//! argument, the way `libgfxd` prints it.
+
+use crate::arg_type::ArgType;
+use crate::settings::Ucode;
+
+// RDP command opcodes
+
+pub const G_SETCIMG: u32 = 0xFF;
+pub const G_SETZIMG: u32 = 0xFE;
+pub const G_SETTIMG: u32 = 0xFD;
+pub const G_SETCOMBINE: u32 = 0xFC;
+pub const G_SETENVCOLOR: u32 = 0xFB;
+pub const G_SETPRIMCOLOR: u32 = 0xFA;
+pub const G_SETBLENDCOLOR: u32 = 0xF9;
+pub const G_SETFOGCOLOR: u32 = 0xF8;
+pub const G_SETFILLCOLOR: u32 = 0xF7;
+pub const G_FILLRECT: u32 = 0xF6;
+pub const G_SETTILE: u32 = 0xF5;
+pub const G_LOADTILE: u32 = 0xF4;
+pub const G_LOADBLOCK: u32 = 0xF3;
+pub const G_SETTILESIZE: u32 = 0xF2;
+pub const G_LOADTLUT: u32 = 0xF0;
+pub const G_RDPSETOTHERMODE: u32 = 0xEF;
+pub const G_SETPRIMDEPTH: u32 = 0xEE;
+pub const G_SETSCISSOR: u32 = 0xED;
+pub const G_SETCONVERT: u32 = 0xEC;
+pub const G_SETKEYR: u32 = 0xEB;
+pub const G_SETKEYGB: u32 = 0xEA;
+pub const G_RDPFULLSYNC: u32 = 0xE9;
+pub const G_RDPTILESYNC: u32 = 0xE8;
+pub const G_RDPPIPESYNC: u32 = 0xE7;
+pub const G_RDPLOADSYNC: u32 = 0xE6;
+pub const G_TEXRECTFLIP: u32 = 0xE5;
+pub const G_TEXRECT: u32 = 0xE4;
+
+pub static RDP_OPCODES: &[(&str, u32)] = &[
+    ("G_SETCIMG", G_SETCIMG),
+    ("G_SETZIMG", G_SETZIMG),
+    ("G_SETTIMG", G_SETTIMG),
+    ("G_SETCOMBINE", G_SETCOMBINE),
+    ("G_SETENVCOLOR", G_SETENVCOLOR),
+    ("G_SETPRIMCOLOR", G_SETPRIMCOLOR),
+    ("G_SETBLENDCOLOR", G_SETBLENDCOLOR),
+    ("G_SETFOGCOLOR", G_SETFOGCOLOR),
+    ("G_SETFILLCOLOR", G_SETFILLCOLOR),
+    ("G_FILLRECT", G_FILLRECT),
+    ("G_SETTILE", G_SETTILE),
+    ("G_LOADTILE", G_LOADTILE),
+    ("G_LOADBLOCK", G_LOADBLOCK),
+    ("G_SETTILESIZE", G_SETTILESIZE),
+    ("G_LOADTLUT", G_LOADTLUT),
+    ("G_RDPSETOTHERMODE", G_RDPSETOTHERMODE),
+    ("G_SETPRIMDEPTH", G_SETPRIMDEPTH),
+    ("G_SETSCISSOR", G_SETSCISSOR),
+    ("G_SETCONVERT", G_SETCONVERT),
+    ("G_SETKEYR", G_SETKEYR),
+    ("G_SETKEYGB", G_SETKEYGB),
+    ("G_RDPFULLSYNC", G_RDPFULLSYNC),
+    ("G_RDPTILESYNC", G_RDPTILESYNC),
+    ("G_RDPPIPESYNC", G_RDPPIPESYNC),
+    ("G_RDPLOADSYNC", G_RDPLOADSYNC),
+    ("G_TEXRECTFLIP", G_TEXRECTFLIP),
+    ("G_TEXRECT", G_TEXRECT),
+];
+
+// Image formats and pixel sizes
+
+pub const G_IM_FMT_RGBA: u32 = 0;
+pub const G_IM_FMT_YUV: u32 = 1;
+pub const G_IM_FMT_CI: u32 = 2;
+pub const G_IM_FMT_IA: u32 = 3;
+pub const G_IM_FMT_I: u32 = 4;
+pub const G_IM_SIZ_4b: u32 = 0;
+pub const G_IM_SIZ_8b: u32 = 1;
+pub const G_IM_SIZ_16b: u32 = 2;
+pub const G_IM_SIZ_32b: u32 = 3;
+pub const G_IM_SIZ_DD: u32 = 5;
+
+pub static IM_FMT: &[(&str, u32)] = &[
+    ("G_IM_FMT_RGBA", G_IM_FMT_RGBA),
+    ("G_IM_FMT_YUV", G_IM_FMT_YUV),
+    ("G_IM_FMT_CI", G_IM_FMT_CI),
+    ("G_IM_FMT_IA", G_IM_FMT_IA),
+    ("G_IM_FMT_I", G_IM_FMT_I),
+];
+pub static IM_SIZ: &[(&str, u32)] = &[
+    ("G_IM_SIZ_4b", G_IM_SIZ_4b),
+    ("G_IM_SIZ_8b", G_IM_SIZ_8b),
+    ("G_IM_SIZ_16b", G_IM_SIZ_16b),
+    ("G_IM_SIZ_32b", G_IM_SIZ_32b),
+    ("G_IM_SIZ_DD", G_IM_SIZ_DD),
+];
+
+// Tile descriptor flags
+
+pub const G_TX_NOMIRROR: u32 = 0;
+pub const G_TX_WRAP: u32 = 0;
+pub const G_TX_MIRROR: u32 = 0x1;
+pub const G_TX_CLAMP: u32 = 0x2;
+pub const G_TX_NOMASK: u32 = 0;
+pub const G_TX_NOLOD: u32 = 0;
+pub const G_TX_LOADTILE: u32 = 7;
+pub const G_TX_RENDERTILE: u32 = 0;
+pub const G_TX_DXT_FRAC: u32 = 11;
+pub const G_TEXTURE_IMAGE_FRAC: u32 = 2;
+pub const G_TEXTURE_SCALE_FRAC: u32 = 16;
+pub const G_SCALE_FRAC: u32 = 8;
+pub const G_ROTATE_FRAC: u32 = 16;
+
+pub static TX_CM: &[(&str, u32)] = &[
+    ("G_TX_NOMIRROR", G_TX_NOMIRROR),
+    ("G_TX_WRAP", G_TX_WRAP),
+    ("G_TX_MIRROR", G_TX_MIRROR),
+    ("G_TX_CLAMP", G_TX_CLAMP),
+];
+pub static TX_TILE: &[(&str, u32)] = &[
+    ("G_TX_LOADTILE", G_TX_LOADTILE),
+    ("G_TX_RENDERTILE", G_TX_RENDERTILE),
+];
+
+// Miscellaneous
+
+pub const G_ON: u32 = 1;
+pub const G_OFF: u32 = 0;
+pub const G_DL_PUSH: u32 = 0x00;
+pub const G_DL_NOPUSH: u32 = 0x01;
+pub const G_MAXFBZ: u32 = 0x3FFF;
+pub const BOWTIE_VAL: u32 = 0;
+
+pub static SWITCH: &[(&str, u32)] = &[("G_ON", G_ON), ("G_OFF", G_OFF)];
+pub static DL_FLAG: &[(&str, u32)] = &[("G_DL_PUSH", G_DL_PUSH), ("G_DL_NOPUSH", G_DL_NOPUSH)];
+
+// Scissor modes
+
+pub const G_SC_NON_INTERLACE: u32 = 0;
+pub const G_SC_ODD_INTERLACE: u32 = 3;
+pub const G_SC_EVEN_INTERLACE: u32 = 2;
+
+pub static SC: &[(&str, u32)] = &[
+    ("G_SC_NON_INTERLACE", G_SC_NON_INTERLACE),
+    ("G_SC_ODD_INTERLACE", G_SC_ODD_INTERLACE),
+    ("G_SC_EVEN_INTERLACE", G_SC_EVEN_INTERLACE),
+];
+
+// Othermode shifts
+
+pub const G_MDSFT_ALPHACOMPARE: u32 = 0;
+pub const G_MDSFT_ZSRCSEL: u32 = 2;
+pub const G_MDSFT_RENDERMODE: u32 = 3;
+pub const G_MDSFT_BLENDER: u32 = 16;
+pub const G_MDSFT_BLENDMASK: u32 = 0;
+pub const G_MDSFT_ALPHADITHER: u32 = 4;
+pub const G_MDSFT_RGBDITHER: u32 = 6;
+pub const G_MDSFT_COMBKEY: u32 = 8;
+pub const G_MDSFT_TEXTCONV: u32 = 9;
+pub const G_MDSFT_TEXTFILT: u32 = 12;
+pub const G_MDSFT_TEXTLUT: u32 = 14;
+pub const G_MDSFT_TEXTLOD: u32 = 16;
+pub const G_MDSFT_TEXTDETAIL: u32 = 17;
+pub const G_MDSFT_TEXTPERSP: u32 = 19;
+pub const G_MDSFT_CYCLETYPE: u32 = 20;
+pub const G_MDSFT_COLORDITHER: u32 = 22;
+pub const G_MDSFT_PIPELINE: u32 = 23;
+
+pub static MDSFT_LO: &[(&str, u32)] = &[
+    ("G_MDSFT_ALPHACOMPARE", G_MDSFT_ALPHACOMPARE),
+    ("G_MDSFT_ZSRCSEL", G_MDSFT_ZSRCSEL),
+    ("G_MDSFT_RENDERMODE", G_MDSFT_RENDERMODE),
+    ("G_MDSFT_BLENDER", G_MDSFT_BLENDER),
+];
+pub static MDSFT_HI: &[(&str, u32)] = &[
+    ("G_MDSFT_BLENDMASK", G_MDSFT_BLENDMASK),
+    ("G_MDSFT_ALPHADITHER", G_MDSFT_ALPHADITHER),
+    ("G_MDSFT_RGBDITHER", G_MDSFT_RGBDITHER),
+    ("G_MDSFT_COMBKEY", G_MDSFT_COMBKEY),
+    ("G_MDSFT_TEXTCONV", G_MDSFT_TEXTCONV),
+    ("G_MDSFT_TEXTFILT", G_MDSFT_TEXTFILT),
+    ("G_MDSFT_TEXTLUT", G_MDSFT_TEXTLUT),
+    ("G_MDSFT_TEXTLOD", G_MDSFT_TEXTLOD),
+    ("G_MDSFT_TEXTDETAIL", G_MDSFT_TEXTDETAIL),
+    ("G_MDSFT_TEXTPERSP", G_MDSFT_TEXTPERSP),
+    ("G_MDSFT_CYCLETYPE", G_MDSFT_CYCLETYPE),
+    ("G_MDSFT_COLORDITHER", G_MDSFT_COLORDITHER),
+    ("G_MDSFT_PIPELINE", G_MDSFT_PIPELINE),
+];
+
+// Othermode values
+
+pub const G_PM_1PRIMITIVE: u32 = 1 << G_MDSFT_PIPELINE;
+pub const G_PM_NPRIMITIVE: u32 = 0 << G_MDSFT_PIPELINE;
+pub const G_CYC_1CYCLE: u32 = 0 << G_MDSFT_CYCLETYPE;
+pub const G_CYC_2CYCLE: u32 = 1 << G_MDSFT_CYCLETYPE;
+pub const G_CYC_COPY: u32 = 2 << G_MDSFT_CYCLETYPE;
+pub const G_CYC_FILL: u32 = 3 << G_MDSFT_CYCLETYPE;
+pub const G_TP_NONE: u32 = 0 << G_MDSFT_TEXTPERSP;
+pub const G_TP_PERSP: u32 = 1 << G_MDSFT_TEXTPERSP;
+pub const G_TD_CLAMP: u32 = 0 << G_MDSFT_TEXTDETAIL;
+pub const G_TD_SHARPEN: u32 = 1 << G_MDSFT_TEXTDETAIL;
+pub const G_TD_DETAIL: u32 = 2 << G_MDSFT_TEXTDETAIL;
+pub const G_TL_TILE: u32 = 0 << G_MDSFT_TEXTLOD;
+pub const G_TL_LOD: u32 = 1 << G_MDSFT_TEXTLOD;
+pub const G_TT_NONE: u32 = 0 << G_MDSFT_TEXTLUT;
+pub const G_TT_RGBA16: u32 = 2 << G_MDSFT_TEXTLUT;
+pub const G_TT_IA16: u32 = 3 << G_MDSFT_TEXTLUT;
+pub const G_TF_POINT: u32 = 0 << G_MDSFT_TEXTFILT;
+pub const G_TF_AVERAGE: u32 = 3 << G_MDSFT_TEXTFILT;
+pub const G_TF_BILERP: u32 = 2 << G_MDSFT_TEXTFILT;
+pub const G_TC_CONV: u32 = 0 << G_MDSFT_TEXTCONV;
+pub const G_TC_FILTCONV: u32 = 5 << G_MDSFT_TEXTCONV;
+pub const G_TC_FILT: u32 = 6 << G_MDSFT_TEXTCONV;
+pub const G_CK_NONE: u32 = 0 << G_MDSFT_COMBKEY;
+pub const G_CK_KEY: u32 = 1 << G_MDSFT_COMBKEY;
+pub const G_CD_MAGICSQ: u32 = 0 << G_MDSFT_RGBDITHER;
+pub const G_CD_BAYER: u32 = 1 << G_MDSFT_RGBDITHER;
+pub const G_CD_NOISE: u32 = 2 << G_MDSFT_RGBDITHER;
+pub const G_CD_DISABLE: u32 = 3 << G_MDSFT_RGBDITHER;
+pub const G_AD_PATTERN: u32 = 0 << G_MDSFT_ALPHADITHER;
+pub const G_AD_NOTPATTERN: u32 = 1 << G_MDSFT_ALPHADITHER;
+pub const G_AD_NOISE: u32 = 2 << G_MDSFT_ALPHADITHER;
+pub const G_AD_DISABLE: u32 = 3 << G_MDSFT_ALPHADITHER;
+pub const G_AC_NONE: u32 = 0 << G_MDSFT_ALPHACOMPARE;
+pub const G_AC_THRESHOLD: u32 = 1 << G_MDSFT_ALPHACOMPARE;
+pub const G_AC_DITHER: u32 = 3 << G_MDSFT_ALPHACOMPARE;
+pub const G_ZS_PIXEL: u32 = 0 << G_MDSFT_ZSRCSEL;
+pub const G_ZS_PRIM: u32 = 1 << G_MDSFT_ZSRCSEL;
+
+pub static PM: &[(&str, u32)] = &[
+    ("G_PM_1PRIMITIVE", G_PM_1PRIMITIVE),
+    ("G_PM_NPRIMITIVE", G_PM_NPRIMITIVE),
+];
+pub static CYC: &[(&str, u32)] = &[
+    ("G_CYC_1CYCLE", G_CYC_1CYCLE),
+    ("G_CYC_2CYCLE", G_CYC_2CYCLE),
+    ("G_CYC_COPY", G_CYC_COPY),
+    ("G_CYC_FILL", G_CYC_FILL),
+];
+pub static TP: &[(&str, u32)] = &[("G_TP_NONE", G_TP_NONE), ("G_TP_PERSP", G_TP_PERSP)];
+pub static TD: &[(&str, u32)] = &[
+    ("G_TD_CLAMP", G_TD_CLAMP),
+    ("G_TD_SHARPEN", G_TD_SHARPEN),
+    ("G_TD_DETAIL", G_TD_DETAIL),
+];
+pub static TL: &[(&str, u32)] = &[("G_TL_TILE", G_TL_TILE), ("G_TL_LOD", G_TL_LOD)];
+pub static TT: &[(&str, u32)] = &[
+    ("G_TT_NONE", G_TT_NONE),
+    ("G_TT_RGBA16", G_TT_RGBA16),
+    ("G_TT_IA16", G_TT_IA16),
+];
+pub static TF: &[(&str, u32)] = &[
+    ("G_TF_POINT", G_TF_POINT),
+    ("G_TF_AVERAGE", G_TF_AVERAGE),
+    ("G_TF_BILERP", G_TF_BILERP),
+];
+pub static TC: &[(&str, u32)] = &[
+    ("G_TC_CONV", G_TC_CONV),
+    ("G_TC_FILTCONV", G_TC_FILTCONV),
+    ("G_TC_FILT", G_TC_FILT),
+];
+pub static CK: &[(&str, u32)] = &[("G_CK_NONE", G_CK_NONE), ("G_CK_KEY", G_CK_KEY)];
+pub static CD: &[(&str, u32)] = &[
+    ("G_CD_MAGICSQ", G_CD_MAGICSQ),
+    ("G_CD_BAYER", G_CD_BAYER),
+    ("G_CD_NOISE", G_CD_NOISE),
+    ("G_CD_DISABLE", G_CD_DISABLE),
+];
+pub static AD: &[(&str, u32)] = &[
+    ("G_AD_PATTERN", G_AD_PATTERN),
+    ("G_AD_NOTPATTERN", G_AD_NOTPATTERN),
+    ("G_AD_NOISE", G_AD_NOISE),
+    ("G_AD_DISABLE", G_AD_DISABLE),
+];
+pub static AC: &[(&str, u32)] = &[
+    ("G_AC_NONE", G_AC_NONE),
+    ("G_AC_THRESHOLD", G_AC_THRESHOLD),
+    ("G_AC_DITHER", G_AC_DITHER),
+];
+pub static ZS: &[(&str, u32)] = &[("G_ZS_PIXEL", G_ZS_PIXEL), ("G_ZS_PRIM", G_ZS_PRIM)];
+
+// Render mode flags and blender inputs
+
+pub const AA_EN: u32 = 0x8;
+pub const Z_CMP: u32 = 0x10;
+pub const Z_UPD: u32 = 0x20;
+pub const IM_RD: u32 = 0x40;
+pub const CLR_ON_CVG: u32 = 0x80;
+pub const CVG_DST_CLAMP: u32 = 0;
+pub const CVG_DST_WRAP: u32 = 0x100;
+pub const CVG_DST_FULL: u32 = 0x200;
+pub const CVG_DST_SAVE: u32 = 0x300;
+pub const ZMODE_OPA: u32 = 0;
+pub const ZMODE_INTER: u32 = 0x400;
+pub const ZMODE_XLU: u32 = 0x800;
+pub const ZMODE_DEC: u32 = 0xC00;
+pub const CVG_X_ALPHA: u32 = 0x1000;
+pub const ALPHA_CVG_SEL: u32 = 0x2000;
+pub const FORCE_BL: u32 = 0x4000;
+pub const TEX_EDGE: u32 = 0x0000;
+pub const G_BL_CLR_IN: u32 = 0;
+pub const G_BL_CLR_MEM: u32 = 1;
+pub const G_BL_CLR_BL: u32 = 2;
+pub const G_BL_CLR_FOG: u32 = 3;
+pub const G_BL_1MA: u32 = 0;
+pub const G_BL_A_MEM: u32 = 1;
+pub const G_BL_A_IN: u32 = 0;
+pub const G_BL_A_FOG: u32 = 1;
+pub const G_BL_A_SHADE: u32 = 2;
+pub const G_BL_1: u32 = 2;
+pub const G_BL_0: u32 = 3;
+
+pub static RM_FLAGS: &[(&str, u32)] = &[
+    ("AA_EN", AA_EN),
+    ("Z_CMP", Z_CMP),
+    ("Z_UPD", Z_UPD),
+    ("IM_RD", IM_RD),
+    ("CLR_ON_CVG", CLR_ON_CVG),
+    ("CVG_DST_CLAMP", CVG_DST_CLAMP),
+    ("CVG_DST_WRAP", CVG_DST_WRAP),
+    ("CVG_DST_FULL", CVG_DST_FULL),
+    ("CVG_DST_SAVE", CVG_DST_SAVE),
+    ("ZMODE_OPA", ZMODE_OPA),
+    ("ZMODE_INTER", ZMODE_INTER),
+    ("ZMODE_XLU", ZMODE_XLU),
+    ("ZMODE_DEC", ZMODE_DEC),
+    ("CVG_X_ALPHA", CVG_X_ALPHA),
+    ("ALPHA_CVG_SEL", ALPHA_CVG_SEL),
+    ("FORCE_BL", FORCE_BL),
+    ("TEX_EDGE", TEX_EDGE),
+];
+pub static BL: &[(&str, u32)] = &[
+    ("G_BL_CLR_IN", G_BL_CLR_IN),
+    ("G_BL_CLR_MEM", G_BL_CLR_MEM),
+    ("G_BL_CLR_BL", G_BL_CLR_BL),
+    ("G_BL_CLR_FOG", G_BL_CLR_FOG),
+    ("G_BL_1MA", G_BL_1MA),
+    ("G_BL_A_MEM", G_BL_A_MEM),
+    ("G_BL_A_IN", G_BL_A_IN),
+    ("G_BL_A_FOG", G_BL_A_FOG),
+    ("G_BL_A_SHADE", G_BL_A_SHADE),
+    ("G_BL_1", G_BL_1),
+    ("G_BL_0", G_BL_0),
+];
+
+/// Blender configuration for the first cycle, `GBL_c1` in `gbi.h`.
+#[allow(non_snake_case)]
+#[must_use]
+pub const fn GBL_c1(m1a: u32, m1b: u32, m2a: u32, m2b: u32) -> u32 {
+    (m1a << 30) | (m1b << 26) | (m2a << 22) | (m2b << 18)
+}
+
+/// Blender configuration for the second cycle, `GBL_c2` in `gbi.h`.
+#[allow(non_snake_case)]
+#[must_use]
+pub const fn GBL_c2(m1a: u32, m1b: u32, m2a: u32, m2b: u32) -> u32 {
+    (m1a << 28) | (m1b << 24) | (m2a << 20) | (m2b << 16)
+}
+
+// Render mode presets
+
+pub const G_RM_AA_ZB_OPA_SURF: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_OPA_SURF2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_RA_ZB_OPA_SURF: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_RA_ZB_OPA_SURF2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_XLU_SURF: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_WRAP
+    | CLR_ON_CVG
+    | FORCE_BL
+    | ZMODE_XLU
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_XLU_SURF2: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_WRAP
+    | CLR_ON_CVG
+    | FORCE_BL
+    | ZMODE_XLU
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_OPA_DECAL: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_WRAP
+    | ALPHA_CVG_SEL
+    | ZMODE_DEC
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_OPA_DECAL2: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_WRAP
+    | ALPHA_CVG_SEL
+    | ZMODE_DEC
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_RA_ZB_OPA_DECAL: u32 = AA_EN
+    | Z_CMP
+    | CVG_DST_WRAP
+    | ALPHA_CVG_SEL
+    | ZMODE_DEC
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_RA_ZB_OPA_DECAL2: u32 = AA_EN
+    | Z_CMP
+    | CVG_DST_WRAP
+    | ALPHA_CVG_SEL
+    | ZMODE_DEC
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_XLU_DECAL: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_WRAP
+    | CLR_ON_CVG
+    | FORCE_BL
+    | ZMODE_DEC
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_XLU_DECAL2: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_WRAP
+    | CLR_ON_CVG
+    | FORCE_BL
+    | ZMODE_DEC
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_OPA_INTER: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ALPHA_CVG_SEL
+    | ZMODE_INTER
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_OPA_INTER2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ALPHA_CVG_SEL
+    | ZMODE_INTER
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_RA_ZB_OPA_INTER: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | CVG_DST_CLAMP
+    | ALPHA_CVG_SEL
+    | ZMODE_INTER
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_RA_ZB_OPA_INTER2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | CVG_DST_CLAMP
+    | ALPHA_CVG_SEL
+    | ZMODE_INTER
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_XLU_INTER: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_WRAP
+    | CLR_ON_CVG
+    | FORCE_BL
+    | ZMODE_INTER
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_XLU_INTER2: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_WRAP
+    | CLR_ON_CVG
+    | FORCE_BL
+    | ZMODE_INTER
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_XLU_LINE: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_XLU
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_XLU_LINE2: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_XLU
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_DEC_LINE: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_SAVE
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_DEC
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_DEC_LINE2: u32 = AA_EN
+    | Z_CMP
+    | IM_RD
+    | CVG_DST_SAVE
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_DEC
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_TEX_EDGE: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_TEX_EDGE2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_TEX_INTER: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_INTER
+    | TEX_EDGE
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_TEX_INTER2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_INTER
+    | TEX_EDGE
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_SUB_SURF: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_SUB_SURF2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_ZB_PCL_SURF: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | G_AC_DITHER
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_PCL_SURF2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | G_AC_DITHER
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_OPA_TERR: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_OPA_TERR2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_TEX_TERR: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_TEX_TERR2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_SUB_TERR: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_ZB_SUB_TERR2: u32 = AA_EN
+    | Z_CMP
+    | Z_UPD
+    | IM_RD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_OPA_SURF: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_OPA_SURF2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_RA_OPA_SURF: u32 = AA_EN
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_RA_OPA_SURF2: u32 = AA_EN
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_XLU_SURF: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_WRAP
+    | CLR_ON_CVG
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_XLU_SURF2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_WRAP
+    | CLR_ON_CVG
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_XLU_LINE: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_XLU_LINE2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_DEC_LINE: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_FULL
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_DEC_LINE2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_FULL
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_TEX_EDGE: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_TEX_EDGE2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_SUB_SURF: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_SUB_SURF2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_AA_PCL_SURF: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | G_AC_DITHER
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_PCL_SURF2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | G_AC_DITHER
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_OPA_TERR: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_OPA_TERR2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_TEX_TERR: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_TEX_TERR2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_SUB_TERR: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_AA_SUB_TERR2: u32 = AA_EN
+    | IM_RD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | ALPHA_CVG_SEL
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_OPA_SURF: u32 = Z_CMP
+    | Z_UPD
+    | CVG_DST_FULL
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_ZB_OPA_SURF2: u32 = Z_CMP
+    | Z_UPD
+    | CVG_DST_FULL
+    | ALPHA_CVG_SEL
+    | ZMODE_OPA
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_ZB_XLU_SURF: u32 = Z_CMP
+    | IM_RD
+    | CVG_DST_FULL
+    | FORCE_BL
+    | ZMODE_XLU
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_XLU_SURF2: u32 = Z_CMP
+    | IM_RD
+    | CVG_DST_FULL
+    | FORCE_BL
+    | ZMODE_XLU
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_OPA_DECAL: u32 = Z_CMP
+    | CVG_DST_FULL
+    | ALPHA_CVG_SEL
+    | ZMODE_DEC
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_ZB_OPA_DECAL2: u32 = Z_CMP
+    | CVG_DST_FULL
+    | ALPHA_CVG_SEL
+    | ZMODE_DEC
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_A_MEM);
+pub const G_RM_ZB_XLU_DECAL: u32 = Z_CMP
+    | IM_RD
+    | CVG_DST_FULL
+    | FORCE_BL
+    | ZMODE_DEC
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_XLU_DECAL2: u32 = Z_CMP
+    | IM_RD
+    | CVG_DST_FULL
+    | FORCE_BL
+    | ZMODE_DEC
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_CLD_SURF: u32 = Z_CMP
+    | IM_RD
+    | CVG_DST_SAVE
+    | FORCE_BL
+    | ZMODE_XLU
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_CLD_SURF2: u32 = Z_CMP
+    | IM_RD
+    | CVG_DST_SAVE
+    | FORCE_BL
+    | ZMODE_XLU
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_OVL_SURF: u32 = Z_CMP
+    | IM_RD
+    | CVG_DST_SAVE
+    | FORCE_BL
+    | ZMODE_DEC
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_OVL_SURF2: u32 = Z_CMP
+    | IM_RD
+    | CVG_DST_SAVE
+    | FORCE_BL
+    | ZMODE_DEC
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_PCL_SURF: u32 = Z_CMP
+    | Z_UPD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | G_AC_DITHER
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ZB_PCL_SURF2: u32 = Z_CMP
+    | Z_UPD
+    | CVG_DST_FULL
+    | ZMODE_OPA
+    | G_AC_DITHER
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_OPA_SURF: u32 =
+    CVG_DST_CLAMP | FORCE_BL | ZMODE_OPA | GBL_c1(G_BL_CLR_IN, G_BL_0, G_BL_CLR_IN, G_BL_1);
+pub const G_RM_OPA_SURF2: u32 =
+    CVG_DST_CLAMP | FORCE_BL | ZMODE_OPA | GBL_c2(G_BL_CLR_IN, G_BL_0, G_BL_CLR_IN, G_BL_1);
+pub const G_RM_XLU_SURF: u32 = IM_RD
+    | CVG_DST_FULL
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_XLU_SURF2: u32 = IM_RD
+    | CVG_DST_FULL
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_TEX_EDGE: u32 = CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | AA_EN
+    | GBL_c1(G_BL_CLR_IN, G_BL_0, G_BL_CLR_IN, G_BL_1);
+pub const G_RM_TEX_EDGE2: u32 = CVG_DST_CLAMP
+    | CVG_X_ALPHA
+    | ALPHA_CVG_SEL
+    | FORCE_BL
+    | ZMODE_OPA
+    | TEX_EDGE
+    | AA_EN
+    | GBL_c2(G_BL_CLR_IN, G_BL_0, G_BL_CLR_IN, G_BL_1);
+pub const G_RM_CLD_SURF: u32 = IM_RD
+    | CVG_DST_SAVE
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_CLD_SURF2: u32 = IM_RD
+    | CVG_DST_SAVE
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_PCL_SURF: u32 = CVG_DST_FULL
+    | FORCE_BL
+    | ZMODE_OPA
+    | G_AC_DITHER
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_PCL_SURF2: u32 = CVG_DST_FULL
+    | FORCE_BL
+    | ZMODE_OPA
+    | G_AC_DITHER
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_IN, G_BL_CLR_MEM, G_BL_1MA);
+pub const G_RM_ADD: u32 = IM_RD
+    | CVG_DST_SAVE
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c1(G_BL_CLR_IN, G_BL_A_FOG, G_BL_CLR_MEM, G_BL_1);
+pub const G_RM_ADD2: u32 = IM_RD
+    | CVG_DST_SAVE
+    | FORCE_BL
+    | ZMODE_OPA
+    | GBL_c2(G_BL_CLR_IN, G_BL_A_FOG, G_BL_CLR_MEM, G_BL_1);
+pub const G_RM_NOOP: u32 = GBL_c1(0, 0, 0, 0);
+pub const G_RM_NOOP2: u32 = GBL_c2(0, 0, 0, 0);
+pub const G_RM_VISCVG: u32 =
+    IM_RD | FORCE_BL | GBL_c1(G_BL_CLR_IN, G_BL_0, G_BL_CLR_BL, G_BL_A_MEM);
+pub const G_RM_VISCVG2: u32 =
+    IM_RD | FORCE_BL | GBL_c2(G_BL_CLR_IN, G_BL_0, G_BL_CLR_BL, G_BL_A_MEM);
+pub const G_RM_OPA_CI: u32 =
+    CVG_DST_CLAMP | ZMODE_OPA | GBL_c1(G_BL_CLR_IN, G_BL_0, G_BL_CLR_IN, G_BL_1);
+pub const G_RM_OPA_CI2: u32 =
+    CVG_DST_CLAMP | ZMODE_OPA | GBL_c2(G_BL_CLR_IN, G_BL_0, G_BL_CLR_IN, G_BL_1);
+pub const G_RM_FOG_SHADE_A: u32 = GBL_c1(G_BL_CLR_FOG, G_BL_A_SHADE, G_BL_CLR_IN, G_BL_1MA);
+pub const G_RM_FOG_PRIM_A: u32 = GBL_c1(G_BL_CLR_FOG, G_BL_A_FOG, G_BL_CLR_IN, G_BL_1MA);
+pub const G_RM_PASS: u32 = GBL_c1(G_BL_CLR_IN, G_BL_0, G_BL_CLR_IN, G_BL_1);
+
+/// Render mode presets for the first cycle.
+pub static RENDER_MODES_1: &[(&str, u32)] = &[
+    ("G_RM_AA_ZB_OPA_SURF", G_RM_AA_ZB_OPA_SURF),
+    ("G_RM_RA_ZB_OPA_SURF", G_RM_RA_ZB_OPA_SURF),
+    ("G_RM_AA_ZB_XLU_SURF", G_RM_AA_ZB_XLU_SURF),
+    ("G_RM_AA_ZB_OPA_DECAL", G_RM_AA_ZB_OPA_DECAL),
+    ("G_RM_RA_ZB_OPA_DECAL", G_RM_RA_ZB_OPA_DECAL),
+    ("G_RM_AA_ZB_XLU_DECAL", G_RM_AA_ZB_XLU_DECAL),
+    ("G_RM_AA_ZB_OPA_INTER", G_RM_AA_ZB_OPA_INTER),
+    ("G_RM_RA_ZB_OPA_INTER", G_RM_RA_ZB_OPA_INTER),
+    ("G_RM_AA_ZB_XLU_INTER", G_RM_AA_ZB_XLU_INTER),
+    ("G_RM_AA_ZB_XLU_LINE", G_RM_AA_ZB_XLU_LINE),
+    ("G_RM_AA_ZB_DEC_LINE", G_RM_AA_ZB_DEC_LINE),
+    ("G_RM_AA_ZB_TEX_EDGE", G_RM_AA_ZB_TEX_EDGE),
+    ("G_RM_AA_ZB_TEX_INTER", G_RM_AA_ZB_TEX_INTER),
+    ("G_RM_AA_ZB_SUB_SURF", G_RM_AA_ZB_SUB_SURF),
+    ("G_RM_AA_ZB_PCL_SURF", G_RM_AA_ZB_PCL_SURF),
+    ("G_RM_AA_ZB_OPA_TERR", G_RM_AA_ZB_OPA_TERR),
+    ("G_RM_AA_ZB_TEX_TERR", G_RM_AA_ZB_TEX_TERR),
+    ("G_RM_AA_ZB_SUB_TERR", G_RM_AA_ZB_SUB_TERR),
+    ("G_RM_AA_OPA_SURF", G_RM_AA_OPA_SURF),
+    ("G_RM_RA_OPA_SURF", G_RM_RA_OPA_SURF),
+    ("G_RM_AA_XLU_SURF", G_RM_AA_XLU_SURF),
+    ("G_RM_AA_XLU_LINE", G_RM_AA_XLU_LINE),
+    ("G_RM_AA_DEC_LINE", G_RM_AA_DEC_LINE),
+    ("G_RM_AA_TEX_EDGE", G_RM_AA_TEX_EDGE),
+    ("G_RM_AA_SUB_SURF", G_RM_AA_SUB_SURF),
+    ("G_RM_AA_PCL_SURF", G_RM_AA_PCL_SURF),
+    ("G_RM_AA_OPA_TERR", G_RM_AA_OPA_TERR),
+    ("G_RM_AA_TEX_TERR", G_RM_AA_TEX_TERR),
+    ("G_RM_AA_SUB_TERR", G_RM_AA_SUB_TERR),
+    ("G_RM_ZB_OPA_SURF", G_RM_ZB_OPA_SURF),
+    ("G_RM_ZB_XLU_SURF", G_RM_ZB_XLU_SURF),
+    ("G_RM_ZB_OPA_DECAL", G_RM_ZB_OPA_DECAL),
+    ("G_RM_ZB_XLU_DECAL", G_RM_ZB_XLU_DECAL),
+    ("G_RM_ZB_CLD_SURF", G_RM_ZB_CLD_SURF),
+    ("G_RM_ZB_OVL_SURF", G_RM_ZB_OVL_SURF),
+    ("G_RM_ZB_PCL_SURF", G_RM_ZB_PCL_SURF),
+    ("G_RM_OPA_SURF", G_RM_OPA_SURF),
+    ("G_RM_XLU_SURF", G_RM_XLU_SURF),
+    ("G_RM_TEX_EDGE", G_RM_TEX_EDGE),
+    ("G_RM_CLD_SURF", G_RM_CLD_SURF),
+    ("G_RM_PCL_SURF", G_RM_PCL_SURF),
+    ("G_RM_ADD", G_RM_ADD),
+    ("G_RM_NOOP", G_RM_NOOP),
+    ("G_RM_VISCVG", G_RM_VISCVG),
+    ("G_RM_OPA_CI", G_RM_OPA_CI),
+    ("G_RM_FOG_SHADE_A", G_RM_FOG_SHADE_A),
+    ("G_RM_FOG_PRIM_A", G_RM_FOG_PRIM_A),
+    ("G_RM_PASS", G_RM_PASS),
+];
+/// Render mode presets for the second cycle.
+pub static RENDER_MODES_2: &[(&str, u32)] = &[
+    ("G_RM_AA_ZB_OPA_SURF2", G_RM_AA_ZB_OPA_SURF2),
+    ("G_RM_RA_ZB_OPA_SURF2", G_RM_RA_ZB_OPA_SURF2),
+    ("G_RM_AA_ZB_XLU_SURF2", G_RM_AA_ZB_XLU_SURF2),
+    ("G_RM_AA_ZB_OPA_DECAL2", G_RM_AA_ZB_OPA_DECAL2),
+    ("G_RM_RA_ZB_OPA_DECAL2", G_RM_RA_ZB_OPA_DECAL2),
+    ("G_RM_AA_ZB_XLU_DECAL2", G_RM_AA_ZB_XLU_DECAL2),
+    ("G_RM_AA_ZB_OPA_INTER2", G_RM_AA_ZB_OPA_INTER2),
+    ("G_RM_RA_ZB_OPA_INTER2", G_RM_RA_ZB_OPA_INTER2),
+    ("G_RM_AA_ZB_XLU_INTER2", G_RM_AA_ZB_XLU_INTER2),
+    ("G_RM_AA_ZB_XLU_LINE2", G_RM_AA_ZB_XLU_LINE2),
+    ("G_RM_AA_ZB_DEC_LINE2", G_RM_AA_ZB_DEC_LINE2),
+    ("G_RM_AA_ZB_TEX_EDGE2", G_RM_AA_ZB_TEX_EDGE2),
+    ("G_RM_AA_ZB_TEX_INTER2", G_RM_AA_ZB_TEX_INTER2),
+    ("G_RM_AA_ZB_SUB_SURF2", G_RM_AA_ZB_SUB_SURF2),
+    ("G_RM_AA_ZB_PCL_SURF2", G_RM_AA_ZB_PCL_SURF2),
+    ("G_RM_AA_ZB_OPA_TERR2", G_RM_AA_ZB_OPA_TERR2),
+    ("G_RM_AA_ZB_TEX_TERR2", G_RM_AA_ZB_TEX_TERR2),
+    ("G_RM_AA_ZB_SUB_TERR2", G_RM_AA_ZB_SUB_TERR2),
+    ("G_RM_AA_OPA_SURF2", G_RM_AA_OPA_SURF2),
+    ("G_RM_RA_OPA_SURF2", G_RM_RA_OPA_SURF2),
+    ("G_RM_AA_XLU_SURF2", G_RM_AA_XLU_SURF2),
+    ("G_RM_AA_XLU_LINE2", G_RM_AA_XLU_LINE2),
+    ("G_RM_AA_DEC_LINE2", G_RM_AA_DEC_LINE2),
+    ("G_RM_AA_TEX_EDGE2", G_RM_AA_TEX_EDGE2),
+    ("G_RM_AA_SUB_SURF2", G_RM_AA_SUB_SURF2),
+    ("G_RM_AA_PCL_SURF2", G_RM_AA_PCL_SURF2),
+    ("G_RM_AA_OPA_TERR2", G_RM_AA_OPA_TERR2),
+    ("G_RM_AA_TEX_TERR2", G_RM_AA_TEX_TERR2),
+    ("G_RM_AA_SUB_TERR2", G_RM_AA_SUB_TERR2),
+    ("G_RM_ZB_OPA_SURF2", G_RM_ZB_OPA_SURF2),
+    ("G_RM_ZB_XLU_SURF2", G_RM_ZB_XLU_SURF2),
+    ("G_RM_ZB_OPA_DECAL2", G_RM_ZB_OPA_DECAL2),
+    ("G_RM_ZB_XLU_DECAL2", G_RM_ZB_XLU_DECAL2),
+    ("G_RM_ZB_CLD_SURF2", G_RM_ZB_CLD_SURF2),
+    ("G_RM_ZB_OVL_SURF2", G_RM_ZB_OVL_SURF2),
+    ("G_RM_ZB_PCL_SURF2", G_RM_ZB_PCL_SURF2),
+    ("G_RM_OPA_SURF2", G_RM_OPA_SURF2),
+    ("G_RM_XLU_SURF2", G_RM_XLU_SURF2),
+    ("G_RM_TEX_EDGE2", G_RM_TEX_EDGE2),
+    ("G_RM_CLD_SURF2", G_RM_CLD_SURF2),
+    ("G_RM_PCL_SURF2", G_RM_PCL_SURF2),
+    ("G_RM_ADD2", G_RM_ADD2),
+    ("G_RM_NOOP2", G_RM_NOOP2),
+    ("G_RM_VISCVG2", G_RM_VISCVG2),
+    ("G_RM_OPA_CI2", G_RM_OPA_CI2),
+];
+
+// Color combiner inputs
+
+pub const G_CCMUX_COMBINED: u32 = 0;
+pub const G_CCMUX_TEXEL0: u32 = 1;
+pub const G_CCMUX_TEXEL1: u32 = 2;
+pub const G_CCMUX_PRIMITIVE: u32 = 3;
+pub const G_CCMUX_SHADE: u32 = 4;
+pub const G_CCMUX_ENVIRONMENT: u32 = 5;
+pub const G_CCMUX_CENTER: u32 = 6;
+pub const G_CCMUX_SCALE: u32 = 6;
+pub const G_CCMUX_COMBINED_ALPHA: u32 = 7;
+pub const G_CCMUX_TEXEL0_ALPHA: u32 = 8;
+pub const G_CCMUX_TEXEL1_ALPHA: u32 = 9;
+pub const G_CCMUX_PRIMITIVE_ALPHA: u32 = 10;
+pub const G_CCMUX_SHADE_ALPHA: u32 = 11;
+pub const G_CCMUX_ENV_ALPHA: u32 = 12;
+pub const G_CCMUX_LOD_FRACTION: u32 = 13;
+pub const G_CCMUX_PRIM_LOD_FRAC: u32 = 14;
+pub const G_CCMUX_NOISE: u32 = 7;
+pub const G_CCMUX_K4: u32 = 7;
+pub const G_CCMUX_K5: u32 = 15;
+pub const G_CCMUX_1: u32 = 6;
+pub const G_CCMUX_0: u32 = 31;
+pub const G_ACMUX_COMBINED: u32 = 0;
+pub const G_ACMUX_TEXEL0: u32 = 1;
+pub const G_ACMUX_TEXEL1: u32 = 2;
+pub const G_ACMUX_PRIMITIVE: u32 = 3;
+pub const G_ACMUX_SHADE: u32 = 4;
+pub const G_ACMUX_ENVIRONMENT: u32 = 5;
+pub const G_ACMUX_LOD_FRACTION: u32 = 0;
+pub const G_ACMUX_PRIM_LOD_FRAC: u32 = 6;
+pub const G_ACMUX_1: u32 = 6;
+pub const G_ACMUX_0: u32 = 7;
+
+pub static CCMUX: &[(&str, u32)] = &[
+    ("G_CCMUX_COMBINED", G_CCMUX_COMBINED),
+    ("G_CCMUX_TEXEL0", G_CCMUX_TEXEL0),
+    ("G_CCMUX_TEXEL1", G_CCMUX_TEXEL1),
+    ("G_CCMUX_PRIMITIVE", G_CCMUX_PRIMITIVE),
+    ("G_CCMUX_SHADE", G_CCMUX_SHADE),
+    ("G_CCMUX_ENVIRONMENT", G_CCMUX_ENVIRONMENT),
+    ("G_CCMUX_CENTER", G_CCMUX_CENTER),
+    ("G_CCMUX_SCALE", G_CCMUX_SCALE),
+    ("G_CCMUX_COMBINED_ALPHA", G_CCMUX_COMBINED_ALPHA),
+    ("G_CCMUX_TEXEL0_ALPHA", G_CCMUX_TEXEL0_ALPHA),
+    ("G_CCMUX_TEXEL1_ALPHA", G_CCMUX_TEXEL1_ALPHA),
+    ("G_CCMUX_PRIMITIVE_ALPHA", G_CCMUX_PRIMITIVE_ALPHA),
+    ("G_CCMUX_SHADE_ALPHA", G_CCMUX_SHADE_ALPHA),
+    ("G_CCMUX_ENV_ALPHA", G_CCMUX_ENV_ALPHA),
+    ("G_CCMUX_LOD_FRACTION", G_CCMUX_LOD_FRACTION),
+    ("G_CCMUX_PRIM_LOD_FRAC", G_CCMUX_PRIM_LOD_FRAC),
+    ("G_CCMUX_NOISE", G_CCMUX_NOISE),
+    ("G_CCMUX_K4", G_CCMUX_K4),
+    ("G_CCMUX_K5", G_CCMUX_K5),
+    ("G_CCMUX_1", G_CCMUX_1),
+    ("G_CCMUX_0", G_CCMUX_0),
+];
+pub static ACMUX: &[(&str, u32)] = &[
+    ("G_ACMUX_COMBINED", G_ACMUX_COMBINED),
+    ("G_ACMUX_TEXEL0", G_ACMUX_TEXEL0),
+    ("G_ACMUX_TEXEL1", G_ACMUX_TEXEL1),
+    ("G_ACMUX_PRIMITIVE", G_ACMUX_PRIMITIVE),
+    ("G_ACMUX_SHADE", G_ACMUX_SHADE),
+    ("G_ACMUX_ENVIRONMENT", G_ACMUX_ENVIRONMENT),
+    ("G_ACMUX_LOD_FRACTION", G_ACMUX_LOD_FRACTION),
+    ("G_ACMUX_PRIM_LOD_FRAC", G_ACMUX_PRIM_LOD_FRAC),
+    ("G_ACMUX_1", G_ACMUX_1),
+    ("G_ACMUX_0", G_ACMUX_0),
+];
+
+/// Names of the color combiner inputs accepted by the `a` slot, as used by `gsDPSetCombineLERP`.
+pub static CCMUX_A: &[(&str, u32)] = &[
+    ("COMBINED", G_CCMUX_COMBINED),
+    ("TEXEL0", G_CCMUX_TEXEL0),
+    ("TEXEL1", G_CCMUX_TEXEL1),
+    ("PRIMITIVE", G_CCMUX_PRIMITIVE),
+    ("SHADE", G_CCMUX_SHADE),
+    ("ENVIRONMENT", G_CCMUX_ENVIRONMENT),
+    ("1", G_CCMUX_1),
+    ("NOISE", G_CCMUX_NOISE),
+    ("0", G_CCMUX_0 & 0xF),
+];
+/// Names of the color combiner inputs accepted by the `b` slot.
+pub static CCMUX_B: &[(&str, u32)] = &[
+    ("COMBINED", G_CCMUX_COMBINED),
+    ("TEXEL0", G_CCMUX_TEXEL0),
+    ("TEXEL1", G_CCMUX_TEXEL1),
+    ("PRIMITIVE", G_CCMUX_PRIMITIVE),
+    ("SHADE", G_CCMUX_SHADE),
+    ("ENVIRONMENT", G_CCMUX_ENVIRONMENT),
+    ("CENTER", G_CCMUX_CENTER),
+    ("K4", G_CCMUX_K4),
+    ("0", G_CCMUX_0 & 0xF),
+];
+/// Names of the color combiner inputs accepted by the `c` slot.
+pub static CCMUX_C: &[(&str, u32)] = &[
+    ("COMBINED", G_CCMUX_COMBINED),
+    ("TEXEL0", G_CCMUX_TEXEL0),
+    ("TEXEL1", G_CCMUX_TEXEL1),
+    ("PRIMITIVE", G_CCMUX_PRIMITIVE),
+    ("SHADE", G_CCMUX_SHADE),
+    ("ENVIRONMENT", G_CCMUX_ENVIRONMENT),
+    ("SCALE", G_CCMUX_SCALE),
+    ("COMBINED_ALPHA", G_CCMUX_COMBINED_ALPHA),
+    ("TEXEL0_ALPHA", G_CCMUX_TEXEL0_ALPHA),
+    ("TEXEL1_ALPHA", G_CCMUX_TEXEL1_ALPHA),
+    ("PRIMITIVE_ALPHA", G_CCMUX_PRIMITIVE_ALPHA),
+    ("SHADE_ALPHA", G_CCMUX_SHADE_ALPHA),
+    ("ENV_ALPHA", G_CCMUX_ENV_ALPHA),
+    ("LOD_FRACTION", G_CCMUX_LOD_FRACTION),
+    ("PRIM_LOD_FRAC", G_CCMUX_PRIM_LOD_FRAC),
+    ("K5", G_CCMUX_K5),
+    ("0", G_CCMUX_0),
+];
+/// Names of the color combiner inputs accepted by the `d` slot.
+pub static CCMUX_D: &[(&str, u32)] = &[
+    ("COMBINED", G_CCMUX_COMBINED),
+    ("TEXEL0", G_CCMUX_TEXEL0),
+    ("TEXEL1", G_CCMUX_TEXEL1),
+    ("PRIMITIVE", G_CCMUX_PRIMITIVE),
+    ("SHADE", G_CCMUX_SHADE),
+    ("ENVIRONMENT", G_CCMUX_ENVIRONMENT),
+    ("1", G_CCMUX_1),
+    ("0", G_CCMUX_0 & 0x7),
+];
+/// Names of the alpha combiner inputs accepted by the `a`, `b` and `d` slots.
+pub static ACMUX_ABD: &[(&str, u32)] = &[
+    ("COMBINED", G_ACMUX_COMBINED),
+    ("TEXEL0", G_ACMUX_TEXEL0),
+    ("TEXEL1", G_ACMUX_TEXEL1),
+    ("PRIMITIVE", G_ACMUX_PRIMITIVE),
+    ("SHADE", G_ACMUX_SHADE),
+    ("ENVIRONMENT", G_ACMUX_ENVIRONMENT),
+    ("1", G_ACMUX_1),
+    ("0", G_ACMUX_0),
+];
+/// Names of the alpha combiner inputs accepted by the `c` slot.
+pub static ACMUX_C: &[(&str, u32)] = &[
+    ("LOD_FRACTION", G_ACMUX_LOD_FRACTION),
+    ("TEXEL0", G_ACMUX_TEXEL0),
+    ("TEXEL1", G_ACMUX_TEXEL1),
+    ("PRIMITIVE", G_ACMUX_PRIMITIVE),
+    ("SHADE", G_ACMUX_SHADE),
+    ("ENVIRONMENT", G_ACMUX_ENVIRONMENT),
+    ("PRIM_LOD_FRAC", G_ACMUX_PRIM_LOD_FRAC),
+    ("0", G_ACMUX_0),
+];
+
+/// A color combiner preset, one of the `G_CC_*` definitions of `gbi.h`.
+///
+/// Each field holds the raw value of a single combiner input, in the order
+/// used by `gsDPSetCombineLERP`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct CombinePreset {
+    pub name: &'static str,
+    pub a: u32,
+    pub b: u32,
+    pub c: u32,
+    pub d: u32,
+    pub aa: u32,
+    pub ab: u32,
+    pub ac: u32,
+    pub ad: u32,
+}
+
+// Color combiner presets
+
+pub const G_CC_PRIMITIVE: CombinePreset = CombinePreset {
+    name: "G_CC_PRIMITIVE",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_PRIMITIVE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_PRIMITIVE,
+};
+pub const G_CC_SHADE: CombinePreset = CombinePreset {
+    name: "G_CC_SHADE",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_MODULATEI: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEI",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_MODULATEIDECALA: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEIDECALA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_MODULATEIFADE: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEIFADE",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_ENVIRONMENT,
+};
+pub const G_CC_MODULATERGB: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGB",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_MODULATERGBDECALA: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGBDECALA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_MODULATERGBFADE: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGBFADE",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_ENVIRONMENT,
+};
+pub const G_CC_MODULATEIA: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEIA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_SHADE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATEIFADEA: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEIFADEA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_ENVIRONMENT,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATEFADE: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEFADE",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_ENVIRONMENT,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_TEXEL0,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATERGBA: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGBA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_SHADE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATERGBFADEA: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGBFADEA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_ENVIRONMENT,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATEI_PRIM: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEI_PRIM",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_PRIMITIVE,
+};
+pub const G_CC_MODULATEIA_PRIM: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEIA_PRIM",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_PRIMITIVE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATEIDECALA_PRIM: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEIDECALA_PRIM",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_MODULATERGB_PRIM: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGB_PRIM",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_PRIMITIVE,
+};
+pub const G_CC_MODULATERGBA_PRIM: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGBA_PRIM",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_PRIMITIVE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATERGBDECALA_PRIM: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGBDECALA_PRIM",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_FADE: CombinePreset = CombinePreset {
+    name: "G_CC_FADE",
+    a: G_CCMUX_SHADE,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_ENVIRONMENT,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_SHADE,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_ENVIRONMENT,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_FADEA: CombinePreset = CombinePreset {
+    name: "G_CC_FADEA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_ENVIRONMENT,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_ENVIRONMENT,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_DECALRGB: CombinePreset = CombinePreset {
+    name: "G_CC_DECALRGB",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_TEXEL0,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_DECALRGBA: CombinePreset = CombinePreset {
+    name: "G_CC_DECALRGBA",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_TEXEL0,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_DECALFADE: CombinePreset = CombinePreset {
+    name: "G_CC_DECALFADE",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_TEXEL0,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_ENVIRONMENT,
+};
+pub const G_CC_DECALFADEA: CombinePreset = CombinePreset {
+    name: "G_CC_DECALFADEA",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_TEXEL0,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_ENVIRONMENT,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_BLENDI: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDI",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_BLENDIA: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDIA",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_SHADE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_BLENDIDECALA: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDIDECALA",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_BLENDRGBA: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDRGBA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0_ALPHA,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_BLENDRGBDECALA: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDRGBDECALA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0_ALPHA,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_BLENDRGBFADEA: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDRGBFADEA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0_ALPHA,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_ENVIRONMENT,
+};
+pub const G_CC_ADDRGB: CombinePreset = CombinePreset {
+    name: "G_CC_ADDRGB",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_ADDRGBDECALA: CombinePreset = CombinePreset {
+    name: "G_CC_ADDRGBDECALA",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_ADDRGBFADE: CombinePreset = CombinePreset {
+    name: "G_CC_ADDRGBFADE",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_ENVIRONMENT,
+};
+pub const G_CC_REFLECTRGB: CombinePreset = CombinePreset {
+    name: "G_CC_REFLECTRGB",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_REFLECTRGBDECALA: CombinePreset = CombinePreset {
+    name: "G_CC_REFLECTRGBDECALA",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_HILITERGB: CombinePreset = CombinePreset {
+    name: "G_CC_HILITERGB",
+    a: G_CCMUX_PRIMITIVE,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_HILITERGBA: CombinePreset = CombinePreset {
+    name: "G_CC_HILITERGBA",
+    a: G_CCMUX_PRIMITIVE,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_PRIMITIVE,
+    ab: G_ACMUX_SHADE,
+    ac: G_ACMUX_TEXEL0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_HILITERGBDECALA: CombinePreset = CombinePreset {
+    name: "G_CC_HILITERGBDECALA",
+    a: G_CCMUX_PRIMITIVE,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_SHADEDECALA: CombinePreset = CombinePreset {
+    name: "G_CC_SHADEDECALA",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_SHADEFADEA: CombinePreset = CombinePreset {
+    name: "G_CC_SHADEFADEA",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_ENVIRONMENT,
+};
+pub const G_CC_BLENDPE: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDPE",
+    a: G_CCMUX_PRIMITIVE,
+    b: G_CCMUX_ENVIRONMENT,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_ENVIRONMENT,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_SHADE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_BLENDPEDECALA: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDPEDECALA",
+    a: G_CCMUX_PRIMITIVE,
+    b: G_CCMUX_ENVIRONMENT,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_ENVIRONMENT,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const _G_CC_BLENDPE: CombinePreset = CombinePreset {
+    name: "_G_CC_BLENDPE",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_PRIMITIVE,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_PRIMITIVE,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_SHADE,
+    ad: G_ACMUX_0,
+};
+pub const _G_CC_BLENDPEDECALA: CombinePreset = CombinePreset {
+    name: "_G_CC_BLENDPEDECALA",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_PRIMITIVE,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_PRIMITIVE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const _G_CC_TWOCOLORTEX: CombinePreset = CombinePreset {
+    name: "_G_CC_TWOCOLORTEX",
+    a: G_CCMUX_PRIMITIVE,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const _G_CC_SPARSEST: CombinePreset = CombinePreset {
+    name: "_G_CC_SPARSEST",
+    a: G_CCMUX_PRIMITIVE,
+    b: G_CCMUX_TEXEL0,
+    c: G_CCMUX_LOD_FRACTION,
+    d: G_CCMUX_TEXEL0,
+    aa: G_ACMUX_PRIMITIVE,
+    ab: G_ACMUX_TEXEL0,
+    ac: G_ACMUX_LOD_FRACTION,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_TEMPLERP: CombinePreset = CombinePreset {
+    name: "G_CC_TEMPLERP",
+    a: G_CCMUX_TEXEL1,
+    b: G_CCMUX_TEXEL0,
+    c: G_CCMUX_PRIM_LOD_FRAC,
+    d: G_CCMUX_TEXEL0,
+    aa: G_ACMUX_TEXEL1,
+    ab: G_ACMUX_TEXEL0,
+    ac: G_ACMUX_PRIM_LOD_FRAC,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_TRILERP: CombinePreset = CombinePreset {
+    name: "G_CC_TRILERP",
+    a: G_CCMUX_TEXEL1,
+    b: G_CCMUX_TEXEL0,
+    c: G_CCMUX_LOD_FRACTION,
+    d: G_CCMUX_TEXEL0,
+    aa: G_ACMUX_TEXEL1,
+    ab: G_ACMUX_TEXEL0,
+    ac: G_ACMUX_LOD_FRACTION,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_INTERFERENCE: CombinePreset = CombinePreset {
+    name: "G_CC_INTERFERENCE",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_TEXEL1,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_TEXEL0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_TEXEL1,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_1CYUV2RGB: CombinePreset = CombinePreset {
+    name: "G_CC_1CYUV2RGB",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_K4,
+    c: G_CCMUX_K5,
+    d: G_CCMUX_TEXEL0,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_YUV2RGB: CombinePreset = CombinePreset {
+    name: "G_CC_YUV2RGB",
+    a: G_CCMUX_TEXEL1,
+    b: G_CCMUX_K4,
+    c: G_CCMUX_K5,
+    d: G_CCMUX_TEXEL1,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_PASS2: CombinePreset = CombinePreset {
+    name: "G_CC_PASS2",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_COMBINED,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_COMBINED,
+};
+pub const G_CC_MODULATEI2: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEI2",
+    a: G_CCMUX_COMBINED,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_MODULATEIA2: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEIA2",
+    a: G_CCMUX_COMBINED,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_COMBINED,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_SHADE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATERGB2: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGB2",
+    a: G_CCMUX_COMBINED,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_MODULATERGBA2: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGBA2",
+    a: G_CCMUX_COMBINED,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_SHADE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_COMBINED,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_SHADE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATEI_PRIM2: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEI_PRIM2",
+    a: G_CCMUX_COMBINED,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_PRIMITIVE,
+};
+pub const G_CC_MODULATEIA_PRIM2: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATEIA_PRIM2",
+    a: G_CCMUX_COMBINED,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_COMBINED,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_PRIMITIVE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_MODULATERGB_PRIM2: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGB_PRIM2",
+    a: G_CCMUX_COMBINED,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_PRIMITIVE,
+};
+pub const G_CC_MODULATERGBA_PRIM2: CombinePreset = CombinePreset {
+    name: "G_CC_MODULATERGBA_PRIM2",
+    a: G_CCMUX_COMBINED,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_PRIMITIVE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_COMBINED,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_PRIMITIVE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_DECALRGB2: CombinePreset = CombinePreset {
+    name: "G_CC_DECALRGB2",
+    a: G_CCMUX_0 & 0xF,
+    b: G_CCMUX_0 & 0xF,
+    c: G_CCMUX_0,
+    d: G_CCMUX_COMBINED,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_BLENDI2: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDI2",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_COMBINED,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_BLENDIA2: CombinePreset = CombinePreset {
+    name: "G_CC_BLENDIA2",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_SHADE,
+    c: G_CCMUX_COMBINED,
+    d: G_CCMUX_SHADE,
+    aa: G_ACMUX_COMBINED,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_SHADE,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_CHROMA_KEY2: CombinePreset = CombinePreset {
+    name: "G_CC_CHROMA_KEY2",
+    a: G_CCMUX_TEXEL0,
+    b: G_CCMUX_CENTER,
+    c: G_CCMUX_SCALE,
+    d: G_CCMUX_0 & 0x7,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_0,
+};
+pub const G_CC_HILITERGB2: CombinePreset = CombinePreset {
+    name: "G_CC_HILITERGB2",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_COMBINED,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_COMBINED,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_SHADE,
+};
+pub const G_CC_HILITERGBA2: CombinePreset = CombinePreset {
+    name: "G_CC_HILITERGBA2",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_COMBINED,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_COMBINED,
+    aa: G_ACMUX_ENVIRONMENT,
+    ab: G_ACMUX_COMBINED,
+    ac: G_ACMUX_TEXEL0,
+    ad: G_ACMUX_COMBINED,
+};
+pub const G_CC_HILITERGBDECALA2: CombinePreset = CombinePreset {
+    name: "G_CC_HILITERGBDECALA2",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_COMBINED,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_COMBINED,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_TEXEL0,
+};
+pub const G_CC_HILITERGBPASSA2: CombinePreset = CombinePreset {
+    name: "G_CC_HILITERGBPASSA2",
+    a: G_CCMUX_ENVIRONMENT,
+    b: G_CCMUX_COMBINED,
+    c: G_CCMUX_TEXEL0,
+    d: G_CCMUX_COMBINED,
+    aa: G_ACMUX_0,
+    ab: G_ACMUX_0,
+    ac: G_ACMUX_0,
+    ad: G_ACMUX_COMBINED,
+};
+
+/// Every color combiner preset, in the order `gbi.h` defines them.
+///
+/// Presets that alias others, like `G_CC_MODULATERGB`, are listed after the
+/// ones they alias.
+pub static CC_PRESETS: &[CombinePreset] = &[
+    G_CC_PRIMITIVE,
+    G_CC_SHADE,
+    G_CC_MODULATEI,
+    G_CC_MODULATEIDECALA,
+    G_CC_MODULATEIFADE,
+    G_CC_MODULATERGB,
+    G_CC_MODULATERGBDECALA,
+    G_CC_MODULATERGBFADE,
+    G_CC_MODULATEIA,
+    G_CC_MODULATEIFADEA,
+    G_CC_MODULATEFADE,
+    G_CC_MODULATERGBA,
+    G_CC_MODULATERGBFADEA,
+    G_CC_MODULATEI_PRIM,
+    G_CC_MODULATEIA_PRIM,
+    G_CC_MODULATEIDECALA_PRIM,
+    G_CC_MODULATERGB_PRIM,
+    G_CC_MODULATERGBA_PRIM,
+    G_CC_MODULATERGBDECALA_PRIM,
+    G_CC_FADE,
+    G_CC_FADEA,
+    G_CC_DECALRGB,
+    G_CC_DECALRGBA,
+    G_CC_DECALFADE,
+    G_CC_DECALFADEA,
+    G_CC_BLENDI,
+    G_CC_BLENDIA,
+    G_CC_BLENDIDECALA,
+    G_CC_BLENDRGBA,
+    G_CC_BLENDRGBDECALA,
+    G_CC_BLENDRGBFADEA,
+    G_CC_ADDRGB,
+    G_CC_ADDRGBDECALA,
+    G_CC_ADDRGBFADE,
+    G_CC_REFLECTRGB,
+    G_CC_REFLECTRGBDECALA,
+    G_CC_HILITERGB,
+    G_CC_HILITERGBA,
+    G_CC_HILITERGBDECALA,
+    G_CC_SHADEDECALA,
+    G_CC_SHADEFADEA,
+    G_CC_BLENDPE,
+    G_CC_BLENDPEDECALA,
+    _G_CC_BLENDPE,
+    _G_CC_BLENDPEDECALA,
+    _G_CC_TWOCOLORTEX,
+    _G_CC_SPARSEST,
+    G_CC_TEMPLERP,
+    G_CC_TRILERP,
+    G_CC_INTERFERENCE,
+    G_CC_1CYUV2RGB,
+    G_CC_YUV2RGB,
+    G_CC_PASS2,
+    G_CC_MODULATEI2,
+    G_CC_MODULATEIA2,
+    G_CC_MODULATERGB2,
+    G_CC_MODULATERGBA2,
+    G_CC_MODULATEI_PRIM2,
+    G_CC_MODULATEIA_PRIM2,
+    G_CC_MODULATERGB_PRIM2,
+    G_CC_MODULATERGBA_PRIM2,
+    G_CC_DECALRGB2,
+    G_CC_BLENDI2,
+    G_CC_BLENDIA2,
+    G_CC_CHROMA_KEY2,
+    G_CC_HILITERGB2,
+    G_CC_HILITERGBA2,
+    G_CC_HILITERGBDECALA2,
+    G_CC_HILITERGBPASSA2,
+];
+
+// Clip ratios, light counts and light numbers
+
+pub const FRUSTRATIO_1: u32 = 1;
+pub const FRUSTRATIO_2: u32 = 2;
+pub const FRUSTRATIO_3: u32 = 3;
+pub const FRUSTRATIO_4: u32 = 4;
+pub const FRUSTRATIO_5: u32 = 5;
+pub const FRUSTRATIO_6: u32 = 6;
+pub const NUMLIGHTS_0: u32 = 1;
+pub const NUMLIGHTS_1: u32 = 1;
+pub const NUMLIGHTS_2: u32 = 2;
+pub const NUMLIGHTS_3: u32 = 3;
+pub const NUMLIGHTS_4: u32 = 4;
+pub const NUMLIGHTS_5: u32 = 5;
+pub const NUMLIGHTS_6: u32 = 6;
+pub const NUMLIGHTS_7: u32 = 7;
+pub const LIGHT_1: u32 = 1;
+pub const LIGHT_2: u32 = 2;
+pub const LIGHT_3: u32 = 3;
+pub const LIGHT_4: u32 = 4;
+pub const LIGHT_5: u32 = 5;
+pub const LIGHT_6: u32 = 6;
+pub const LIGHT_7: u32 = 7;
+pub const LIGHT_8: u32 = 8;
+
+pub static FRUSTRATIO: &[(&str, u32)] = &[
+    ("FRUSTRATIO_1", FRUSTRATIO_1),
+    ("FRUSTRATIO_2", FRUSTRATIO_2),
+    ("FRUSTRATIO_3", FRUSTRATIO_3),
+    ("FRUSTRATIO_4", FRUSTRATIO_4),
+    ("FRUSTRATIO_5", FRUSTRATIO_5),
+    ("FRUSTRATIO_6", FRUSTRATIO_6),
+];
+pub static NUMLIGHTS: &[(&str, u32)] = &[
+    ("NUMLIGHTS_0", NUMLIGHTS_0),
+    ("NUMLIGHTS_1", NUMLIGHTS_1),
+    ("NUMLIGHTS_2", NUMLIGHTS_2),
+    ("NUMLIGHTS_3", NUMLIGHTS_3),
+    ("NUMLIGHTS_4", NUMLIGHTS_4),
+    ("NUMLIGHTS_5", NUMLIGHTS_5),
+    ("NUMLIGHTS_6", NUMLIGHTS_6),
+    ("NUMLIGHTS_7", NUMLIGHTS_7),
+];
+pub static LIGHTS: &[(&str, u32)] = &[
+    ("LIGHT_1", LIGHT_1),
+    ("LIGHT_2", LIGHT_2),
+    ("LIGHT_3", LIGHT_3),
+    ("LIGHT_4", LIGHT_4),
+    ("LIGHT_5", LIGHT_5),
+    ("LIGHT_6", LIGHT_6),
+    ("LIGHT_7", LIGHT_7),
+    ("LIGHT_8", LIGHT_8),
+];
+
+// Moveword offsets
+
+pub const G_MWO_NUMLIGHT: u32 = 0x00;
+pub const G_MWO_CLIP_RNX: u32 = 0x04;
+pub const G_MWO_CLIP_RNY: u32 = 0x0C;
+pub const G_MWO_CLIP_RPX: u32 = 0x14;
+pub const G_MWO_CLIP_RPY: u32 = 0x1C;
+pub const G_MWO_FOG: u32 = 0x00;
+pub const G_MWO_MATRIX_XX_XY_I: u32 = 0x00;
+pub const G_MWO_MATRIX_XZ_XW_I: u32 = 0x04;
+pub const G_MWO_MATRIX_YX_YY_I: u32 = 0x08;
+pub const G_MWO_MATRIX_YZ_YW_I: u32 = 0x0C;
+pub const G_MWO_MATRIX_ZX_ZY_I: u32 = 0x10;
+pub const G_MWO_MATRIX_ZZ_ZW_I: u32 = 0x14;
+pub const G_MWO_MATRIX_WX_WY_I: u32 = 0x18;
+pub const G_MWO_MATRIX_WZ_WW_I: u32 = 0x1C;
+pub const G_MWO_MATRIX_XX_XY_F: u32 = 0x20;
+pub const G_MWO_MATRIX_XZ_XW_F: u32 = 0x24;
+pub const G_MWO_MATRIX_YX_YY_F: u32 = 0x28;
+pub const G_MWO_MATRIX_YZ_YW_F: u32 = 0x2C;
+pub const G_MWO_MATRIX_ZX_ZY_F: u32 = 0x30;
+pub const G_MWO_MATRIX_ZZ_ZW_F: u32 = 0x34;
+pub const G_MWO_MATRIX_WX_WY_F: u32 = 0x38;
+pub const G_MWO_MATRIX_WZ_WW_F: u32 = 0x3C;
+pub const G_MWO_POINT_RGBA: u32 = 0x10;
+pub const G_MWO_POINT_ST: u32 = 0x14;
+pub const G_MWO_POINT_XYSCREEN: u32 = 0x18;
+pub const G_MWO_POINT_ZSCREEN: u32 = 0x1C;
+
+pub static MWO_CLIP: &[(&str, u32)] = &[
+    ("G_MWO_CLIP_RNX", G_MWO_CLIP_RNX),
+    ("G_MWO_CLIP_RNY", G_MWO_CLIP_RNY),
+    ("G_MWO_CLIP_RPX", G_MWO_CLIP_RPX),
+    ("G_MWO_CLIP_RPY", G_MWO_CLIP_RPY),
+];
+pub static MWO_MATRIX: &[(&str, u32)] = &[
+    ("G_MWO_MATRIX_XX_XY_I", G_MWO_MATRIX_XX_XY_I),
+    ("G_MWO_MATRIX_XZ_XW_I", G_MWO_MATRIX_XZ_XW_I),
+    ("G_MWO_MATRIX_YX_YY_I", G_MWO_MATRIX_YX_YY_I),
+    ("G_MWO_MATRIX_YZ_YW_I", G_MWO_MATRIX_YZ_YW_I),
+    ("G_MWO_MATRIX_ZX_ZY_I", G_MWO_MATRIX_ZX_ZY_I),
+    ("G_MWO_MATRIX_ZZ_ZW_I", G_MWO_MATRIX_ZZ_ZW_I),
+    ("G_MWO_MATRIX_WX_WY_I", G_MWO_MATRIX_WX_WY_I),
+    ("G_MWO_MATRIX_WZ_WW_I", G_MWO_MATRIX_WZ_WW_I),
+    ("G_MWO_MATRIX_XX_XY_F", G_MWO_MATRIX_XX_XY_F),
+    ("G_MWO_MATRIX_XZ_XW_F", G_MWO_MATRIX_XZ_XW_F),
+    ("G_MWO_MATRIX_YX_YY_F", G_MWO_MATRIX_YX_YY_F),
+    ("G_MWO_MATRIX_YZ_YW_F", G_MWO_MATRIX_YZ_YW_F),
+    ("G_MWO_MATRIX_ZX_ZY_F", G_MWO_MATRIX_ZX_ZY_F),
+    ("G_MWO_MATRIX_ZZ_ZW_F", G_MWO_MATRIX_ZZ_ZW_F),
+    ("G_MWO_MATRIX_WX_WY_F", G_MWO_MATRIX_WX_WY_F),
+    ("G_MWO_MATRIX_WZ_WW_F", G_MWO_MATRIX_WZ_WW_F),
+];
+pub static MWO_POINT: &[(&str, u32)] = &[
+    ("G_MWO_POINT_RGBA", G_MWO_POINT_RGBA),
+    ("G_MWO_POINT_ST", G_MWO_POINT_ST),
+    ("G_MWO_POINT_XYSCREEN", G_MWO_POINT_XYSCREEN),
+    ("G_MWO_POINT_ZSCREEN", G_MWO_POINT_ZSCREEN),
+];
+
+/// Constants that do not belong to any other table.
+pub static MISC: &[(&str, u32)] = &[
+    ("G_TX_NOMASK", G_TX_NOMASK),
+    ("G_TX_NOLOD", G_TX_NOLOD),
+    ("G_TX_DXT_FRAC", G_TX_DXT_FRAC),
+    ("G_TEXTURE_IMAGE_FRAC", G_TEXTURE_IMAGE_FRAC),
+    ("G_TEXTURE_SCALE_FRAC", G_TEXTURE_SCALE_FRAC),
+    ("G_SCALE_FRAC", G_SCALE_FRAC),
+    ("G_ROTATE_FRAC", G_ROTATE_FRAC),
+    ("G_MAXFBZ", G_MAXFBZ),
+    ("BOWTIE_VAL", BOWTIE_VAL),
+    ("G_MWO_NUMLIGHT", G_MWO_NUMLIGHT),
+    ("G_MWO_FOG", G_MWO_FOG),
+];
+
+/// Constants for the `f3d` and `f3db` microcodes.
+pub mod f3d {
+    #[allow(unused_imports)]
+    use super::*;
+
+    // Command opcodes
+
+    pub const G_SPNOOP: u32 = 0x00;
+    pub const G_MTX: u32 = 0x01;
+    pub const G_MOVEMEM: u32 = 0x03;
+    pub const G_VTX: u32 = 0x04;
+    pub const G_DL: u32 = 0x06;
+    pub const G_SPRITE2D_BASE: u32 = 0x09;
+    pub const G_TRI1: u32 = 0xBF;
+    pub const G_CULLDL: u32 = 0xBE;
+    pub const G_POPMTX: u32 = 0xBD;
+    pub const G_MOVEWORD: u32 = 0xBC;
+    pub const G_TEXTURE: u32 = 0xBB;
+    pub const G_SETOTHERMODE_H: u32 = 0xBA;
+    pub const G_SETOTHERMODE_L: u32 = 0xB9;
+    pub const G_ENDDL: u32 = 0xB8;
+    pub const G_SETGEOMETRYMODE: u32 = 0xB7;
+    pub const G_CLEARGEOMETRYMODE: u32 = 0xB6;
+    pub const G_LINE3D: u32 = 0xB5;
+    pub const G_RDPHALF_1: u32 = 0xB4;
+    pub const G_RDPHALF_2: u32 = 0xB3;
+    pub const G_RDPHALF_CONT: u32 = 0xB2;
+    pub const G_NOOP: u32 = 0xC0;
+
+    // Geometry mode flags
+
+    pub const G_ZBUFFER: u32 = 0x00000001;
+    pub const G_SHADE: u32 = 0x00000004;
+    pub const G_TEXTURE_ENABLE: u32 = 0x00000002;
+    pub const G_SHADING_SMOOTH: u32 = 0x00000200;
+    pub const G_CULL_FRONT: u32 = 0x00001000;
+    pub const G_CULL_BACK: u32 = 0x00002000;
+    pub const G_CULL_BOTH: u32 = 0x00003000;
+    pub const G_FOG: u32 = 0x00010000;
+    pub const G_LIGHTING: u32 = 0x00020000;
+    pub const G_TEXTURE_GEN: u32 = 0x00040000;
+    pub const G_TEXTURE_GEN_LINEAR: u32 = 0x00080000;
+    pub const G_LOD: u32 = 0x00100000;
+    pub const G_CLIPPING: u32 = 0x00000000;
+
+    // Matrix parameters
+
+    pub const G_MTX_MODELVIEW: u32 = 0x00;
+    pub const G_MTX_PROJECTION: u32 = 0x01;
+    pub const G_MTX_MUL: u32 = 0x00;
+    pub const G_MTX_LOAD: u32 = 0x02;
+    pub const G_MTX_NOPUSH: u32 = 0x00;
+    pub const G_MTX_PUSH: u32 = 0x04;
+
+    // Moveword indices
+
+    pub const G_MW_MATRIX: u32 = 0x00;
+    pub const G_MW_NUMLIGHT: u32 = 0x02;
+    pub const G_MW_CLIP: u32 = 0x04;
+    pub const G_MW_SEGMENT: u32 = 0x06;
+    pub const G_MW_FOG: u32 = 0x08;
+    pub const G_MW_LIGHTCOL: u32 = 0x0A;
+    pub const G_MW_POINTS: u32 = 0x0C;
+    pub const G_MW_PERSPNORM: u32 = 0x0E;
+
+    // Movemem indices
+
+    pub const G_MV_VIEWPORT: u32 = 0x80;
+    pub const G_MV_LOOKATY: u32 = 0x82;
+    pub const G_MV_LOOKATX: u32 = 0x84;
+    pub const G_MV_L0: u32 = 0x86;
+    pub const G_MV_L1: u32 = 0x88;
+    pub const G_MV_L2: u32 = 0x8A;
+    pub const G_MV_L3: u32 = 0x8C;
+    pub const G_MV_L4: u32 = 0x8E;
+    pub const G_MV_L5: u32 = 0x90;
+    pub const G_MV_L6: u32 = 0x92;
+    pub const G_MV_L7: u32 = 0x94;
+    pub const G_MV_TXTATT: u32 = 0x96;
+    pub const G_MV_MATRIX_1: u32 = 0x9E;
+    pub const G_MV_MATRIX_2: u32 = 0x98;
+    pub const G_MV_MATRIX_3: u32 = 0x9A;
+    pub const G_MV_MATRIX_4: u32 = 0x9C;
+
+    // Light color moveword offsets
+
+    pub const G_MWO_aLIGHT_1: u32 = 0x00;
+    pub const G_MWO_bLIGHT_1: u32 = 0x04;
+    pub const G_MWO_aLIGHT_2: u32 = 0x20;
+    pub const G_MWO_bLIGHT_2: u32 = 0x24;
+    pub const G_MWO_aLIGHT_3: u32 = 0x40;
+    pub const G_MWO_bLIGHT_3: u32 = 0x44;
+    pub const G_MWO_aLIGHT_4: u32 = 0x60;
+    pub const G_MWO_bLIGHT_4: u32 = 0x64;
+    pub const G_MWO_aLIGHT_5: u32 = 0x80;
+    pub const G_MWO_bLIGHT_5: u32 = 0x84;
+    pub const G_MWO_aLIGHT_6: u32 = 0xA0;
+    pub const G_MWO_bLIGHT_6: u32 = 0xA4;
+    pub const G_MWO_aLIGHT_7: u32 = 0xC0;
+    pub const G_MWO_bLIGHT_7: u32 = 0xC4;
+    pub const G_MWO_aLIGHT_8: u32 = 0xE0;
+    pub const G_MWO_bLIGHT_8: u32 = 0xE4;
+
+    // Texture loading
+
+    pub const G_TX_LDBLK_MAX_TXL: u32 = 2047;
+
+    pub static OPCODES: &[(&str, u32)] = &[
+        ("G_SPNOOP", G_SPNOOP),
+        ("G_MTX", G_MTX),
+        ("G_MOVEMEM", G_MOVEMEM),
+        ("G_VTX", G_VTX),
+        ("G_DL", G_DL),
+        ("G_SPRITE2D_BASE", G_SPRITE2D_BASE),
+        ("G_TRI1", G_TRI1),
+        ("G_CULLDL", G_CULLDL),
+        ("G_POPMTX", G_POPMTX),
+        ("G_MOVEWORD", G_MOVEWORD),
+        ("G_TEXTURE", G_TEXTURE),
+        ("G_SETOTHERMODE_H", G_SETOTHERMODE_H),
+        ("G_SETOTHERMODE_L", G_SETOTHERMODE_L),
+        ("G_ENDDL", G_ENDDL),
+        ("G_SETGEOMETRYMODE", G_SETGEOMETRYMODE),
+        ("G_CLEARGEOMETRYMODE", G_CLEARGEOMETRYMODE),
+        ("G_LINE3D", G_LINE3D),
+        ("G_RDPHALF_1", G_RDPHALF_1),
+        ("G_RDPHALF_2", G_RDPHALF_2),
+        ("G_RDPHALF_CONT", G_RDPHALF_CONT),
+        ("G_NOOP", G_NOOP),
+    ];
+    pub static GEOMETRY_MODE: &[(&str, u32)] = &[
+        ("G_ZBUFFER", G_ZBUFFER),
+        ("G_SHADE", G_SHADE),
+        ("G_TEXTURE_ENABLE", G_TEXTURE_ENABLE),
+        ("G_SHADING_SMOOTH", G_SHADING_SMOOTH),
+        ("G_CULL_FRONT", G_CULL_FRONT),
+        ("G_CULL_BACK", G_CULL_BACK),
+        ("G_CULL_BOTH", G_CULL_BOTH),
+        ("G_FOG", G_FOG),
+        ("G_LIGHTING", G_LIGHTING),
+        ("G_TEXTURE_GEN", G_TEXTURE_GEN),
+        ("G_TEXTURE_GEN_LINEAR", G_TEXTURE_GEN_LINEAR),
+        ("G_LOD", G_LOD),
+        ("G_CLIPPING", G_CLIPPING),
+    ];
+    pub static MTX: &[(&str, u32)] = &[
+        ("G_MTX_MODELVIEW", G_MTX_MODELVIEW),
+        ("G_MTX_PROJECTION", G_MTX_PROJECTION),
+        ("G_MTX_MUL", G_MTX_MUL),
+        ("G_MTX_LOAD", G_MTX_LOAD),
+        ("G_MTX_NOPUSH", G_MTX_NOPUSH),
+        ("G_MTX_PUSH", G_MTX_PUSH),
+    ];
+    pub static MW: &[(&str, u32)] = &[
+        ("G_MW_MATRIX", G_MW_MATRIX),
+        ("G_MW_NUMLIGHT", G_MW_NUMLIGHT),
+        ("G_MW_CLIP", G_MW_CLIP),
+        ("G_MW_SEGMENT", G_MW_SEGMENT),
+        ("G_MW_FOG", G_MW_FOG),
+        ("G_MW_LIGHTCOL", G_MW_LIGHTCOL),
+        ("G_MW_POINTS", G_MW_POINTS),
+        ("G_MW_PERSPNORM", G_MW_PERSPNORM),
+    ];
+    pub static MV: &[(&str, u32)] = &[
+        ("G_MV_VIEWPORT", G_MV_VIEWPORT),
+        ("G_MV_LOOKATY", G_MV_LOOKATY),
+        ("G_MV_LOOKATX", G_MV_LOOKATX),
+        ("G_MV_L0", G_MV_L0),
+        ("G_MV_L1", G_MV_L1),
+        ("G_MV_L2", G_MV_L2),
+        ("G_MV_L3", G_MV_L3),
+        ("G_MV_L4", G_MV_L4),
+        ("G_MV_L5", G_MV_L5),
+        ("G_MV_L6", G_MV_L6),
+        ("G_MV_L7", G_MV_L7),
+        ("G_MV_TXTATT", G_MV_TXTATT),
+        ("G_MV_MATRIX_1", G_MV_MATRIX_1),
+        ("G_MV_MATRIX_2", G_MV_MATRIX_2),
+        ("G_MV_MATRIX_3", G_MV_MATRIX_3),
+        ("G_MV_MATRIX_4", G_MV_MATRIX_4),
+    ];
+    pub static MWO_LIGHTCOL: &[(&str, u32)] = &[
+        ("G_MWO_aLIGHT_1", G_MWO_aLIGHT_1),
+        ("G_MWO_bLIGHT_1", G_MWO_bLIGHT_1),
+        ("G_MWO_aLIGHT_2", G_MWO_aLIGHT_2),
+        ("G_MWO_bLIGHT_2", G_MWO_bLIGHT_2),
+        ("G_MWO_aLIGHT_3", G_MWO_aLIGHT_3),
+        ("G_MWO_bLIGHT_3", G_MWO_bLIGHT_3),
+        ("G_MWO_aLIGHT_4", G_MWO_aLIGHT_4),
+        ("G_MWO_bLIGHT_4", G_MWO_bLIGHT_4),
+        ("G_MWO_aLIGHT_5", G_MWO_aLIGHT_5),
+        ("G_MWO_bLIGHT_5", G_MWO_bLIGHT_5),
+        ("G_MWO_aLIGHT_6", G_MWO_aLIGHT_6),
+        ("G_MWO_bLIGHT_6", G_MWO_bLIGHT_6),
+        ("G_MWO_aLIGHT_7", G_MWO_aLIGHT_7),
+        ("G_MWO_bLIGHT_7", G_MWO_bLIGHT_7),
+        ("G_MWO_aLIGHT_8", G_MWO_aLIGHT_8),
+        ("G_MWO_bLIGHT_8", G_MWO_bLIGHT_8),
+    ];
+
+    /// Every table of constants in this module.
+    pub static CONSTANTS: &[&[(&str, u32)]] = &[OPCODES, GEOMETRY_MODE, MTX, MW, MV, MWO_LIGHTCOL];
+
+    /// The value loaded by `gsSPNumLights`, `NUML` in `gbi.h`.
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub const fn NUML(n: u32) -> u32 {
+        n.wrapping_add(1).wrapping_mul(32).wrapping_add(0x8000_0000)
+    }
+}
+
+/// Constants for the `f3dex` and `f3dexb` microcodes.
+pub mod f3dex {
+    #[allow(unused_imports)]
+    use super::*;
+
+    // Command opcodes
+
+    pub const G_SPNOOP: u32 = 0x00;
+    pub const G_MTX: u32 = 0x01;
+    pub const G_MOVEMEM: u32 = 0x03;
+    pub const G_VTX: u32 = 0x04;
+    pub const G_DL: u32 = 0x06;
+    pub const G_SPRITE2D_BASE: u32 = 0x09;
+    pub const G_TRI1: u32 = 0xBF;
+    pub const G_CULLDL: u32 = 0xBE;
+    pub const G_POPMTX: u32 = 0xBD;
+    pub const G_MOVEWORD: u32 = 0xBC;
+    pub const G_TEXTURE: u32 = 0xBB;
+    pub const G_SETOTHERMODE_H: u32 = 0xBA;
+    pub const G_SETOTHERMODE_L: u32 = 0xB9;
+    pub const G_ENDDL: u32 = 0xB8;
+    pub const G_SETGEOMETRYMODE: u32 = 0xB7;
+    pub const G_CLEARGEOMETRYMODE: u32 = 0xB6;
+    pub const G_LINE3D: u32 = 0xB5;
+    pub const G_RDPHALF_1: u32 = 0xB4;
+    pub const G_RDPHALF_2: u32 = 0xB3;
+    pub const G_NOOP: u32 = 0xC0;
+    pub const G_MODIFYVTX: u32 = 0xB2;
+    pub const G_TRI2: u32 = 0xB1;
+    pub const G_BRANCH_Z: u32 = 0xB0;
+    pub const G_LOAD_UCODE: u32 = 0xAF;
+
+    // Geometry mode flags
+
+    pub const G_ZBUFFER: u32 = 0x00000001;
+    pub const G_SHADE: u32 = 0x00000004;
+    pub const G_TEXTURE_ENABLE: u32 = 0x00000002;
+    pub const G_SHADING_SMOOTH: u32 = 0x00000200;
+    pub const G_CULL_FRONT: u32 = 0x00001000;
+    pub const G_CULL_BACK: u32 = 0x00002000;
+    pub const G_CULL_BOTH: u32 = 0x00003000;
+    pub const G_FOG: u32 = 0x00010000;
+    pub const G_LIGHTING: u32 = 0x00020000;
+    pub const G_TEXTURE_GEN: u32 = 0x00040000;
+    pub const G_TEXTURE_GEN_LINEAR: u32 = 0x00080000;
+    pub const G_LOD: u32 = 0x00100000;
+    pub const G_CLIPPING: u32 = 0x00800000;
+
+    // Matrix parameters
+
+    pub const G_MTX_MODELVIEW: u32 = 0x00;
+    pub const G_MTX_PROJECTION: u32 = 0x01;
+    pub const G_MTX_MUL: u32 = 0x00;
+    pub const G_MTX_LOAD: u32 = 0x02;
+    pub const G_MTX_NOPUSH: u32 = 0x00;
+    pub const G_MTX_PUSH: u32 = 0x04;
+
+    // Moveword indices
+
+    pub const G_MW_MATRIX: u32 = 0x00;
+    pub const G_MW_NUMLIGHT: u32 = 0x02;
+    pub const G_MW_CLIP: u32 = 0x04;
+    pub const G_MW_SEGMENT: u32 = 0x06;
+    pub const G_MW_FOG: u32 = 0x08;
+    pub const G_MW_LIGHTCOL: u32 = 0x0A;
+    pub const G_MW_POINTS: u32 = 0x0C;
+    pub const G_MW_PERSPNORM: u32 = 0x0E;
+
+    // Movemem indices
+
+    pub const G_MV_VIEWPORT: u32 = 0x80;
+    pub const G_MV_LOOKATY: u32 = 0x82;
+    pub const G_MV_LOOKATX: u32 = 0x84;
+    pub const G_MV_L0: u32 = 0x86;
+    pub const G_MV_L1: u32 = 0x88;
+    pub const G_MV_L2: u32 = 0x8A;
+    pub const G_MV_L3: u32 = 0x8C;
+    pub const G_MV_L4: u32 = 0x8E;
+    pub const G_MV_L5: u32 = 0x90;
+    pub const G_MV_L6: u32 = 0x92;
+    pub const G_MV_L7: u32 = 0x94;
+    pub const G_MV_TXTATT: u32 = 0x96;
+    pub const G_MV_MATRIX_1: u32 = 0x9E;
+    pub const G_MV_MATRIX_2: u32 = 0x98;
+    pub const G_MV_MATRIX_3: u32 = 0x9A;
+    pub const G_MV_MATRIX_4: u32 = 0x9C;
+
+    // Light color moveword offsets
+
+    pub const G_MWO_aLIGHT_1: u32 = 0x00;
+    pub const G_MWO_bLIGHT_1: u32 = 0x04;
+    pub const G_MWO_aLIGHT_2: u32 = 0x20;
+    pub const G_MWO_bLIGHT_2: u32 = 0x24;
+    pub const G_MWO_aLIGHT_3: u32 = 0x40;
+    pub const G_MWO_bLIGHT_3: u32 = 0x44;
+    pub const G_MWO_aLIGHT_4: u32 = 0x60;
+    pub const G_MWO_bLIGHT_4: u32 = 0x64;
+    pub const G_MWO_aLIGHT_5: u32 = 0x80;
+    pub const G_MWO_bLIGHT_5: u32 = 0x84;
+    pub const G_MWO_aLIGHT_6: u32 = 0xA0;
+    pub const G_MWO_bLIGHT_6: u32 = 0xA4;
+    pub const G_MWO_aLIGHT_7: u32 = 0xC0;
+    pub const G_MWO_bLIGHT_7: u32 = 0xC4;
+    pub const G_MWO_aLIGHT_8: u32 = 0xE0;
+    pub const G_MWO_bLIGHT_8: u32 = 0xE4;
+
+    // Texture loading
+
+    pub const G_TX_LDBLK_MAX_TXL: u32 = 4095;
+
+    pub static OPCODES: &[(&str, u32)] = &[
+        ("G_SPNOOP", G_SPNOOP),
+        ("G_MTX", G_MTX),
+        ("G_MOVEMEM", G_MOVEMEM),
+        ("G_VTX", G_VTX),
+        ("G_DL", G_DL),
+        ("G_SPRITE2D_BASE", G_SPRITE2D_BASE),
+        ("G_TRI1", G_TRI1),
+        ("G_CULLDL", G_CULLDL),
+        ("G_POPMTX", G_POPMTX),
+        ("G_MOVEWORD", G_MOVEWORD),
+        ("G_TEXTURE", G_TEXTURE),
+        ("G_SETOTHERMODE_H", G_SETOTHERMODE_H),
+        ("G_SETOTHERMODE_L", G_SETOTHERMODE_L),
+        ("G_ENDDL", G_ENDDL),
+        ("G_SETGEOMETRYMODE", G_SETGEOMETRYMODE),
+        ("G_CLEARGEOMETRYMODE", G_CLEARGEOMETRYMODE),
+        ("G_LINE3D", G_LINE3D),
+        ("G_RDPHALF_1", G_RDPHALF_1),
+        ("G_RDPHALF_2", G_RDPHALF_2),
+        ("G_NOOP", G_NOOP),
+        ("G_MODIFYVTX", G_MODIFYVTX),
+        ("G_TRI2", G_TRI2),
+        ("G_BRANCH_Z", G_BRANCH_Z),
+        ("G_LOAD_UCODE", G_LOAD_UCODE),
+    ];
+    pub static GEOMETRY_MODE: &[(&str, u32)] = &[
+        ("G_ZBUFFER", G_ZBUFFER),
+        ("G_SHADE", G_SHADE),
+        ("G_TEXTURE_ENABLE", G_TEXTURE_ENABLE),
+        ("G_SHADING_SMOOTH", G_SHADING_SMOOTH),
+        ("G_CULL_FRONT", G_CULL_FRONT),
+        ("G_CULL_BACK", G_CULL_BACK),
+        ("G_CULL_BOTH", G_CULL_BOTH),
+        ("G_FOG", G_FOG),
+        ("G_LIGHTING", G_LIGHTING),
+        ("G_TEXTURE_GEN", G_TEXTURE_GEN),
+        ("G_TEXTURE_GEN_LINEAR", G_TEXTURE_GEN_LINEAR),
+        ("G_LOD", G_LOD),
+        ("G_CLIPPING", G_CLIPPING),
+    ];
+    pub static MTX: &[(&str, u32)] = &[
+        ("G_MTX_MODELVIEW", G_MTX_MODELVIEW),
+        ("G_MTX_PROJECTION", G_MTX_PROJECTION),
+        ("G_MTX_MUL", G_MTX_MUL),
+        ("G_MTX_LOAD", G_MTX_LOAD),
+        ("G_MTX_NOPUSH", G_MTX_NOPUSH),
+        ("G_MTX_PUSH", G_MTX_PUSH),
+    ];
+    pub static MW: &[(&str, u32)] = &[
+        ("G_MW_MATRIX", G_MW_MATRIX),
+        ("G_MW_NUMLIGHT", G_MW_NUMLIGHT),
+        ("G_MW_CLIP", G_MW_CLIP),
+        ("G_MW_SEGMENT", G_MW_SEGMENT),
+        ("G_MW_FOG", G_MW_FOG),
+        ("G_MW_LIGHTCOL", G_MW_LIGHTCOL),
+        ("G_MW_POINTS", G_MW_POINTS),
+        ("G_MW_PERSPNORM", G_MW_PERSPNORM),
+    ];
+    pub static MV: &[(&str, u32)] = &[
+        ("G_MV_VIEWPORT", G_MV_VIEWPORT),
+        ("G_MV_LOOKATY", G_MV_LOOKATY),
+        ("G_MV_LOOKATX", G_MV_LOOKATX),
+        ("G_MV_L0", G_MV_L0),
+        ("G_MV_L1", G_MV_L1),
+        ("G_MV_L2", G_MV_L2),
+        ("G_MV_L3", G_MV_L3),
+        ("G_MV_L4", G_MV_L4),
+        ("G_MV_L5", G_MV_L5),
+        ("G_MV_L6", G_MV_L6),
+        ("G_MV_L7", G_MV_L7),
+        ("G_MV_TXTATT", G_MV_TXTATT),
+        ("G_MV_MATRIX_1", G_MV_MATRIX_1),
+        ("G_MV_MATRIX_2", G_MV_MATRIX_2),
+        ("G_MV_MATRIX_3", G_MV_MATRIX_3),
+        ("G_MV_MATRIX_4", G_MV_MATRIX_4),
+    ];
+    pub static MWO_LIGHTCOL: &[(&str, u32)] = &[
+        ("G_MWO_aLIGHT_1", G_MWO_aLIGHT_1),
+        ("G_MWO_bLIGHT_1", G_MWO_bLIGHT_1),
+        ("G_MWO_aLIGHT_2", G_MWO_aLIGHT_2),
+        ("G_MWO_bLIGHT_2", G_MWO_bLIGHT_2),
+        ("G_MWO_aLIGHT_3", G_MWO_aLIGHT_3),
+        ("G_MWO_bLIGHT_3", G_MWO_bLIGHT_3),
+        ("G_MWO_aLIGHT_4", G_MWO_aLIGHT_4),
+        ("G_MWO_bLIGHT_4", G_MWO_bLIGHT_4),
+        ("G_MWO_aLIGHT_5", G_MWO_aLIGHT_5),
+        ("G_MWO_bLIGHT_5", G_MWO_bLIGHT_5),
+        ("G_MWO_aLIGHT_6", G_MWO_aLIGHT_6),
+        ("G_MWO_bLIGHT_6", G_MWO_bLIGHT_6),
+        ("G_MWO_aLIGHT_7", G_MWO_aLIGHT_7),
+        ("G_MWO_bLIGHT_7", G_MWO_bLIGHT_7),
+        ("G_MWO_aLIGHT_8", G_MWO_aLIGHT_8),
+        ("G_MWO_bLIGHT_8", G_MWO_bLIGHT_8),
+    ];
+
+    /// Every table of constants in this module.
+    pub static CONSTANTS: &[&[(&str, u32)]] = &[OPCODES, GEOMETRY_MODE, MTX, MW, MV, MWO_LIGHTCOL];
+
+    /// The value loaded by `gsSPNumLights`, `NUML` in `gbi.h`.
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub const fn NUML(n: u32) -> u32 {
+        n.wrapping_add(1).wrapping_mul(32).wrapping_add(0x8000_0000)
+    }
+}
+
+/// Constants for the `f3dex2` microcode.
+pub mod f3dex2 {
+    #[allow(unused_imports)]
+    use super::*;
+
+    // Command opcodes
+
+    pub const G_NOOP: u32 = 0x00;
+    pub const G_VTX: u32 = 0x01;
+    pub const G_MODIFYVTX: u32 = 0x02;
+    pub const G_CULLDL: u32 = 0x03;
+    pub const G_BRANCH_Z: u32 = 0x04;
+    pub const G_TRI1: u32 = 0x05;
+    pub const G_TRI2: u32 = 0x06;
+    pub const G_QUAD: u32 = 0x07;
+    pub const G_LINE3D: u32 = 0x08;
+    pub const G_SPECIAL_3: u32 = 0xD3;
+    pub const G_SPECIAL_2: u32 = 0xD4;
+    pub const G_SPECIAL_1: u32 = 0xD5;
+    pub const G_DMA_IO: u32 = 0xD6;
+    pub const G_TEXTURE: u32 = 0xD7;
+    pub const G_POPMTX: u32 = 0xD8;
+    pub const G_GEOMETRYMODE: u32 = 0xD9;
+    pub const G_MTX: u32 = 0xDA;
+    pub const G_MOVEWORD: u32 = 0xDB;
+    pub const G_MOVEMEM: u32 = 0xDC;
+    pub const G_LOAD_UCODE: u32 = 0xDD;
+    pub const G_DL: u32 = 0xDE;
+    pub const G_ENDDL: u32 = 0xDF;
+    pub const G_SPNOOP: u32 = 0xE0;
+    pub const G_RDPHALF_1: u32 = 0xE1;
+    pub const G_SETOTHERMODE_L: u32 = 0xE2;
+    pub const G_SETOTHERMODE_H: u32 = 0xE3;
+    pub const G_RDPHALF_2: u32 = 0xF1;
+
+    // Geometry mode flags
+
+    pub const G_ZBUFFER: u32 = 0x00000001;
+    pub const G_SHADE: u32 = 0x00000004;
+    pub const G_TEXTURE_ENABLE: u32 = 0x00000000;
+    pub const G_CULL_FRONT: u32 = 0x00000200;
+    pub const G_CULL_BACK: u32 = 0x00000400;
+    pub const G_CULL_BOTH: u32 = 0x00000600;
+    pub const G_FOG: u32 = 0x00010000;
+    pub const G_LIGHTING: u32 = 0x00020000;
+    pub const G_TEXTURE_GEN: u32 = 0x00040000;
+    pub const G_TEXTURE_GEN_LINEAR: u32 = 0x00080000;
+    pub const G_LOD: u32 = 0x00100000;
+    pub const G_SHADING_SMOOTH: u32 = 0x00200000;
+    pub const G_CLIPPING: u32 = 0x00800000;
+
+    // Matrix parameters
+
+    pub const G_MTX_NOPUSH: u32 = 0x00;
+    pub const G_MTX_PUSH: u32 = 0x01;
+    pub const G_MTX_MUL: u32 = 0x00;
+    pub const G_MTX_LOAD: u32 = 0x02;
+    pub const G_MTX_MODELVIEW: u32 = 0x00;
+    pub const G_MTX_PROJECTION: u32 = 0x04;
+
+    // Moveword indices
+
+    pub const G_MW_MATRIX: u32 = 0x00;
+    pub const G_MW_NUMLIGHT: u32 = 0x02;
+    pub const G_MW_CLIP: u32 = 0x04;
+    pub const G_MW_SEGMENT: u32 = 0x06;
+    pub const G_MW_FOG: u32 = 0x08;
+    pub const G_MW_LIGHTCOL: u32 = 0x0A;
+    pub const G_MW_FORCEMTX: u32 = 0x0C;
+    pub const G_MW_PERSPNORM: u32 = 0x0E;
+
+    // Movemem indices and offsets
+
+    pub const G_MV_MMTX: u32 = 2;
+    pub const G_MV_PMTX: u32 = 6;
+    pub const G_MV_VIEWPORT: u32 = 8;
+    pub const G_MV_LIGHT: u32 = 10;
+    pub const G_MV_POINT: u32 = 12;
+    pub const G_MV_MATRIX: u32 = 14;
+    pub const G_MVO_LOOKATX: u32 = 0;
+    pub const G_MVO_LOOKATY: u32 = 24;
+    pub const G_MVO_L0: u32 = 48;
+    pub const G_MVO_L1: u32 = 72;
+    pub const G_MVO_L2: u32 = 96;
+    pub const G_MVO_L3: u32 = 120;
+    pub const G_MVO_L4: u32 = 144;
+    pub const G_MVO_L5: u32 = 168;
+    pub const G_MVO_L6: u32 = 192;
+    pub const G_MVO_L7: u32 = 216;
+
+    // Light color moveword offsets
+
+    pub const G_MWO_aLIGHT_1: u32 = 0x00;
+    pub const G_MWO_bLIGHT_1: u32 = 0x04;
+    pub const G_MWO_aLIGHT_2: u32 = 0x18;
+    pub const G_MWO_bLIGHT_2: u32 = 0x1C;
+    pub const G_MWO_aLIGHT_3: u32 = 0x30;
+    pub const G_MWO_bLIGHT_3: u32 = 0x34;
+    pub const G_MWO_aLIGHT_4: u32 = 0x48;
+    pub const G_MWO_bLIGHT_4: u32 = 0x4C;
+    pub const G_MWO_aLIGHT_5: u32 = 0x60;
+    pub const G_MWO_bLIGHT_5: u32 = 0x64;
+    pub const G_MWO_aLIGHT_6: u32 = 0x78;
+    pub const G_MWO_bLIGHT_6: u32 = 0x7C;
+    pub const G_MWO_aLIGHT_7: u32 = 0x90;
+    pub const G_MWO_bLIGHT_7: u32 = 0x94;
+    pub const G_MWO_aLIGHT_8: u32 = 0xA8;
+    pub const G_MWO_bLIGHT_8: u32 = 0xAC;
+
+    // Texture loading
+
+    pub const G_TX_LDBLK_MAX_TXL: u32 = 4095;
+
+    pub static OPCODES: &[(&str, u32)] = &[
+        ("G_NOOP", G_NOOP),
+        ("G_VTX", G_VTX),
+        ("G_MODIFYVTX", G_MODIFYVTX),
+        ("G_CULLDL", G_CULLDL),
+        ("G_BRANCH_Z", G_BRANCH_Z),
+        ("G_TRI1", G_TRI1),
+        ("G_TRI2", G_TRI2),
+        ("G_QUAD", G_QUAD),
+        ("G_LINE3D", G_LINE3D),
+        ("G_SPECIAL_3", G_SPECIAL_3),
+        ("G_SPECIAL_2", G_SPECIAL_2),
+        ("G_SPECIAL_1", G_SPECIAL_1),
+        ("G_DMA_IO", G_DMA_IO),
+        ("G_TEXTURE", G_TEXTURE),
+        ("G_POPMTX", G_POPMTX),
+        ("G_GEOMETRYMODE", G_GEOMETRYMODE),
+        ("G_MTX", G_MTX),
+        ("G_MOVEWORD", G_MOVEWORD),
+        ("G_MOVEMEM", G_MOVEMEM),
+        ("G_LOAD_UCODE", G_LOAD_UCODE),
+        ("G_DL", G_DL),
+        ("G_ENDDL", G_ENDDL),
+        ("G_SPNOOP", G_SPNOOP),
+        ("G_RDPHALF_1", G_RDPHALF_1),
+        ("G_SETOTHERMODE_L", G_SETOTHERMODE_L),
+        ("G_SETOTHERMODE_H", G_SETOTHERMODE_H),
+        ("G_RDPHALF_2", G_RDPHALF_2),
+    ];
+    pub static GEOMETRY_MODE: &[(&str, u32)] = &[
+        ("G_ZBUFFER", G_ZBUFFER),
+        ("G_SHADE", G_SHADE),
+        ("G_TEXTURE_ENABLE", G_TEXTURE_ENABLE),
+        ("G_CULL_FRONT", G_CULL_FRONT),
+        ("G_CULL_BACK", G_CULL_BACK),
+        ("G_CULL_BOTH", G_CULL_BOTH),
+        ("G_FOG", G_FOG),
+        ("G_LIGHTING", G_LIGHTING),
+        ("G_TEXTURE_GEN", G_TEXTURE_GEN),
+        ("G_TEXTURE_GEN_LINEAR", G_TEXTURE_GEN_LINEAR),
+        ("G_LOD", G_LOD),
+        ("G_SHADING_SMOOTH", G_SHADING_SMOOTH),
+        ("G_CLIPPING", G_CLIPPING),
+    ];
+    pub static MTX: &[(&str, u32)] = &[
+        ("G_MTX_NOPUSH", G_MTX_NOPUSH),
+        ("G_MTX_PUSH", G_MTX_PUSH),
+        ("G_MTX_MUL", G_MTX_MUL),
+        ("G_MTX_LOAD", G_MTX_LOAD),
+        ("G_MTX_MODELVIEW", G_MTX_MODELVIEW),
+        ("G_MTX_PROJECTION", G_MTX_PROJECTION),
+    ];
+    pub static MW: &[(&str, u32)] = &[
+        ("G_MW_MATRIX", G_MW_MATRIX),
+        ("G_MW_NUMLIGHT", G_MW_NUMLIGHT),
+        ("G_MW_CLIP", G_MW_CLIP),
+        ("G_MW_SEGMENT", G_MW_SEGMENT),
+        ("G_MW_FOG", G_MW_FOG),
+        ("G_MW_LIGHTCOL", G_MW_LIGHTCOL),
+        ("G_MW_FORCEMTX", G_MW_FORCEMTX),
+        ("G_MW_PERSPNORM", G_MW_PERSPNORM),
+    ];
+    pub static MV: &[(&str, u32)] = &[
+        ("G_MV_MMTX", G_MV_MMTX),
+        ("G_MV_PMTX", G_MV_PMTX),
+        ("G_MV_VIEWPORT", G_MV_VIEWPORT),
+        ("G_MV_LIGHT", G_MV_LIGHT),
+        ("G_MV_POINT", G_MV_POINT),
+        ("G_MV_MATRIX", G_MV_MATRIX),
+    ];
+    pub static MVO: &[(&str, u32)] = &[
+        ("G_MVO_LOOKATX", G_MVO_LOOKATX),
+        ("G_MVO_LOOKATY", G_MVO_LOOKATY),
+        ("G_MVO_L0", G_MVO_L0),
+        ("G_MVO_L1", G_MVO_L1),
+        ("G_MVO_L2", G_MVO_L2),
+        ("G_MVO_L3", G_MVO_L3),
+        ("G_MVO_L4", G_MVO_L4),
+        ("G_MVO_L5", G_MVO_L5),
+        ("G_MVO_L6", G_MVO_L6),
+        ("G_MVO_L7", G_MVO_L7),
+    ];
+    pub static MWO_LIGHTCOL: &[(&str, u32)] = &[
+        ("G_MWO_aLIGHT_1", G_MWO_aLIGHT_1),
+        ("G_MWO_bLIGHT_1", G_MWO_bLIGHT_1),
+        ("G_MWO_aLIGHT_2", G_MWO_aLIGHT_2),
+        ("G_MWO_bLIGHT_2", G_MWO_bLIGHT_2),
+        ("G_MWO_aLIGHT_3", G_MWO_aLIGHT_3),
+        ("G_MWO_bLIGHT_3", G_MWO_bLIGHT_3),
+        ("G_MWO_aLIGHT_4", G_MWO_aLIGHT_4),
+        ("G_MWO_bLIGHT_4", G_MWO_bLIGHT_4),
+        ("G_MWO_aLIGHT_5", G_MWO_aLIGHT_5),
+        ("G_MWO_bLIGHT_5", G_MWO_bLIGHT_5),
+        ("G_MWO_aLIGHT_6", G_MWO_aLIGHT_6),
+        ("G_MWO_bLIGHT_6", G_MWO_bLIGHT_6),
+        ("G_MWO_aLIGHT_7", G_MWO_aLIGHT_7),
+        ("G_MWO_bLIGHT_7", G_MWO_bLIGHT_7),
+        ("G_MWO_aLIGHT_8", G_MWO_aLIGHT_8),
+        ("G_MWO_bLIGHT_8", G_MWO_bLIGHT_8),
+    ];
+
+    /// Every table of constants in this module.
+    pub static CONSTANTS: &[&[(&str, u32)]] =
+        &[OPCODES, GEOMETRY_MODE, MTX, MW, MV, MVO, MWO_LIGHTCOL];
+
+    /// The value loaded by `gsSPNumLights`, `NUML` in `gbi.h`.
+    #[allow(non_snake_case)]
+    #[must_use]
+    pub const fn NUML(n: u32) -> u32 {
+        n.wrapping_mul(24)
+    }
+}
+
+/// Every table of constants specific to `ucode`.
+#[must_use]
+pub fn ucode_constants(ucode: Ucode) -> &'static [&'static [(&'static str, u32)]] {
+    match ucode {
+        Ucode::gfxd_f3d | Ucode::gfxd_f3db => f3d::CONSTANTS,
+        Ucode::gfxd_f3dex | Ucode::gfxd_f3dexb => f3dex::CONSTANTS,
+        Ucode::gfxd_f3dex2 => f3dex2::CONSTANTS,
+    }
+}
+
+/// The RSP command opcodes of `ucode`, without the RDP ones in
+/// [`RDP_OPCODES`].
+#[must_use]
+pub fn ucode_opcodes(ucode: Ucode) -> &'static [(&'static str, u32)] {
+    match ucode {
+        Ucode::gfxd_f3d | Ucode::gfxd_f3db => f3d::OPCODES,
+        Ucode::gfxd_f3dex | Ucode::gfxd_f3dexb => f3dex::OPCODES,
+        Ucode::gfxd_f3dex2 => f3dex2::OPCODES,
+    }
+}
+
+/// Every table of constants shared by all microcodes.
+pub static COMMON_CONSTANTS: &[&[(&str, u32)]] = &[
+    RDP_OPCODES,
+    IM_FMT,
+    IM_SIZ,
+    TX_CM,
+    TX_TILE,
+    SWITCH,
+    DL_FLAG,
+    SC,
+    MDSFT_LO,
+    MDSFT_HI,
+    PM,
+    CYC,
+    TP,
+    TD,
+    TL,
+    TT,
+    TF,
+    TC,
+    CK,
+    CD,
+    AD,
+    AC,
+    ZS,
+    RM_FLAGS,
+    BL,
+    RENDER_MODES_1,
+    RENDER_MODES_2,
+    CCMUX,
+    ACMUX,
+    FRUSTRATIO,
+    NUMLIGHTS,
+    LIGHTS,
+    MWO_CLIP,
+    MWO_MATRIX,
+    MWO_POINT,
+    MISC,
+];
+
+/// Looks up the value of the constant called `name` for `ucode`.
+///
+/// Microcode specific constants take precedence over shared ones.
+#[must_use]
+pub fn lookup(ucode: Ucode, name: &str) -> Option<u32> {
+    ucode_constants(ucode)
+        .iter()
+        .chain(COMMON_CONSTANTS.iter())
+        .flat_map(|x| x.iter())
+        .find(|(x, _)| *x == name)
+        .map(|(_, v)| *v)
+}
+
+/// The table of constants that name the values of arguments of type `type_`
+/// for `ucode`, or `None` if values of that type are not enumerated.
+///
+/// Color combiner presets are described by [`CC_PRESETS`] instead, so
+/// [`gfxd_Ccpre`] has no table.
+///
+/// [`gfxd_Ccpre`]: ArgType::gfxd_Ccpre
+#[must_use]
+pub fn arg_constants(ucode: Ucode, type_: ArgType) -> Option<&'static [(&'static str, u32)]> {
+    let family = |f3d, f3dex, f3dex2| match ucode {
+        Ucode::gfxd_f3d | Ucode::gfxd_f3db => f3d,
+        Ucode::gfxd_f3dex | Ucode::gfxd_f3dexb => f3dex,
+        Ucode::gfxd_f3dex2 => f3dex2,
+    };
+
+    let table = match type_ {
+        ArgType::gfxd_Fmt => IM_FMT,
+        ArgType::gfxd_Siz => IM_SIZ,
+        ArgType::gfxd_Cm => TX_CM,
+        ArgType::gfxd_Tile => TX_TILE,
+        ArgType::gfxd_Switch => SWITCH,
+        ArgType::gfxd_Dlflag => DL_FLAG,
+        ArgType::gfxd_Sc => SC,
+        ArgType::gfxd_Sftlo => MDSFT_LO,
+        ArgType::gfxd_Sfthi => MDSFT_HI,
+        ArgType::gfxd_Pm => PM,
+        ArgType::gfxd_Cyc => CYC,
+        ArgType::gfxd_Tp => TP,
+        ArgType::gfxd_Td => TD,
+        ArgType::gfxd_Tl => TL,
+        ArgType::gfxd_Tt => TT,
+        ArgType::gfxd_Tf => TF,
+        ArgType::gfxd_Tc => TC,
+        ArgType::gfxd_Ck => CK,
+        ArgType::gfxd_Cd => CD,
+        ArgType::gfxd_Ad => AD,
+        ArgType::gfxd_Ac => AC,
+        ArgType::gfxd_Zs => ZS,
+        ArgType::gfxd_Rm1 => RENDER_MODES_1,
+        ArgType::gfxd_Rm2 => RENDER_MODES_2,
+        ArgType::gfxd_Ccmuxa => CCMUX_A,
+        ArgType::gfxd_Ccmuxb => CCMUX_B,
+        ArgType::gfxd_Ccmuxc => CCMUX_C,
+        ArgType::gfxd_Ccmuxd => CCMUX_D,
+        ArgType::gfxd_Acmuxabd => ACMUX_ABD,
+        ArgType::gfxd_Acmuxc => ACMUX_C,
+        ArgType::gfxd_Numlights => NUMLIGHTS,
+        ArgType::gfxd_Lightnum => LIGHTS,
+        ArgType::gfxd_Mwo_clip => MWO_CLIP,
+        ArgType::gfxd_Mwo_matrix => MWO_MATRIX,
+        ArgType::gfxd_Mwo_point => MWO_POINT,
+        ArgType::gfxd_Mwo_lightcol => {
+            family(f3d::MWO_LIGHTCOL, f3dex::MWO_LIGHTCOL, f3dex2::MWO_LIGHTCOL)
+        }
+        ArgType::gfxd_Mw => family(f3d::MW, f3dex::MW, f3dex2::MW),
+        ArgType::gfxd_Mv => family(f3d::MV, f3dex::MV, f3dex2::MV),
+        ArgType::gfxd_Mvo if ucode == Ucode::gfxd_f3dex2 => f3dex2::MVO,
+        _ => return None,
+    };
+    Some(table)
+}
+
+/// The name of the constant that `value` stands for in an argument of type
+/// `type_`, like `G_IM_FMT_CI` for a [`gfxd_Fmt`] of `2`.
+///
+/// `value` is the raw value reported by [`gfxd_arg_value`]. Flags that
+/// combine several constants, like `G_TX_MIRROR | G_TX_CLAMP`, only have a
+/// name when they match a single constant. When several constants share a
+/// value the first one `gbi.h` defines is returned.
+///
+/// [`gfxd_Fmt`]: ArgType::gfxd_Fmt
+/// [`gfxd_arg_value`]: crate::macro_info::gfxd_arg_value
+#[must_use]
+pub fn symbolic_name(ucode: Ucode, type_: ArgType, value: u32) -> Option<&'static str> {
+    if type_ == ArgType::gfxd_Ccpre {
+        return CC_PRESETS.get(value as usize).map(|x| x.name);
+    }
+    arg_constants(ucode, type_)?
+        .iter()
+        .find(|(_, x)| *x == value)
+        .map(|(name, _)| *name)
+}
@@ -0,0 +1,261 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Geometry mode flags.
+//!
+//! The bits of the geometry mode flags are not the same on every microcode:
+//! F3DEX2 moves the culling and smooth shading flags, and `G_CLIPPING` and
+//! `G_TEXTURE_ENABLE` only exist on some of them. A [`GeometryMode`] keeps
+//! the microcode its bits belong to, so it can be printed and parsed in the
+//! `G_ZBUFFER | G_SHADE | G_CULL_BACK` form of C source and converted to the
+//! bits of another microcode.
+//!
+//! A [`GeometryModeChange`] describes the bits cleared and set by any of the
+//! commands that change the geometry mode.
+
+use core::fmt;
+
+use crate::command::Command;
+use crate::gbi;
+use crate::gfx::Gfx;
+use crate::settings::Ucode;
+
+/// The geometry mode flags of `ucode` and their values.
+///
+/// Flags that do not exist on `ucode` have a value of `0`.
+#[must_use]
+pub fn flags(ucode: Ucode) -> &'static [(&'static str, u32)] {
+    match ucode {
+        Ucode::gfxd_f3d | Ucode::gfxd_f3db => gbi::f3d::GEOMETRY_MODE,
+        Ucode::gfxd_f3dex | Ucode::gfxd_f3dexb => gbi::f3dex::GEOMETRY_MODE,
+        Ucode::gfxd_f3dex2 => gbi::f3dex2::GEOMETRY_MODE,
+    }
+}
+
+/// The bits of the geometry mode that `gsSPGeometryMode` can clear.
+const F3DEX2_CLEAR_MASK: u32 = 0x00FF_FFFF;
+
+/// A set of geometry mode flags for a given microcode.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct GeometryMode {
+    pub ucode: Ucode,
+    pub bits: u32,
+}
+
+impl GeometryMode {
+    #[must_use]
+    pub fn new(ucode: Ucode, bits: u32) -> Self {
+        Self { ucode, bits }
+    }
+
+    /// Parses a list of flags separated by `|`, like
+    /// `G_ZBUFFER | G_SHADE | G_CULL_BACK`.
+    ///
+    /// Each flag may be the name of a geometry mode flag of `ucode` or a
+    /// number, in decimal or in hexadecimal with a `0x` prefix. Flags that
+    /// do not exist on `ucode` are accepted and set no bits.
+    pub fn parse(ucode: Ucode, s: &str) -> Result<Self, GeometryModeParseError> {
+        let mut bits = 0;
+        let mut offset = 0;
+
+        for token in s.split('|') {
+            let start = offset + (token.len() - token.trim_start().len());
+            offset += token.len() + 1;
+
+            let token = token.trim();
+            let value = flags(ucode)
+                .iter()
+                .find(|(name, _)| *name == token)
+                .map(|(_, value)| *value)
+                .or_else(|| parse_number(token));
+            match value {
+                Some(value) => bits |= value,
+                None => return Err(GeometryModeParseError { offset: start }),
+            }
+        }
+        Ok(Self { ucode, bits })
+    }
+
+    /// The names of the flags set in this mode, in the order `gbi.h`
+    /// defines them.
+    ///
+    /// Flags that combine others, like `G_CULL_BOTH`, are preferred over the
+    /// flags they contain. Bits that no flag names are left out, see
+    /// [`GeometryMode::unnamed_bits`].
+    pub fn names(&self) -> impl Iterator<Item = &'static str> {
+        let table = flags(self.ucode);
+        let bits = self.bits;
+        table
+            .iter()
+            .filter(move |&&(_, value)| {
+                value != 0
+                    && bits & value == value
+                    && !table.iter().any(|&(_, other)| {
+                        other != value && other & value == value && bits & other == other
+                    })
+            })
+            .map(|(name, _)| *name)
+    }
+
+    /// The bits of this mode that no flag of its microcode names.
+    #[must_use]
+    pub fn unnamed_bits(&self) -> u32 {
+        flags(self.ucode)
+            .iter()
+            .fold(self.bits, |bits, (_, value)| bits & !value)
+    }
+
+    /// Whether every flag of `other` is set in this mode.
+    ///
+    /// `other` is expected to hold bits of the same microcode.
+    #[must_use]
+    pub fn contains(&self, other: u32) -> bool {
+        self.bits & other == other
+    }
+
+    /// The same flags with the bits of `ucode`.
+    ///
+    /// Flags that do not exist on `ucode`, like `G_CLIPPING` on F3D, are
+    /// dropped, while bits that no flag names are kept as they are.
+    #[must_use]
+    pub fn to_ucode(&self, ucode: Ucode) -> Self {
+        let target = flags(ucode);
+        let bits = self
+            .names()
+            .filter_map(|name| target.iter().find(|(x, _)| *x == name))
+            .fold(self.unnamed_bits(), |bits, (_, value)| bits | value);
+        Self { ucode, bits }
+    }
+}
+
+impl fmt::Display for GeometryMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for name in self.names() {
+            if !first {
+                write!(f, " | ")?;
+            }
+            first = false;
+            write!(f, "{}", name)?;
+        }
+
+        let unnamed = self.unnamed_bits();
+        if first && unnamed == 0 {
+            write!(f, "0")?;
+        } else if unnamed != 0 {
+            if !first {
+                write!(f, " | ")?;
+            }
+            write!(f, "0x{:X}", unnamed)?;
+        }
+        Ok(())
+    }
+}
+
+fn parse_number(s: &str) -> Option<u32> {
+    if s.starts_with("0x") || s.starts_with("0X") {
+        u32::from_str_radix(&s[2..], 16).ok()
+    } else {
+        s.parse().ok()
+    }
+}
+
+/// An error produced by [`GeometryMode::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeometryModeParseError {
+    /// The byte offset of the flag that could not be parsed.
+    pub offset: usize,
+}
+
+impl fmt::Display for GeometryModeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown geometry mode flag at offset {}", self.offset)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GeometryModeParseError {}
+
+/// The bits cleared and then set by a geometry mode command.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct GeometryModeChange {
+    pub clear: GeometryMode,
+    pub set: GeometryMode,
+}
+
+impl GeometryModeChange {
+    #[must_use]
+    pub fn new(ucode: Ucode, clear: u32, set: u32) -> Self {
+        Self {
+            clear: GeometryMode::new(ucode, clear),
+            set: GeometryMode::new(ucode, set),
+        }
+    }
+
+    /// Decodes a `G_SETGEOMETRYMODE` or `G_CLEARGEOMETRYMODE` packet on F3D
+    /// and F3DEX, or a `G_GEOMETRYMODE` packet on F3DEX2. Returns `None` for
+    /// any other command.
+    #[must_use]
+    pub fn from_gfx(ucode: Ucode, gfx: Gfx) -> Option<Self> {
+        let opcode = u32::from(gfx.opcode());
+        let (set, clear) = match ucode {
+            Ucode::gfxd_f3d | Ucode::gfxd_f3db => {
+                (gbi::f3d::G_SETGEOMETRYMODE, gbi::f3d::G_CLEARGEOMETRYMODE)
+            }
+            Ucode::gfxd_f3dex | Ucode::gfxd_f3dexb => (
+                gbi::f3dex::G_SETGEOMETRYMODE,
+                gbi::f3dex::G_CLEARGEOMETRYMODE,
+            ),
+            Ucode::gfxd_f3dex2 => {
+                if opcode != gbi::f3dex2::G_GEOMETRYMODE {
+                    return None;
+                }
+                return Some(Self::new(ucode, !gfx.w0 & F3DEX2_CLEAR_MASK, gfx.w1));
+            }
+        };
+
+        if opcode == set {
+            Some(Self::new(ucode, 0, gfx.w1))
+        } else if opcode == clear {
+            Some(Self::new(ucode, gfx.w1, 0))
+        } else {
+            None
+        }
+    }
+
+    /// Builds the change made by a [`Command::SPSetGeometryMode`],
+    /// [`Command::SPClearGeometryMode`], [`Command::SPLoadGeometryMode`] or
+    /// [`Command::SPGeometryMode`], or returns `None` for any other command.
+    ///
+    /// On F3DEX2 only the low 24 bits of the geometry mode can be cleared,
+    /// so the cleared bits are limited to those like [`from_gfx`] does.
+    ///
+    /// [`from_gfx`]: GeometryModeChange::from_gfx
+    #[must_use]
+    pub fn from_command(ucode: Ucode, command: &Command) -> Option<Self> {
+        let (clear, set) = match *command {
+            Command::SPSetGeometryMode { mode } => (0, mode),
+            Command::SPClearGeometryMode { mode } => (mode, 0),
+            Command::SPLoadGeometryMode { mode } => (!0, mode),
+            Command::SPGeometryMode { clearbits, setbits } => (clearbits, setbits),
+            _ => return None,
+        };
+        let clear = match ucode {
+            Ucode::gfxd_f3dex2 => clear & F3DEX2_CLEAR_MASK,
+            _ => clear,
+        };
+        Some(Self::new(ucode, clear, set))
+    }
+
+    /// The geometry mode that results from applying this change to `mode`.
+    #[must_use]
+    pub fn apply(&self, mode: u32) -> u32 {
+        (mode & !self.clear.bits) | self.set.bits
+    }
+}
+
+impl fmt::Display for GeometryModeChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "clear {}, set {}", self.clear, self.set)
+    }
+}
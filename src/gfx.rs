@@ -0,0 +1,317 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! The `Gfx` display list packet.
+//!
+//! Input buffers can be read in place with [`GfxView`], which converts each
+//! packet as it is accessed following the same endianness and word size
+//! rules as [`gfxd_endian`]. Buffers already laid out like the host can be
+//! reinterpreted directly with [`Gfx::cast_slice`].
+//!
+//! [`gfxd_endian`]: crate::settings::gfxd_endian
+
+use core::iter::FusedIterator;
+use core::{mem, slice};
+
+use crate::gbi;
+use crate::settings::{Endian, Ucode};
+
+/// A single display list packet, made of two 32-bit words.
+///
+/// The words hold their logical values, so `w0 >> 24` is always the opcode
+/// of the command regardless of how the packet is stored in memory.
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Gfx {
+    /// The high word.
+    pub w0: u32,
+    /// The low word.
+    pub w1: u32,
+}
+
+impl Gfx {
+    /// The size in bytes of a single packet.
+    pub const SIZE: usize = 8;
+
+    /// Builds a packet from its two words.
+    #[must_use]
+    pub const fn new(w0: u32, w1: u32) -> Self {
+        Self { w0, w1 }
+    }
+
+    /// Serializes this packet as `libgfxd` would expect to read it with
+    /// [`gfxd_endian`] set to `endian` and `wordsize`.
+    ///
+    /// A `wordsize` of 0 is treated like 1.
+    ///
+    /// [`gfxd_endian`]: crate::settings::gfxd_endian
+    #[must_use]
+    pub fn to_bytes(self, endian: Endian, wordsize: u32) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[..4].copy_from_slice(&self.w0.to_be_bytes());
+        bytes[4..].copy_from_slice(&self.w1.to_be_bytes());
+        swap_words(&mut bytes, endian, wordsize);
+        bytes
+    }
+
+    /// Reads a packet stored with the given `endian` and `wordsize`, the
+    /// inverse of [`Gfx::to_bytes`].
+    #[must_use]
+    pub fn from_bytes(mut bytes: [u8; Self::SIZE], endian: Endian, wordsize: u32) -> Self {
+        swap_words(&mut bytes, endian, wordsize);
+        let mut w0 = [0; 4];
+        let mut w1 = [0; 4];
+        w0.copy_from_slice(&bytes[..4]);
+        w1.copy_from_slice(&bytes[4..]);
+        Self {
+            w0: u32::from_be_bytes(w0),
+            w1: u32::from_be_bytes(w1),
+        }
+    }
+
+    /// Reads the packet at the start of `bytes`, stored with the given
+    /// `endian` and `wordsize`.
+    ///
+    /// Returns [`None`] if `bytes` is shorter than a packet.
+    #[must_use]
+    pub fn read(bytes: &[u8], endian: Endian, wordsize: u32) -> Option<Self> {
+        read_record(bytes, endian, wordsize, Self::from_bytes)
+    }
+
+    /// Reinterprets `bytes` as packets without copying.
+    ///
+    /// This only works for buffers laid out like the host, which is
+    /// [`Endian::gfxd_endian_host`] with a word size of 4. Returns [`None`]
+    /// if `bytes` is not aligned to 4 bytes or its length is not a multiple
+    /// of [`Gfx::SIZE`].
+    #[must_use]
+    pub fn cast_slice(bytes: &[u8]) -> Option<&[Self]> {
+        if bytes.len() % Self::SIZE != 0 || bytes.as_ptr() as usize % mem::align_of::<Self>() != 0 {
+            return None;
+        }
+
+        // SAFETY: `Gfx` is `repr(C)` with two `u32`s, so every bit pattern is
+        // valid, and both the length and the alignment were checked above.
+        Some(unsafe {
+            slice::from_raw_parts(bytes.as_ptr() as *const Self, bytes.len() / Self::SIZE)
+        })
+    }
+
+    /// The command opcode, the highest byte of [`Gfx::w0`].
+    #[must_use]
+    pub const fn opcode(self) -> u8 {
+        (self.w0 >> 24) as u8
+    }
+
+    /// The `gbi.h` name of the opcode of this packet for `ucode`, like
+    /// `G_VTX` or `G_SETCOMBINE`.
+    ///
+    /// Returns [`None`] for opcodes that `ucode` does not define.
+    #[must_use]
+    pub fn opcode_name(self, ucode: Ucode) -> Option<&'static str> {
+        let opcode = u32::from(self.opcode());
+        gbi::ucode_opcodes(ucode)
+            .iter()
+            .chain(gbi::RDP_OPCODES.iter())
+            .find(|(_, x)| *x == opcode)
+            .map(|(name, _)| *name)
+    }
+}
+
+/// A borrowed buffer of packets stored with a given endianness and word
+/// size, converted one by one as they are accessed.
+///
+/// Trailing bytes that do not make up a whole packet are ignored.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct GfxView<'a> {
+    bytes: &'a [u8],
+    endian: Endian,
+    wordsize: u32,
+}
+
+impl<'a> GfxView<'a> {
+    /// A view of `bytes`, laid out as described by `endian` and `wordsize`
+    /// like [`gfxd_endian`] would expect. A `wordsize` of 0 is treated like 1.
+    ///
+    /// [`gfxd_endian`]: crate::settings::gfxd_endian
+    #[must_use]
+    pub fn new(bytes: &'a [u8], endian: Endian, wordsize: u32) -> Self {
+        let len = bytes.len() - bytes.len() % Gfx::SIZE;
+        Self {
+            bytes: &bytes[..len],
+            endian,
+            wordsize,
+        }
+    }
+
+    /// The number of whole packets in the view.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.bytes.len() / Gfx::SIZE
+    }
+
+    /// Whether the view has no whole packets.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// The packet at `index`, or [`None`] if out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<Gfx> {
+        let start = index.checked_mul(Gfx::SIZE)?;
+        let bytes = self.bytes.get(start..)?;
+        Gfx::read(bytes, self.endian, self.wordsize)
+    }
+
+    /// The packet at byte `offset`, which must be a multiple of
+    /// [`Gfx::SIZE`].
+    #[must_use]
+    pub fn at_offset(&self, offset: usize) -> Option<Gfx> {
+        if offset % Gfx::SIZE != 0 {
+            return None;
+        }
+        self.get(offset / Gfx::SIZE)
+    }
+
+    /// The bytes of the whole packets in the view.
+    #[must_use]
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Iterates over every packet in the view.
+    #[must_use]
+    pub fn iter(&self) -> Iter<'a> {
+        Iter {
+            chunks: self.bytes.chunks(Gfx::SIZE),
+            endian: self.endian,
+            wordsize: self.wordsize,
+        }
+    }
+}
+
+impl<'a> IntoIterator for GfxView<'a> {
+    type Item = Gfx;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &GfxView<'a> {
+    type Item = Gfx;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// An iterator over the packets of a [`GfxView`].
+#[derive(Debug, Clone)]
+pub struct Iter<'a> {
+    chunks: slice::Chunks<'a, u8>,
+    endian: Endian,
+    wordsize: u32,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = Gfx;
+
+    fn next(&mut self) -> Option<Gfx> {
+        let chunk = self.chunks.next()?;
+        Gfx::read(chunk, self.endian, self.wordsize)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Iter<'a> {
+    fn next_back(&mut self) -> Option<Gfx> {
+        let chunk = self.chunks.next_back()?;
+        Gfx::read(chunk, self.endian, self.wordsize)
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl<'a> FusedIterator for Iter<'a> {}
+
+/// Serializes every packet in `packets`, see [`Gfx::to_bytes`].
+#[cfg(feature = "alloc")]
+pub(crate) fn to_byte_vec(packets: &[Gfx], endian: Endian, wordsize: u32) -> alloc::vec::Vec<u8> {
+    let mut bytes = alloc::vec::Vec::with_capacity(packets.len() * Gfx::SIZE);
+    for gfx in packets.iter() {
+        bytes.extend_from_slice(&gfx.to_bytes(endian, wordsize));
+    }
+    bytes
+}
+
+/// Whether `endian` means little endian on this host.
+pub(crate) fn is_little(endian: Endian) -> bool {
+    match endian {
+        Endian::gfxd_endian_big => false,
+        Endian::gfxd_endian_little => true,
+        Endian::gfxd_endian_host => cfg!(target_endian = "little"),
+    }
+}
+
+/// Converts between big endian bytes and the layout described by `endian`
+/// and `wordsize`. The conversion is its own inverse.
+///
+/// A `wordsize` of 0 is treated like 1, leaving the bytes in order.
+pub(crate) fn swap_words(bytes: &mut [u8], endian: Endian, wordsize: u32) {
+    let wordsize = wordsize.max(1) as usize;
+    if is_little(endian) && wordsize > 1 {
+        for word in bytes.chunks_mut(wordsize) {
+            word.reverse();
+        }
+    }
+}
+
+/// A fixed size byte array that a record like [`Gfx`] is read from.
+///
+/// Arrays only implement `Default` and `AsMut<[u8]>` up to 32 bytes on the
+/// oldest supported compiler, so the sizes in use are listed here instead.
+pub(crate) trait RawBytes: Copy {
+    fn zeroed() -> Self;
+    fn as_mut_slice(&mut self) -> &mut [u8];
+}
+
+macro_rules! impl_raw_bytes {
+    ($($size:expr),*) => {
+        $(
+            impl RawBytes for [u8; $size] {
+                fn zeroed() -> Self {
+                    [0; $size]
+                }
+
+                fn as_mut_slice(&mut self) -> &mut [u8] {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+impl_raw_bytes!(8, 16, 32, 64);
+
+/// Copies the record at the start of `bytes` and decodes it with
+/// `from_bytes`, which is given the same `endian` and `wordsize`.
+///
+/// Returns [`None`] if `bytes` is shorter than the record.
+pub(crate) fn read_record<R: RawBytes, T>(
+    bytes: &[u8],
+    endian: Endian,
+    wordsize: u32,
+    from_bytes: fn(R, Endian, u32) -> T,
+) -> Option<T> {
+    let mut raw = R::zeroed();
+    let slice = raw.as_mut_slice();
+    slice.copy_from_slice(bytes.get(..slice.len())?);
+    Some(from_bytes(raw, endian, wordsize))
+}
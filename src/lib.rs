@@ -15,16 +15,62 @@
 // #![deny(unsafe_op_in_unsafe_fn)]
 #![deny(clippy::semicolon_if_nothing_returned)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod address;
 pub mod arg_type;
 pub mod argument_callbacks;
+#[cfg(feature = "alloc")]
+pub mod assembler;
+#[cfg(feature = "alloc")]
+pub mod c_data;
+#[cfg(feature = "alloc")]
+pub mod c_file;
+#[cfg(feature = "alloc")]
+pub mod call_graph;
+pub mod combiner;
+pub mod command;
 pub mod config;
 pub mod custom_output;
+#[cfg(feature = "alloc")]
+pub mod decode;
+#[cfg(feature = "alloc")]
+pub mod differential;
+pub mod encode;
 pub mod execution;
+pub mod gbi;
+pub mod geometry_mode;
+pub mod gfx;
 pub mod handlers;
 pub mod io;
+#[cfg(feature = "alloc")]
+pub mod json;
+pub mod light;
 pub mod macro_id;
 pub mod macro_info;
+pub mod mtx;
+#[cfg(any(feature = "alloc", feature = "serde"))]
+mod names;
+pub mod othermode;
+#[cfg(feature = "alloc")]
+pub mod png;
+#[cfg(feature = "alloc")]
+pub mod references;
+#[cfg(feature = "serde")]
+mod serde_impls;
 pub mod settings;
+pub mod state;
+pub mod static_dl;
+#[cfg(feature = "alloc")]
+pub mod symbols;
+pub mod texture;
+#[cfg(feature = "alloc")]
+pub mod traverse;
+#[cfg(feature = "alloc")]
+pub mod verify;
+pub mod vp;
+pub mod vtx;
 
 pub mod ffi;
 pub mod ptr;
@@ -0,0 +1,280 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Lights.
+//!
+//! Reads and writes the `Light`, `Ambient` and `LookAt` structures, and the
+//! `LightsN` sets loaded by `gsSPSetLights`, with the same endianness and
+//! word size rules as [`GfxView`].
+//!
+//! Light directions are stored as signed bytes, usually scaled so that their
+//! length is close to 127. [`Light::direction`] gives them back as unit
+//! vectors.
+//!
+//! [`GfxView`]: crate::gfx::GfxView
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::gfx;
+use crate::settings::Endian;
+
+/// A square root good enough for normalizing vectors, since `core` does not
+/// provide one.
+fn sqrt(x: f32) -> f32 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    let mut y = f32::from_bits((x.to_bits() >> 1) + 0x1FC0_0000);
+    for _ in 0..4 {
+        y = 0.5 * (y + x / y);
+    }
+    y
+}
+
+/// `v` scaled to a length of 1, or the zero vector if `v` is.
+fn normalize(v: [f32; 3]) -> [f32; 3] {
+    let len = sqrt(v[0] * v[0] + v[1] * v[1] + v[2] * v[2]);
+    if len == 0.0 {
+        [0.0; 3]
+    } else {
+        [v[0] / len, v[1] / len, v[2] / len]
+    }
+}
+
+/// Converts a coordinate of a unit vector to a signed byte, rounding to the
+/// nearest.
+fn to_byte(x: f32) -> i8 {
+    let x = x * 127.0;
+    if x < 0.0 {
+        (x - 0.5) as i8
+    } else {
+        (x + 0.5) as i8
+    }
+}
+
+/// A directional light, as `Light_t` lays it out.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Light {
+    /// The color.
+    pub col: [u8; 3],
+    /// A copy of the color.
+    pub colc: [u8; 3],
+    /// The direction, towards the light.
+    pub dir: [i8; 3],
+}
+
+impl Light {
+    /// The size in bytes of a light.
+    pub const SIZE: usize = 16;
+
+    /// A light of color `col` in direction `dir`, which does not need to be
+    /// a unit vector.
+    #[must_use]
+    pub fn new(col: [u8; 3], dir: [f32; 3]) -> Self {
+        let dir = normalize(dir);
+        Self {
+            col,
+            colc: col,
+            dir: [to_byte(dir[0]), to_byte(dir[1]), to_byte(dir[2])],
+        }
+    }
+
+    /// The direction of the light as a unit vector, or the zero vector if
+    /// the light has no direction.
+    #[must_use]
+    pub fn direction(&self) -> [f32; 3] {
+        normalize([
+            f32::from(self.dir[0]),
+            f32::from(self.dir[1]),
+            f32::from(self.dir[2]),
+        ])
+    }
+
+    /// Converts the light to bytes, laid out as described by `endian` and
+    /// `wordsize`.
+    #[must_use]
+    pub fn to_bytes(&self, endian: Endian, wordsize: u32) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[..3].copy_from_slice(&self.col);
+        bytes[4..7].copy_from_slice(&self.colc);
+        for (x, &d) in bytes[8..11].iter_mut().zip(self.dir.iter()) {
+            *x = d as u8;
+        }
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        bytes
+    }
+
+    /// Reads a light stored with the given `endian` and `wordsize`, the
+    /// inverse of [`Light::to_bytes`].
+    #[must_use]
+    pub fn from_bytes(mut bytes: [u8; Self::SIZE], endian: Endian, wordsize: u32) -> Self {
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        Self {
+            col: [bytes[0], bytes[1], bytes[2]],
+            colc: [bytes[4], bytes[5], bytes[6]],
+            dir: [bytes[8] as i8, bytes[9] as i8, bytes[10] as i8],
+        }
+    }
+
+    /// Reads the light at the start of `bytes`, stored with the given
+    /// `endian` and `wordsize`.
+    ///
+    /// Returns [`None`] if `bytes` is shorter than [`Light::SIZE`].
+    #[must_use]
+    pub fn read(bytes: &[u8], endian: Endian, wordsize: u32) -> Option<Self> {
+        gfx::read_record(bytes, endian, wordsize, Self::from_bytes)
+    }
+}
+
+/// An ambient light, as `Ambient_t` lays it out.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Ambient {
+    /// The color.
+    pub col: [u8; 3],
+    /// A copy of the color.
+    pub colc: [u8; 3],
+}
+
+impl Ambient {
+    /// The size in bytes of an ambient light.
+    pub const SIZE: usize = 8;
+
+    /// An ambient light of color `col`.
+    #[must_use]
+    pub fn new(col: [u8; 3]) -> Self {
+        Self { col, colc: col }
+    }
+
+    /// Converts the light to bytes, laid out as described by `endian` and
+    /// `wordsize`.
+    #[must_use]
+    pub fn to_bytes(&self, endian: Endian, wordsize: u32) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[..3].copy_from_slice(&self.col);
+        bytes[4..7].copy_from_slice(&self.colc);
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        bytes
+    }
+
+    /// Reads an ambient light stored with the given `endian` and `wordsize`,
+    /// the inverse of [`Ambient::to_bytes`].
+    #[must_use]
+    pub fn from_bytes(mut bytes: [u8; Self::SIZE], endian: Endian, wordsize: u32) -> Self {
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        Self {
+            col: [bytes[0], bytes[1], bytes[2]],
+            colc: [bytes[4], bytes[5], bytes[6]],
+        }
+    }
+
+    /// Reads the ambient light at the start of `bytes`, stored with the
+    /// given `endian` and `wordsize`.
+    ///
+    /// Returns [`None`] if `bytes` is shorter than [`Ambient::SIZE`].
+    #[must_use]
+    pub fn read(bytes: &[u8], endian: Endian, wordsize: u32) -> Option<Self> {
+        gfx::read_record(bytes, endian, wordsize, Self::from_bytes)
+    }
+}
+
+/// The directions used to generate texture coordinates, as `LookAt` lays
+/// them out. Only the directions of the lights are used.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct LookAt {
+    /// The x and y directions.
+    pub l: [Light; 2],
+}
+
+impl LookAt {
+    /// The size in bytes of a look-at.
+    pub const SIZE: usize = 32;
+
+    /// A look-at with the given x and y directions, which do not need to be
+    /// unit vectors.
+    #[must_use]
+    pub fn new(x: [f32; 3], y: [f32; 3]) -> Self {
+        Self {
+            l: [Light::new([0; 3], x), Light::new([0; 3], y)],
+        }
+    }
+
+    /// Converts the look-at to bytes, laid out as described by `endian` and
+    /// `wordsize`.
+    #[must_use]
+    pub fn to_bytes(&self, endian: Endian, wordsize: u32) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        bytes[..Light::SIZE].copy_from_slice(&self.l[0].to_bytes(endian, wordsize));
+        bytes[Light::SIZE..].copy_from_slice(&self.l[1].to_bytes(endian, wordsize));
+        bytes
+    }
+
+    /// Reads a look-at stored with the given `endian` and `wordsize`, the
+    /// inverse of [`LookAt::to_bytes`].
+    #[must_use]
+    pub fn from_bytes(bytes: [u8; Self::SIZE], endian: Endian, wordsize: u32) -> Self {
+        let light = |i: usize| Light::read(&bytes[i * Light::SIZE..], endian, wordsize);
+        Self {
+            l: [light(0).unwrap_or_default(), light(1).unwrap_or_default()],
+        }
+    }
+
+    /// Reads the look-at at the start of `bytes`, stored with the given
+    /// `endian` and `wordsize`.
+    ///
+    /// Returns [`None`] if `bytes` is shorter than [`LookAt::SIZE`].
+    #[must_use]
+    pub fn read(bytes: &[u8], endian: Endian, wordsize: u32) -> Option<Self> {
+        gfx::read_record(bytes, endian, wordsize, Self::from_bytes)
+    }
+}
+
+/// A set of lights, as the `LightsN` structures lay them out: an ambient
+/// light followed by `N` directional lights.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default, Clone, Hash, PartialEq, Eq)]
+pub struct Lights {
+    pub ambient: Ambient,
+    pub lights: Vec<Light>,
+}
+
+#[cfg(feature = "alloc")]
+impl Lights {
+    /// The size in bytes of a set of `num` lights.
+    #[must_use]
+    pub fn size(num: usize) -> usize {
+        Ambient::SIZE + num * Light::SIZE
+    }
+
+    /// Converts the set to bytes, laid out as described by `endian` and
+    /// `wordsize`.
+    #[must_use]
+    pub fn to_bytes(&self, endian: Endian, wordsize: u32) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(Self::size(self.lights.len()));
+        bytes.extend_from_slice(&self.ambient.to_bytes(endian, wordsize));
+        for light in self.lights.iter() {
+            bytes.extend_from_slice(&light.to_bytes(endian, wordsize));
+        }
+        bytes
+    }
+
+    /// Reads a set of `num` lights at the start of `bytes`, like the one
+    /// given to [`gfxd_lightsn_callback`], stored with the given `endian`
+    /// and `wordsize`.
+    ///
+    /// Returns [`None`] if `bytes` is too short.
+    ///
+    /// [`gfxd_lightsn_callback`]: crate::argument_callbacks::gfxd_lightsn_callback
+    #[must_use]
+    pub fn read(bytes: &[u8], num: usize, endian: Endian, wordsize: u32) -> Option<Self> {
+        if bytes.len() < Self::size(num) {
+            return None;
+        }
+        let ambient = Ambient::read(bytes, endian, wordsize)?;
+        let lights = bytes[Ambient::SIZE..Self::size(num)]
+            .chunks_exact(Light::SIZE)
+            .filter_map(|x| Light::read(x, endian, wordsize))
+            .collect();
+        Some(Self { ambient, lights })
+    }
+}
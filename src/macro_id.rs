@@ -3,6 +3,8 @@
 
 //! Macro ids
 
+use crate::arg_type::ArgType;
+
 pub const gfxd_Invalid: MacroId = MacroId::gfxd_Invalid;
 pub const gfxd_DPFillRectangle: MacroId = MacroId::gfxd_DPFillRectangle;
 pub const gfxd_DPFullSync: MacroId = MacroId::gfxd_DPFullSync;
@@ -285,3 +287,1353 @@ pub enum MacroId {
     gfxd_Special2 = 137,
     gfxd_Special1 = 138,
 }
+
+impl MacroId {
+    /// The highest valid macro id.
+    pub const MAX: u32 = MacroId::gfxd_Special1 as u32;
+
+    /// Converts a raw id, like the one returned by [`gfxd_macro_id`], into a
+    /// [`MacroId`].
+    ///
+    /// Returns [`None`] if `raw` is not a known id.
+    ///
+    /// [`gfxd_macro_id`]: crate::macro_info::gfxd_macro_id
+    #[must_use]
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        if raw <= Self::MAX {
+            // SAFETY: `MacroId` is `repr(u32)` and its values are contiguous
+            // from zero up to `MAX`.
+            Some(unsafe { core::mem::transmute::<u32, MacroId>(raw) })
+        } else {
+            None
+        }
+    }
+
+    /// The name of the static (`gs`) form of this macro, like `gsSPVertex`.
+    ///
+    /// Returns [`None`] for [`MacroId::gfxd_Invalid`].
+    #[must_use]
+    pub fn static_name(self) -> Option<&'static str> {
+        let name = SIGNATURES[self as usize].name;
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    }
+
+    /// The arguments of this macro, in the order the macro takes them.
+    ///
+    /// The dynamic (`g`) form of a macro takes the display list pointer as an
+    /// extra first argument, which is not listed here.
+    #[must_use]
+    pub fn args(self) -> &'static [ArgInfo] {
+        SIGNATURES[self as usize].args
+    }
+
+    /// Finds the macro named `name`, accepting both the static (`gs`) and
+    /// the dynamic (`g`) form of the name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (prefix, rest) = if name.starts_with('_') {
+            ("_", &name[1..])
+        } else {
+            ("", name)
+        };
+        let rest = if rest.starts_with("gs") {
+            &rest[2..]
+        } else if rest.starts_with('g') {
+            &rest[1..]
+        } else {
+            return None;
+        };
+
+        SIGNATURES
+            .iter()
+            .position(|x| {
+                x.name.len() == prefix.len() + 2 + rest.len()
+                    && x.name.starts_with(prefix)
+                    && x.name[prefix.len()..].starts_with("gs")
+                    && x.name.ends_with(rest)
+            })
+            .and_then(|i| Self::from_raw(i as u32))
+    }
+}
+
+/// An argument of a macro, see [`MacroId::args`].
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct ArgInfo {
+    /// The name of the argument, like the one reported by [`gfxd_arg_name`].
+    ///
+    /// [`gfxd_arg_name`]: crate::macro_info::gfxd_arg_name
+    pub name: &'static str,
+    /// The type of the argument.
+    pub type_: ArgType,
+}
+
+struct Signature {
+    name: &'static str,
+    args: &'static [ArgInfo],
+}
+
+const fn arg(name: &'static str, type_: ArgType) -> ArgInfo {
+    ArgInfo { name, type_ }
+}
+
+static SIGNATURES: [Signature; MacroId::MAX as usize + 1] = [
+    Signature {
+        name: "",
+        args: &[],
+    },
+    Signature {
+        name: "gsDPFillRectangle",
+        args: &[
+            arg("ulx", ArgType::gfxd_Coordi),
+            arg("uly", ArgType::gfxd_Coordi),
+            arg("lrx", ArgType::gfxd_Coordi),
+            arg("lry", ArgType::gfxd_Coordi),
+        ],
+    },
+    Signature {
+        name: "gsDPFullSync",
+        args: &[],
+    },
+    Signature {
+        name: "gsDPLoadSync",
+        args: &[],
+    },
+    Signature {
+        name: "gsDPTileSync",
+        args: &[],
+    },
+    Signature {
+        name: "gsDPPipeSync",
+        args: &[],
+    },
+    Signature {
+        name: "gsDPLoadTLUT_pal16",
+        args: &[
+            arg("pal", ArgType::gfxd_Pal),
+            arg("dram", ArgType::gfxd_Tlut),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTLUT_pal256",
+        args: &[arg("dram", ArgType::gfxd_Tlut)],
+    },
+    Signature {
+        name: "gsDPLoadMultiBlockYuvS",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("rtile", ArgType::gfxd_Tile),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadMultiBlockYuv",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("rtile", ArgType::gfxd_Tile),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadMultiBlock_4bS",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("rtile", ArgType::gfxd_Tile),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadMultiBlock_4b",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("rtile", ArgType::gfxd_Tile),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadMultiBlockS",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("rtile", ArgType::gfxd_Tile),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadMultiBlock",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("rtile", ArgType::gfxd_Tile),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "_gsDPLoadTextureBlockYuvS",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "_gsDPLoadTextureBlockYuv",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "_gsDPLoadTextureBlock_4bS",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "_gsDPLoadTextureBlock_4b",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "_gsDPLoadTextureBlockS",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "_gsDPLoadTextureBlock",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTextureBlockYuvS",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTextureBlockYuv",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTextureBlock_4bS",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTextureBlock_4b",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTextureBlockS",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTextureBlock",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadMultiTileYuv",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("rtile", ArgType::gfxd_Tile),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("lrt", ArgType::gfxd_Coordi),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadMultiTile_4b",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("rtile", ArgType::gfxd_Tile),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("lrt", ArgType::gfxd_Coordi),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadMultiTile",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("rtile", ArgType::gfxd_Tile),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("lrt", ArgType::gfxd_Coordi),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "_gsDPLoadTextureTileYuv",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("lrt", ArgType::gfxd_Coordi),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "_gsDPLoadTextureTile_4b",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("lrt", ArgType::gfxd_Coordi),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "_gsDPLoadTextureTile",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("lrt", ArgType::gfxd_Coordi),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTextureTileYuv",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("lrt", ArgType::gfxd_Coordi),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTextureTile_4b",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("lrt", ArgType::gfxd_Coordi),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTextureTile",
+        args: &[
+            arg("timg", ArgType::gfxd_Timg),
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("height", ArgType::gfxd_Dim),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("lrt", ArgType::gfxd_Coordi),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+            arg("shiftt", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadBlock",
+        args: &[
+            arg("tile", ArgType::gfxd_Tile),
+            arg("uls", ArgType::gfxd_Coordi),
+            arg("ult", ArgType::gfxd_Coordi),
+            arg("lrs", ArgType::gfxd_Coordi),
+            arg("dxt", ArgType::gfxd_Dxt),
+        ],
+    },
+    Signature {
+        name: "gsDPNoOp",
+        args: &[],
+    },
+    Signature {
+        name: "gsDPNoOpTag",
+        args: &[arg("tag", ArgType::gfxd_Tag)],
+    },
+    Signature {
+        name: "gsDPPipelineMode",
+        args: &[arg("mode", ArgType::gfxd_Pm)],
+    },
+    Signature {
+        name: "gsDPSetBlendColor",
+        args: &[
+            arg("r", ArgType::gfxd_Colorpart),
+            arg("g", ArgType::gfxd_Colorpart),
+            arg("b", ArgType::gfxd_Colorpart),
+            arg("a", ArgType::gfxd_Colorpart),
+        ],
+    },
+    Signature {
+        name: "gsDPSetEnvColor",
+        args: &[
+            arg("r", ArgType::gfxd_Colorpart),
+            arg("g", ArgType::gfxd_Colorpart),
+            arg("b", ArgType::gfxd_Colorpart),
+            arg("a", ArgType::gfxd_Colorpart),
+        ],
+    },
+    Signature {
+        name: "gsDPSetFillColor",
+        args: &[arg("c", ArgType::gfxd_Color)],
+    },
+    Signature {
+        name: "gsDPSetFogColor",
+        args: &[
+            arg("r", ArgType::gfxd_Colorpart),
+            arg("g", ArgType::gfxd_Colorpart),
+            arg("b", ArgType::gfxd_Colorpart),
+            arg("a", ArgType::gfxd_Colorpart),
+        ],
+    },
+    Signature {
+        name: "gsDPSetPrimColor",
+        args: &[
+            arg("m", ArgType::gfxd_Lodfrac),
+            arg("l", ArgType::gfxd_Lodfrac),
+            arg("r", ArgType::gfxd_Colorpart),
+            arg("g", ArgType::gfxd_Colorpart),
+            arg("b", ArgType::gfxd_Colorpart),
+            arg("a", ArgType::gfxd_Colorpart),
+        ],
+    },
+    Signature {
+        name: "gsDPSetColorImage",
+        args: &[
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("cimg", ArgType::gfxd_Cimg),
+        ],
+    },
+    Signature {
+        name: "gsDPSetDepthImage",
+        args: &[arg("zimg", ArgType::gfxd_Zimg)],
+    },
+    Signature {
+        name: "gsDPSetTextureImage",
+        args: &[
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("width", ArgType::gfxd_Dim),
+            arg("timg", ArgType::gfxd_Timg),
+        ],
+    },
+    Signature {
+        name: "gsDPSetAlphaCompare",
+        args: &[arg("mode", ArgType::gfxd_Ac)],
+    },
+    Signature {
+        name: "gsDPSetAlphaDither",
+        args: &[arg("mode", ArgType::gfxd_Ad)],
+    },
+    Signature {
+        name: "gsDPSetColorDither",
+        args: &[arg("mode", ArgType::gfxd_Cd)],
+    },
+    Signature {
+        name: "gsDPSetCombineMode",
+        args: &[
+            arg("mode1", ArgType::gfxd_Ccpre),
+            arg("mode2", ArgType::gfxd_Ccpre),
+        ],
+    },
+    Signature {
+        name: "gsDPSetCombineLERP",
+        args: &[
+            arg("a0", ArgType::gfxd_Ccmuxa),
+            arg("b0", ArgType::gfxd_Ccmuxb),
+            arg("c0", ArgType::gfxd_Ccmuxc),
+            arg("d0", ArgType::gfxd_Ccmuxd),
+            arg("Aa0", ArgType::gfxd_Acmuxabd),
+            arg("Ab0", ArgType::gfxd_Acmuxabd),
+            arg("Ac0", ArgType::gfxd_Acmuxc),
+            arg("Ad0", ArgType::gfxd_Acmuxabd),
+            arg("a1", ArgType::gfxd_Ccmuxa),
+            arg("b1", ArgType::gfxd_Ccmuxb),
+            arg("c1", ArgType::gfxd_Ccmuxc),
+            arg("d1", ArgType::gfxd_Ccmuxd),
+            arg("Aa1", ArgType::gfxd_Acmuxabd),
+            arg("Ab1", ArgType::gfxd_Acmuxabd),
+            arg("Ac1", ArgType::gfxd_Acmuxc),
+            arg("Ad1", ArgType::gfxd_Acmuxabd),
+        ],
+    },
+    Signature {
+        name: "gsDPSetConvert",
+        args: &[
+            arg("k0", ArgType::gfxd_Cv),
+            arg("k1", ArgType::gfxd_Cv),
+            arg("k2", ArgType::gfxd_Cv),
+            arg("k3", ArgType::gfxd_Cv),
+            arg("k4", ArgType::gfxd_Cv),
+            arg("k5", ArgType::gfxd_Cv),
+        ],
+    },
+    Signature {
+        name: "gsDPSetTextureConvert",
+        args: &[arg("mode", ArgType::gfxd_Tc)],
+    },
+    Signature {
+        name: "gsDPSetCycleType",
+        args: &[arg("mode", ArgType::gfxd_Cyc)],
+    },
+    Signature {
+        name: "gsDPSetDepthSource",
+        args: &[arg("mode", ArgType::gfxd_Zs)],
+    },
+    Signature {
+        name: "gsDPSetCombineKey",
+        args: &[arg("mode", ArgType::gfxd_Ck)],
+    },
+    Signature {
+        name: "gsDPSetKeyGB",
+        args: &[
+            arg("cG", ArgType::gfxd_Colorpart),
+            arg("sG", ArgType::gfxd_Keyscale),
+            arg("wG", ArgType::gfxd_Keywidth),
+            arg("cB", ArgType::gfxd_Colorpart),
+            arg("sB", ArgType::gfxd_Keyscale),
+            arg("wB", ArgType::gfxd_Keywidth),
+        ],
+    },
+    Signature {
+        name: "gsDPSetKeyR",
+        args: &[
+            arg("cR", ArgType::gfxd_Colorpart),
+            arg("sR", ArgType::gfxd_Keyscale),
+            arg("wR", ArgType::gfxd_Keywidth),
+        ],
+    },
+    Signature {
+        name: "gsDPSetPrimDepth",
+        args: &[arg("z", ArgType::gfxd_Zi), arg("dz", ArgType::gfxd_Zi)],
+    },
+    Signature {
+        name: "gsDPSetRenderMode",
+        args: &[
+            arg("mode1", ArgType::gfxd_Rm1),
+            arg("mode2", ArgType::gfxd_Rm2),
+        ],
+    },
+    Signature {
+        name: "gsDPSetScissor",
+        args: &[
+            arg("mode", ArgType::gfxd_Sc),
+            arg("ulx", ArgType::gfxd_Coordi),
+            arg("uly", ArgType::gfxd_Coordi),
+            arg("lrx", ArgType::gfxd_Coordi),
+            arg("lry", ArgType::gfxd_Coordi),
+        ],
+    },
+    Signature {
+        name: "gsDPSetScissorFrac",
+        args: &[
+            arg("mode", ArgType::gfxd_Sc),
+            arg("ulx", ArgType::gfxd_Coordq),
+            arg("uly", ArgType::gfxd_Coordq),
+            arg("lrx", ArgType::gfxd_Coordq),
+            arg("lry", ArgType::gfxd_Coordq),
+        ],
+    },
+    Signature {
+        name: "gsDPSetTextureDetail",
+        args: &[arg("mode", ArgType::gfxd_Td)],
+    },
+    Signature {
+        name: "gsDPSetTextureFilter",
+        args: &[arg("mode", ArgType::gfxd_Tf)],
+    },
+    Signature {
+        name: "gsDPSetTextureLOD",
+        args: &[arg("mode", ArgType::gfxd_Tl)],
+    },
+    Signature {
+        name: "gsDPSetTextureLUT",
+        args: &[arg("mode", ArgType::gfxd_Tt)],
+    },
+    Signature {
+        name: "gsDPSetTexturePersp",
+        args: &[arg("mode", ArgType::gfxd_Tp)],
+    },
+    Signature {
+        name: "gsDPSetTile",
+        args: &[
+            arg("fmt", ArgType::gfxd_Fmt),
+            arg("siz", ArgType::gfxd_Siz),
+            arg("line", ArgType::gfxd_Line),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("tile", ArgType::gfxd_Tile),
+            arg("pal", ArgType::gfxd_Pal),
+            arg("cmt", ArgType::gfxd_Cm),
+            arg("maskt", ArgType::gfxd_Tm),
+            arg("shiftt", ArgType::gfxd_Ts),
+            arg("cms", ArgType::gfxd_Cm),
+            arg("masks", ArgType::gfxd_Tm),
+            arg("shifts", ArgType::gfxd_Ts),
+        ],
+    },
+    Signature {
+        name: "gsDPSetTileSize",
+        args: &[
+            arg("tile", ArgType::gfxd_Tile),
+            arg("uls", ArgType::gfxd_Coordq),
+            arg("ult", ArgType::gfxd_Coordq),
+            arg("lrs", ArgType::gfxd_Coordq),
+            arg("lrt", ArgType::gfxd_Coordq),
+        ],
+    },
+    Signature {
+        name: "gsSP1Triangle",
+        args: &[
+            arg("v0", ArgType::gfxd_Vtx),
+            arg("v1", ArgType::gfxd_Vtx),
+            arg("v2", ArgType::gfxd_Vtx),
+            arg("flag", ArgType::gfxd_Vtxflag),
+        ],
+    },
+    Signature {
+        name: "gsSP2Triangles",
+        args: &[
+            arg("v00", ArgType::gfxd_Vtx),
+            arg("v01", ArgType::gfxd_Vtx),
+            arg("v02", ArgType::gfxd_Vtx),
+            arg("flag0", ArgType::gfxd_Vtxflag),
+            arg("v10", ArgType::gfxd_Vtx),
+            arg("v11", ArgType::gfxd_Vtx),
+            arg("v12", ArgType::gfxd_Vtx),
+            arg("flag1", ArgType::gfxd_Vtxflag),
+        ],
+    },
+    Signature {
+        name: "gsSP1Quadrangle",
+        args: &[
+            arg("v0", ArgType::gfxd_Vtx),
+            arg("v1", ArgType::gfxd_Vtx),
+            arg("v2", ArgType::gfxd_Vtx),
+            arg("v3", ArgType::gfxd_Vtx),
+            arg("flag", ArgType::gfxd_Vtxflag),
+        ],
+    },
+    Signature {
+        name: "gsSPBranchLessZraw",
+        args: &[
+            arg("dl", ArgType::gfxd_Dl),
+            arg("vtx", ArgType::gfxd_Vtx),
+            arg("zval", ArgType::gfxd_Zraw),
+        ],
+    },
+    Signature {
+        name: "gsSPBranchList",
+        args: &[arg("dl", ArgType::gfxd_Dl)],
+    },
+    Signature {
+        name: "gsSPClipRatio",
+        args: &[arg("r", ArgType::gfxd_Cr)],
+    },
+    Signature {
+        name: "gsSPCullDisplayList",
+        args: &[
+            arg("vstart", ArgType::gfxd_Vtx),
+            arg("vend", ArgType::gfxd_Vtx),
+        ],
+    },
+    Signature {
+        name: "gsSPDisplayList",
+        args: &[arg("dl", ArgType::gfxd_Dl)],
+    },
+    Signature {
+        name: "gsSPEndDisplayList",
+        args: &[],
+    },
+    Signature {
+        name: "gsSPFogFactor",
+        args: &[arg("fm", ArgType::gfxd_Fogz), arg("fo", ArgType::gfxd_Fogz)],
+    },
+    Signature {
+        name: "gsSPFogPosition",
+        args: &[
+            arg("min", ArgType::gfxd_Fogp),
+            arg("max", ArgType::gfxd_Fogp),
+        ],
+    },
+    Signature {
+        name: "gsSPForceMatrix",
+        args: &[arg("mtx", ArgType::gfxd_Mtxptr)],
+    },
+    Signature {
+        name: "gsSPSetGeometryMode",
+        args: &[arg("mode", ArgType::gfxd_Gm)],
+    },
+    Signature {
+        name: "gsSPClearGeometryMode",
+        args: &[arg("mode", ArgType::gfxd_Gm)],
+    },
+    Signature {
+        name: "gsSPLoadGeometryMode",
+        args: &[arg("mode", ArgType::gfxd_Gm)],
+    },
+    Signature {
+        name: "gsSPInsertMatrix",
+        args: &[
+            arg("where", ArgType::gfxd_Mwo_matrix),
+            arg("val", ArgType::gfxd_Word),
+        ],
+    },
+    Signature {
+        name: "gsSPLine3D",
+        args: &[
+            arg("v0", ArgType::gfxd_Vtx),
+            arg("v1", ArgType::gfxd_Vtx),
+            arg("flag", ArgType::gfxd_Vtxflag),
+        ],
+    },
+    Signature {
+        name: "gsSPLineW3D",
+        args: &[
+            arg("v0", ArgType::gfxd_Vtx),
+            arg("v1", ArgType::gfxd_Vtx),
+            arg("wd", ArgType::gfxd_Linewd),
+            arg("flag", ArgType::gfxd_Vtxflag),
+        ],
+    },
+    Signature {
+        name: "gsSPLoadUcode",
+        args: &[
+            arg("uc_start", ArgType::gfxd_Uctext),
+            arg("uc_dstart", ArgType::gfxd_Ucdata),
+        ],
+    },
+    Signature {
+        name: "gsSPLookAtX",
+        args: &[arg("l", ArgType::gfxd_Lookatptr)],
+    },
+    Signature {
+        name: "gsSPLookAtY",
+        args: &[arg("l", ArgType::gfxd_Lookatptr)],
+    },
+    Signature {
+        name: "gsSPLookAt",
+        args: &[arg("l", ArgType::gfxd_Lookatptr)],
+    },
+    Signature {
+        name: "gsSPMatrix",
+        args: &[
+            arg("mtx", ArgType::gfxd_Mtxptr),
+            arg("param", ArgType::gfxd_Mtxparam),
+        ],
+    },
+    Signature {
+        name: "gsSPModifyVertex",
+        args: &[
+            arg("vtx", ArgType::gfxd_Vtx),
+            arg("where", ArgType::gfxd_Mwo_point),
+            arg("val", ArgType::gfxd_Word),
+        ],
+    },
+    Signature {
+        name: "gsSPPerspNormalize",
+        args: &[arg("scale", ArgType::gfxd_Wscale)],
+    },
+    Signature {
+        name: "gsSPPopMatrix",
+        args: &[arg("param", ArgType::gfxd_Mtxstack)],
+    },
+    Signature {
+        name: "gsSPPopMatrixN",
+        args: &[
+            arg("param", ArgType::gfxd_Mtxstack),
+            arg("num", ArgType::gfxd_Num),
+        ],
+    },
+    Signature {
+        name: "gsSPSegment",
+        args: &[
+            arg("seg", ArgType::gfxd_Seg),
+            arg("base", ArgType::gfxd_Segptr),
+        ],
+    },
+    Signature {
+        name: "gsSPSetLights1",
+        args: &[arg("lights", ArgType::gfxd_Lightsn)],
+    },
+    Signature {
+        name: "gsSPSetLights2",
+        args: &[arg("lights", ArgType::gfxd_Lightsn)],
+    },
+    Signature {
+        name: "gsSPSetLights3",
+        args: &[arg("lights", ArgType::gfxd_Lightsn)],
+    },
+    Signature {
+        name: "gsSPSetLights4",
+        args: &[arg("lights", ArgType::gfxd_Lightsn)],
+    },
+    Signature {
+        name: "gsSPSetLights5",
+        args: &[arg("lights", ArgType::gfxd_Lightsn)],
+    },
+    Signature {
+        name: "gsSPSetLights6",
+        args: &[arg("lights", ArgType::gfxd_Lightsn)],
+    },
+    Signature {
+        name: "gsSPSetLights7",
+        args: &[arg("lights", ArgType::gfxd_Lightsn)],
+    },
+    Signature {
+        name: "gsSPNumLights",
+        args: &[arg("n", ArgType::gfxd_Numlights)],
+    },
+    Signature {
+        name: "gsSPLight",
+        args: &[
+            arg("light", ArgType::gfxd_Lightptr),
+            arg("n", ArgType::gfxd_Lightnum),
+        ],
+    },
+    Signature {
+        name: "gsSPLightColor",
+        args: &[
+            arg("n", ArgType::gfxd_Lightnum),
+            arg("color", ArgType::gfxd_Color),
+        ],
+    },
+    Signature {
+        name: "gsSPTexture",
+        args: &[
+            arg("sc", ArgType::gfxd_Tcscale),
+            arg("tc", ArgType::gfxd_Tcscale),
+            arg("level", ArgType::gfxd_Num),
+            arg("tile", ArgType::gfxd_Tile),
+            arg("on", ArgType::gfxd_Switch),
+        ],
+    },
+    Signature {
+        name: "gsSPTextureRectangle",
+        args: &[
+            arg("ulx", ArgType::gfxd_Coordq),
+            arg("uly", ArgType::gfxd_Coordq),
+            arg("lrx", ArgType::gfxd_Coordq),
+            arg("lry", ArgType::gfxd_Coordq),
+            arg("tile", ArgType::gfxd_Tile),
+            arg("s", ArgType::gfxd_St),
+            arg("t", ArgType::gfxd_St),
+            arg("dsdx", ArgType::gfxd_Stdelta),
+            arg("dtdy", ArgType::gfxd_Stdelta),
+        ],
+    },
+    Signature {
+        name: "gsSPTextureRectangleFlip",
+        args: &[
+            arg("ulx", ArgType::gfxd_Coordq),
+            arg("uly", ArgType::gfxd_Coordq),
+            arg("lrx", ArgType::gfxd_Coordq),
+            arg("lry", ArgType::gfxd_Coordq),
+            arg("tile", ArgType::gfxd_Tile),
+            arg("s", ArgType::gfxd_St),
+            arg("t", ArgType::gfxd_St),
+            arg("dsdx", ArgType::gfxd_Stdelta),
+            arg("dtdy", ArgType::gfxd_Stdelta),
+        ],
+    },
+    Signature {
+        name: "gsSPVertex",
+        args: &[
+            arg("v", ArgType::gfxd_Vtxptr),
+            arg("n", ArgType::gfxd_Num),
+            arg("v0", ArgType::gfxd_Vtx),
+        ],
+    },
+    Signature {
+        name: "gsSPViewport",
+        args: &[arg("v", ArgType::gfxd_Vpptr)],
+    },
+    Signature {
+        name: "gsDPLoadTLUTCmd",
+        args: &[
+            arg("tile", ArgType::gfxd_Tile),
+            arg("count", ArgType::gfxd_Num),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTLUT",
+        args: &[
+            arg("count", ArgType::gfxd_Num),
+            arg("tmem", ArgType::gfxd_Tmem),
+            arg("dram", ArgType::gfxd_Tlut),
+        ],
+    },
+    Signature {
+        name: "gsBranchZ",
+        args: &[
+            arg("vtx", ArgType::gfxd_Vtx),
+            arg("zval", ArgType::gfxd_Zraw),
+        ],
+    },
+    Signature {
+        name: "gsDisplayList",
+        args: &[
+            arg("dl", ArgType::gfxd_Dl),
+            arg("flag", ArgType::gfxd_Dlflag),
+        ],
+    },
+    Signature {
+        name: "gsDPHalf1",
+        args: &[arg("wordhi", ArgType::gfxd_Word)],
+    },
+    Signature {
+        name: "gsDPHalf2",
+        args: &[arg("wordlo", ArgType::gfxd_Word)],
+    },
+    Signature {
+        name: "gsDPWord",
+        args: &[
+            arg("wordhi", ArgType::gfxd_Word),
+            arg("wordlo", ArgType::gfxd_Word),
+        ],
+    },
+    Signature {
+        name: "gsDPLoadTile",
+        args: &[
+            arg("tile", ArgType::gfxd_Tile),
+            arg("uls", ArgType::gfxd_Coordq),
+            arg("ult", ArgType::gfxd_Coordq),
+            arg("lrs", ArgType::gfxd_Coordq),
+            arg("lrt", ArgType::gfxd_Coordq),
+        ],
+    },
+    Signature {
+        name: "gsSPGeometryMode",
+        args: &[
+            arg("clearbits", ArgType::gfxd_Gm),
+            arg("setbits", ArgType::gfxd_Gm),
+        ],
+    },
+    Signature {
+        name: "gsSPSetOtherMode",
+        args: &[
+            arg("opc", ArgType::gfxd_Opcode),
+            arg("sft", ArgType::gfxd_Sftlo),
+            arg("len", ArgType::gfxd_Num),
+            arg("data", ArgType::gfxd_Othermodelo),
+        ],
+    },
+    Signature {
+        name: "gsSPSetOtherModeLo",
+        args: &[
+            arg("sft", ArgType::gfxd_Sftlo),
+            arg("len", ArgType::gfxd_Num),
+            arg("data", ArgType::gfxd_Othermodelo),
+        ],
+    },
+    Signature {
+        name: "gsSPSetOtherModeHi",
+        args: &[
+            arg("sft", ArgType::gfxd_Sfthi),
+            arg("len", ArgType::gfxd_Num),
+            arg("data", ArgType::gfxd_Othermodehi),
+        ],
+    },
+    Signature {
+        name: "gsDPSetOtherMode",
+        args: &[
+            arg("hi", ArgType::gfxd_Othermodehi),
+            arg("lo", ArgType::gfxd_Othermodelo),
+        ],
+    },
+    Signature {
+        name: "gsMoveWd",
+        args: &[
+            arg("index", ArgType::gfxd_Mw),
+            arg("offset", ArgType::gfxd_Mwo),
+            arg("data", ArgType::gfxd_Word),
+        ],
+    },
+    Signature {
+        name: "gsMoveMem",
+        args: &[
+            arg("size", ArgType::gfxd_Size),
+            arg("index", ArgType::gfxd_Mv),
+            arg("offset", ArgType::gfxd_Mvo),
+            arg("dram", ArgType::gfxd_Dram),
+        ],
+    },
+    Signature {
+        name: "gsSPDma_io",
+        args: &[
+            arg("flag", ArgType::gfxd_Dmaflag),
+            arg("dmem", ArgType::gfxd_Dmem),
+            arg("dram", ArgType::gfxd_Dram),
+            arg("size", ArgType::gfxd_Size),
+        ],
+    },
+    Signature {
+        name: "gsSPDmaRead",
+        args: &[
+            arg("dmem", ArgType::gfxd_Dmem),
+            arg("dram", ArgType::gfxd_Dram),
+            arg("size", ArgType::gfxd_Size),
+        ],
+    },
+    Signature {
+        name: "gsSPDmaWrite",
+        args: &[
+            arg("dmem", ArgType::gfxd_Dmem),
+            arg("dram", ArgType::gfxd_Dram),
+            arg("size", ArgType::gfxd_Size),
+        ],
+    },
+    Signature {
+        name: "gsLoadUcode",
+        args: &[
+            arg("uc_start", ArgType::gfxd_Uctext),
+            arg("uc_dsize", ArgType::gfxd_Size),
+        ],
+    },
+    Signature {
+        name: "gsSPLoadUcodeEx",
+        args: &[
+            arg("uc_start", ArgType::gfxd_Uctext),
+            arg("uc_dstart", ArgType::gfxd_Ucdata),
+            arg("uc_dsize", ArgType::gfxd_Size),
+        ],
+    },
+    Signature {
+        name: "gsTexRect",
+        args: &[
+            arg("ulx", ArgType::gfxd_Coordq),
+            arg("uly", ArgType::gfxd_Coordq),
+            arg("lrx", ArgType::gfxd_Coordq),
+            arg("lry", ArgType::gfxd_Coordq),
+            arg("tile", ArgType::gfxd_Tile),
+        ],
+    },
+    Signature {
+        name: "gsTexRectFlip",
+        args: &[
+            arg("ulx", ArgType::gfxd_Coordq),
+            arg("uly", ArgType::gfxd_Coordq),
+            arg("lrx", ArgType::gfxd_Coordq),
+            arg("lry", ArgType::gfxd_Coordq),
+            arg("tile", ArgType::gfxd_Tile),
+        ],
+    },
+    Signature {
+        name: "gsSPNoOp",
+        args: &[],
+    },
+    Signature {
+        name: "gsSpecial3",
+        args: &[arg("hi", ArgType::gfxd_Word), arg("lo", ArgType::gfxd_Word)],
+    },
+    Signature {
+        name: "gsSpecial2",
+        args: &[arg("hi", ArgType::gfxd_Word), arg("lo", ArgType::gfxd_Word)],
+    },
+    Signature {
+        name: "gsSpecial1",
+        args: &[arg("hi", ArgType::gfxd_Word), arg("lo", ArgType::gfxd_Word)],
+    },
+];
@@ -139,6 +139,22 @@ pub enum ArgFmt {
     gfxd_argfmt_f = 2,
 }
 
+impl ArgFmt {
+    /// Converts a raw format, like the one returned by [`gfxd_arg_fmt`], into
+    /// an [`ArgFmt`].
+    ///
+    /// Returns [`None`] if `raw` is not a known format.
+    #[must_use]
+    pub fn from_raw(raw: u32) -> Option<Self> {
+        match raw {
+            0 => Some(ArgFmt::gfxd_argfmt_i),
+            1 => Some(ArgFmt::gfxd_argfmt_u),
+            2 => Some(ArgFmt::gfxd_argfmt_f),
+            _ => None,
+        }
+    }
+}
+
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union gfxd_value_t {
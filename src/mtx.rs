@@ -0,0 +1,115 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Fixed point matrices.
+//!
+//! An `Mtx` holds a 4x4 matrix of s15.16 fixed point numbers, split in two
+//! halves: the integer parts of every element come first, followed by their
+//! fractional parts. [`Mtx`] reads and writes that layout, with the same
+//! endianness and word size rules as [`GfxView`], and converts it to and
+//! from floats like `guMtxL2F` and `guMtxF2L` do.
+//!
+//! Elements are indexed by row then column, with the translation in the last
+//! row, as in `libultra`.
+//!
+//! [`GfxView`]: crate::gfx::GfxView
+
+use crate::gfx;
+use crate::settings::Endian;
+
+/// A 4x4 matrix of s15.16 fixed point numbers.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Mtx {
+    /// The elements, as 32-bit fixed point numbers with 16 fractional bits.
+    pub m: [[i32; 4]; 4],
+}
+
+/// Converts `x` to s15.16 fixed point, truncating like `FTOFIX32` and
+/// saturating values that do not fit.
+fn to_fixed(x: f32) -> i32 {
+    let x = x * 65536.0;
+    if x.is_nan() {
+        0
+    } else if x >= 2_147_483_647.0 {
+        i32::max_value()
+    } else if x <= -2_147_483_648.0 {
+        i32::min_value()
+    } else {
+        x as i32
+    }
+}
+
+impl Mtx {
+    /// The size in bytes of a matrix.
+    pub const SIZE: usize = 64;
+
+    /// The identity matrix.
+    #[must_use]
+    pub fn identity() -> Self {
+        let mut m = [[0; 4]; 4];
+        for (i, row) in m.iter_mut().enumerate() {
+            row[i] = 0x0001_0000;
+        }
+        Self { m }
+    }
+
+    /// Converts a float matrix, like `guMtxF2L`.
+    #[must_use]
+    pub fn from_f32(mf: &[[f32; 4]; 4]) -> Self {
+        let mut m = [[0; 4]; 4];
+        for (row, frow) in m.iter_mut().zip(mf.iter()) {
+            for (x, &f) in row.iter_mut().zip(frow.iter()) {
+                *x = to_fixed(f);
+            }
+        }
+        Self { m }
+    }
+
+    /// Converts the matrix to floats, like `guMtxL2F`.
+    #[must_use]
+    pub fn to_f32(&self) -> [[f32; 4]; 4] {
+        let mut mf = [[0.0; 4]; 4];
+        for (frow, row) in mf.iter_mut().zip(self.m.iter()) {
+            for (f, &x) in frow.iter_mut().zip(row.iter()) {
+                *f = x as f32 / 65536.0;
+            }
+        }
+        mf
+    }
+
+    /// Converts the matrix to bytes, laid out as described by `endian` and
+    /// `wordsize`.
+    #[must_use]
+    pub fn to_bytes(&self, endian: Endian, wordsize: u32) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        for (i, &x) in self.m.iter().flat_map(|row| row.iter()).enumerate() {
+            let x = x as u32;
+            bytes[i * 2..i * 2 + 2].copy_from_slice(&((x >> 16) as u16).to_be_bytes());
+            bytes[32 + i * 2..32 + i * 2 + 2].copy_from_slice(&(x as u16).to_be_bytes());
+        }
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        bytes
+    }
+
+    /// Reads a matrix stored with the given `endian` and `wordsize`, the
+    /// inverse of [`Mtx::to_bytes`].
+    #[must_use]
+    pub fn from_bytes(mut bytes: [u8; Self::SIZE], endian: Endian, wordsize: u32) -> Self {
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        let half = |i: usize| u32::from(u16::from_be_bytes([bytes[i], bytes[i + 1]]));
+        let mut m = [[0; 4]; 4];
+        for (i, x) in m.iter_mut().flat_map(|row| row.iter_mut()).enumerate() {
+            *x = ((half(i * 2) << 16) | half(32 + i * 2)) as i32;
+        }
+        Self { m }
+    }
+
+    /// Reads the matrix at the start of `bytes`, stored with the given
+    /// `endian` and `wordsize`.
+    ///
+    /// Returns [`None`] if `bytes` is shorter than [`Mtx::SIZE`].
+    #[must_use]
+    pub fn read(bytes: &[u8], endian: Endian, wordsize: u32) -> Option<Self> {
+        gfx::read_record(bytes, endian, wordsize, Self::from_bytes)
+    }
+}
@@ -0,0 +1,321 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! The `libgfxd` names of the variants of the enums of this crate, like
+//! `gfxd_SPVertex` or `gfxd_endian_big`, which are also their names in Rust.
+//!
+//! These are the names the JSON and `serde` representations use.
+
+use crate::arg_type::ArgType;
+use crate::macro_id::MacroId;
+use crate::macro_info::ArgFmt;
+use crate::settings::{Endian, FeatureOption};
+
+/// An enum whose variants are numbered from zero, looked up by name.
+pub(crate) trait VariantName: Copy + 'static {
+    /// The name of every variant, indexed by its value.
+    const NAMES: &'static [&'static str];
+
+    /// The variant with value `raw`.
+    fn from_raw(raw: u32) -> Option<Self>;
+
+    /// The value of the variant.
+    fn to_raw(self) -> u32;
+
+    fn variant_name(self) -> &'static str {
+        Self::NAMES[self.to_raw() as usize]
+    }
+
+    fn from_variant_name(name: &str) -> Option<Self> {
+        let raw = Self::NAMES.iter().position(|&x| x == name)?;
+        Self::from_raw(raw as u32)
+    }
+}
+
+macro_rules! impl_variant_name {
+    ($ty:ident, $names:expr, $from_raw:expr) => {
+        impl VariantName for $ty {
+            const NAMES: &'static [&'static str] = &$names;
+
+            fn from_raw(raw: u32) -> Option<Self> {
+                $from_raw(raw)
+            }
+
+            fn to_raw(self) -> u32 {
+                self as u32
+            }
+        }
+    };
+}
+
+fn endian_from_raw(raw: u32) -> Option<Endian> {
+    match raw {
+        0 => Some(Endian::gfxd_endian_big),
+        1 => Some(Endian::gfxd_endian_little),
+        2 => Some(Endian::gfxd_endian_host),
+        _ => None,
+    }
+}
+
+fn feature_option_from_raw(raw: u32) -> Option<FeatureOption> {
+    FeatureOption::ALL.get(raw as usize).cloned()
+}
+
+impl_variant_name!(MacroId, MACRO_ID_NAMES, MacroId::from_raw);
+impl_variant_name!(ArgType, ARG_TYPE_NAMES, ArgType::from_raw);
+impl_variant_name!(ArgFmt, ARG_FMT_NAMES, ArgFmt::from_raw);
+impl_variant_name!(Endian, ENDIAN_NAMES, endian_from_raw);
+impl_variant_name!(FeatureOption, FEATURE_OPTION_NAMES, feature_option_from_raw);
+
+const MACRO_ID_NAMES: [&str; 139] = [
+    "gfxd_Invalid",
+    "gfxd_DPFillRectangle",
+    "gfxd_DPFullSync",
+    "gfxd_DPLoadSync",
+    "gfxd_DPTileSync",
+    "gfxd_DPPipeSync",
+    "gfxd_DPLoadTLUT_pal16",
+    "gfxd_DPLoadTLUT_pal256",
+    "gfxd_DPLoadMultiBlockYuvS",
+    "gfxd_DPLoadMultiBlockYuv",
+    "gfxd_DPLoadMultiBlock_4bS",
+    "gfxd_DPLoadMultiBlock_4b",
+    "gfxd_DPLoadMultiBlockS",
+    "gfxd_DPLoadMultiBlock",
+    "gfxd__DPLoadTextureBlockYuvS",
+    "gfxd__DPLoadTextureBlockYuv",
+    "gfxd__DPLoadTextureBlock_4bS",
+    "gfxd__DPLoadTextureBlock_4b",
+    "gfxd__DPLoadTextureBlockS",
+    "gfxd__DPLoadTextureBlock",
+    "gfxd_DPLoadTextureBlockYuvS",
+    "gfxd_DPLoadTextureBlockYuv",
+    "gfxd_DPLoadTextureBlock_4bS",
+    "gfxd_DPLoadTextureBlock_4b",
+    "gfxd_DPLoadTextureBlockS",
+    "gfxd_DPLoadTextureBlock",
+    "gfxd_DPLoadMultiTileYuv",
+    "gfxd_DPLoadMultiTile_4b",
+    "gfxd_DPLoadMultiTile",
+    "gfxd__DPLoadTextureTileYuv",
+    "gfxd__DPLoadTextureTile_4b",
+    "gfxd__DPLoadTextureTile",
+    "gfxd_DPLoadTextureTileYuv",
+    "gfxd_DPLoadTextureTile_4b",
+    "gfxd_DPLoadTextureTile",
+    "gfxd_DPLoadBlock",
+    "gfxd_DPNoOp",
+    "gfxd_DPNoOpTag",
+    "gfxd_DPPipelineMode",
+    "gfxd_DPSetBlendColor",
+    "gfxd_DPSetEnvColor",
+    "gfxd_DPSetFillColor",
+    "gfxd_DPSetFogColor",
+    "gfxd_DPSetPrimColor",
+    "gfxd_DPSetColorImage",
+    "gfxd_DPSetDepthImage",
+    "gfxd_DPSetTextureImage",
+    "gfxd_DPSetAlphaCompare",
+    "gfxd_DPSetAlphaDither",
+    "gfxd_DPSetColorDither",
+    "gfxd_DPSetCombineMode",
+    "gfxd_DPSetCombineLERP",
+    "gfxd_DPSetConvert",
+    "gfxd_DPSetTextureConvert",
+    "gfxd_DPSetCycleType",
+    "gfxd_DPSetDepthSource",
+    "gfxd_DPSetCombineKey",
+    "gfxd_DPSetKeyGB",
+    "gfxd_DPSetKeyR",
+    "gfxd_DPSetPrimDepth",
+    "gfxd_DPSetRenderMode",
+    "gfxd_DPSetScissor",
+    "gfxd_DPSetScissorFrac",
+    "gfxd_DPSetTextureDetail",
+    "gfxd_DPSetTextureFilter",
+    "gfxd_DPSetTextureLOD",
+    "gfxd_DPSetTextureLUT",
+    "gfxd_DPSetTexturePersp",
+    "gfxd_DPSetTile",
+    "gfxd_DPSetTileSize",
+    "gfxd_SP1Triangle",
+    "gfxd_SP2Triangles",
+    "gfxd_SP1Quadrangle",
+    "gfxd_SPBranchLessZraw",
+    "gfxd_SPBranchList",
+    "gfxd_SPClipRatio",
+    "gfxd_SPCullDisplayList",
+    "gfxd_SPDisplayList",
+    "gfxd_SPEndDisplayList",
+    "gfxd_SPFogFactor",
+    "gfxd_SPFogPosition",
+    "gfxd_SPForceMatrix",
+    "gfxd_SPSetGeometryMode",
+    "gfxd_SPClearGeometryMode",
+    "gfxd_SPLoadGeometryMode",
+    "gfxd_SPInsertMatrix",
+    "gfxd_SPLine3D",
+    "gfxd_SPLineW3D",
+    "gfxd_SPLoadUcode",
+    "gfxd_SPLookAtX",
+    "gfxd_SPLookAtY",
+    "gfxd_SPLookAt",
+    "gfxd_SPMatrix",
+    "gfxd_SPModifyVertex",
+    "gfxd_SPPerspNormalize",
+    "gfxd_SPPopMatrix",
+    "gfxd_SPPopMatrixN",
+    "gfxd_SPSegment",
+    "gfxd_SPSetLights1",
+    "gfxd_SPSetLights2",
+    "gfxd_SPSetLights3",
+    "gfxd_SPSetLights4",
+    "gfxd_SPSetLights5",
+    "gfxd_SPSetLights6",
+    "gfxd_SPSetLights7",
+    "gfxd_SPNumLights",
+    "gfxd_SPLight",
+    "gfxd_SPLightColor",
+    "gfxd_SPTexture",
+    "gfxd_SPTextureRectangle",
+    "gfxd_SPTextureRectangleFlip",
+    "gfxd_SPVertex",
+    "gfxd_SPViewport",
+    "gfxd_DPLoadTLUTCmd",
+    "gfxd_DPLoadTLUT",
+    "gfxd_BranchZ",
+    "gfxd_DisplayList",
+    "gfxd_DPHalf1",
+    "gfxd_DPHalf2",
+    "gfxd_DPWord",
+    "gfxd_DPLoadTile",
+    "gfxd_SPGeometryMode",
+    "gfxd_SPSetOtherMode",
+    "gfxd_SPSetOtherModeLo",
+    "gfxd_SPSetOtherModeHi",
+    "gfxd_DPSetOtherMode",
+    "gfxd_MoveWd",
+    "gfxd_MoveMem",
+    "gfxd_SPDma_io",
+    "gfxd_SPDmaRead",
+    "gfxd_SPDmaWrite",
+    "gfxd_LoadUcode",
+    "gfxd_SPLoadUcodeEx",
+    "gfxd_TexRect",
+    "gfxd_TexRectFlip",
+    "gfxd_SPNoOp",
+    "gfxd_Special3",
+    "gfxd_Special2",
+    "gfxd_Special1",
+];
+
+const ARG_TYPE_NAMES: [&str; 96] = [
+    "gfxd_Word",
+    "gfxd_Opcode",
+    "gfxd_Coordi",
+    "gfxd_Coordq",
+    "gfxd_Pal",
+    "gfxd_Tlut",
+    "gfxd_Timg",
+    "gfxd_Tmem",
+    "gfxd_Tile",
+    "gfxd_Fmt",
+    "gfxd_Siz",
+    "gfxd_Dim",
+    "gfxd_Cm",
+    "gfxd_Tm",
+    "gfxd_Ts",
+    "gfxd_Dxt",
+    "gfxd_Tag",
+    "gfxd_Pm",
+    "gfxd_Colorpart",
+    "gfxd_Color",
+    "gfxd_Lodfrac",
+    "gfxd_Cimg",
+    "gfxd_Zimg",
+    "gfxd_Ac",
+    "gfxd_Ad",
+    "gfxd_Cd",
+    "gfxd_Ccpre",
+    "gfxd_Ccmuxa",
+    "gfxd_Ccmuxb",
+    "gfxd_Ccmuxc",
+    "gfxd_Ccmuxd",
+    "gfxd_Acmuxabd",
+    "gfxd_Acmuxc",
+    "gfxd_Cv",
+    "gfxd_Tc",
+    "gfxd_Cyc",
+    "gfxd_Zs",
+    "gfxd_Ck",
+    "gfxd_Keyscale",
+    "gfxd_Keywidth",
+    "gfxd_Zi",
+    "gfxd_Rm1",
+    "gfxd_Rm2",
+    "gfxd_Sc",
+    "gfxd_Td",
+    "gfxd_Tf",
+    "gfxd_Tl",
+    "gfxd_Tt",
+    "gfxd_Tp",
+    "gfxd_Line",
+    "gfxd_Vtx",
+    "gfxd_Vtxflag",
+    "gfxd_Dl",
+    "gfxd_Zraw",
+    "gfxd_Dlflag",
+    "gfxd_Cr",
+    "gfxd_Num",
+    "gfxd_Fogz",
+    "gfxd_Fogp",
+    "gfxd_Mtxptr",
+    "gfxd_Gm",
+    "gfxd_Mwo_matrix",
+    "gfxd_Linewd",
+    "gfxd_Uctext",
+    "gfxd_Ucdata",
+    "gfxd_Size",
+    "gfxd_Lookatptr",
+    "gfxd_Mtxparam",
+    "gfxd_Mtxstack",
+    "gfxd_Mwo_point",
+    "gfxd_Wscale",
+    "gfxd_Seg",
+    "gfxd_Segptr",
+    "gfxd_Lightsn",
+    "gfxd_Numlights",
+    "gfxd_Lightnum",
+    "gfxd_Lightptr",
+    "gfxd_Tcscale",
+    "gfxd_Switch",
+    "gfxd_St",
+    "gfxd_Stdelta",
+    "gfxd_Vtxptr",
+    "gfxd_Vpptr",
+    "gfxd_Dram",
+    "gfxd_Sftlo",
+    "gfxd_Othermodelo",
+    "gfxd_Sfthi",
+    "gfxd_Othermodehi",
+    "gfxd_Mw",
+    "gfxd_Mwo",
+    "gfxd_Mwo_clip",
+    "gfxd_Mwo_lightcol",
+    "gfxd_Mv",
+    "gfxd_Mvo",
+    "gfxd_Dmem",
+    "gfxd_Dmaflag",
+];
+
+const ARG_FMT_NAMES: [&str; 3] = ["gfxd_argfmt_i", "gfxd_argfmt_u", "gfxd_argfmt_f"];
+
+const ENDIAN_NAMES: [&str; 3] = ["gfxd_endian_big", "gfxd_endian_little", "gfxd_endian_host"];
+
+const FEATURE_OPTION_NAMES: [&str; 5] = [
+    "gfxd_stop_on_invalid",
+    "gfxd_stop_on_end",
+    "gfxd_emit_dec_color",
+    "gfxd_emit_q_macro",
+    "gfxd_emit_ext_macro",
+];
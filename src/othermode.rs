@@ -0,0 +1,388 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Othermode and render mode decomposition.
+//!
+//! The RDP othermode is made of two words. The high one, set by
+//! `G_SETOTHERMODE_H`, holds the pipeline and texture settings, see
+//! [`OtherModeHi`]. The low one, set by `G_SETOTHERMODE_L`, holds the alpha
+//! compare and depth source settings along with the [`RenderMode`], see
+//! [`OtherModeLo`].
+//!
+//! Every field holds its value in place, already shifted like the `gbi.h`
+//! constants it can be compared against, so `hi.texture_filter ==
+//! gbi::G_TF_BILERP` works as expected. Printing a value lists each field by
+//! the name of its constant, joined by ` | ` like in C source.
+
+use core::fmt;
+
+use crate::gbi;
+use crate::gfx::Gfx;
+
+/// The bits `sft` and `len` select, as used by `gsSPSetOtherMode`.
+fn mask(sft: u32, len: u32) -> u32 {
+    let bits = if len >= 32 { !0 } else { (1u32 << len) - 1 };
+    bits.checked_shl(sft).unwrap_or(0)
+}
+
+/// Replaces the `len` bits at `sft` of `word` with the same bits of `data`,
+/// like `gsSPSetOtherMode` does.
+#[must_use]
+pub fn update(word: u32, sft: u32, len: u32, data: u32) -> u32 {
+    let mask = mask(sft, len);
+    (word & !mask) | (data & mask)
+}
+
+fn write_field(
+    f: &mut fmt::Formatter<'_>,
+    first: &mut bool,
+    table: &[(&str, u32)],
+    value: u32,
+) -> fmt::Result {
+    if !*first {
+        write!(f, " | ")?;
+    }
+    *first = false;
+    match table.iter().find(|(_, x)| *x == value) {
+        Some((name, _)) => write!(f, "{}", name),
+        None => write!(f, "0x{:X}", value),
+    }
+}
+
+/// The fields of the high othermode word.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct OtherModeHi {
+    /// `G_MDSFT_BLENDMASK`, unused by the hardware.
+    pub blend_mask: u32,
+    /// One of the `G_AD_*` constants.
+    pub alpha_dither: u32,
+    /// One of the `G_CD_*` constants.
+    pub rgb_dither: u32,
+    /// One of the `G_CK_*` constants.
+    pub combine_key: u32,
+    /// One of the `G_TC_*` constants.
+    pub texture_convert: u32,
+    /// One of the `G_TF_*` constants.
+    pub texture_filter: u32,
+    /// One of the `G_TT_*` constants.
+    pub texture_lut: u32,
+    /// One of the `G_TL_*` constants.
+    pub texture_lod: u32,
+    /// One of the `G_TD_*` constants.
+    pub texture_detail: u32,
+    /// One of the `G_TP_*` constants.
+    pub texture_persp: u32,
+    /// One of the `G_CYC_*` constants.
+    pub cycle_type: u32,
+    /// `G_MDSFT_COLORDITHER`, only used by early hardware revisions.
+    pub color_dither: u32,
+    /// One of the `G_PM_*` constants.
+    pub pipeline: u32,
+}
+
+impl OtherModeHi {
+    /// Splits a high othermode word into its fields.
+    #[must_use]
+    pub fn from_bits(bits: u32) -> Self {
+        let field = |sft, len| bits & mask(sft, len);
+        Self {
+            blend_mask: field(gbi::G_MDSFT_BLENDMASK, 4),
+            alpha_dither: field(gbi::G_MDSFT_ALPHADITHER, 2),
+            rgb_dither: field(gbi::G_MDSFT_RGBDITHER, 2),
+            combine_key: field(gbi::G_MDSFT_COMBKEY, 1),
+            texture_convert: field(gbi::G_MDSFT_TEXTCONV, 3),
+            texture_filter: field(gbi::G_MDSFT_TEXTFILT, 2),
+            texture_lut: field(gbi::G_MDSFT_TEXTLUT, 2),
+            texture_lod: field(gbi::G_MDSFT_TEXTLOD, 1),
+            texture_detail: field(gbi::G_MDSFT_TEXTDETAIL, 2),
+            texture_persp: field(gbi::G_MDSFT_TEXTPERSP, 1),
+            cycle_type: field(gbi::G_MDSFT_CYCLETYPE, 2),
+            color_dither: field(gbi::G_MDSFT_COLORDITHER, 1),
+            pipeline: field(gbi::G_MDSFT_PIPELINE, 1),
+        }
+    }
+
+    /// Joins the fields back into a high othermode word.
+    #[must_use]
+    pub fn to_bits(&self) -> u32 {
+        self.blend_mask
+            | self.alpha_dither
+            | self.rgb_dither
+            | self.combine_key
+            | self.texture_convert
+            | self.texture_filter
+            | self.texture_lut
+            | self.texture_lod
+            | self.texture_detail
+            | self.texture_persp
+            | self.cycle_type
+            | self.color_dither
+            | self.pipeline
+    }
+}
+
+impl fmt::Display for OtherModeHi {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let first = &mut true;
+        write_field(f, first, gbi::AD, self.alpha_dither)?;
+        write_field(f, first, gbi::CD, self.rgb_dither)?;
+        write_field(f, first, gbi::CK, self.combine_key)?;
+        write_field(f, first, gbi::TC, self.texture_convert)?;
+        write_field(f, first, gbi::TF, self.texture_filter)?;
+        write_field(f, first, gbi::TT, self.texture_lut)?;
+        write_field(f, first, gbi::TL, self.texture_lod)?;
+        write_field(f, first, gbi::TD, self.texture_detail)?;
+        write_field(f, first, gbi::TP, self.texture_persp)?;
+        write_field(f, first, gbi::CYC, self.cycle_type)?;
+        write_field(f, first, gbi::PM, self.pipeline)?;
+        if self.blend_mask != 0 {
+            write_field(f, first, &[], self.blend_mask)?;
+        }
+        if self.color_dither != 0 {
+            write_field(f, first, &[], self.color_dither)?;
+        }
+        Ok(())
+    }
+}
+
+/// The inputs of one cycle of the blender, which computes
+/// `(p * a + m * b) / (a + b)`.
+///
+/// The fields hold the unshifted `G_BL_*` values, in the order `GBL_c1` and
+/// `GBL_c2` take them.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Blender {
+    /// One of the `G_BL_CLR_*` constants.
+    pub p: u32,
+    /// One of `G_BL_A_IN`, `G_BL_A_FOG`, `G_BL_A_SHADE` and `G_BL_0`.
+    pub a: u32,
+    /// One of the `G_BL_CLR_*` constants.
+    pub m: u32,
+    /// One of `G_BL_1MA`, `G_BL_A_MEM`, `G_BL_1` and `G_BL_0`.
+    pub b: u32,
+}
+
+impl Blender {
+    /// The names of the `p`, `a`, `m` and `b` inputs.
+    #[must_use]
+    pub fn input_names(&self) -> [&'static str; 4] {
+        const CLR: [&str; 4] = ["G_BL_CLR_IN", "G_BL_CLR_MEM", "G_BL_CLR_BL", "G_BL_CLR_FOG"];
+        const A: [&str; 4] = ["G_BL_A_IN", "G_BL_A_FOG", "G_BL_A_SHADE", "G_BL_0"];
+        const B: [&str; 4] = ["G_BL_1MA", "G_BL_A_MEM", "G_BL_1", "G_BL_0"];
+        [
+            CLR[(self.p & 3) as usize],
+            A[(self.a & 3) as usize],
+            CLR[(self.m & 3) as usize],
+            B[(self.b & 3) as usize],
+        ]
+    }
+}
+
+/// The render mode, bits 3 to 31 of the low othermode word.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct RenderMode {
+    /// The flags from `AA_EN` to `FORCE_BL`, in place.
+    pub flags: u32,
+    /// The blender inputs of the first and second cycles.
+    pub cycles: [Blender; 2],
+}
+
+impl RenderMode {
+    /// The bits of the low othermode word that hold the flags.
+    pub const FLAGS_MASK: u32 = 0xFFF8;
+
+    /// Splits the render mode out of a low othermode word, or out of the
+    /// value of a `gsDPSetRenderMode`, `mode1 | mode2`.
+    #[must_use]
+    pub fn from_bits(bits: u32) -> Self {
+        let field = |sft: u32| (bits >> sft) & 3;
+        Self {
+            flags: bits & Self::FLAGS_MASK,
+            cycles: [
+                Blender {
+                    p: field(30),
+                    a: field(26),
+                    m: field(22),
+                    b: field(18),
+                },
+                Blender {
+                    p: field(28),
+                    a: field(24),
+                    m: field(20),
+                    b: field(16),
+                },
+            ],
+        }
+    }
+
+    /// Joins the render mode back into the bits of a low othermode word.
+    #[must_use]
+    pub fn to_bits(&self) -> u32 {
+        let [c1, c2] = self.cycles;
+        self.flags & Self::FLAGS_MASK
+            | gbi::GBL_c1(c1.p & 3, c1.a & 3, c1.m & 3, c1.b & 3)
+            | gbi::GBL_c2(c2.p & 3, c2.a & 3, c2.m & 3, c2.b & 3)
+    }
+
+    /// The `G_RM_*` presets for the first and second cycles that combine
+    /// into this render mode, if any.
+    ///
+    /// Pairs whose presets set the same flags are preferred, so
+    /// `G_RM_AA_ZB_OPA_SURF` is paired with `G_RM_AA_ZB_OPA_SURF2` rather
+    /// than with another preset that only differs in the flags.
+    #[must_use]
+    pub fn presets(&self) -> Option<(&'static str, &'static str)> {
+        let bits = self.to_bits();
+        let find = |same_flags: bool| {
+            gbi::RENDER_MODES_1.iter().find_map(|&(n1, m1)| {
+                gbi::RENDER_MODES_2
+                    .iter()
+                    .find(|&&(_, m2)| {
+                        m1 | m2 == bits
+                            && (!same_flags || m1 & Self::FLAGS_MASK == m2 & Self::FLAGS_MASK)
+                    })
+                    .map(|&(n2, _)| (n1, n2))
+            })
+        };
+        find(true).or_else(|| find(false))
+    }
+}
+
+impl fmt::Display for RenderMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((m1, m2)) = self.presets() {
+            return write!(f, "{} | {}", m1, m2);
+        }
+
+        const SINGLE: [(&str, u32); 8] = [
+            ("AA_EN", gbi::AA_EN),
+            ("Z_CMP", gbi::Z_CMP),
+            ("Z_UPD", gbi::Z_UPD),
+            ("IM_RD", gbi::IM_RD),
+            ("CLR_ON_CVG", gbi::CLR_ON_CVG),
+            ("CVG_X_ALPHA", gbi::CVG_X_ALPHA),
+            ("ALPHA_CVG_SEL", gbi::ALPHA_CVG_SEL),
+            ("FORCE_BL", gbi::FORCE_BL),
+        ];
+        const CVG_DST: [&str; 4] = [
+            "CVG_DST_CLAMP",
+            "CVG_DST_WRAP",
+            "CVG_DST_FULL",
+            "CVG_DST_SAVE",
+        ];
+        const ZMODE: [&str; 4] = ["ZMODE_OPA", "ZMODE_INTER", "ZMODE_XLU", "ZMODE_DEC"];
+
+        for &(name, flag) in SINGLE[..5].iter() {
+            if self.flags & flag != 0 {
+                write!(f, "{} | ", name)?;
+            }
+        }
+        write!(
+            f,
+            "{} | {} | ",
+            CVG_DST[((self.flags >> 8) & 3) as usize],
+            ZMODE[((self.flags >> 10) & 3) as usize]
+        )?;
+        for &(name, flag) in SINGLE[5..].iter() {
+            if self.flags & flag != 0 {
+                write!(f, "{} | ", name)?;
+            }
+        }
+        for (i, cycle) in self.cycles.iter().enumerate() {
+            let [p, a, m, b] = cycle.input_names();
+            if i != 0 {
+                write!(f, " | ")?;
+            }
+            write!(f, "GBL_c{}({}, {}, {}, {})", i + 1, p, a, m, b)?;
+        }
+        Ok(())
+    }
+}
+
+/// The fields of the low othermode word.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct OtherModeLo {
+    /// One of the `G_AC_*` constants.
+    pub alpha_compare: u32,
+    /// One of the `G_ZS_*` constants.
+    pub z_source: u32,
+    pub render_mode: RenderMode,
+}
+
+impl OtherModeLo {
+    /// Splits a low othermode word into its fields.
+    #[must_use]
+    pub fn from_bits(bits: u32) -> Self {
+        Self {
+            alpha_compare: bits & mask(gbi::G_MDSFT_ALPHACOMPARE, 2),
+            z_source: bits & mask(gbi::G_MDSFT_ZSRCSEL, 1),
+            render_mode: RenderMode::from_bits(bits),
+        }
+    }
+
+    /// Joins the fields back into a low othermode word.
+    #[must_use]
+    pub fn to_bits(&self) -> u32 {
+        self.alpha_compare | self.z_source | self.render_mode.to_bits()
+    }
+}
+
+impl fmt::Display for OtherModeLo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let first = &mut true;
+        write_field(f, first, gbi::AC, self.alpha_compare)?;
+        write_field(f, first, gbi::ZS, self.z_source)?;
+        write!(f, " | {}", self.render_mode)
+    }
+}
+
+/// Both othermode words.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct OtherMode {
+    pub hi: OtherModeHi,
+    pub lo: OtherModeLo,
+}
+
+impl OtherMode {
+    /// Splits both othermode words into their fields.
+    #[must_use]
+    pub fn from_bits(hi: u32, lo: u32) -> Self {
+        Self {
+            hi: OtherModeHi::from_bits(hi),
+            lo: OtherModeLo::from_bits(lo),
+        }
+    }
+
+    /// Decodes a `G_RDPSETOTHERMODE` packet, or returns `None` if `gfx` is
+    /// any other command.
+    #[must_use]
+    pub fn from_gfx(gfx: Gfx) -> Option<Self> {
+        if gfx.opcode() as u32 != gbi::G_RDPSETOTHERMODE {
+            return None;
+        }
+        Some(Self::from_bits(gfx.w0 & 0x00FF_FFFF, gfx.w1))
+    }
+
+    /// Encodes both words as a `G_RDPSETOTHERMODE` packet.
+    #[must_use]
+    pub fn to_gfx(&self) -> Gfx {
+        crate::static_dl::gsDPSetOtherMode(self.hi.to_bits(), self.lo.to_bits())
+    }
+
+    /// Applies a `gsSPSetOtherMode` to the high word if `hi` is set, or to
+    /// the low word otherwise.
+    ///
+    /// `sft` and `len` are the values `gbi.h` takes, like the
+    /// [`gfxd_Sfthi`] and [`gfxd_Sftlo`] arguments, not their encoding for
+    /// a given microcode.
+    ///
+    /// [`gfxd_Sfthi`]: crate::arg_type::ArgType::gfxd_Sfthi
+    /// [`gfxd_Sftlo`]: crate::arg_type::ArgType::gfxd_Sftlo
+    pub fn set(&mut self, hi: bool, sft: u32, len: u32, data: u32) {
+        if hi {
+            self.hi = OtherModeHi::from_bits(update(self.hi.to_bits(), sft, len, data));
+        } else {
+            self.lo = OtherModeLo::from_bits(update(self.lo.to_bits(), sft, len, data));
+        }
+    }
+}
@@ -0,0 +1,117 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! A minimal PNG encoder.
+//!
+//! Writes 8-bit RGBA images, like the ones produced by [`texture::decode`],
+//! without any dependency. The image data is stored in uncompressed deflate
+//! blocks, which every PNG decoder reads, trading file size for simplicity.
+//!
+//! [`texture::decode`]: crate::texture::decode
+
+use alloc::vec::Vec;
+
+use crate::texture::RGBA8_SIZE;
+
+const SIGNATURE: [u8; 8] = [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n'];
+
+/// The largest amount of data a stored deflate block can hold.
+const MAX_STORED: usize = 0xFFFF;
+
+fn crc32(chunks: &[&[u8]]) -> u32 {
+    let mut crc = !0u32;
+    for chunk in chunks.iter() {
+        for &byte in chunk.iter() {
+            crc ^= u32::from(byte);
+            for _ in 0..8 {
+                crc = if crc & 1 != 0 {
+                    (crc >> 1) ^ 0xEDB8_8320
+                } else {
+                    crc >> 1
+                };
+            }
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let mut a = 1u32;
+    let mut b = 0u32;
+    for chunk in data.chunks(5552) {
+        for &byte in chunk.iter() {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= 65521;
+        b %= 65521;
+    }
+    (b << 16) | a
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    out.extend_from_slice(&crc32(&[&kind[..], data]).to_be_bytes());
+}
+
+/// Wraps `data` in a zlib stream made of stored deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let blocks = (data.len() + MAX_STORED - 1) / MAX_STORED;
+    let mut out = Vec::with_capacity(data.len() + blocks.max(1) * 5 + 6);
+
+    // Deflate, 32K window, no dictionary, fastest compression.
+    out.extend_from_slice(&[0x78, 0x01]);
+
+    if data.is_empty() {
+        out.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    let mut chunks = data.chunks(MAX_STORED).peekable();
+    while let Some(chunk) = chunks.next() {
+        let last = chunks.peek().is_none();
+        let len = chunk.len() as u16;
+        out.push(last as u8);
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(chunk);
+    }
+
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+/// Encodes an image of `width` by `height` 8-bit RGBA pixels as a PNG file.
+///
+/// Returns `None` if `pixels` does not hold exactly `width * height` pixels.
+#[must_use]
+pub fn encode_rgba8(width: u32, height: u32, pixels: &[u8]) -> Option<Vec<u8>> {
+    let stride = width as usize * RGBA8_SIZE;
+    if pixels.len() != stride * height as usize {
+        return None;
+    }
+
+    let mut header = [0; 13];
+    header[..4].copy_from_slice(&width.to_be_bytes());
+    header[4..8].copy_from_slice(&height.to_be_bytes());
+    // 8 bits per channel, RGBA, deflate, adaptive filtering, no interlace.
+    header[8..].copy_from_slice(&[8, 6, 0, 0, 0]);
+
+    // Every row starts with its filter type, which is always "none".
+    let mut raw = Vec::with_capacity((stride + 1) * height as usize);
+    if stride != 0 {
+        for row in pixels.chunks(stride) {
+            raw.push(0);
+            raw.extend_from_slice(row);
+        }
+    } else {
+        raw.resize(height as usize, 0);
+    }
+
+    let mut out = Vec::new();
+    out.extend_from_slice(&SIGNATURE);
+    write_chunk(&mut out, b"IHDR", &header);
+    write_chunk(&mut out, b"IDAT", &zlib_stored(&raw));
+    write_chunk(&mut out, b"IEND", &[]);
+    Some(out)
+}
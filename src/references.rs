@@ -0,0 +1,428 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! The data a display list references.
+//!
+//! [`collect`] executes a display list with every pointer argument callback
+//! set, and groups the addresses they receive by kind: textures, palettes,
+//! vertices, matrices, lights, viewports, other display lists, color and
+//! depth images, segments and microcode. This is what a display list
+//! depends on when splitting a ROM into assets.
+//!
+//! Every kind is deduplicated and listed in the order it is first
+//! referenced, except vertices, which are merged into [`VtxRanges`].
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Write;
+use core::num::NonZeroU32;
+
+use crate::argument_callbacks as callbacks;
+use crate::decode::DecodeSettings;
+use crate::ffi;
+use crate::json;
+use crate::texture::{self, TextureInfo};
+use crate::vtx::VtxRanges;
+
+use crate::ptr::{NonNullConst, NonNullMut};
+
+/// A texture image, from a [`gfxd_timg_callback`].
+///
+/// [`gfxd_timg_callback`]: crate::argument_callbacks::gfxd_timg_callback
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TextureUse {
+    pub addr: u32,
+    /// One of the `G_IM_FMT_*` constants.
+    pub fmt: u32,
+    /// One of the `G_IM_SIZ_*` constants.
+    pub siz: u32,
+    /// The width of the image, in texels.
+    pub width: u32,
+    /// The height of the image, in texels, if known. A lone
+    /// `gsDPSetTextureImage` does not tell it, only the texture loading
+    /// macros do.
+    pub height: Option<u32>,
+    /// The palette of 4-bit color indexed images.
+    pub pal: u32,
+}
+
+impl TextureUse {
+    /// The format and dimensions of the image, if its height is known.
+    #[must_use]
+    pub fn info(&self) -> Option<TextureInfo> {
+        Some(TextureInfo {
+            fmt: self.fmt,
+            siz: self.siz,
+            width: self.width,
+            height: self.height?,
+            pal: self.pal,
+        })
+    }
+}
+
+/// A palette, from a [`gfxd_tlut_callback`].
+///
+/// [`gfxd_tlut_callback`]: crate::argument_callbacks::gfxd_tlut_callback
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct PaletteUse {
+    pub addr: u32,
+    /// The palette number, for palettes loaded to a single 4-bit palette.
+    pub idx: Option<u32>,
+    /// The number of colors.
+    pub count: u32,
+}
+
+/// Lights, from a [`gfxd_light_callback`] or a [`gfxd_lightsn_callback`].
+///
+/// [`gfxd_light_callback`]: crate::argument_callbacks::gfxd_light_callback
+/// [`gfxd_lightsn_callback`]: crate::argument_callbacks::gfxd_lightsn_callback
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct LightsUse {
+    pub addr: u32,
+    /// The number of directional lights of a `LightsN`, or `None` for a
+    /// single `Light`.
+    pub num: Option<u32>,
+}
+
+/// A color image, from a [`gfxd_cimg_callback`].
+///
+/// [`gfxd_cimg_callback`]: crate::argument_callbacks::gfxd_cimg_callback
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct ColorImageUse {
+    pub addr: u32,
+    pub fmt: u32,
+    pub siz: u32,
+    pub width: u32,
+}
+
+/// A segment base address, from a [`gfxd_seg_callback`].
+///
+/// [`gfxd_seg_callback`]: crate::argument_callbacks::gfxd_seg_callback
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct SegmentUse {
+    pub addr: u32,
+    /// The segment number.
+    pub seg: u32,
+}
+
+/// A block of memory given by its address and size in bytes.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Range {
+    pub addr: u32,
+    pub size: u32,
+}
+
+/// Everything a display list references, grouped by kind.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct References {
+    pub textures: Vec<TextureUse>,
+    pub palettes: Vec<PaletteUse>,
+    pub vertices: VtxRanges,
+    pub matrices: Vec<u32>,
+    pub lights: Vec<LightsUse>,
+    pub lookats: Vec<u32>,
+    pub viewports: Vec<u32>,
+    /// The display lists called or branched to.
+    pub display_lists: Vec<u32>,
+    pub color_images: Vec<ColorImageUse>,
+    pub depth_images: Vec<u32>,
+    pub segments: Vec<SegmentUse>,
+    /// The microcode text loaded, from a [`gfxd_uctext_callback`].
+    ///
+    /// [`gfxd_uctext_callback`]: crate::argument_callbacks::gfxd_uctext_callback
+    pub ucode_text: Vec<Range>,
+    /// The microcode data loaded, from a [`gfxd_ucdata_callback`].
+    ///
+    /// [`gfxd_ucdata_callback`]: crate::argument_callbacks::gfxd_ucdata_callback
+    pub ucode_data: Vec<Range>,
+    /// Other memory addressed directly, from a [`gfxd_dram_callback`].
+    ///
+    /// [`gfxd_dram_callback`]: crate::argument_callbacks::gfxd_dram_callback
+    pub dram: Vec<Range>,
+}
+
+fn push_unique<T: PartialEq>(list: &mut Vec<T>, x: T) {
+    if !list.contains(&x) {
+        list.push(x);
+    }
+}
+
+fn write_list<T, F>(out: &mut String, key: &str, list: &[T], mut f: F)
+where
+    F: FnMut(&mut String, &T),
+{
+    let _ = write!(out, ",\"{}\":[", key);
+    for (i, x) in list.iter().enumerate() {
+        if i != 0 {
+            out.push(',');
+        }
+        f(out, x);
+    }
+    out.push(']');
+}
+
+fn write_addr(out: &mut String, addr: &u32) {
+    let _ = write!(out, "{}", addr);
+}
+
+fn write_range(out: &mut String, range: &Range) {
+    let _ = write!(out, "{{\"addr\":{},\"size\":{}}}", range.addr, range.size);
+}
+
+fn write_option(out: &mut String, x: Option<u32>) {
+    match x {
+        Some(x) => {
+            let _ = write!(out, "{}", x);
+        }
+        None => out.push_str("null"),
+    }
+}
+
+impl References {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The report as a JSON object, with an array for each kind.
+    ///
+    /// Addresses and sizes are numbers. Textures also have the short name of
+    /// their format, as given by [`texture::format_name`], or `null` if it is
+    /// not supported, and a `null` height when it is not known.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("{\"textures\":[");
+        for (i, x) in self.textures.iter().enumerate() {
+            if i != 0 {
+                out.push(',');
+            }
+            let _ = write!(
+                out,
+                "{{\"addr\":{},\"fmt\":{},\"siz\":{},\"format\":",
+                x.addr, x.fmt, x.siz
+            );
+            match texture::format_name(x.fmt, x.siz) {
+                Some(name) => json::write_str(&mut out, name),
+                None => out.push_str("null"),
+            }
+            let _ = write!(out, ",\"width\":{},\"height\":", x.width);
+            write_option(&mut out, x.height);
+            let _ = write!(out, ",\"pal\":{}}}", x.pal);
+        }
+        out.push(']');
+
+        write_list(&mut out, "palettes", &self.palettes, |out, x| {
+            let _ = write!(out, "{{\"addr\":{},\"idx\":", x.addr);
+            write_option(out, x.idx);
+            let _ = write!(out, ",\"count\":{}}}", x.count);
+        });
+        write_list(&mut out, "vertices", self.vertices.ranges(), |out, x| {
+            let _ = write!(out, "{{\"addr\":{},\"count\":{}}}", x.addr, x.count);
+        });
+        write_list(&mut out, "matrices", &self.matrices, write_addr);
+        write_list(&mut out, "lights", &self.lights, |out, x| {
+            let _ = write!(out, "{{\"addr\":{},\"num\":", x.addr);
+            write_option(out, x.num);
+            out.push('}');
+        });
+        write_list(&mut out, "lookats", &self.lookats, write_addr);
+        write_list(&mut out, "viewports", &self.viewports, write_addr);
+        write_list(&mut out, "display_lists", &self.display_lists, write_addr);
+        write_list(&mut out, "color_images", &self.color_images, |out, x| {
+            let _ = write!(
+                out,
+                "{{\"addr\":{},\"fmt\":{},\"siz\":{},\"width\":{}}}",
+                x.addr, x.fmt, x.siz, x.width
+            );
+        });
+        write_list(&mut out, "depth_images", &self.depth_images, write_addr);
+        write_list(&mut out, "segments", &self.segments, |out, x| {
+            let _ = write!(out, "{{\"addr\":{},\"seg\":{}}}", x.addr, x.seg);
+        });
+        write_list(&mut out, "ucode_text", &self.ucode_text, write_range);
+        write_list(&mut out, "ucode_data", &self.ucode_data, write_range);
+        write_list(&mut out, "dram", &self.dram, write_range);
+
+        out.push('}');
+        out
+    }
+}
+
+/// Collects everything the display list in `input` references, decoded
+/// with `settings`.
+#[must_use]
+pub fn collect(input: &[u8], settings: &DecodeSettings) -> References {
+    let mut refs = References::new();
+
+    crate::decode::with_config(|| {
+        settings.apply();
+        // SAFETY: `input` and `refs` outlive the execution.
+        unsafe {
+            crate::io::gfxd_input_buffer(NonNullConst::new_void(input.as_ptr()), input.len() as _);
+            crate::io::gfxd_output_callback(Some(crate::decode::discard_output));
+            crate::handlers::gfxd_macro_fn(Some(crate::handlers::gfxd_macro_dflt));
+
+            callbacks::gfxd_tlut_callback(Some(record_tlut));
+            callbacks::gfxd_timg_callback(Some(record_timg));
+            callbacks::gfxd_cimg_callback(Some(record_cimg));
+            callbacks::gfxd_zimg_callback(Some(record_zimg));
+            callbacks::gfxd_dl_callback(Some(record_dl));
+            callbacks::gfxd_mtx_callback(Some(record_mtx));
+            callbacks::gfxd_lookat_callback(Some(record_lookat));
+            callbacks::gfxd_light_callback(Some(record_light));
+            callbacks::gfxd_lightsn_callback(Some(record_lightsn));
+            callbacks::gfxd_seg_callback(Some(record_seg));
+            callbacks::gfxd_vtx_callback(Some(record_vtx));
+            callbacks::gfxd_vp_callback(Some(record_vp));
+            callbacks::gfxd_uctext_callback(Some(record_uctext));
+            callbacks::gfxd_ucdata_callback(Some(record_ucdata));
+            callbacks::gfxd_dram_callback(Some(record_dram));
+            crate::settings::gfxd_udata_set(NonNullMut::new_void(&mut refs));
+
+            crate::execution::gfxd_execute();
+        }
+    });
+
+    refs
+}
+
+unsafe fn refs<'a>() -> &'a mut References {
+    unsafe { crate::decode::udata() }
+}
+
+unsafe extern "C" fn record_tlut(tlut: u32, idx: i32, count: i32) -> ffi::c_int {
+    if count > 0 {
+        let palette = PaletteUse {
+            addr: tlut,
+            idx: if idx >= 0 { Some(idx as u32) } else { None },
+            count: count as u32,
+        };
+        push_unique(unsafe { &mut refs().palettes }, palette);
+    }
+    0
+}
+
+unsafe extern "C" fn record_timg(
+    timg: u32,
+    fmt: i32,
+    siz: i32,
+    width: i32,
+    height: i32,
+    pal: i32,
+) -> ffi::c_int {
+    if fmt >= 0 && siz >= 0 && width > 0 {
+        let texture = TextureUse {
+            addr: timg,
+            fmt: fmt as u32,
+            siz: siz as u32,
+            width: width as u32,
+            height: if height > 0 {
+                Some(height as u32)
+            } else {
+                None
+            },
+            pal: if pal >= 0 { pal as u32 } else { 0 },
+        };
+        push_unique(unsafe { &mut refs().textures }, texture);
+    }
+    0
+}
+
+unsafe extern "C" fn record_cimg(cimg: u32, fmt: i32, siz: i32, width: i32) -> ffi::c_int {
+    let image = ColorImageUse {
+        addr: cimg,
+        fmt: fmt as u32,
+        siz: siz as u32,
+        width: width as u32,
+    };
+    push_unique(unsafe { &mut refs().color_images }, image);
+    0
+}
+
+unsafe extern "C" fn record_zimg(zimg: u32) -> ffi::c_int {
+    push_unique(unsafe { &mut refs().depth_images }, zimg);
+    0
+}
+
+unsafe extern "C" fn record_dl(dl: u32) -> ffi::c_int {
+    push_unique(unsafe { &mut refs().display_lists }, dl);
+    0
+}
+
+unsafe extern "C" fn record_mtx(mtx: u32) -> ffi::c_int {
+    push_unique(unsafe { &mut refs().matrices }, mtx);
+    0
+}
+
+unsafe extern "C" fn record_lookat(lookat: u32, _count: i32) -> ffi::c_int {
+    push_unique(unsafe { &mut refs().lookats }, lookat);
+    0
+}
+
+unsafe extern "C" fn record_light(light: u32) -> ffi::c_int {
+    let lights = LightsUse {
+        addr: light,
+        num: None,
+    };
+    push_unique(unsafe { &mut refs().lights }, lights);
+    0
+}
+
+unsafe extern "C" fn record_lightsn(lightsn: u32, num: i32) -> ffi::c_int {
+    let lights = LightsUse {
+        addr: lightsn,
+        num: Some(num.max(0) as u32),
+    };
+    push_unique(unsafe { &mut refs().lights }, lights);
+    0
+}
+
+unsafe extern "C" fn record_seg(seg: u32, num: i32) -> ffi::c_int {
+    let segment = SegmentUse {
+        addr: seg,
+        seg: num as u32,
+    };
+    push_unique(unsafe { &mut refs().segments }, segment);
+    0
+}
+
+unsafe extern "C" fn record_vtx(vtx: u32, num: i32) -> ffi::c_int {
+    if num > 0 {
+        unsafe { refs() }.vertices.add(vtx, num as u32);
+    }
+    0
+}
+
+unsafe extern "C" fn record_vp(vp: u32) -> ffi::c_int {
+    push_unique(unsafe { &mut refs().viewports }, vp);
+    0
+}
+
+unsafe extern "C" fn record_uctext(text: u32, size: NonZeroU32) -> ffi::c_int {
+    let range = Range {
+        addr: text,
+        size: size.get(),
+    };
+    push_unique(unsafe { &mut refs().ucode_text }, range);
+    0
+}
+
+unsafe extern "C" fn record_ucdata(data: u32, size: NonZeroU32) -> ffi::c_int {
+    let range = Range {
+        addr: data,
+        size: size.get(),
+    };
+    push_unique(unsafe { &mut refs().ucode_data }, range);
+    0
+}
+
+unsafe extern "C" fn record_dram(dram: u32, size: NonZeroU32) -> ffi::c_int {
+    let range = Range {
+        addr: dram,
+        size: size.get(),
+    };
+    push_unique(unsafe { &mut refs().dram }, range);
+    0
+}
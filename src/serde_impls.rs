@@ -0,0 +1,65 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! `serde` support for the enums of this crate.
+//!
+//! Every enum is serialized as a string holding the `libgfxd` name of its
+//! variant, like `"gfxd_SPVertex"` or `"gfxd_endian_big"`, which is also the
+//! name of the variant in Rust.
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::arg_type::ArgType;
+use crate::macro_id::MacroId;
+use crate::macro_info::ArgFmt;
+use crate::names::VariantName;
+use crate::settings::{Endian, FeatureOption};
+
+/// An enum that is (de)serialized as the name of its variants.
+trait Named: VariantName {
+    const EXPECTING: &'static str;
+}
+
+struct NameVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Named> Visitor<'de> for NameVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(T::EXPECTING)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<T, E> {
+        T::from_variant_name(v).ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+    }
+}
+
+macro_rules! impl_serde {
+    ($ty:ident, $expecting:expr) => {
+        impl Named for $ty {
+            const EXPECTING: &'static str = $expecting;
+        }
+
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.variant_name())
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(NameVisitor(PhantomData))
+            }
+        }
+    };
+}
+
+impl_serde!(MacroId, "a libgfxd macro id");
+impl_serde!(ArgType, "a libgfxd argument type");
+impl_serde!(ArgFmt, "a libgfxd argument format");
+impl_serde!(Endian, "a libgfxd endianness");
+impl_serde!(FeatureOption, "a libgfxd feature option");
@@ -105,11 +105,75 @@ extern "C" {
     pub static gfxd_f3dex2: gfxd_ucode_t;
 }
 
+/// A safe handle to one of the microcodes supported by `libgfxd`.
+///
+/// Each variant maps to the `gfxd_ucode_t` static of the same name, which can
+/// be obtained with [`Ucode::as_raw`] and passed to [`gfxd_target`].
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Ucode {
+    gfxd_f3d = 0,
+    gfxd_f3db = 1,
+    gfxd_f3dex = 2,
+    gfxd_f3dexb = 3,
+    gfxd_f3dex2 = 4,
+}
+
+impl Ucode {
+    /// Every supported microcode.
+    pub const ALL: [Ucode; 5] = [
+        Ucode::gfxd_f3d,
+        Ucode::gfxd_f3db,
+        Ucode::gfxd_f3dex,
+        Ucode::gfxd_f3dexb,
+        Ucode::gfxd_f3dex2,
+    ];
+
+    /// Returns the raw `gfxd_ucode_t` for this microcode.
+    #[must_use]
+    pub fn as_raw(self) -> gfxd_ucode_t {
+        // SAFETY: The ucode statics are immutable and initialized by libgfxd.
+        unsafe {
+            match self {
+                Ucode::gfxd_f3d => gfxd_f3d,
+                Ucode::gfxd_f3db => gfxd_f3db,
+                Ucode::gfxd_f3dex => gfxd_f3dex,
+                Ucode::gfxd_f3dexb => gfxd_f3dexb,
+                Ucode::gfxd_f3dex2 => gfxd_f3dex2,
+            }
+        }
+    }
+
+    /// The name of the microcode, without the `gfxd_` prefix.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Ucode::gfxd_f3d => "f3d",
+            Ucode::gfxd_f3db => "f3db",
+            Ucode::gfxd_f3dex => "f3dex",
+            Ucode::gfxd_f3dexb => "f3dexb",
+            Ucode::gfxd_f3dex2 => "f3dex2",
+        }
+    }
+
+    /// Finds a microcode by its name, with or without the `gfxd_` prefix.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = if name.starts_with("gfxd_") {
+            &name[5..]
+        } else {
+            name
+        };
+
+        Self::ALL.iter().cloned().find(|x| x.name() == name)
+    }
+}
+
 pub const gfxd_endian_big: Endian = Endian::gfxd_endian_big;
 pub const gfxd_endian_little: Endian = Endian::gfxd_endian_little;
 pub const gfxd_endian_host: Endian = Endian::gfxd_endian_host;
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub enum Endian {
     gfxd_endian_big = 0,
     gfxd_endian_little = 1,
@@ -185,3 +249,111 @@ pub enum FeatureOption {
     /// Disabled by default.
     gfxd_emit_ext_macro = 4,
 }
+
+impl FeatureOption {
+    /// Every feature option.
+    pub const ALL: [FeatureOption; 5] = [
+        FeatureOption::gfxd_stop_on_invalid,
+        FeatureOption::gfxd_stop_on_end,
+        FeatureOption::gfxd_emit_dec_color,
+        FeatureOption::gfxd_emit_q_macro,
+        FeatureOption::gfxd_emit_ext_macro,
+    ];
+}
+
+/// A set of [`FeatureOption`]s, stored as a bitmask indexed by the value of
+/// each option.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FeatureSet {
+    bits: u32,
+}
+
+impl FeatureSet {
+    /// The amount of distinct feature sets.
+    pub const COUNT: u32 = 1 << 5;
+
+    /// A set without any feature enabled.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self { bits: 0 }
+    }
+
+    /// A set with every feature enabled.
+    #[must_use]
+    pub const fn all() -> Self {
+        Self {
+            bits: Self::COUNT - 1,
+        }
+    }
+
+    /// The features `libgfxd` enables by default, [`gfxd_stop_on_invalid`]
+    /// and [`gfxd_stop_on_end`].
+    #[must_use]
+    pub const fn libgfxd_default() -> Self {
+        Self {
+            bits: (1 << gfxd_stop_on_invalid as u32) | (1 << gfxd_stop_on_end as u32),
+        }
+    }
+
+    /// Creates a set from a raw bitmask, discarding unknown bits.
+    #[must_use]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self {
+            bits: bits & (Self::COUNT - 1),
+        }
+    }
+
+    /// The raw bitmask of this set.
+    #[must_use]
+    pub const fn bits(self) -> u32 {
+        self.bits
+    }
+
+    /// Returns a copy of this set with `cap` enabled.
+    #[must_use]
+    pub const fn with(self, cap: FeatureOption) -> Self {
+        Self {
+            bits: self.bits | (1 << cap as u32),
+        }
+    }
+
+    /// Returns a copy of this set with `cap` disabled.
+    #[must_use]
+    pub const fn without(self, cap: FeatureOption) -> Self {
+        Self {
+            bits: self.bits & !(1 << cap as u32),
+        }
+    }
+
+    /// Checks if `cap` is enabled in this set.
+    #[must_use]
+    pub const fn contains(self, cap: FeatureOption) -> bool {
+        self.bits & (1 << cap as u32) != 0
+    }
+
+    /// Iterates over every possible feature set.
+    pub fn every() -> impl Iterator<Item = FeatureSet> {
+        (0..Self::COUNT).map(Self::from_bits)
+    }
+
+    /// Enables or disables every feature on the current `libgfxd` config to
+    /// match this set.
+    pub fn apply(self) {
+        for cap in FeatureOption::ALL.iter().cloned() {
+            // SAFETY: Plain setters on the current config.
+            unsafe {
+                if self.contains(cap) {
+                    gfxd_enable(cap);
+                } else {
+                    gfxd_disable(cap);
+                }
+            }
+        }
+    }
+}
+
+impl Default for FeatureSet {
+    fn default() -> Self {
+        Self::libgfxd_default()
+    }
+}
@@ -0,0 +1,580 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! RDP and RSP state tracking across macros.
+//!
+//! `libgfxd` looks at every macro in isolation. A [`StateTracker`] consumes
+//! macros in order and keeps the [`State`] they leave the pipeline in: the
+//! othermode, combiner, colors, geometry mode, tile descriptors, images,
+//! texture memory loads, segment table, vertex buffer and matrix stack.
+//!
+//! Macros are tracked through the packets they encode to, so compound
+//! macros like `gsDPLoadTextureBlock` update every piece of state their
+//! expansion touches. Nothing is read from memory: vertices, matrices and
+//! viewports are recorded by the address they were loaded from, exactly as
+//! the display list gives it, without resolving segments.
+//!
+//! A [`State`] is a plain value, so a snapshot of the state at any macro is
+//! just a clone of [`StateTracker::state`]. With the `alloc` feature,
+//! `track` collects the snapshot after every macro of a decoded display
+//! list.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::address::{AddressResolver, ResolveError, NUM_SEGMENTS};
+use crate::combiner::Combiner;
+use crate::command::Command;
+#[cfg(feature = "alloc")]
+use crate::decode::Macro;
+use crate::encode::{EncodeError, Family};
+use crate::gbi;
+use crate::geometry_mode::{GeometryMode, GeometryModeChange};
+use crate::gfx::Gfx;
+use crate::othermode::OtherMode;
+use crate::settings::Ucode;
+
+/// Picks the value of a constant defined by every microcode family.
+macro_rules! uc {
+    ($family:expr, $name:ident) => {
+        match $family {
+            Family::F3d => gbi::f3d::$name,
+            Family::F3dex => gbi::f3dex::$name,
+            Family::F3dex2 => gbi::f3dex2::$name,
+        }
+    };
+}
+
+/// The number of vertices the largest vertex buffer of any microcode holds.
+pub const VTX_BUFFER_SIZE: usize = 32;
+
+/// The number of modelview matrices the stack can hold. Pushes past this
+/// depth are dropped.
+pub const MATRIX_STACK_SIZE: usize = 32;
+
+/// The number of texture memory loads remembered by [`State::loads`].
+pub const MAX_LOADS: usize = 8;
+
+/// Extracts `w` bits at `s` of `v`, the inverse of `_SHIFTL`.
+fn field(v: u32, s: u32, w: u32) -> u32 {
+    (v >> s) & ((1u64 << w) - 1) as u32
+}
+
+/// An image set by `gsDPSetTextureImage` or `gsDPSetColorImage`.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Image {
+    /// One of the `G_IM_FMT_*` constants.
+    pub fmt: u32,
+    /// One of the `G_IM_SIZ_*` constants.
+    pub siz: u32,
+    /// The width of the image, in texels.
+    pub width: u32,
+    /// The address of the image.
+    pub addr: u32,
+}
+
+/// A tile descriptor, as set by `gsDPSetTile` and `gsDPSetTileSize`.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Tile {
+    pub fmt: u32,
+    pub siz: u32,
+    /// The size of a row, in 64-bit words.
+    pub line: u32,
+    /// The address in texture memory, in 64-bit words.
+    pub tmem: u32,
+    pub palette: u32,
+    pub cmt: u32,
+    pub maskt: u32,
+    pub shiftt: u32,
+    pub cms: u32,
+    pub masks: u32,
+    pub shifts: u32,
+    /// The coordinates of the tile, in 10.2 fixed point.
+    pub uls: u32,
+    pub ult: u32,
+    pub lrs: u32,
+    pub lrt: u32,
+}
+
+/// The command that loaded texture memory.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum LoadKind {
+    /// `G_LOADBLOCK`. `lrs` is the index of the last texel and `lrt` is
+    /// `dxt`.
+    Block,
+    /// `G_LOADTILE`. The coordinates are in 10.2 fixed point.
+    Tile,
+    /// `G_LOADTLUT`. `lrs` is the index of the last color, in 10.2 fixed
+    /// point.
+    Tlut,
+}
+
+/// A load of the texture image into texture memory.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TmemLoad {
+    pub kind: LoadKind,
+    /// The texture image at the time of the load.
+    pub image: Image,
+    /// The tile descriptor used for the load, at the time of the load.
+    pub tile: Tile,
+    pub uls: u32,
+    pub ult: u32,
+    pub lrs: u32,
+    pub lrt: u32,
+}
+
+/// The colors set by the `gsDPSet*Color` macros, packed as `0xRRGGBBAA`.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Colors {
+    pub env: u32,
+    pub prim: u32,
+    pub prim_min_level: u32,
+    pub prim_lod_frac: u32,
+    pub blend: u32,
+    pub fog: u32,
+    /// The fill color, as given to `gsDPSetFillColor`.
+    pub fill: u32,
+    pub prim_z: u32,
+    pub prim_dz: u32,
+}
+
+/// The texture settings of `gsSPTexture`.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Texture {
+    /// The scales, in 0.16 fixed point.
+    pub s: u32,
+    pub t: u32,
+    pub level: u32,
+    pub tile: u32,
+    pub on: bool,
+}
+
+/// The scissor box of `gsDPSetScissorFrac`, in 10.2 fixed point.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Scissor {
+    /// One of the `G_SC_*` constants.
+    pub mode: u32,
+    pub ulx: u32,
+    pub uly: u32,
+    pub lrx: u32,
+    pub lry: u32,
+}
+
+/// A vertex in the vertex buffer.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Vertex {
+    /// The address the vertex was loaded from.
+    pub addr: u32,
+    /// If `gsSPModifyVertex` changed the vertex after it was loaded.
+    pub modified: bool,
+}
+
+/// A matrix applied by `gsSPMatrix`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct MatrixRef {
+    /// The address of the matrix.
+    pub addr: u32,
+    /// If the matrix replaced the previous one, rather than being multiplied
+    /// with it.
+    pub load: bool,
+}
+
+/// The projection matrix and the modelview matrix stack.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct MatrixStack {
+    /// The last projection matrix.
+    pub projection: Option<MatrixRef>,
+    /// The last modelview matrix of each level of the stack, from the bottom
+    /// up to [`MatrixStack::depth`].
+    pub modelview: [Option<MatrixRef>; MATRIX_STACK_SIZE],
+    /// The index of the current level of the stack.
+    pub depth: usize,
+}
+
+impl Default for MatrixStack {
+    fn default() -> Self {
+        Self {
+            projection: None,
+            modelview: [None; MATRIX_STACK_SIZE],
+            depth: 0,
+        }
+    }
+}
+
+impl MatrixStack {
+    /// The last modelview matrix of the current level.
+    #[must_use]
+    pub fn current(&self) -> Option<MatrixRef> {
+        self.modelview[self.depth]
+    }
+}
+
+/// The state of the RDP and RSP.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct State {
+    /// The microcode the state belongs to.
+    pub ucode: Ucode,
+    pub othermode: OtherMode,
+    pub combiner: Combiner,
+    pub colors: Colors,
+    pub geometry_mode: GeometryMode,
+    pub texture: Texture,
+    /// The eight tile descriptors.
+    pub tiles: [Tile; 8],
+    pub texture_image: Image,
+    pub color_image: Image,
+    /// The address of the depth image.
+    pub depth_image: u32,
+    /// The most recent loads into texture memory, newest first. A load
+    /// replaces any earlier one to the same address.
+    pub loads: [Option<TmemLoad>; MAX_LOADS],
+    /// The base address of each segment, `None` for segments that have not
+    /// been set.
+    pub segments: [Option<u32>; NUM_SEGMENTS],
+    pub vertices: [Option<Vertex>; VTX_BUFFER_SIZE],
+    pub matrices: MatrixStack,
+    /// The address of the last viewport.
+    pub viewport: Option<u32>,
+    pub scissor: Scissor,
+}
+
+impl State {
+    /// The state at the start of a display list, with every field zeroed.
+    #[must_use]
+    pub fn new(ucode: Ucode) -> Self {
+        Self {
+            ucode,
+            othermode: OtherMode::default(),
+            combiner: Combiner::default(),
+            colors: Colors::default(),
+            geometry_mode: GeometryMode::new(ucode, 0),
+            texture: Texture::default(),
+            tiles: [Tile::default(); 8],
+            texture_image: Image::default(),
+            color_image: Image::default(),
+            depth_image: 0,
+            loads: [None; MAX_LOADS],
+            segments: [None; NUM_SEGMENTS],
+            vertices: [None; VTX_BUFFER_SIZE],
+            matrices: MatrixStack::default(),
+            viewport: None,
+            scissor: Scissor::default(),
+        }
+    }
+
+    /// The most recent load to the texture memory address `tmem`, in 64-bit
+    /// words.
+    #[must_use]
+    pub fn load_at(&self, tmem: u32) -> Option<&TmemLoad> {
+        self.loads
+            .iter()
+            .filter_map(Option::as_ref)
+            .find(|x| x.tile.tmem == tmem)
+    }
+
+    /// Applies the segment table to `addr`, following segments set to
+    /// addresses in other segments, see [`AddressResolver::translate`].
+    pub fn resolve(&self, addr: u32) -> Result<u32, ResolveError> {
+        let resolver = AddressResolver {
+            segments: self.segments,
+            ..AddressResolver::new()
+        };
+        resolver.translate(addr)
+    }
+
+    fn load(&mut self, kind: LoadKind, tile: usize, uls: u32, ult: u32, lrs: u32, lrt: u32) {
+        let load = TmemLoad {
+            kind,
+            image: self.texture_image,
+            tile: self.tiles[tile],
+            uls,
+            ult,
+            lrs,
+            lrt,
+        };
+
+        let tmem = load.tile.tmem;
+        let end = self
+            .loads
+            .iter()
+            .position(|x| x.map_or(true, |x| x.tile.tmem == tmem))
+            .unwrap_or(MAX_LOADS - 1);
+        for i in (0..end).rev() {
+            self.loads[i + 1] = self.loads[i];
+        }
+        self.loads[0] = Some(load);
+    }
+
+    fn modify_vertex(&mut self, index: u32) {
+        if let Some(Some(vertex)) = self.vertices.get_mut(index as usize) {
+            vertex.modified = true;
+        }
+    }
+}
+
+/// Keeps the [`State`] left by a sequence of macros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StateTracker {
+    state: State,
+}
+
+impl StateTracker {
+    /// A tracker for `ucode`, starting from [`State::new`].
+    #[must_use]
+    pub fn new(ucode: Ucode) -> Self {
+        Self::with_state(State::new(ucode))
+    }
+
+    /// A tracker starting from `state`, such as a snapshot taken by another
+    /// tracker.
+    #[must_use]
+    pub fn with_state(state: State) -> Self {
+        Self { state }
+    }
+
+    /// The current state.
+    #[must_use]
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Updates the state with a single packet.
+    pub fn apply_gfx(&mut self, gfx: Gfx) {
+        let s = &mut self.state;
+        let family = Family::of(s.ucode);
+        let (w0, w1) = (gfx.w0, gfx.w1);
+        let opcode = u32::from(gfx.opcode());
+
+        if let Some(change) = GeometryModeChange::from_gfx(s.ucode, gfx) {
+            s.geometry_mode.bits = change.apply(s.geometry_mode.bits);
+            return;
+        }
+        if let Some(combiner) = Combiner::from_gfx(gfx) {
+            s.combiner = combiner;
+            return;
+        }
+        if let Some(othermode) = OtherMode::from_gfx(gfx) {
+            s.othermode = othermode;
+            return;
+        }
+
+        match opcode {
+            gbi::G_SETENVCOLOR => s.colors.env = w1,
+            gbi::G_SETPRIMCOLOR => {
+                s.colors.prim = w1;
+                s.colors.prim_min_level = field(w0, 8, 8);
+                s.colors.prim_lod_frac = field(w0, 0, 8);
+            }
+            gbi::G_SETBLENDCOLOR => s.colors.blend = w1,
+            gbi::G_SETFOGCOLOR => s.colors.fog = w1,
+            gbi::G_SETFILLCOLOR => s.colors.fill = w1,
+            gbi::G_SETPRIMDEPTH => {
+                s.colors.prim_z = field(w1, 16, 16);
+                s.colors.prim_dz = field(w1, 0, 16);
+            }
+            gbi::G_SETTIMG | gbi::G_SETCIMG => {
+                let image = Image {
+                    fmt: field(w0, 21, 3),
+                    siz: field(w0, 19, 2),
+                    width: field(w0, 0, 12) + 1,
+                    addr: w1,
+                };
+                if opcode == gbi::G_SETTIMG {
+                    s.texture_image = image;
+                } else {
+                    s.color_image = image;
+                }
+            }
+            gbi::G_SETZIMG => s.depth_image = w1,
+            gbi::G_SETTILE => {
+                let tile = &mut s.tiles[field(w1, 24, 3) as usize];
+                tile.fmt = field(w0, 21, 3);
+                tile.siz = field(w0, 19, 2);
+                tile.line = field(w0, 9, 9);
+                tile.tmem = field(w0, 0, 9);
+                tile.palette = field(w1, 20, 4);
+                tile.cmt = field(w1, 18, 2);
+                tile.maskt = field(w1, 14, 4);
+                tile.shiftt = field(w1, 10, 4);
+                tile.cms = field(w1, 8, 2);
+                tile.masks = field(w1, 4, 4);
+                tile.shifts = field(w1, 0, 4);
+            }
+            gbi::G_SETTILESIZE | gbi::G_LOADTILE | gbi::G_LOADBLOCK | gbi::G_LOADTLUT => {
+                let index = field(w1, 24, 3) as usize;
+                let (uls, ult) = (field(w0, 12, 12), field(w0, 0, 12));
+                let (lrs, lrt) = (field(w1, 12, 12), field(w1, 0, 12));
+
+                if opcode == gbi::G_SETTILESIZE || opcode == gbi::G_LOADTILE {
+                    let tile = &mut s.tiles[index];
+                    tile.uls = uls;
+                    tile.ult = ult;
+                    tile.lrs = lrs;
+                    tile.lrt = lrt;
+                }
+                let kind = match opcode {
+                    gbi::G_LOADTILE => LoadKind::Tile,
+                    gbi::G_LOADBLOCK => LoadKind::Block,
+                    gbi::G_LOADTLUT => LoadKind::Tlut,
+                    _ => return,
+                };
+                s.load(kind, index, uls, ult, lrs, lrt);
+            }
+            gbi::G_SETSCISSOR => {
+                s.scissor = Scissor {
+                    mode: field(w1, 24, 2),
+                    ulx: field(w0, 12, 12),
+                    uly: field(w0, 0, 12),
+                    lrx: field(w1, 12, 12),
+                    lry: field(w1, 0, 12),
+                };
+            }
+            _ => self.apply_rsp(family, gfx),
+        }
+    }
+
+    /// Updates the state with the packets of the microcode dependent
+    /// commands.
+    fn apply_rsp(&mut self, family: Family, gfx: Gfx) {
+        let s = &mut self.state;
+        let (w0, w1) = (gfx.w0, gfx.w1);
+        let opcode = u32::from(gfx.opcode());
+
+        if opcode == uc!(family, G_VTX) {
+            let (n, v0) = match family {
+                Family::F3d => (field(w0, 20, 4) + 1, field(w0, 16, 4)),
+                Family::F3dex => (field(w0, 10, 6), field(w0, 16, 8) / 2),
+                Family::F3dex2 => {
+                    let n = field(w0, 12, 8);
+                    (n, field(w0, 1, 7).wrapping_sub(n))
+                }
+            };
+            for i in 0..n {
+                if let Some(slot) = s.vertices.get_mut(v0.wrapping_add(i) as usize) {
+                    *slot = Some(Vertex {
+                        addr: w1.wrapping_add(i * 16),
+                        modified: false,
+                    });
+                }
+            }
+        } else if (family == Family::F3dex && opcode == gbi::f3dex::G_MODIFYVTX)
+            || (family == Family::F3dex2 && opcode == gbi::f3dex2::G_MODIFYVTX)
+        {
+            s.modify_vertex(field(w0, 0, 16) / 2);
+        } else if opcode == uc!(family, G_MTX) {
+            let (param, push) = match family {
+                Family::F3dex2 => {
+                    let param = field(w0, 0, 8) ^ gbi::f3dex2::G_MTX_PUSH;
+                    (param, param & gbi::f3dex2::G_MTX_PUSH != 0)
+                }
+                _ => {
+                    let param = field(w0, 16, 8);
+                    (param, param & gbi::f3d::G_MTX_PUSH != 0)
+                }
+            };
+            let matrix = MatrixRef {
+                addr: w1,
+                load: param & uc!(family, G_MTX_LOAD) != 0,
+            };
+            let stack = &mut s.matrices;
+            if param & uc!(family, G_MTX_PROJECTION) != 0 {
+                stack.projection = Some(matrix);
+            } else {
+                if push && stack.depth + 1 < MATRIX_STACK_SIZE {
+                    stack.modelview[stack.depth + 1] = stack.modelview[stack.depth];
+                    stack.depth += 1;
+                }
+                stack.modelview[stack.depth] = Some(matrix);
+            }
+        } else if opcode == uc!(family, G_POPMTX) {
+            let num = match family {
+                Family::F3dex2 => w1 / 64,
+                _ if w1 & gbi::f3d::G_MTX_PROJECTION == 0 => 1,
+                _ => 0,
+            };
+            let stack = &mut s.matrices;
+            for _ in 0..num {
+                if stack.depth == 0 {
+                    break;
+                }
+                stack.modelview[stack.depth] = None;
+                stack.depth -= 1;
+            }
+        } else if opcode == uc!(family, G_MOVEWORD) {
+            let (index, offset) = match family {
+                Family::F3dex2 => (field(w0, 16, 8), field(w0, 0, 16)),
+                _ => (field(w0, 0, 8), field(w0, 8, 16)),
+            };
+            if index == uc!(family, G_MW_SEGMENT) {
+                if let Some(segment) = s.segments.get_mut(offset as usize / 4) {
+                    *segment = Some(w1);
+                }
+            } else if family != Family::F3dex2 && index == gbi::f3d::G_MW_POINTS {
+                s.modify_vertex(offset / 40);
+            }
+        } else if opcode == uc!(family, G_MOVEMEM) {
+            let index = match family {
+                Family::F3dex2 => field(w0, 0, 8),
+                _ => field(w0, 16, 8),
+            };
+            if index == uc!(family, G_MV_VIEWPORT) {
+                s.viewport = Some(w1);
+            }
+        } else if opcode == uc!(family, G_TEXTURE) {
+            let on = match family {
+                Family::F3dex2 => field(w0, 1, 7),
+                _ => field(w0, 0, 8),
+            };
+            s.texture = Texture {
+                s: field(w1, 16, 16),
+                t: field(w1, 0, 16),
+                level: field(w0, 11, 3),
+                tile: field(w0, 8, 3),
+                on: on != 0,
+            };
+        } else if opcode == uc!(family, G_SETOTHERMODE_H) || opcode == uc!(family, G_SETOTHERMODE_L)
+        {
+            let (sft, len) = match family {
+                Family::F3dex2 => {
+                    let len = field(w0, 0, 8) + 1;
+                    (32u32.wrapping_sub(field(w0, 8, 8)).wrapping_sub(len), len)
+                }
+                _ => (field(w0, 8, 8), field(w0, 0, 8)),
+            };
+            let hi = opcode == uc!(family, G_SETOTHERMODE_H);
+            s.othermode.set(hi, sft, len, w1);
+        }
+    }
+
+    /// Updates the state with every packet of `command`.
+    pub fn apply(&mut self, command: &Command) -> Result<(), EncodeError> {
+        for &gfx in command.encode(self.state.ucode)?.iter() {
+            self.apply_gfx(gfx);
+        }
+        Ok(())
+    }
+
+    /// Updates the state with a decoded macro.
+    #[cfg(feature = "alloc")]
+    pub fn apply_macro(&mut self, m: &Macro) -> Result<(), EncodeError> {
+        let command = Command::from_macro(m).ok_or(EncodeError::ArgCount {
+            id: m.id,
+            expected: m.id.args().len(),
+            found: m.args.len(),
+        })?;
+        self.apply(&command)
+    }
+}
+
+/// The state after each macro of `macros`, starting from [`State::new`].
+#[cfg(feature = "alloc")]
+pub fn track(ucode: Ucode, macros: &[Macro]) -> Result<Vec<State>, EncodeError> {
+    let mut tracker = StateTracker::new(ucode);
+    macros
+        .iter()
+        .map(|m| {
+            tracker.apply_macro(m)?;
+            Ok(tracker.state().clone())
+        })
+        .collect()
+}
@@ -0,0 +1,5382 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! `const fn` builders for static display lists.
+//!
+//! Every static macro of `gbi.h` has a builder named after it, so display
+//! lists can be written directly in Rust as `static` items. Builders of
+//! macros that expand to a single packet return a [`Gfx`], the others return
+//! an array holding every packet of the expansion. [`gs_list!`] concatenates
+//! the output of many builders into a single array.
+//!
+//! The RDP macros, which are encoded the same way by every microcode, are
+//! defined in this module. The microcode dependent ones are defined in
+//! [`f3d`], [`f3dex`] and [`f3dex2`], each of which also re-exports the RDP
+//! builders.
+//!
+//! [`encode`] is implemented on top of these builders. Unlike it, the
+//! builders do not validate their arguments: values that do not fit are
+//! truncated like `gbi.h` does, and an invalid texture size makes the
+//! evaluation fail. `gsDPSetCombineMode` takes
+//! the presets themselves, like [`gbi::G_CC_SHADE`].
+//!
+//! ```
+//! use gfxd_sys::gfx::Gfx;
+//! use gfxd_sys::gs_list;
+//!
+//! static DL: [Gfx; 3] = gs_list!(f3dex2;
+//!     gsSPVertex(0x0600_0000, 3, 0),
+//!     gsSP1Triangle(0, 1, 2, 0),
+//!     gsSPEndDisplayList(),
+//! );
+//! # assert_eq!(0xDF00_0000, DL[2].w0);
+//! ```
+//!
+//! [`encode`]: crate::encode::encode
+//! [`gs_list!`]: crate::gs_list
+
+#![allow(non_snake_case)]
+#![allow(clippy::too_many_arguments)]
+
+use crate::gbi::{self, CombinePreset};
+use crate::gfx::Gfx;
+
+/// `_SHIFTL` from `gbi.h`.
+const fn sh(v: u32, s: u32, w: u32) -> u32 {
+    (v & ((1u64 << w) - 1) as u32) << s
+}
+
+/// `cond ? a : b`, without branching so that it can be used in a `const fn`.
+const fn select(cond: bool, a: u32, b: u32) -> u32 {
+    let mask = 0u32.wrapping_sub(cond as u32);
+    (a & mask) | (b & !mask)
+}
+
+const fn min(a: u32, b: u32) -> u32 {
+    select(a < b, a, b)
+}
+
+const fn max(a: u32, b: u32) -> u32 {
+    select(a > b, a, b)
+}
+
+// Texture size dependent constants, the `G_IM_SIZ_*_*` definitions of
+// `gbi.h`, indexed by `G_IM_SIZ_*`.
+const SIZ_LOAD_BLOCK: [u32; 4] = [
+    gbi::G_IM_SIZ_16b,
+    gbi::G_IM_SIZ_16b,
+    gbi::G_IM_SIZ_16b,
+    gbi::G_IM_SIZ_32b,
+];
+const SIZ_INCR: [u32; 4] = [3, 1, 0, 0];
+const SIZ_SHIFT: [u32; 4] = [2, 1, 0, 0];
+const SIZ_BYTES: [u32; 4] = [0, 1, 2, 4];
+const SIZ_LINE_BYTES: [u32; 4] = [0, 1, 2, 2];
+
+/// `CALC_DXT` from `gbi.h`, `words` being `TXL2WORDS` of the texture width.
+const fn calc_dxt(words: u32) -> u32 {
+    let words = max(words, 1) as u64;
+    (((1 << gbi::G_TX_DXT_FRAC) + words - 1) / words) as u32
+}
+
+const fn op(op: u32) -> Gfx {
+    Gfx::new(sh(op, 24, 8), 0)
+}
+
+const fn imm(op: u32, w1: u32) -> Gfx {
+    Gfx::new(sh(op, 24, 8), w1)
+}
+
+const fn dma1p(op: u32, adrs: u32, len: u32, idx: u32) -> Gfx {
+    Gfx::new(sh(op, 24, 8) | sh(idx, 16, 8) | sh(len, 0, 16), adrs)
+}
+
+const fn dma2p(op: u32, adrs: u32, len: u32, idx: u32, ofs: u32) -> Gfx {
+    Gfx::new(
+        sh(op, 24, 8) | sh(len.wrapping_sub(1) / 8, 19, 5) | sh(ofs / 8, 8, 8) | sh(idx, 0, 8),
+        adrs,
+    )
+}
+
+const fn color(op: u32, r: u32, g: u32, b: u32, a: u32) -> Gfx {
+    Gfx::new(
+        sh(op, 24, 8),
+        sh(r, 24, 8) | sh(g, 16, 8) | sh(b, 8, 8) | sh(a, 0, 8),
+    )
+}
+
+const fn image(op: u32, fmt: u32, siz: u32, width: u32, img: u32) -> Gfx {
+    Gfx::new(
+        sh(op, 24, 8) | sh(fmt, 21, 3) | sh(siz, 19, 2) | sh(width.wrapping_sub(1), 0, 12),
+        img,
+    )
+}
+
+const fn set_tile(
+    fmt: u32,
+    siz: u32,
+    line: u32,
+    tmem: u32,
+    tile: u32,
+    pal: u32,
+    cmt: u32,
+    maskt: u32,
+    shiftt: u32,
+    cms: u32,
+    masks: u32,
+    shifts: u32,
+) -> Gfx {
+    Gfx::new(
+        sh(gbi::G_SETTILE, 24, 8)
+            | sh(fmt, 21, 3)
+            | sh(siz, 19, 2)
+            | sh(line, 9, 9)
+            | sh(tmem, 0, 9),
+        sh(tile, 24, 3)
+            | sh(pal, 20, 4)
+            | sh(cmt, 18, 2)
+            | sh(maskt, 14, 4)
+            | sh(shiftt, 10, 4)
+            | sh(cms, 8, 2)
+            | sh(masks, 4, 4)
+            | sh(shifts, 0, 4),
+    )
+}
+
+/// The layout shared by `gsDPLoadTile`, `gsDPSetTileSize` and
+/// `gsDPLoadBlock`.
+const fn tile_rect(op: u32, tile: u32, uls: u32, ult: u32, lrs: u32, lrt: u32) -> Gfx {
+    Gfx::new(
+        sh(op, 24, 8) | sh(uls, 12, 12) | sh(ult, 0, 12),
+        sh(tile, 24, 3) | sh(lrs, 12, 12) | sh(lrt, 0, 12),
+    )
+}
+
+const fn scissor(mode: u32, ulx: u32, uly: u32, lrx: u32, lry: u32) -> Gfx {
+    Gfx::new(
+        sh(gbi::G_SETSCISSOR, 24, 8) | sh(ulx, 12, 12) | sh(uly, 0, 12),
+        sh(mode, 24, 2) | sh(lrx, 12, 12) | sh(lry, 0, 12),
+    )
+}
+
+const fn tex_rect(op: u32, ulx: u32, uly: u32, lrx: u32, lry: u32, tile: u32) -> Gfx {
+    Gfx::new(
+        sh(op, 24, 8) | sh(lrx, 12, 12) | sh(lry, 0, 12),
+        sh(tile, 24, 3) | sh(ulx, 12, 12) | sh(uly, 0, 12),
+    )
+}
+
+/// `__gsSP1Triangle_w1f`, only for the `f3dex` family.
+const fn tri(v0: u32, v1: u32, v2: u32, flag: u32) -> u32 {
+    let v = [v0, v1, v2, v0, v1];
+    let f = min(flag, 2) as usize;
+    sh(v[f].wrapping_mul(2), 16, 8)
+        | sh(v[f + 1].wrapping_mul(2), 8, 8)
+        | sh(v[f + 2].wrapping_mul(2), 0, 8)
+}
+
+const fn load_tlut(count: u32, tmem: u32, dram: u32) -> [Gfx; 6] {
+    [
+        image(
+            gbi::G_SETTIMG,
+            gbi::G_IM_FMT_RGBA,
+            gbi::G_IM_SIZ_16b,
+            1,
+            dram,
+        ),
+        op(gbi::G_RDPTILESYNC),
+        set_tile(0, 0, 0, tmem, gbi::G_TX_LOADTILE, 0, 0, 0, 0, 0, 0, 0),
+        op(gbi::G_RDPLOADSYNC),
+        gsDPLoadTLUTCmd(gbi::G_TX_LOADTILE, count),
+        op(gbi::G_RDPPIPESYNC),
+    ]
+}
+
+/// The packets shared by every `*DPLoadTextureBlock*` and
+/// `*DPLoadMultiBlock*` macro.
+const fn block(
+    max_txl: u32,
+    timg: u32,
+    tmem: u32,
+    rtile: u32,
+    fmt: u32,
+    load_siz: u32,
+    render_siz: u32,
+    lrs: u32,
+    dxt: u32,
+    line: u32,
+    width: u32,
+    height: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    let loadtile = gbi::G_TX_LOADTILE;
+    [
+        image(gbi::G_SETTIMG, fmt, load_siz, 1, timg),
+        set_tile(
+            fmt, load_siz, 0, tmem, loadtile, 0, cmt, maskt, shiftt, cms, masks, shifts,
+        ),
+        op(gbi::G_RDPLOADSYNC),
+        tile_rect(gbi::G_LOADBLOCK, loadtile, 0, 0, min(lrs, max_txl), dxt),
+        op(gbi::G_RDPPIPESYNC),
+        set_tile(
+            fmt, render_siz, line, tmem, rtile, pal, cmt, maskt, shiftt, cms, masks, shifts,
+        ),
+        tile_rect(
+            gbi::G_SETTILESIZE,
+            rtile,
+            0,
+            0,
+            width.wrapping_sub(1) << gbi::G_TEXTURE_IMAGE_FRAC,
+            height.wrapping_sub(1) << gbi::G_TEXTURE_IMAGE_FRAC,
+        ),
+    ]
+}
+
+const fn load_block(
+    max_txl: u32,
+    timg: u32,
+    tmem: u32,
+    rtile: u32,
+    fmt: u32,
+    siz: u32,
+    width: u32,
+    height: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+    yuv: bool,
+    dxt_zero: bool,
+) -> [Gfx; 7] {
+    let s = siz as usize;
+    let texels = width.wrapping_mul(height);
+    let lrs = (texels.wrapping_add(SIZ_INCR[s]) >> SIZ_SHIFT[s]).wrapping_sub(1);
+    let dxt = calc_dxt(width.wrapping_mul(SIZ_BYTES[s]) / 8);
+    let line_bytes = select(yuv, 1, SIZ_LINE_BYTES[s]);
+    let line = width.wrapping_mul(line_bytes).wrapping_add(7) >> 3;
+    block(
+        max_txl,
+        timg,
+        tmem,
+        rtile,
+        fmt,
+        SIZ_LOAD_BLOCK[s],
+        siz,
+        lrs,
+        select(dxt_zero, 0, dxt),
+        line,
+        width,
+        height,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+    )
+}
+
+const fn load_block_4b(
+    max_txl: u32,
+    timg: u32,
+    tmem: u32,
+    rtile: u32,
+    fmt: u32,
+    width: u32,
+    height: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+    dxt_zero: bool,
+) -> [Gfx; 7] {
+    let texels = width.wrapping_mul(height);
+    let lrs = (texels.wrapping_add(3) >> 2).wrapping_sub(1);
+    let dxt = calc_dxt(width / 16);
+    let line = ((width >> 1).wrapping_add(7)) >> 3;
+    block(
+        max_txl,
+        timg,
+        tmem,
+        rtile,
+        fmt,
+        gbi::G_IM_SIZ_16b,
+        gbi::G_IM_SIZ_4b,
+        lrs,
+        select(dxt_zero, 0, dxt),
+        line,
+        width,
+        height,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+    )
+}
+
+/// The packets shared by every `*DPLoadTextureTile*` and `*DPLoadMultiTile*`
+/// macro.
+const fn tile(
+    timg: u32,
+    tmem: u32,
+    rtile: u32,
+    fmt: u32,
+    load_siz: u32,
+    render_siz: u32,
+    img_width: u32,
+    line: u32,
+    load_uls: u32,
+    load_lrs: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    let loadtile = gbi::G_TX_LOADTILE;
+    [
+        image(gbi::G_SETTIMG, fmt, load_siz, img_width, timg),
+        set_tile(
+            fmt, load_siz, line, tmem, loadtile, 0, cmt, maskt, shiftt, cms, masks, shifts,
+        ),
+        op(gbi::G_RDPLOADSYNC),
+        tile_rect(
+            gbi::G_LOADTILE,
+            loadtile,
+            load_uls,
+            ult << 2,
+            load_lrs,
+            lrt << 2,
+        ),
+        op(gbi::G_RDPPIPESYNC),
+        set_tile(
+            fmt, render_siz, line, tmem, rtile, pal, cmt, maskt, shiftt, cms, masks, shifts,
+        ),
+        tile_rect(
+            gbi::G_SETTILESIZE,
+            rtile,
+            uls << 2,
+            ult << 2,
+            lrs << 2,
+            lrt << 2,
+        ),
+    ]
+}
+
+const fn load_tile(
+    timg: u32,
+    tmem: u32,
+    rtile: u32,
+    fmt: u32,
+    siz: u32,
+    width: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+    yuv: bool,
+) -> [Gfx; 7] {
+    let texels = lrs.wrapping_sub(uls).wrapping_add(1);
+    let line_bytes = select(yuv, 1, SIZ_LINE_BYTES[siz as usize]);
+    let line = texels.wrapping_mul(line_bytes).wrapping_add(7) >> 3;
+    tile(
+        timg,
+        tmem,
+        rtile,
+        fmt,
+        siz,
+        siz,
+        width,
+        line,
+        uls << 2,
+        lrs << 2,
+        uls,
+        ult,
+        lrs,
+        lrt,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+    )
+}
+
+const fn load_tile_4b(
+    timg: u32,
+    tmem: u32,
+    rtile: u32,
+    fmt: u32,
+    width: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    let texels = lrs.wrapping_sub(uls).wrapping_add(1);
+    let line = ((texels >> 1).wrapping_add(7)) >> 3;
+    tile(
+        timg,
+        tmem,
+        rtile,
+        fmt,
+        gbi::G_IM_SIZ_8b,
+        gbi::G_IM_SIZ_4b,
+        width >> 1,
+        line,
+        uls << 1,
+        lrs << 1,
+        uls,
+        ult,
+        lrs,
+        lrt,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+    )
+}
+
+/// `gsDPFillRectangle`.
+#[must_use]
+pub const fn gsDPFillRectangle(ulx: u32, uly: u32, lrx: u32, lry: u32) -> Gfx {
+    Gfx::new(
+        sh(gbi::G_FILLRECT, 24, 8) | sh(lrx, 14, 10) | sh(lry, 2, 10),
+        sh(ulx, 14, 10) | sh(uly, 2, 10),
+    )
+}
+
+/// `gsDPFullSync`.
+#[must_use]
+pub const fn gsDPFullSync() -> Gfx {
+    op(gbi::G_RDPFULLSYNC)
+}
+
+/// `gsDPLoadSync`.
+#[must_use]
+pub const fn gsDPLoadSync() -> Gfx {
+    op(gbi::G_RDPLOADSYNC)
+}
+
+/// `gsDPTileSync`.
+#[must_use]
+pub const fn gsDPTileSync() -> Gfx {
+    op(gbi::G_RDPTILESYNC)
+}
+
+/// `gsDPPipeSync`.
+#[must_use]
+pub const fn gsDPPipeSync() -> Gfx {
+    op(gbi::G_RDPPIPESYNC)
+}
+
+/// `gsDPLoadTLUT_pal16`.
+#[must_use]
+pub const fn gsDPLoadTLUT_pal16(pal: u32, dram: u32) -> [Gfx; 6] {
+    load_tlut(15, 256 + (pal & 0xF) * 16, dram)
+}
+
+/// `gsDPLoadTLUT_pal256`.
+#[must_use]
+pub const fn gsDPLoadTLUT_pal256(dram: u32) -> [Gfx; 6] {
+    load_tlut(255, 256, dram)
+}
+
+/// `gsDPLoadMultiTileYuv`.
+#[must_use]
+pub const fn gsDPLoadMultiTileYuv(
+    timg: u32,
+    tmem: u32,
+    rtile: u32,
+    fmt: u32,
+    siz: u32,
+    width: u32,
+    _height: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    load_tile(
+        timg, tmem, rtile, fmt, siz, width, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt,
+        shifts, shiftt, true,
+    )
+}
+
+/// `gsDPLoadMultiTile_4b`.
+#[must_use]
+pub const fn gsDPLoadMultiTile_4b(
+    timg: u32,
+    tmem: u32,
+    rtile: u32,
+    fmt: u32,
+    width: u32,
+    _height: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    load_tile_4b(
+        timg, tmem, rtile, fmt, width, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt, shifts,
+        shiftt,
+    )
+}
+
+/// `gsDPLoadMultiTile`.
+#[must_use]
+pub const fn gsDPLoadMultiTile(
+    timg: u32,
+    tmem: u32,
+    rtile: u32,
+    fmt: u32,
+    siz: u32,
+    width: u32,
+    _height: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    load_tile(
+        timg, tmem, rtile, fmt, siz, width, uls, ult, lrs, lrt, pal, cms, cmt, masks, maskt,
+        shifts, shiftt, false,
+    )
+}
+
+/// `_gsDPLoadTextureTileYuv`.
+#[must_use]
+pub const fn _gsDPLoadTextureTileYuv(
+    timg: u32,
+    tmem: u32,
+    fmt: u32,
+    siz: u32,
+    width: u32,
+    _height: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    load_tile(
+        timg,
+        tmem,
+        gbi::G_TX_RENDERTILE,
+        fmt,
+        siz,
+        width,
+        uls,
+        ult,
+        lrs,
+        lrt,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+        true,
+    )
+}
+
+/// `_gsDPLoadTextureTile_4b`.
+#[must_use]
+pub const fn _gsDPLoadTextureTile_4b(
+    timg: u32,
+    tmem: u32,
+    fmt: u32,
+    width: u32,
+    _height: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    load_tile_4b(
+        timg,
+        tmem,
+        gbi::G_TX_RENDERTILE,
+        fmt,
+        width,
+        uls,
+        ult,
+        lrs,
+        lrt,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+    )
+}
+
+/// `_gsDPLoadTextureTile`.
+#[must_use]
+pub const fn _gsDPLoadTextureTile(
+    timg: u32,
+    tmem: u32,
+    fmt: u32,
+    siz: u32,
+    width: u32,
+    _height: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    load_tile(
+        timg,
+        tmem,
+        gbi::G_TX_RENDERTILE,
+        fmt,
+        siz,
+        width,
+        uls,
+        ult,
+        lrs,
+        lrt,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+        false,
+    )
+}
+
+/// `gsDPLoadTextureTileYuv`.
+#[must_use]
+pub const fn gsDPLoadTextureTileYuv(
+    timg: u32,
+    fmt: u32,
+    siz: u32,
+    width: u32,
+    _height: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    load_tile(
+        timg,
+        0,
+        gbi::G_TX_RENDERTILE,
+        fmt,
+        siz,
+        width,
+        uls,
+        ult,
+        lrs,
+        lrt,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+        true,
+    )
+}
+
+/// `gsDPLoadTextureTile_4b`.
+#[must_use]
+pub const fn gsDPLoadTextureTile_4b(
+    timg: u32,
+    fmt: u32,
+    width: u32,
+    _height: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    load_tile_4b(
+        timg,
+        0,
+        gbi::G_TX_RENDERTILE,
+        fmt,
+        width,
+        uls,
+        ult,
+        lrs,
+        lrt,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+    )
+}
+
+/// `gsDPLoadTextureTile`.
+#[must_use]
+pub const fn gsDPLoadTextureTile(
+    timg: u32,
+    fmt: u32,
+    siz: u32,
+    width: u32,
+    _height: u32,
+    uls: u32,
+    ult: u32,
+    lrs: u32,
+    lrt: u32,
+    pal: u32,
+    cms: u32,
+    cmt: u32,
+    masks: u32,
+    maskt: u32,
+    shifts: u32,
+    shiftt: u32,
+) -> [Gfx; 7] {
+    load_tile(
+        timg,
+        0,
+        gbi::G_TX_RENDERTILE,
+        fmt,
+        siz,
+        width,
+        uls,
+        ult,
+        lrs,
+        lrt,
+        pal,
+        cms,
+        cmt,
+        masks,
+        maskt,
+        shifts,
+        shiftt,
+        false,
+    )
+}
+
+/// `gsDPLoadBlock`.
+#[must_use]
+pub const fn gsDPLoadBlock(tile: u32, uls: u32, ult: u32, lrs: u32, dxt: u32) -> Gfx {
+    tile_rect(gbi::G_LOADBLOCK, tile, uls, ult, lrs, dxt)
+}
+
+/// `gsDPSetBlendColor`.
+#[must_use]
+pub const fn gsDPSetBlendColor(r: u32, g: u32, b: u32, a: u32) -> Gfx {
+    color(gbi::G_SETBLENDCOLOR, r, g, b, a)
+}
+
+/// `gsDPSetEnvColor`.
+#[must_use]
+pub const fn gsDPSetEnvColor(r: u32, g: u32, b: u32, a: u32) -> Gfx {
+    color(gbi::G_SETENVCOLOR, r, g, b, a)
+}
+
+/// `gsDPSetFillColor`.
+#[must_use]
+pub const fn gsDPSetFillColor(c: u32) -> Gfx {
+    imm(gbi::G_SETFILLCOLOR, c)
+}
+
+/// `gsDPSetFogColor`.
+#[must_use]
+pub const fn gsDPSetFogColor(r: u32, g: u32, b: u32, a: u32) -> Gfx {
+    color(gbi::G_SETFOGCOLOR, r, g, b, a)
+}
+
+/// `gsDPSetPrimColor`.
+#[must_use]
+pub const fn gsDPSetPrimColor(m: u32, l: u32, r: u32, g: u32, b: u32, a: u32) -> Gfx {
+    Gfx::new(
+        sh(gbi::G_SETPRIMCOLOR, 24, 8) | sh(m, 8, 8) | sh(l, 0, 8),
+        sh(r, 24, 8) | sh(g, 16, 8) | sh(b, 8, 8) | sh(a, 0, 8),
+    )
+}
+
+/// `gsDPSetColorImage`.
+#[must_use]
+pub const fn gsDPSetColorImage(fmt: u32, siz: u32, width: u32, cimg: u32) -> Gfx {
+    image(gbi::G_SETCIMG, fmt, siz, width, cimg)
+}
+
+/// `gsDPSetDepthImage`.
+#[must_use]
+pub const fn gsDPSetDepthImage(zimg: u32) -> Gfx {
+    imm(gbi::G_SETZIMG, zimg)
+}
+
+/// `gsDPSetTextureImage`.
+#[must_use]
+pub const fn gsDPSetTextureImage(fmt: u32, siz: u32, width: u32, timg: u32) -> Gfx {
+    image(gbi::G_SETTIMG, fmt, siz, width, timg)
+}
+
+/// `gsDPSetCombineMode`.
+#[must_use]
+pub const fn gsDPSetCombineMode(mode1: CombinePreset, mode2: CombinePreset) -> Gfx {
+    gsDPSetCombineLERP(
+        mode1.a, mode1.b, mode1.c, mode1.d, mode1.aa, mode1.ab, mode1.ac, mode1.ad, mode2.a,
+        mode2.b, mode2.c, mode2.d, mode2.aa, mode2.ab, mode2.ac, mode2.ad,
+    )
+}
+
+/// `gsDPSetCombineLERP`.
+#[must_use]
+pub const fn gsDPSetCombineLERP(
+    a0: u32,
+    b0: u32,
+    c0: u32,
+    d0: u32,
+    Aa0: u32,
+    Ab0: u32,
+    Ac0: u32,
+    Ad0: u32,
+    a1: u32,
+    b1: u32,
+    c1: u32,
+    d1: u32,
+    Aa1: u32,
+    Ab1: u32,
+    Ac1: u32,
+    Ad1: u32,
+) -> Gfx {
+    Gfx::new(
+        sh(gbi::G_SETCOMBINE, 24, 8)
+            | sh(a0, 20, 4)
+            | sh(c0, 15, 5)
+            | sh(Aa0, 12, 3)
+            | sh(Ac0, 9, 3)
+            | sh(a1, 5, 4)
+            | sh(c1, 0, 5),
+        sh(b0, 28, 4)
+            | sh(d0, 15, 3)
+            | sh(Ab0, 12, 3)
+            | sh(Ad0, 9, 3)
+            | sh(b1, 24, 4)
+            | sh(Aa1, 21, 3)
+            | sh(Ac1, 18, 3)
+            | sh(d1, 6, 3)
+            | sh(Ab1, 3, 3)
+            | sh(Ad1, 0, 3),
+    )
+}
+
+/// `gsDPSetConvert`.
+#[must_use]
+pub const fn gsDPSetConvert(k0: u32, k1: u32, k2: u32, k3: u32, k4: u32, k5: u32) -> Gfx {
+    Gfx::new(
+        sh(gbi::G_SETCONVERT, 24, 8) | sh(k0, 13, 9) | sh(k1, 4, 9) | ((k2 >> 5) & 0xF),
+        sh(k2, 27, 5) | sh(k3, 18, 9) | sh(k4, 9, 9) | sh(k5, 0, 9),
+    )
+}
+
+/// `gsDPSetKeyGB`.
+#[must_use]
+pub const fn gsDPSetKeyGB(cG: u32, sG: u32, wG: u32, cB: u32, sB: u32, wB: u32) -> Gfx {
+    Gfx::new(
+        sh(gbi::G_SETKEYGB, 24, 8) | sh(wG, 12, 12) | sh(wB, 0, 12),
+        sh(cG, 24, 8) | sh(sG, 16, 8) | sh(cB, 8, 8) | sh(sB, 0, 8),
+    )
+}
+
+/// `gsDPSetKeyR`.
+#[must_use]
+pub const fn gsDPSetKeyR(cR: u32, sR: u32, wR: u32) -> Gfx {
+    Gfx::new(
+        sh(gbi::G_SETKEYR, 24, 8),
+        sh(wR, 16, 12) | sh(cR, 8, 8) | sh(sR, 0, 8),
+    )
+}
+
+/// `gsDPSetPrimDepth`.
+#[must_use]
+pub const fn gsDPSetPrimDepth(z: u32, dz: u32) -> Gfx {
+    imm(gbi::G_SETPRIMDEPTH, sh(z, 16, 16) | sh(dz, 0, 16))
+}
+
+/// `gsDPSetScissor`.
+#[must_use]
+pub const fn gsDPSetScissor(mode: u32, ulx: u32, uly: u32, lrx: u32, lry: u32) -> Gfx {
+    scissor(
+        mode,
+        ulx.wrapping_mul(4),
+        uly.wrapping_mul(4),
+        lrx.wrapping_mul(4),
+        lry.wrapping_mul(4),
+    )
+}
+
+/// `gsDPSetScissorFrac`.
+#[must_use]
+pub const fn gsDPSetScissorFrac(mode: u32, ulx: u32, uly: u32, lrx: u32, lry: u32) -> Gfx {
+    scissor(mode, ulx, uly, lrx, lry)
+}
+
+/// `gsDPSetTile`.
+#[must_use]
+pub const fn gsDPSetTile(
+    fmt: u32,
+    siz: u32,
+    line: u32,
+    tmem: u32,
+    tile: u32,
+    pal: u32,
+    cmt: u32,
+    maskt: u32,
+    shiftt: u32,
+    cms: u32,
+    masks: u32,
+    shifts: u32,
+) -> Gfx {
+    set_tile(
+        fmt, siz, line, tmem, tile, pal, cmt, maskt, shiftt, cms, masks, shifts,
+    )
+}
+
+/// `gsDPSetTileSize`.
+#[must_use]
+pub const fn gsDPSetTileSize(tile: u32, uls: u32, ult: u32, lrs: u32, lrt: u32) -> Gfx {
+    tile_rect(gbi::G_SETTILESIZE, tile, uls, ult, lrs, lrt)
+}
+
+/// `gsDPLoadTLUTCmd`.
+#[must_use]
+pub const fn gsDPLoadTLUTCmd(tile: u32, count: u32) -> Gfx {
+    Gfx::new(
+        sh(gbi::G_LOADTLUT, 24, 8),
+        sh(tile, 24, 3) | sh(count, 14, 10),
+    )
+}
+
+/// `gsDPLoadTLUT`.
+#[must_use]
+pub const fn gsDPLoadTLUT(count: u32, tmem: u32, dram: u32) -> [Gfx; 6] {
+    load_tlut(count.wrapping_sub(1), tmem, dram)
+}
+
+/// `gsDPLoadTile`.
+#[must_use]
+pub const fn gsDPLoadTile(tile: u32, uls: u32, ult: u32, lrs: u32, lrt: u32) -> Gfx {
+    tile_rect(gbi::G_LOADTILE, tile, uls, ult, lrs, lrt)
+}
+
+/// `gsDPSetOtherMode`.
+#[must_use]
+pub const fn gsDPSetOtherMode(hi: u32, lo: u32) -> Gfx {
+    Gfx::new(sh(gbi::G_RDPSETOTHERMODE, 24, 8) | sh(hi, 0, 24), lo)
+}
+
+/// `gsTexRect`.
+#[must_use]
+pub const fn gsTexRect(ulx: u32, uly: u32, lrx: u32, lry: u32, tile: u32) -> Gfx {
+    tex_rect(gbi::G_TEXRECT, ulx, uly, lrx, lry, tile)
+}
+
+/// `gsTexRectFlip`.
+#[must_use]
+pub const fn gsTexRectFlip(ulx: u32, uly: u32, lrx: u32, lry: u32, tile: u32) -> Gfx {
+    tex_rect(gbi::G_TEXRECTFLIP, ulx, uly, lrx, lry, tile)
+}
+
+pub mod f3d {
+    //! Builders for the `f3d` family, `gbi.h` built without `F3DEX_GBI` or
+    //! `F3DEX_GBI_2`. They also apply to `f3db`.
+
+    #![allow(non_snake_case)]
+
+    use super::sh;
+    use crate::gbi::{self, f3d as uc};
+    use crate::gfx::Gfx;
+
+    pub use super::{
+        _gsDPLoadTextureTile, _gsDPLoadTextureTileYuv, _gsDPLoadTextureTile_4b, gsDPFillRectangle,
+        gsDPFullSync, gsDPLoadBlock, gsDPLoadMultiTile, gsDPLoadMultiTileYuv, gsDPLoadMultiTile_4b,
+        gsDPLoadSync, gsDPLoadTLUT, gsDPLoadTLUTCmd, gsDPLoadTLUT_pal16, gsDPLoadTLUT_pal256,
+        gsDPLoadTextureTile, gsDPLoadTextureTileYuv, gsDPLoadTextureTile_4b, gsDPLoadTile,
+        gsDPPipeSync, gsDPSetBlendColor, gsDPSetColorImage, gsDPSetCombineLERP, gsDPSetCombineMode,
+        gsDPSetConvert, gsDPSetDepthImage, gsDPSetEnvColor, gsDPSetFillColor, gsDPSetFogColor,
+        gsDPSetKeyGB, gsDPSetKeyR, gsDPSetOtherMode, gsDPSetPrimColor, gsDPSetPrimDepth,
+        gsDPSetScissor, gsDPSetScissorFrac, gsDPSetTextureImage, gsDPSetTile, gsDPSetTileSize,
+        gsDPTileSync, gsTexRect, gsTexRectFlip,
+    };
+
+    const fn move_wd(index: u32, offset: u32, data: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_MOVEWORD, 24, 8) | sh(offset, 8, 16) | sh(index, 0, 8),
+            data,
+        )
+    }
+
+    const fn move_mem(len: u32, index: u32, offset: u32, adrs: u32) -> Gfx {
+        super::dma1p(uc::G_MOVEMEM, adrs, len, index.wrapping_add(offset))
+    }
+
+    const fn set_other_mode(op: u32, sft: u32, len: u32, data: u32) -> Gfx {
+        Gfx::new(sh(op, 24, 8) | sh(sft, 8, 8) | sh(len, 0, 8), data)
+    }
+
+    const fn set_light(light: u32, n: u32) -> Gfx {
+        let idx = n.wrapping_sub(1).wrapping_mul(2).wrapping_add(uc::G_MV_L0);
+        super::dma1p(uc::G_MOVEMEM, light, 16, idx)
+    }
+
+    const fn look_at_x(l: u32) -> Gfx {
+        move_mem(16, uc::G_MV_LOOKATX, 0, l)
+    }
+
+    const fn look_at_y(l: u32) -> Gfx {
+        move_mem(16, uc::G_MV_LOOKATY, 0, l)
+    }
+
+    const fn half_1(w1: u32) -> Gfx {
+        super::imm(uc::G_RDPHALF_1, w1)
+    }
+
+    const fn half_2(w1: u32) -> Gfx {
+        super::imm(uc::G_RDPHALF_2, w1)
+    }
+
+    const fn display_list(dl: u32, flag: u32) -> Gfx {
+        Gfx::new(sh(uc::G_DL, 24, 8) | sh(flag, 16, 8), dl)
+    }
+
+    const fn line_3d(v0: u32, v1: u32, wd: u32, flag: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_LINE3D, 24, 8),
+            sh(flag, 24, 8)
+                | sh(v0.wrapping_mul(10), 16, 8)
+                | sh(v1.wrapping_mul(10), 8, 8)
+                | sh(wd, 0, 8),
+        )
+    }
+
+    const fn num_lights(n: u32) -> Gfx {
+        move_wd(uc::G_MW_NUMLIGHT, gbi::G_MWO_NUMLIGHT, uc::NUML(n))
+    }
+
+    const fn fog_factor(fm: u32, fo: u32) -> Gfx {
+        move_wd(uc::G_MW_FOG, gbi::G_MWO_FOG, sh(fm, 16, 16) | sh(fo, 0, 16))
+    }
+
+    /// `gsDPLoadMultiBlockYuvS`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlockYuvS(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            true,
+        )
+    }
+
+    /// `gsDPLoadMultiBlockYuv`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlockYuv(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            false,
+        )
+    }
+
+    /// `gsDPLoadMultiBlock_4bS`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlock_4bS(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+        )
+    }
+
+    /// `gsDPLoadMultiBlock_4b`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlock_4b(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+        )
+    }
+
+    /// `gsDPLoadMultiBlockS`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlockS(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            true,
+        )
+    }
+
+    /// `gsDPLoadMultiBlock`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlock(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            false,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlockYuvS`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlockYuvS(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            true,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlockYuv`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlockYuv(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            false,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlock_4bS`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlock_4bS(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlock_4b`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlock_4b(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlockS`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlockS(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            true,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlock`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlock(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            false,
+        )
+    }
+
+    /// `gsDPLoadTextureBlockYuvS`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlockYuvS(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            true,
+        )
+    }
+
+    /// `gsDPLoadTextureBlockYuv`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlockYuv(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            false,
+        )
+    }
+
+    /// `gsDPLoadTextureBlock_4bS`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlock_4bS(
+        timg: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+        )
+    }
+
+    /// `gsDPLoadTextureBlock_4b`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlock_4b(
+        timg: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+        )
+    }
+
+    /// `gsDPLoadTextureBlockS`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlockS(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            true,
+        )
+    }
+
+    /// `gsDPLoadTextureBlock`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlock(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            false,
+        )
+    }
+
+    /// `gsDPNoOp`.
+    #[must_use]
+    pub const fn gsDPNoOp() -> Gfx {
+        super::op(uc::G_NOOP)
+    }
+
+    /// `gsDPNoOpTag`.
+    #[must_use]
+    pub const fn gsDPNoOpTag(tag: u32) -> Gfx {
+        super::imm(uc::G_NOOP, tag)
+    }
+
+    /// `gsDPPipelineMode`.
+    #[must_use]
+    pub const fn gsDPPipelineMode(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_PIPELINE, 1, mode)
+    }
+
+    /// `gsDPSetAlphaCompare`.
+    #[must_use]
+    pub const fn gsDPSetAlphaCompare(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_L, gbi::G_MDSFT_ALPHACOMPARE, 2, mode)
+    }
+
+    /// `gsDPSetAlphaDither`.
+    #[must_use]
+    pub const fn gsDPSetAlphaDither(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_ALPHADITHER, 2, mode)
+    }
+
+    /// `gsDPSetColorDither`.
+    #[must_use]
+    pub const fn gsDPSetColorDither(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_RGBDITHER, 2, mode)
+    }
+
+    /// `gsDPSetTextureConvert`.
+    #[must_use]
+    pub const fn gsDPSetTextureConvert(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTCONV, 3, mode)
+    }
+
+    /// `gsDPSetCycleType`.
+    #[must_use]
+    pub const fn gsDPSetCycleType(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_CYCLETYPE, 2, mode)
+    }
+
+    /// `gsDPSetDepthSource`.
+    #[must_use]
+    pub const fn gsDPSetDepthSource(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_L, gbi::G_MDSFT_ZSRCSEL, 1, mode)
+    }
+
+    /// `gsDPSetCombineKey`.
+    #[must_use]
+    pub const fn gsDPSetCombineKey(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_COMBKEY, 1, mode)
+    }
+
+    /// `gsDPSetRenderMode`.
+    #[must_use]
+    pub const fn gsDPSetRenderMode(mode1: u32, mode2: u32) -> Gfx {
+        set_other_mode(
+            uc::G_SETOTHERMODE_L,
+            gbi::G_MDSFT_RENDERMODE,
+            29,
+            mode1 | mode2,
+        )
+    }
+
+    /// `gsDPSetTextureDetail`.
+    #[must_use]
+    pub const fn gsDPSetTextureDetail(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTDETAIL, 2, mode)
+    }
+
+    /// `gsDPSetTextureFilter`.
+    #[must_use]
+    pub const fn gsDPSetTextureFilter(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTFILT, 2, mode)
+    }
+
+    /// `gsDPSetTextureLOD`.
+    #[must_use]
+    pub const fn gsDPSetTextureLOD(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTLOD, 1, mode)
+    }
+
+    /// `gsDPSetTextureLUT`.
+    #[must_use]
+    pub const fn gsDPSetTextureLUT(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTLUT, 2, mode)
+    }
+
+    /// `gsDPSetTexturePersp`.
+    #[must_use]
+    pub const fn gsDPSetTexturePersp(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTPERSP, 1, mode)
+    }
+
+    /// `gsSP1Triangle`.
+    #[must_use]
+    pub const fn gsSP1Triangle(v0: u32, v1: u32, v2: u32, flag: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_TRI1, 24, 8),
+            sh(flag, 24, 8)
+                | sh(v0.wrapping_mul(10), 16, 8)
+                | sh(v1.wrapping_mul(10), 8, 8)
+                | sh(v2.wrapping_mul(10), 0, 8),
+        )
+    }
+
+    /// `gsSPBranchList`.
+    #[must_use]
+    pub const fn gsSPBranchList(dl: u32) -> Gfx {
+        display_list(dl, gbi::G_DL_NOPUSH)
+    }
+
+    /// `gsSPClipRatio`.
+    #[must_use]
+    pub const fn gsSPClipRatio(r: u32) -> [Gfx; 4] {
+        let neg = 0u32.wrapping_sub(r) & 0xFFFF;
+        [
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RNX, neg),
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RNY, neg),
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RPX, r),
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RPY, r),
+        ]
+    }
+
+    /// `gsSPCullDisplayList`.
+    #[must_use]
+    pub const fn gsSPCullDisplayList(vstart: u32, vend: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_CULLDL, 24, 8) | ((vstart & 0xF) * 40),
+            (vend.wrapping_add(1) & 0xF) * 40,
+        )
+    }
+
+    /// `gsSPDisplayList`.
+    #[must_use]
+    pub const fn gsSPDisplayList(dl: u32) -> Gfx {
+        display_list(dl, gbi::G_DL_PUSH)
+    }
+
+    /// `gsSPEndDisplayList`.
+    #[must_use]
+    pub const fn gsSPEndDisplayList() -> Gfx {
+        super::op(uc::G_ENDDL)
+    }
+
+    /// `gsSPFogFactor`.
+    #[must_use]
+    pub const fn gsSPFogFactor(fm: u32, fo: u32) -> Gfx {
+        fog_factor(fm, fo)
+    }
+
+    /// `gsSPFogPosition`.
+    #[must_use]
+    pub const fn gsSPFogPosition(min: u32, max: u32) -> Gfx {
+        let min = min as i32 as i64;
+        let max = max as i32 as i64;
+        let fm = 128_000 / (max - min);
+        let fo = (500 - min) * 256 / (max - min);
+        fog_factor(fm as u32, fo as u32)
+    }
+
+    /// `gsSPForceMatrix`.
+    #[must_use]
+    pub const fn gsSPForceMatrix(mtx: u32) -> [Gfx; 4] {
+        [
+            move_mem(16, uc::G_MV_MATRIX_1, 0, mtx),
+            move_mem(16, uc::G_MV_MATRIX_2, 0, mtx.wrapping_add(16)),
+            move_mem(16, uc::G_MV_MATRIX_3, 0, mtx.wrapping_add(32)),
+            move_mem(16, uc::G_MV_MATRIX_4, 0, mtx.wrapping_add(48)),
+        ]
+    }
+
+    /// `gsSPSetGeometryMode`.
+    #[must_use]
+    pub const fn gsSPSetGeometryMode(mode: u32) -> Gfx {
+        super::imm(uc::G_SETGEOMETRYMODE, mode)
+    }
+
+    /// `gsSPClearGeometryMode`.
+    #[must_use]
+    pub const fn gsSPClearGeometryMode(mode: u32) -> Gfx {
+        super::imm(uc::G_CLEARGEOMETRYMODE, mode)
+    }
+
+    /// `gsSPInsertMatrix`.
+    #[must_use]
+    pub const fn gsSPInsertMatrix(r#where: u32, val: u32) -> Gfx {
+        move_wd(uc::G_MW_MATRIX, r#where, val)
+    }
+
+    /// `gsSPLine3D`.
+    #[must_use]
+    pub const fn gsSPLine3D(v0: u32, v1: u32, flag: u32) -> Gfx {
+        line_3d(v0, v1, 0, flag)
+    }
+
+    /// `gsSPLineW3D`.
+    #[must_use]
+    pub const fn gsSPLineW3D(v0: u32, v1: u32, wd: u32, flag: u32) -> Gfx {
+        line_3d(v0, v1, wd, flag)
+    }
+
+    /// `gsSPLookAtX`.
+    #[must_use]
+    pub const fn gsSPLookAtX(l: u32) -> Gfx {
+        look_at_x(l)
+    }
+
+    /// `gsSPLookAtY`.
+    #[must_use]
+    pub const fn gsSPLookAtY(l: u32) -> Gfx {
+        look_at_y(l)
+    }
+
+    /// `gsSPLookAt`.
+    #[must_use]
+    pub const fn gsSPLookAt(l: u32) -> [Gfx; 2] {
+        [look_at_x(l), look_at_y(l.wrapping_add(16))]
+    }
+
+    /// `gsSPMatrix`.
+    #[must_use]
+    pub const fn gsSPMatrix(mtx: u32, param: u32) -> Gfx {
+        super::dma1p(uc::G_MTX, mtx, 64, param)
+    }
+
+    /// `gsSPModifyVertex`.
+    #[must_use]
+    pub const fn gsSPModifyVertex(vtx: u32, r#where: u32, val: u32) -> Gfx {
+        move_wd(
+            uc::G_MW_POINTS,
+            vtx.wrapping_mul(40).wrapping_add(r#where),
+            val,
+        )
+    }
+
+    /// `gsSPPerspNormalize`.
+    #[must_use]
+    pub const fn gsSPPerspNormalize(scale: u32) -> Gfx {
+        move_wd(uc::G_MW_PERSPNORM, 0, scale)
+    }
+
+    /// `gsSPPopMatrix`.
+    #[must_use]
+    pub const fn gsSPPopMatrix(param: u32) -> Gfx {
+        super::imm(uc::G_POPMTX, param)
+    }
+
+    /// `gsSPSegment`.
+    #[must_use]
+    pub const fn gsSPSegment(seg: u32, base: u32) -> Gfx {
+        move_wd(uc::G_MW_SEGMENT, seg.wrapping_mul(4), base)
+    }
+
+    /// `gsSPSetLights1`.
+    #[must_use]
+    pub const fn gsSPSetLights1(lights: u32) -> [Gfx; 3] {
+        [
+            num_lights(1),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights, 2),
+        ]
+    }
+
+    /// `gsSPSetLights2`.
+    #[must_use]
+    pub const fn gsSPSetLights2(lights: u32) -> [Gfx; 4] {
+        [
+            num_lights(2),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights, 3),
+        ]
+    }
+
+    /// `gsSPSetLights3`.
+    #[must_use]
+    pub const fn gsSPSetLights3(lights: u32) -> [Gfx; 5] {
+        [
+            num_lights(3),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights, 4),
+        ]
+    }
+
+    /// `gsSPSetLights4`.
+    #[must_use]
+    pub const fn gsSPSetLights4(lights: u32) -> [Gfx; 6] {
+        [
+            num_lights(4),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights, 5),
+        ]
+    }
+
+    /// `gsSPSetLights5`.
+    #[must_use]
+    pub const fn gsSPSetLights5(lights: u32) -> [Gfx; 7] {
+        [
+            num_lights(5),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights.wrapping_add(72), 5),
+            set_light(lights, 6),
+        ]
+    }
+
+    /// `gsSPSetLights6`.
+    #[must_use]
+    pub const fn gsSPSetLights6(lights: u32) -> [Gfx; 8] {
+        [
+            num_lights(6),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights.wrapping_add(72), 5),
+            set_light(lights.wrapping_add(88), 6),
+            set_light(lights, 7),
+        ]
+    }
+
+    /// `gsSPSetLights7`.
+    #[must_use]
+    pub const fn gsSPSetLights7(lights: u32) -> [Gfx; 9] {
+        [
+            num_lights(7),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights.wrapping_add(72), 5),
+            set_light(lights.wrapping_add(88), 6),
+            set_light(lights.wrapping_add(104), 7),
+            set_light(lights, 8),
+        ]
+    }
+
+    /// `gsSPNumLights`.
+    #[must_use]
+    pub const fn gsSPNumLights(n: u32) -> Gfx {
+        num_lights(n)
+    }
+
+    /// `gsSPLight`.
+    #[must_use]
+    pub const fn gsSPLight(light: u32, n: u32) -> Gfx {
+        set_light(light, n)
+    }
+
+    /// `gsSPLightColor`.
+    #[must_use]
+    pub const fn gsSPLightColor(n: u32, color: u32) -> [Gfx; 2] {
+        let offset = n.wrapping_sub(1).wrapping_mul(0x20);
+        [
+            move_wd(uc::G_MW_LIGHTCOL, offset, color),
+            move_wd(uc::G_MW_LIGHTCOL, offset.wrapping_add(4), color),
+        ]
+    }
+
+    /// `gsSPTexture`.
+    #[must_use]
+    pub const fn gsSPTexture(sc: u32, tc: u32, level: u32, tile: u32, on: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_TEXTURE, 24, 8)
+                | sh(gbi::BOWTIE_VAL, 16, 8)
+                | sh(level, 11, 3)
+                | sh(tile, 8, 3)
+                | sh(on, 0, 8),
+            sh(sc, 16, 16) | sh(tc, 0, 16),
+        )
+    }
+
+    /// `gsSPTextureRectangle`.
+    #[must_use]
+    pub const fn gsSPTextureRectangle(
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+        s: u32,
+        t: u32,
+        dsdx: u32,
+        dtdy: u32,
+    ) -> [Gfx; 3] {
+        [
+            super::tex_rect(gbi::G_TEXRECT, ulx, uly, lrx, lry, tile),
+            half_1(sh(s, 16, 16) | sh(t, 0, 16)),
+            half_2(sh(dsdx, 16, 16) | sh(dtdy, 0, 16)),
+        ]
+    }
+
+    /// `gsSPTextureRectangleFlip`.
+    #[must_use]
+    pub const fn gsSPTextureRectangleFlip(
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+        s: u32,
+        t: u32,
+        dsdx: u32,
+        dtdy: u32,
+    ) -> [Gfx; 3] {
+        [
+            super::tex_rect(gbi::G_TEXRECTFLIP, ulx, uly, lrx, lry, tile),
+            half_1(sh(s, 16, 16) | sh(t, 0, 16)),
+            half_2(sh(dsdx, 16, 16) | sh(dtdy, 0, 16)),
+        ]
+    }
+
+    /// `gsSPVertex`.
+    #[must_use]
+    pub const fn gsSPVertex(v: u32, n: u32, v0: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_VTX, 24, 8)
+                | sh(n.wrapping_sub(1), 20, 4)
+                | sh(v0, 16, 4)
+                | sh(n.wrapping_mul(16), 0, 16),
+            v,
+        )
+    }
+
+    /// `gsSPViewport`.
+    #[must_use]
+    pub const fn gsSPViewport(v: u32) -> Gfx {
+        move_mem(16, uc::G_MV_VIEWPORT, 0, v)
+    }
+
+    /// `gsDisplayList`.
+    #[must_use]
+    pub const fn gsDisplayList(dl: u32, flag: u32) -> Gfx {
+        display_list(dl, flag)
+    }
+
+    /// `gsDPHalf1`.
+    #[must_use]
+    pub const fn gsDPHalf1(wordhi: u32) -> Gfx {
+        half_1(wordhi)
+    }
+
+    /// `gsDPHalf2`.
+    #[must_use]
+    pub const fn gsDPHalf2(wordlo: u32) -> Gfx {
+        half_2(wordlo)
+    }
+
+    /// `gsDPWord`.
+    #[must_use]
+    pub const fn gsDPWord(wordhi: u32, wordlo: u32) -> [Gfx; 2] {
+        [half_1(wordhi), half_2(wordlo)]
+    }
+
+    /// `gsSPSetOtherMode`.
+    #[must_use]
+    pub const fn gsSPSetOtherMode(opc: u32, sft: u32, len: u32, data: u32) -> Gfx {
+        set_other_mode(opc, sft, len, data)
+    }
+
+    /// `gsSPSetOtherModeLo`.
+    #[must_use]
+    pub const fn gsSPSetOtherModeLo(sft: u32, len: u32, data: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_L, sft, len, data)
+    }
+
+    /// `gsSPSetOtherModeHi`.
+    #[must_use]
+    pub const fn gsSPSetOtherModeHi(sft: u32, len: u32, data: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, sft, len, data)
+    }
+
+    /// `gsMoveWd`.
+    #[must_use]
+    pub const fn gsMoveWd(index: u32, offset: u32, data: u32) -> Gfx {
+        move_wd(index, offset, data)
+    }
+
+    /// `gsMoveMem`.
+    #[must_use]
+    pub const fn gsMoveMem(size: u32, index: u32, offset: u32, dram: u32) -> Gfx {
+        move_mem(size, index, offset, dram)
+    }
+
+    /// `gsSPNoOp`.
+    #[must_use]
+    pub const fn gsSPNoOp() -> Gfx {
+        super::op(uc::G_SPNOOP)
+    }
+}
+
+pub mod f3dex {
+    //! Builders for the `f3dex` family, `gbi.h` built with `F3DEX_GBI`. They also
+    //! apply to `f3dexb`.
+
+    #![allow(non_snake_case)]
+
+    use super::sh;
+    use crate::gbi::{self, f3dex as uc};
+    use crate::gfx::Gfx;
+
+    pub use super::{
+        _gsDPLoadTextureTile, _gsDPLoadTextureTileYuv, _gsDPLoadTextureTile_4b, gsDPFillRectangle,
+        gsDPFullSync, gsDPLoadBlock, gsDPLoadMultiTile, gsDPLoadMultiTileYuv, gsDPLoadMultiTile_4b,
+        gsDPLoadSync, gsDPLoadTLUT, gsDPLoadTLUTCmd, gsDPLoadTLUT_pal16, gsDPLoadTLUT_pal256,
+        gsDPLoadTextureTile, gsDPLoadTextureTileYuv, gsDPLoadTextureTile_4b, gsDPLoadTile,
+        gsDPPipeSync, gsDPSetBlendColor, gsDPSetColorImage, gsDPSetCombineLERP, gsDPSetCombineMode,
+        gsDPSetConvert, gsDPSetDepthImage, gsDPSetEnvColor, gsDPSetFillColor, gsDPSetFogColor,
+        gsDPSetKeyGB, gsDPSetKeyR, gsDPSetOtherMode, gsDPSetPrimColor, gsDPSetPrimDepth,
+        gsDPSetScissor, gsDPSetScissorFrac, gsDPSetTextureImage, gsDPSetTile, gsDPSetTileSize,
+        gsDPTileSync, gsTexRect, gsTexRectFlip,
+    };
+
+    const fn move_wd(index: u32, offset: u32, data: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_MOVEWORD, 24, 8) | sh(offset, 8, 16) | sh(index, 0, 8),
+            data,
+        )
+    }
+
+    const fn move_mem(len: u32, index: u32, offset: u32, adrs: u32) -> Gfx {
+        super::dma1p(uc::G_MOVEMEM, adrs, len, index.wrapping_add(offset))
+    }
+
+    const fn set_other_mode(op: u32, sft: u32, len: u32, data: u32) -> Gfx {
+        Gfx::new(sh(op, 24, 8) | sh(sft, 8, 8) | sh(len, 0, 8), data)
+    }
+
+    const fn set_light(light: u32, n: u32) -> Gfx {
+        let idx = n.wrapping_sub(1).wrapping_mul(2).wrapping_add(uc::G_MV_L0);
+        super::dma1p(uc::G_MOVEMEM, light, 16, idx)
+    }
+
+    const fn look_at_x(l: u32) -> Gfx {
+        move_mem(16, uc::G_MV_LOOKATX, 0, l)
+    }
+
+    const fn look_at_y(l: u32) -> Gfx {
+        move_mem(16, uc::G_MV_LOOKATY, 0, l)
+    }
+
+    const fn half_1(w1: u32) -> Gfx {
+        super::imm(uc::G_RDPHALF_1, w1)
+    }
+
+    const fn half_2(w1: u32) -> Gfx {
+        super::imm(uc::G_RDPHALF_2, w1)
+    }
+
+    const fn display_list(dl: u32, flag: u32) -> Gfx {
+        Gfx::new(sh(uc::G_DL, 24, 8) | sh(flag, 16, 8), dl)
+    }
+
+    const fn line_3d(v0: u32, v1: u32, wd: u32, flag: u32) -> Gfx {
+        let a = super::select(flag == 0, v0, v1);
+        let b = super::select(flag == 0, v1, v0);
+        let w = sh(a.wrapping_mul(2), 16, 8) | sh(b.wrapping_mul(2), 8, 8) | sh(wd, 0, 8);
+        Gfx::new(sh(uc::G_LINE3D, 24, 8), w)
+    }
+
+    const fn load_ucode(uc_start: u32, uc_dsize: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_LOAD_UCODE, 24, 8) | sh(uc_dsize.wrapping_sub(1), 0, 16),
+            uc_start,
+        )
+    }
+
+    const fn branch_z(vtx: u32, zval: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_BRANCH_Z, 24, 8)
+                | sh(vtx.wrapping_mul(5), 12, 12)
+                | sh(vtx.wrapping_mul(2), 0, 12),
+            zval,
+        )
+    }
+
+    const fn num_lights(n: u32) -> Gfx {
+        move_wd(uc::G_MW_NUMLIGHT, gbi::G_MWO_NUMLIGHT, uc::NUML(n))
+    }
+
+    const fn fog_factor(fm: u32, fo: u32) -> Gfx {
+        move_wd(uc::G_MW_FOG, gbi::G_MWO_FOG, sh(fm, 16, 16) | sh(fo, 0, 16))
+    }
+
+    /// `gsDPLoadMultiBlockYuvS`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlockYuvS(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            true,
+        )
+    }
+
+    /// `gsDPLoadMultiBlockYuv`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlockYuv(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            false,
+        )
+    }
+
+    /// `gsDPLoadMultiBlock_4bS`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlock_4bS(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+        )
+    }
+
+    /// `gsDPLoadMultiBlock_4b`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlock_4b(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+        )
+    }
+
+    /// `gsDPLoadMultiBlockS`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlockS(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            true,
+        )
+    }
+
+    /// `gsDPLoadMultiBlock`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlock(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            false,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlockYuvS`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlockYuvS(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            true,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlockYuv`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlockYuv(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            false,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlock_4bS`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlock_4bS(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlock_4b`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlock_4b(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlockS`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlockS(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            true,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlock`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlock(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            false,
+        )
+    }
+
+    /// `gsDPLoadTextureBlockYuvS`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlockYuvS(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            true,
+        )
+    }
+
+    /// `gsDPLoadTextureBlockYuv`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlockYuv(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            false,
+        )
+    }
+
+    /// `gsDPLoadTextureBlock_4bS`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlock_4bS(
+        timg: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+        )
+    }
+
+    /// `gsDPLoadTextureBlock_4b`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlock_4b(
+        timg: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+        )
+    }
+
+    /// `gsDPLoadTextureBlockS`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlockS(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            true,
+        )
+    }
+
+    /// `gsDPLoadTextureBlock`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlock(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            false,
+        )
+    }
+
+    /// `gsDPNoOp`.
+    #[must_use]
+    pub const fn gsDPNoOp() -> Gfx {
+        super::op(uc::G_NOOP)
+    }
+
+    /// `gsDPNoOpTag`.
+    #[must_use]
+    pub const fn gsDPNoOpTag(tag: u32) -> Gfx {
+        super::imm(uc::G_NOOP, tag)
+    }
+
+    /// `gsDPPipelineMode`.
+    #[must_use]
+    pub const fn gsDPPipelineMode(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_PIPELINE, 1, mode)
+    }
+
+    /// `gsDPSetAlphaCompare`.
+    #[must_use]
+    pub const fn gsDPSetAlphaCompare(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_L, gbi::G_MDSFT_ALPHACOMPARE, 2, mode)
+    }
+
+    /// `gsDPSetAlphaDither`.
+    #[must_use]
+    pub const fn gsDPSetAlphaDither(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_ALPHADITHER, 2, mode)
+    }
+
+    /// `gsDPSetColorDither`.
+    #[must_use]
+    pub const fn gsDPSetColorDither(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_RGBDITHER, 2, mode)
+    }
+
+    /// `gsDPSetTextureConvert`.
+    #[must_use]
+    pub const fn gsDPSetTextureConvert(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTCONV, 3, mode)
+    }
+
+    /// `gsDPSetCycleType`.
+    #[must_use]
+    pub const fn gsDPSetCycleType(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_CYCLETYPE, 2, mode)
+    }
+
+    /// `gsDPSetDepthSource`.
+    #[must_use]
+    pub const fn gsDPSetDepthSource(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_L, gbi::G_MDSFT_ZSRCSEL, 1, mode)
+    }
+
+    /// `gsDPSetCombineKey`.
+    #[must_use]
+    pub const fn gsDPSetCombineKey(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_COMBKEY, 1, mode)
+    }
+
+    /// `gsDPSetRenderMode`.
+    #[must_use]
+    pub const fn gsDPSetRenderMode(mode1: u32, mode2: u32) -> Gfx {
+        set_other_mode(
+            uc::G_SETOTHERMODE_L,
+            gbi::G_MDSFT_RENDERMODE,
+            29,
+            mode1 | mode2,
+        )
+    }
+
+    /// `gsDPSetTextureDetail`.
+    #[must_use]
+    pub const fn gsDPSetTextureDetail(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTDETAIL, 2, mode)
+    }
+
+    /// `gsDPSetTextureFilter`.
+    #[must_use]
+    pub const fn gsDPSetTextureFilter(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTFILT, 2, mode)
+    }
+
+    /// `gsDPSetTextureLOD`.
+    #[must_use]
+    pub const fn gsDPSetTextureLOD(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTLOD, 1, mode)
+    }
+
+    /// `gsDPSetTextureLUT`.
+    #[must_use]
+    pub const fn gsDPSetTextureLUT(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTLUT, 2, mode)
+    }
+
+    /// `gsDPSetTexturePersp`.
+    #[must_use]
+    pub const fn gsDPSetTexturePersp(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTPERSP, 1, mode)
+    }
+
+    /// `gsSP1Triangle`.
+    #[must_use]
+    pub const fn gsSP1Triangle(v0: u32, v1: u32, v2: u32, flag: u32) -> Gfx {
+        Gfx::new(sh(uc::G_TRI1, 24, 8), super::tri(v0, v1, v2, flag))
+    }
+
+    /// `gsSP2Triangles`.
+    #[must_use]
+    pub const fn gsSP2Triangles(
+        v00: u32,
+        v01: u32,
+        v02: u32,
+        flag0: u32,
+        v10: u32,
+        v11: u32,
+        v12: u32,
+        flag1: u32,
+    ) -> Gfx {
+        Gfx::new(
+            sh(uc::G_TRI2, 24, 8) | super::tri(v00, v01, v02, flag0),
+            super::tri(v10, v11, v12, flag1),
+        )
+    }
+
+    /// `gsSP1Quadrangle`.
+    #[must_use]
+    pub const fn gsSP1Quadrangle(v0: u32, v1: u32, v2: u32, v3: u32, flag: u32) -> Gfx {
+        let v = [v0, v1, v2, v3, v0, v1, v2];
+        let f = super::min(flag, 3) as usize;
+        Gfx::new(
+            sh(uc::G_TRI2, 24, 8) | super::tri(v[f], v[f + 1], v[f + 2], 0),
+            super::tri(v[f], v[f + 2], v[f + 3], 0),
+        )
+    }
+
+    /// `gsSPBranchLessZraw`.
+    #[must_use]
+    pub const fn gsSPBranchLessZraw(dl: u32, vtx: u32, zval: u32) -> [Gfx; 2] {
+        [half_1(dl), branch_z(vtx, zval)]
+    }
+
+    /// `gsSPBranchList`.
+    #[must_use]
+    pub const fn gsSPBranchList(dl: u32) -> Gfx {
+        display_list(dl, gbi::G_DL_NOPUSH)
+    }
+
+    /// `gsSPClipRatio`.
+    #[must_use]
+    pub const fn gsSPClipRatio(r: u32) -> [Gfx; 4] {
+        let neg = 0u32.wrapping_sub(r) & 0xFFFF;
+        [
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RNX, neg),
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RNY, neg),
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RPX, r),
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RPY, r),
+        ]
+    }
+
+    /// `gsSPCullDisplayList`.
+    #[must_use]
+    pub const fn gsSPCullDisplayList(vstart: u32, vend: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_CULLDL, 24, 8) | sh(vstart.wrapping_mul(2), 0, 16),
+            sh(vend.wrapping_mul(2), 0, 16),
+        )
+    }
+
+    /// `gsSPDisplayList`.
+    #[must_use]
+    pub const fn gsSPDisplayList(dl: u32) -> Gfx {
+        display_list(dl, gbi::G_DL_PUSH)
+    }
+
+    /// `gsSPEndDisplayList`.
+    #[must_use]
+    pub const fn gsSPEndDisplayList() -> Gfx {
+        super::op(uc::G_ENDDL)
+    }
+
+    /// `gsSPFogFactor`.
+    #[must_use]
+    pub const fn gsSPFogFactor(fm: u32, fo: u32) -> Gfx {
+        fog_factor(fm, fo)
+    }
+
+    /// `gsSPFogPosition`.
+    #[must_use]
+    pub const fn gsSPFogPosition(min: u32, max: u32) -> Gfx {
+        let min = min as i32 as i64;
+        let max = max as i32 as i64;
+        let fm = 128_000 / (max - min);
+        let fo = (500 - min) * 256 / (max - min);
+        fog_factor(fm as u32, fo as u32)
+    }
+
+    /// `gsSPForceMatrix`.
+    #[must_use]
+    pub const fn gsSPForceMatrix(mtx: u32) -> [Gfx; 4] {
+        [
+            move_mem(16, uc::G_MV_MATRIX_1, 0, mtx),
+            move_mem(16, uc::G_MV_MATRIX_2, 0, mtx.wrapping_add(16)),
+            move_mem(16, uc::G_MV_MATRIX_3, 0, mtx.wrapping_add(32)),
+            move_mem(16, uc::G_MV_MATRIX_4, 0, mtx.wrapping_add(48)),
+        ]
+    }
+
+    /// `gsSPSetGeometryMode`.
+    #[must_use]
+    pub const fn gsSPSetGeometryMode(mode: u32) -> Gfx {
+        super::imm(uc::G_SETGEOMETRYMODE, mode)
+    }
+
+    /// `gsSPClearGeometryMode`.
+    #[must_use]
+    pub const fn gsSPClearGeometryMode(mode: u32) -> Gfx {
+        super::imm(uc::G_CLEARGEOMETRYMODE, mode)
+    }
+
+    /// `gsSPInsertMatrix`.
+    #[must_use]
+    pub const fn gsSPInsertMatrix(r#where: u32, val: u32) -> Gfx {
+        move_wd(uc::G_MW_MATRIX, r#where, val)
+    }
+
+    /// `gsSPLine3D`.
+    #[must_use]
+    pub const fn gsSPLine3D(v0: u32, v1: u32, flag: u32) -> Gfx {
+        line_3d(v0, v1, 0, flag)
+    }
+
+    /// `gsSPLineW3D`.
+    #[must_use]
+    pub const fn gsSPLineW3D(v0: u32, v1: u32, wd: u32, flag: u32) -> Gfx {
+        line_3d(v0, v1, wd, flag)
+    }
+
+    /// `gsSPLoadUcode`.
+    #[must_use]
+    pub const fn gsSPLoadUcode(uc_start: u32, uc_dstart: u32) -> [Gfx; 2] {
+        [half_1(uc_dstart), load_ucode(uc_start, 0x800)]
+    }
+
+    /// `gsSPLookAtX`.
+    #[must_use]
+    pub const fn gsSPLookAtX(l: u32) -> Gfx {
+        look_at_x(l)
+    }
+
+    /// `gsSPLookAtY`.
+    #[must_use]
+    pub const fn gsSPLookAtY(l: u32) -> Gfx {
+        look_at_y(l)
+    }
+
+    /// `gsSPLookAt`.
+    #[must_use]
+    pub const fn gsSPLookAt(l: u32) -> [Gfx; 2] {
+        [look_at_x(l), look_at_y(l.wrapping_add(16))]
+    }
+
+    /// `gsSPMatrix`.
+    #[must_use]
+    pub const fn gsSPMatrix(mtx: u32, param: u32) -> Gfx {
+        super::dma1p(uc::G_MTX, mtx, 64, param)
+    }
+
+    /// `gsSPModifyVertex`.
+    #[must_use]
+    pub const fn gsSPModifyVertex(vtx: u32, r#where: u32, val: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_MODIFYVTX, 24, 8) | sh(r#where, 16, 8) | sh(vtx.wrapping_mul(2), 0, 16),
+            val,
+        )
+    }
+
+    /// `gsSPPerspNormalize`.
+    #[must_use]
+    pub const fn gsSPPerspNormalize(scale: u32) -> Gfx {
+        move_wd(uc::G_MW_PERSPNORM, 0, scale)
+    }
+
+    /// `gsSPPopMatrix`.
+    #[must_use]
+    pub const fn gsSPPopMatrix(param: u32) -> Gfx {
+        super::imm(uc::G_POPMTX, param)
+    }
+
+    /// `gsSPSegment`.
+    #[must_use]
+    pub const fn gsSPSegment(seg: u32, base: u32) -> Gfx {
+        move_wd(uc::G_MW_SEGMENT, seg.wrapping_mul(4), base)
+    }
+
+    /// `gsSPSetLights1`.
+    #[must_use]
+    pub const fn gsSPSetLights1(lights: u32) -> [Gfx; 3] {
+        [
+            num_lights(1),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights, 2),
+        ]
+    }
+
+    /// `gsSPSetLights2`.
+    #[must_use]
+    pub const fn gsSPSetLights2(lights: u32) -> [Gfx; 4] {
+        [
+            num_lights(2),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights, 3),
+        ]
+    }
+
+    /// `gsSPSetLights3`.
+    #[must_use]
+    pub const fn gsSPSetLights3(lights: u32) -> [Gfx; 5] {
+        [
+            num_lights(3),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights, 4),
+        ]
+    }
+
+    /// `gsSPSetLights4`.
+    #[must_use]
+    pub const fn gsSPSetLights4(lights: u32) -> [Gfx; 6] {
+        [
+            num_lights(4),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights, 5),
+        ]
+    }
+
+    /// `gsSPSetLights5`.
+    #[must_use]
+    pub const fn gsSPSetLights5(lights: u32) -> [Gfx; 7] {
+        [
+            num_lights(5),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights.wrapping_add(72), 5),
+            set_light(lights, 6),
+        ]
+    }
+
+    /// `gsSPSetLights6`.
+    #[must_use]
+    pub const fn gsSPSetLights6(lights: u32) -> [Gfx; 8] {
+        [
+            num_lights(6),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights.wrapping_add(72), 5),
+            set_light(lights.wrapping_add(88), 6),
+            set_light(lights, 7),
+        ]
+    }
+
+    /// `gsSPSetLights7`.
+    #[must_use]
+    pub const fn gsSPSetLights7(lights: u32) -> [Gfx; 9] {
+        [
+            num_lights(7),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights.wrapping_add(72), 5),
+            set_light(lights.wrapping_add(88), 6),
+            set_light(lights.wrapping_add(104), 7),
+            set_light(lights, 8),
+        ]
+    }
+
+    /// `gsSPNumLights`.
+    #[must_use]
+    pub const fn gsSPNumLights(n: u32) -> Gfx {
+        num_lights(n)
+    }
+
+    /// `gsSPLight`.
+    #[must_use]
+    pub const fn gsSPLight(light: u32, n: u32) -> Gfx {
+        set_light(light, n)
+    }
+
+    /// `gsSPLightColor`.
+    #[must_use]
+    pub const fn gsSPLightColor(n: u32, color: u32) -> [Gfx; 2] {
+        let offset = n.wrapping_sub(1).wrapping_mul(0x20);
+        [
+            move_wd(uc::G_MW_LIGHTCOL, offset, color),
+            move_wd(uc::G_MW_LIGHTCOL, offset.wrapping_add(4), color),
+        ]
+    }
+
+    /// `gsSPTexture`.
+    #[must_use]
+    pub const fn gsSPTexture(sc: u32, tc: u32, level: u32, tile: u32, on: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_TEXTURE, 24, 8)
+                | sh(gbi::BOWTIE_VAL, 16, 8)
+                | sh(level, 11, 3)
+                | sh(tile, 8, 3)
+                | sh(on, 0, 8),
+            sh(sc, 16, 16) | sh(tc, 0, 16),
+        )
+    }
+
+    /// `gsSPTextureRectangle`.
+    #[must_use]
+    pub const fn gsSPTextureRectangle(
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+        s: u32,
+        t: u32,
+        dsdx: u32,
+        dtdy: u32,
+    ) -> [Gfx; 3] {
+        [
+            super::tex_rect(gbi::G_TEXRECT, ulx, uly, lrx, lry, tile),
+            half_1(sh(s, 16, 16) | sh(t, 0, 16)),
+            half_2(sh(dsdx, 16, 16) | sh(dtdy, 0, 16)),
+        ]
+    }
+
+    /// `gsSPTextureRectangleFlip`.
+    #[must_use]
+    pub const fn gsSPTextureRectangleFlip(
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+        s: u32,
+        t: u32,
+        dsdx: u32,
+        dtdy: u32,
+    ) -> [Gfx; 3] {
+        [
+            super::tex_rect(gbi::G_TEXRECTFLIP, ulx, uly, lrx, lry, tile),
+            half_1(sh(s, 16, 16) | sh(t, 0, 16)),
+            half_2(sh(dsdx, 16, 16) | sh(dtdy, 0, 16)),
+        ]
+    }
+
+    /// `gsSPVertex`.
+    #[must_use]
+    pub const fn gsSPVertex(v: u32, n: u32, v0: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_VTX, 24, 8)
+                | sh(v0.wrapping_mul(2), 16, 8)
+                | sh(n, 10, 6)
+                | sh(n.wrapping_mul(16).wrapping_sub(1), 0, 10),
+            v,
+        )
+    }
+
+    /// `gsSPViewport`.
+    #[must_use]
+    pub const fn gsSPViewport(v: u32) -> Gfx {
+        move_mem(16, uc::G_MV_VIEWPORT, 0, v)
+    }
+
+    /// `gsBranchZ`.
+    #[must_use]
+    pub const fn gsBranchZ(vtx: u32, zval: u32) -> Gfx {
+        branch_z(vtx, zval)
+    }
+
+    /// `gsDisplayList`.
+    #[must_use]
+    pub const fn gsDisplayList(dl: u32, flag: u32) -> Gfx {
+        display_list(dl, flag)
+    }
+
+    /// `gsDPHalf1`.
+    #[must_use]
+    pub const fn gsDPHalf1(wordhi: u32) -> Gfx {
+        half_1(wordhi)
+    }
+
+    /// `gsDPHalf2`.
+    #[must_use]
+    pub const fn gsDPHalf2(wordlo: u32) -> Gfx {
+        half_2(wordlo)
+    }
+
+    /// `gsDPWord`.
+    #[must_use]
+    pub const fn gsDPWord(wordhi: u32, wordlo: u32) -> [Gfx; 2] {
+        [half_1(wordhi), half_2(wordlo)]
+    }
+
+    /// `gsSPSetOtherMode`.
+    #[must_use]
+    pub const fn gsSPSetOtherMode(opc: u32, sft: u32, len: u32, data: u32) -> Gfx {
+        set_other_mode(opc, sft, len, data)
+    }
+
+    /// `gsSPSetOtherModeLo`.
+    #[must_use]
+    pub const fn gsSPSetOtherModeLo(sft: u32, len: u32, data: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_L, sft, len, data)
+    }
+
+    /// `gsSPSetOtherModeHi`.
+    #[must_use]
+    pub const fn gsSPSetOtherModeHi(sft: u32, len: u32, data: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, sft, len, data)
+    }
+
+    /// `gsMoveWd`.
+    #[must_use]
+    pub const fn gsMoveWd(index: u32, offset: u32, data: u32) -> Gfx {
+        move_wd(index, offset, data)
+    }
+
+    /// `gsMoveMem`.
+    #[must_use]
+    pub const fn gsMoveMem(size: u32, index: u32, offset: u32, dram: u32) -> Gfx {
+        move_mem(size, index, offset, dram)
+    }
+
+    /// `gsLoadUcode`.
+    #[must_use]
+    pub const fn gsLoadUcode(uc_start: u32, uc_dsize: u32) -> Gfx {
+        load_ucode(uc_start, uc_dsize)
+    }
+
+    /// `gsSPLoadUcodeEx`.
+    #[must_use]
+    pub const fn gsSPLoadUcodeEx(uc_start: u32, uc_dstart: u32, uc_dsize: u32) -> [Gfx; 2] {
+        [half_1(uc_dstart), load_ucode(uc_start, uc_dsize)]
+    }
+
+    /// `gsSPNoOp`.
+    #[must_use]
+    pub const fn gsSPNoOp() -> Gfx {
+        super::op(uc::G_SPNOOP)
+    }
+}
+
+pub mod f3dex2 {
+    //! Builders for the `f3dex2` family, `gbi.h` built with `F3DEX_GBI_2`.
+
+    #![allow(non_snake_case)]
+
+    use super::sh;
+    use crate::gbi::{self, f3dex2 as uc};
+    use crate::gfx::Gfx;
+
+    pub use super::{
+        _gsDPLoadTextureTile, _gsDPLoadTextureTileYuv, _gsDPLoadTextureTile_4b, gsDPFillRectangle,
+        gsDPFullSync, gsDPLoadBlock, gsDPLoadMultiTile, gsDPLoadMultiTileYuv, gsDPLoadMultiTile_4b,
+        gsDPLoadSync, gsDPLoadTLUT, gsDPLoadTLUTCmd, gsDPLoadTLUT_pal16, gsDPLoadTLUT_pal256,
+        gsDPLoadTextureTile, gsDPLoadTextureTileYuv, gsDPLoadTextureTile_4b, gsDPLoadTile,
+        gsDPPipeSync, gsDPSetBlendColor, gsDPSetColorImage, gsDPSetCombineLERP, gsDPSetCombineMode,
+        gsDPSetConvert, gsDPSetDepthImage, gsDPSetEnvColor, gsDPSetFillColor, gsDPSetFogColor,
+        gsDPSetKeyGB, gsDPSetKeyR, gsDPSetOtherMode, gsDPSetPrimColor, gsDPSetPrimDepth,
+        gsDPSetScissor, gsDPSetScissorFrac, gsDPSetTextureImage, gsDPSetTile, gsDPSetTileSize,
+        gsDPTileSync, gsTexRect, gsTexRectFlip,
+    };
+
+    const fn move_wd(index: u32, offset: u32, data: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_MOVEWORD, 24, 8) | sh(index, 16, 8) | sh(offset, 0, 16),
+            data,
+        )
+    }
+
+    const fn move_mem(len: u32, index: u32, offset: u32, adrs: u32) -> Gfx {
+        super::dma2p(uc::G_MOVEMEM, adrs, len, index, offset)
+    }
+
+    const fn set_other_mode(op: u32, sft: u32, len: u32, data: u32) -> Gfx {
+        Gfx::new(
+            sh(op, 24, 8)
+                | sh(32u32.wrapping_sub(sft).wrapping_sub(len), 8, 8)
+                | sh(len.wrapping_sub(1), 0, 8),
+            data,
+        )
+    }
+
+    const fn geometry_mode(clear: u32, set: u32) -> Gfx {
+        Gfx::new(sh(uc::G_GEOMETRYMODE, 24, 8) | sh(!clear, 0, 24), set)
+    }
+
+    const fn set_light(light: u32, n: u32) -> Gfx {
+        super::dma2p(
+            uc::G_MOVEMEM,
+            light,
+            16,
+            uc::G_MV_LIGHT,
+            n.wrapping_add(1).wrapping_mul(24),
+        )
+    }
+
+    const fn look_at_x(l: u32) -> Gfx {
+        move_mem(16, uc::G_MV_LIGHT, uc::G_MVO_LOOKATX, l)
+    }
+
+    const fn look_at_y(l: u32) -> Gfx {
+        move_mem(16, uc::G_MV_LIGHT, uc::G_MVO_LOOKATY, l)
+    }
+
+    const fn dma_io(flag: u32, dmem: u32, dram: u32, size: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_DMA_IO, 24, 8)
+                | sh(flag, 23, 1)
+                | sh(dmem / 8, 13, 10)
+                | sh(size.wrapping_sub(1), 0, 12),
+            dram,
+        )
+    }
+
+    const fn half_1(w1: u32) -> Gfx {
+        super::imm(uc::G_RDPHALF_1, w1)
+    }
+
+    const fn half_2(w1: u32) -> Gfx {
+        super::imm(uc::G_RDPHALF_2, w1)
+    }
+
+    const fn display_list(dl: u32, flag: u32) -> Gfx {
+        Gfx::new(sh(uc::G_DL, 24, 8) | sh(flag, 16, 8), dl)
+    }
+
+    const fn line_3d(v0: u32, v1: u32, wd: u32, flag: u32) -> Gfx {
+        let a = super::select(flag == 0, v0, v1);
+        let b = super::select(flag == 0, v1, v0);
+        let w = sh(a.wrapping_mul(2), 16, 8) | sh(b.wrapping_mul(2), 8, 8) | sh(wd, 0, 8);
+        Gfx::new(sh(uc::G_LINE3D, 24, 8) | w, 0)
+    }
+
+    const fn load_ucode(uc_start: u32, uc_dsize: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_LOAD_UCODE, 24, 8) | sh(uc_dsize.wrapping_sub(1), 0, 16),
+            uc_start,
+        )
+    }
+
+    const fn branch_z(vtx: u32, zval: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_BRANCH_Z, 24, 8)
+                | sh(vtx.wrapping_mul(5), 12, 12)
+                | sh(vtx.wrapping_mul(2), 0, 12),
+            zval,
+        )
+    }
+
+    const fn num_lights(n: u32) -> Gfx {
+        move_wd(uc::G_MW_NUMLIGHT, gbi::G_MWO_NUMLIGHT, uc::NUML(n))
+    }
+
+    const fn fog_factor(fm: u32, fo: u32) -> Gfx {
+        move_wd(uc::G_MW_FOG, gbi::G_MWO_FOG, sh(fm, 16, 16) | sh(fo, 0, 16))
+    }
+
+    /// `gsDPLoadMultiBlockYuvS`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlockYuvS(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            true,
+        )
+    }
+
+    /// `gsDPLoadMultiBlockYuv`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlockYuv(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            false,
+        )
+    }
+
+    /// `gsDPLoadMultiBlock_4bS`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlock_4bS(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+        )
+    }
+
+    /// `gsDPLoadMultiBlock_4b`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlock_4b(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+        )
+    }
+
+    /// `gsDPLoadMultiBlockS`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlockS(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            true,
+        )
+    }
+
+    /// `gsDPLoadMultiBlock`.
+    #[must_use]
+    pub const fn gsDPLoadMultiBlock(
+        timg: u32,
+        tmem: u32,
+        rtile: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            rtile,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            false,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlockYuvS`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlockYuvS(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            true,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlockYuv`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlockYuv(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            false,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlock_4bS`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlock_4bS(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlock_4b`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlock_4b(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlockS`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlockS(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            true,
+        )
+    }
+
+    /// `_gsDPLoadTextureBlock`.
+    #[must_use]
+    pub const fn _gsDPLoadTextureBlock(
+        timg: u32,
+        tmem: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            tmem,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            false,
+        )
+    }
+
+    /// `gsDPLoadTextureBlockYuvS`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlockYuvS(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            true,
+        )
+    }
+
+    /// `gsDPLoadTextureBlockYuv`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlockYuv(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+            false,
+        )
+    }
+
+    /// `gsDPLoadTextureBlock_4bS`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlock_4bS(
+        timg: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            true,
+        )
+    }
+
+    /// `gsDPLoadTextureBlock_4b`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlock_4b(
+        timg: u32,
+        fmt: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block_4b(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+        )
+    }
+
+    /// `gsDPLoadTextureBlockS`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlockS(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            true,
+        )
+    }
+
+    /// `gsDPLoadTextureBlock`.
+    #[must_use]
+    pub const fn gsDPLoadTextureBlock(
+        timg: u32,
+        fmt: u32,
+        siz: u32,
+        width: u32,
+        height: u32,
+        pal: u32,
+        cms: u32,
+        cmt: u32,
+        masks: u32,
+        maskt: u32,
+        shifts: u32,
+        shiftt: u32,
+    ) -> [Gfx; 7] {
+        super::load_block(
+            uc::G_TX_LDBLK_MAX_TXL,
+            timg,
+            0,
+            gbi::G_TX_RENDERTILE,
+            fmt,
+            siz,
+            width,
+            height,
+            pal,
+            cms,
+            cmt,
+            masks,
+            maskt,
+            shifts,
+            shiftt,
+            false,
+            false,
+        )
+    }
+
+    /// `gsDPNoOp`.
+    #[must_use]
+    pub const fn gsDPNoOp() -> Gfx {
+        super::op(uc::G_NOOP)
+    }
+
+    /// `gsDPNoOpTag`.
+    #[must_use]
+    pub const fn gsDPNoOpTag(tag: u32) -> Gfx {
+        super::imm(uc::G_NOOP, tag)
+    }
+
+    /// `gsDPPipelineMode`.
+    #[must_use]
+    pub const fn gsDPPipelineMode(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_PIPELINE, 1, mode)
+    }
+
+    /// `gsDPSetAlphaCompare`.
+    #[must_use]
+    pub const fn gsDPSetAlphaCompare(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_L, gbi::G_MDSFT_ALPHACOMPARE, 2, mode)
+    }
+
+    /// `gsDPSetAlphaDither`.
+    #[must_use]
+    pub const fn gsDPSetAlphaDither(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_ALPHADITHER, 2, mode)
+    }
+
+    /// `gsDPSetColorDither`.
+    #[must_use]
+    pub const fn gsDPSetColorDither(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_RGBDITHER, 2, mode)
+    }
+
+    /// `gsDPSetTextureConvert`.
+    #[must_use]
+    pub const fn gsDPSetTextureConvert(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTCONV, 3, mode)
+    }
+
+    /// `gsDPSetCycleType`.
+    #[must_use]
+    pub const fn gsDPSetCycleType(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_CYCLETYPE, 2, mode)
+    }
+
+    /// `gsDPSetDepthSource`.
+    #[must_use]
+    pub const fn gsDPSetDepthSource(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_L, gbi::G_MDSFT_ZSRCSEL, 1, mode)
+    }
+
+    /// `gsDPSetCombineKey`.
+    #[must_use]
+    pub const fn gsDPSetCombineKey(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_COMBKEY, 1, mode)
+    }
+
+    /// `gsDPSetRenderMode`.
+    #[must_use]
+    pub const fn gsDPSetRenderMode(mode1: u32, mode2: u32) -> Gfx {
+        set_other_mode(
+            uc::G_SETOTHERMODE_L,
+            gbi::G_MDSFT_RENDERMODE,
+            29,
+            mode1 | mode2,
+        )
+    }
+
+    /// `gsDPSetTextureDetail`.
+    #[must_use]
+    pub const fn gsDPSetTextureDetail(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTDETAIL, 2, mode)
+    }
+
+    /// `gsDPSetTextureFilter`.
+    #[must_use]
+    pub const fn gsDPSetTextureFilter(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTFILT, 2, mode)
+    }
+
+    /// `gsDPSetTextureLOD`.
+    #[must_use]
+    pub const fn gsDPSetTextureLOD(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTLOD, 1, mode)
+    }
+
+    /// `gsDPSetTextureLUT`.
+    #[must_use]
+    pub const fn gsDPSetTextureLUT(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTLUT, 2, mode)
+    }
+
+    /// `gsDPSetTexturePersp`.
+    #[must_use]
+    pub const fn gsDPSetTexturePersp(mode: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, gbi::G_MDSFT_TEXTPERSP, 1, mode)
+    }
+
+    /// `gsSP1Triangle`.
+    #[must_use]
+    pub const fn gsSP1Triangle(v0: u32, v1: u32, v2: u32, flag: u32) -> Gfx {
+        Gfx::new(sh(uc::G_TRI1, 24, 8) | super::tri(v0, v1, v2, flag), 0)
+    }
+
+    /// `gsSP2Triangles`.
+    #[must_use]
+    pub const fn gsSP2Triangles(
+        v00: u32,
+        v01: u32,
+        v02: u32,
+        flag0: u32,
+        v10: u32,
+        v11: u32,
+        v12: u32,
+        flag1: u32,
+    ) -> Gfx {
+        Gfx::new(
+            sh(uc::G_TRI2, 24, 8) | super::tri(v00, v01, v02, flag0),
+            super::tri(v10, v11, v12, flag1),
+        )
+    }
+
+    /// `gsSP1Quadrangle`.
+    #[must_use]
+    pub const fn gsSP1Quadrangle(v0: u32, v1: u32, v2: u32, v3: u32, flag: u32) -> Gfx {
+        let v = [v0, v1, v2, v3, v0, v1, v2];
+        let f = super::min(flag, 3) as usize;
+        Gfx::new(
+            sh(uc::G_TRI2, 24, 8) | super::tri(v[f], v[f + 1], v[f + 2], 0),
+            super::tri(v[f], v[f + 2], v[f + 3], 0),
+        )
+    }
+
+    /// `gsSPBranchLessZraw`.
+    #[must_use]
+    pub const fn gsSPBranchLessZraw(dl: u32, vtx: u32, zval: u32) -> [Gfx; 2] {
+        [half_1(dl), branch_z(vtx, zval)]
+    }
+
+    /// `gsSPBranchList`.
+    #[must_use]
+    pub const fn gsSPBranchList(dl: u32) -> Gfx {
+        display_list(dl, gbi::G_DL_NOPUSH)
+    }
+
+    /// `gsSPClipRatio`.
+    #[must_use]
+    pub const fn gsSPClipRatio(r: u32) -> [Gfx; 4] {
+        let neg = 0u32.wrapping_sub(r) & 0xFFFF;
+        [
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RNX, neg),
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RNY, neg),
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RPX, r),
+            move_wd(uc::G_MW_CLIP, gbi::G_MWO_CLIP_RPY, r),
+        ]
+    }
+
+    /// `gsSPCullDisplayList`.
+    #[must_use]
+    pub const fn gsSPCullDisplayList(vstart: u32, vend: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_CULLDL, 24, 8) | sh(vstart.wrapping_mul(2), 0, 16),
+            sh(vend.wrapping_mul(2), 0, 16),
+        )
+    }
+
+    /// `gsSPDisplayList`.
+    #[must_use]
+    pub const fn gsSPDisplayList(dl: u32) -> Gfx {
+        display_list(dl, gbi::G_DL_PUSH)
+    }
+
+    /// `gsSPEndDisplayList`.
+    #[must_use]
+    pub const fn gsSPEndDisplayList() -> Gfx {
+        super::op(uc::G_ENDDL)
+    }
+
+    /// `gsSPFogFactor`.
+    #[must_use]
+    pub const fn gsSPFogFactor(fm: u32, fo: u32) -> Gfx {
+        fog_factor(fm, fo)
+    }
+
+    /// `gsSPFogPosition`.
+    #[must_use]
+    pub const fn gsSPFogPosition(min: u32, max: u32) -> Gfx {
+        let min = min as i32 as i64;
+        let max = max as i32 as i64;
+        let fm = 128_000 / (max - min);
+        let fo = (500 - min) * 256 / (max - min);
+        fog_factor(fm as u32, fo as u32)
+    }
+
+    /// `gsSPForceMatrix`.
+    #[must_use]
+    pub const fn gsSPForceMatrix(mtx: u32) -> [Gfx; 2] {
+        [
+            move_mem(64, uc::G_MV_MATRIX, 0, mtx),
+            move_wd(uc::G_MW_FORCEMTX, 0, 0x0001_0000),
+        ]
+    }
+
+    /// `gsSPSetGeometryMode`.
+    #[must_use]
+    pub const fn gsSPSetGeometryMode(mode: u32) -> Gfx {
+        geometry_mode(0, mode)
+    }
+
+    /// `gsSPClearGeometryMode`.
+    #[must_use]
+    pub const fn gsSPClearGeometryMode(mode: u32) -> Gfx {
+        geometry_mode(mode, 0)
+    }
+
+    /// `gsSPLoadGeometryMode`.
+    #[must_use]
+    pub const fn gsSPLoadGeometryMode(mode: u32) -> Gfx {
+        geometry_mode(!0, mode)
+    }
+
+    /// `gsSPInsertMatrix`.
+    #[must_use]
+    pub const fn gsSPInsertMatrix(r#where: u32, val: u32) -> Gfx {
+        move_wd(uc::G_MW_MATRIX, r#where, val)
+    }
+
+    /// `gsSPLine3D`.
+    #[must_use]
+    pub const fn gsSPLine3D(v0: u32, v1: u32, flag: u32) -> Gfx {
+        line_3d(v0, v1, 0, flag)
+    }
+
+    /// `gsSPLineW3D`.
+    #[must_use]
+    pub const fn gsSPLineW3D(v0: u32, v1: u32, wd: u32, flag: u32) -> Gfx {
+        line_3d(v0, v1, wd, flag)
+    }
+
+    /// `gsSPLoadUcode`.
+    #[must_use]
+    pub const fn gsSPLoadUcode(uc_start: u32, uc_dstart: u32) -> [Gfx; 2] {
+        [half_1(uc_dstart), load_ucode(uc_start, 0x800)]
+    }
+
+    /// `gsSPLookAtX`.
+    #[must_use]
+    pub const fn gsSPLookAtX(l: u32) -> Gfx {
+        look_at_x(l)
+    }
+
+    /// `gsSPLookAtY`.
+    #[must_use]
+    pub const fn gsSPLookAtY(l: u32) -> Gfx {
+        look_at_y(l)
+    }
+
+    /// `gsSPLookAt`.
+    #[must_use]
+    pub const fn gsSPLookAt(l: u32) -> [Gfx; 2] {
+        [look_at_x(l), look_at_y(l.wrapping_add(16))]
+    }
+
+    /// `gsSPMatrix`.
+    #[must_use]
+    pub const fn gsSPMatrix(mtx: u32, param: u32) -> Gfx {
+        super::dma2p(uc::G_MTX, mtx, 64, param ^ uc::G_MTX_PUSH, 0)
+    }
+
+    /// `gsSPModifyVertex`.
+    #[must_use]
+    pub const fn gsSPModifyVertex(vtx: u32, r#where: u32, val: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_MODIFYVTX, 24, 8) | sh(r#where, 16, 8) | sh(vtx.wrapping_mul(2), 0, 16),
+            val,
+        )
+    }
+
+    /// `gsSPPerspNormalize`.
+    #[must_use]
+    pub const fn gsSPPerspNormalize(scale: u32) -> Gfx {
+        move_wd(uc::G_MW_PERSPNORM, 0, scale)
+    }
+
+    /// `gsSPPopMatrix`.
+    #[must_use]
+    pub const fn gsSPPopMatrix(_param: u32) -> Gfx {
+        super::dma2p(uc::G_POPMTX, 64, 64, 2, 0)
+    }
+
+    /// `gsSPPopMatrixN`.
+    #[must_use]
+    pub const fn gsSPPopMatrixN(_param: u32, num: u32) -> Gfx {
+        super::dma2p(uc::G_POPMTX, num.wrapping_mul(64), 64, 2, 0)
+    }
+
+    /// `gsSPSegment`.
+    #[must_use]
+    pub const fn gsSPSegment(seg: u32, base: u32) -> Gfx {
+        move_wd(uc::G_MW_SEGMENT, seg.wrapping_mul(4), base)
+    }
+
+    /// `gsSPSetLights1`.
+    #[must_use]
+    pub const fn gsSPSetLights1(lights: u32) -> [Gfx; 3] {
+        [
+            num_lights(1),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights, 2),
+        ]
+    }
+
+    /// `gsSPSetLights2`.
+    #[must_use]
+    pub const fn gsSPSetLights2(lights: u32) -> [Gfx; 4] {
+        [
+            num_lights(2),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights, 3),
+        ]
+    }
+
+    /// `gsSPSetLights3`.
+    #[must_use]
+    pub const fn gsSPSetLights3(lights: u32) -> [Gfx; 5] {
+        [
+            num_lights(3),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights, 4),
+        ]
+    }
+
+    /// `gsSPSetLights4`.
+    #[must_use]
+    pub const fn gsSPSetLights4(lights: u32) -> [Gfx; 6] {
+        [
+            num_lights(4),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights, 5),
+        ]
+    }
+
+    /// `gsSPSetLights5`.
+    #[must_use]
+    pub const fn gsSPSetLights5(lights: u32) -> [Gfx; 7] {
+        [
+            num_lights(5),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights.wrapping_add(72), 5),
+            set_light(lights, 6),
+        ]
+    }
+
+    /// `gsSPSetLights6`.
+    #[must_use]
+    pub const fn gsSPSetLights6(lights: u32) -> [Gfx; 8] {
+        [
+            num_lights(6),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights.wrapping_add(72), 5),
+            set_light(lights.wrapping_add(88), 6),
+            set_light(lights, 7),
+        ]
+    }
+
+    /// `gsSPSetLights7`.
+    #[must_use]
+    pub const fn gsSPSetLights7(lights: u32) -> [Gfx; 9] {
+        [
+            num_lights(7),
+            set_light(lights.wrapping_add(8), 1),
+            set_light(lights.wrapping_add(24), 2),
+            set_light(lights.wrapping_add(40), 3),
+            set_light(lights.wrapping_add(56), 4),
+            set_light(lights.wrapping_add(72), 5),
+            set_light(lights.wrapping_add(88), 6),
+            set_light(lights.wrapping_add(104), 7),
+            set_light(lights, 8),
+        ]
+    }
+
+    /// `gsSPNumLights`.
+    #[must_use]
+    pub const fn gsSPNumLights(n: u32) -> Gfx {
+        num_lights(n)
+    }
+
+    /// `gsSPLight`.
+    #[must_use]
+    pub const fn gsSPLight(light: u32, n: u32) -> Gfx {
+        set_light(light, n)
+    }
+
+    /// `gsSPLightColor`.
+    #[must_use]
+    pub const fn gsSPLightColor(n: u32, color: u32) -> [Gfx; 2] {
+        let offset = n.wrapping_sub(1).wrapping_mul(0x18);
+        [
+            move_wd(uc::G_MW_LIGHTCOL, offset, color),
+            move_wd(uc::G_MW_LIGHTCOL, offset.wrapping_add(4), color),
+        ]
+    }
+
+    /// `gsSPTexture`.
+    #[must_use]
+    pub const fn gsSPTexture(sc: u32, tc: u32, level: u32, tile: u32, on: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_TEXTURE, 24, 8)
+                | sh(gbi::BOWTIE_VAL, 16, 8)
+                | sh(level, 11, 3)
+                | sh(tile, 8, 3)
+                | sh(on, 1, 7),
+            sh(sc, 16, 16) | sh(tc, 0, 16),
+        )
+    }
+
+    /// `gsSPTextureRectangle`.
+    #[must_use]
+    pub const fn gsSPTextureRectangle(
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+        s: u32,
+        t: u32,
+        dsdx: u32,
+        dtdy: u32,
+    ) -> [Gfx; 3] {
+        [
+            super::tex_rect(gbi::G_TEXRECT, ulx, uly, lrx, lry, tile),
+            half_1(sh(s, 16, 16) | sh(t, 0, 16)),
+            half_2(sh(dsdx, 16, 16) | sh(dtdy, 0, 16)),
+        ]
+    }
+
+    /// `gsSPTextureRectangleFlip`.
+    #[must_use]
+    pub const fn gsSPTextureRectangleFlip(
+        ulx: u32,
+        uly: u32,
+        lrx: u32,
+        lry: u32,
+        tile: u32,
+        s: u32,
+        t: u32,
+        dsdx: u32,
+        dtdy: u32,
+    ) -> [Gfx; 3] {
+        [
+            super::tex_rect(gbi::G_TEXRECTFLIP, ulx, uly, lrx, lry, tile),
+            half_1(sh(s, 16, 16) | sh(t, 0, 16)),
+            half_2(sh(dsdx, 16, 16) | sh(dtdy, 0, 16)),
+        ]
+    }
+
+    /// `gsSPVertex`.
+    #[must_use]
+    pub const fn gsSPVertex(v: u32, n: u32, v0: u32) -> Gfx {
+        Gfx::new(
+            sh(uc::G_VTX, 24, 8) | sh(n, 12, 8) | sh(v0.wrapping_add(n), 1, 7),
+            v,
+        )
+    }
+
+    /// `gsSPViewport`.
+    #[must_use]
+    pub const fn gsSPViewport(v: u32) -> Gfx {
+        move_mem(16, uc::G_MV_VIEWPORT, 0, v)
+    }
+
+    /// `gsBranchZ`.
+    #[must_use]
+    pub const fn gsBranchZ(vtx: u32, zval: u32) -> Gfx {
+        branch_z(vtx, zval)
+    }
+
+    /// `gsDisplayList`.
+    #[must_use]
+    pub const fn gsDisplayList(dl: u32, flag: u32) -> Gfx {
+        display_list(dl, flag)
+    }
+
+    /// `gsDPHalf1`.
+    #[must_use]
+    pub const fn gsDPHalf1(wordhi: u32) -> Gfx {
+        half_1(wordhi)
+    }
+
+    /// `gsDPHalf2`.
+    #[must_use]
+    pub const fn gsDPHalf2(wordlo: u32) -> Gfx {
+        half_2(wordlo)
+    }
+
+    /// `gsDPWord`.
+    #[must_use]
+    pub const fn gsDPWord(wordhi: u32, wordlo: u32) -> [Gfx; 2] {
+        [half_1(wordhi), half_2(wordlo)]
+    }
+
+    /// `gsSPGeometryMode`.
+    #[must_use]
+    pub const fn gsSPGeometryMode(clearbits: u32, setbits: u32) -> Gfx {
+        geometry_mode(clearbits, setbits)
+    }
+
+    /// `gsSPSetOtherMode`.
+    #[must_use]
+    pub const fn gsSPSetOtherMode(opc: u32, sft: u32, len: u32, data: u32) -> Gfx {
+        set_other_mode(opc, sft, len, data)
+    }
+
+    /// `gsSPSetOtherModeLo`.
+    #[must_use]
+    pub const fn gsSPSetOtherModeLo(sft: u32, len: u32, data: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_L, sft, len, data)
+    }
+
+    /// `gsSPSetOtherModeHi`.
+    #[must_use]
+    pub const fn gsSPSetOtherModeHi(sft: u32, len: u32, data: u32) -> Gfx {
+        set_other_mode(uc::G_SETOTHERMODE_H, sft, len, data)
+    }
+
+    /// `gsMoveWd`.
+    #[must_use]
+    pub const fn gsMoveWd(index: u32, offset: u32, data: u32) -> Gfx {
+        move_wd(index, offset, data)
+    }
+
+    /// `gsMoveMem`.
+    #[must_use]
+    pub const fn gsMoveMem(size: u32, index: u32, offset: u32, dram: u32) -> Gfx {
+        move_mem(size, index, offset, dram)
+    }
+
+    /// `gsSPDma_io`.
+    #[must_use]
+    pub const fn gsSPDma_io(flag: u32, dmem: u32, dram: u32, size: u32) -> Gfx {
+        dma_io(flag, dmem, dram, size)
+    }
+
+    /// `gsSPDmaRead`.
+    #[must_use]
+    pub const fn gsSPDmaRead(dmem: u32, dram: u32, size: u32) -> Gfx {
+        dma_io(0, dmem, dram, size)
+    }
+
+    /// `gsSPDmaWrite`.
+    #[must_use]
+    pub const fn gsSPDmaWrite(dmem: u32, dram: u32, size: u32) -> Gfx {
+        dma_io(1, dmem, dram, size)
+    }
+
+    /// `gsLoadUcode`.
+    #[must_use]
+    pub const fn gsLoadUcode(uc_start: u32, uc_dsize: u32) -> Gfx {
+        load_ucode(uc_start, uc_dsize)
+    }
+
+    /// `gsSPLoadUcodeEx`.
+    #[must_use]
+    pub const fn gsSPLoadUcodeEx(uc_start: u32, uc_dstart: u32, uc_dsize: u32) -> [Gfx; 2] {
+        [half_1(uc_dstart), load_ucode(uc_start, uc_dsize)]
+    }
+
+    /// `gsSPNoOp`.
+    #[must_use]
+    pub const fn gsSPNoOp() -> Gfx {
+        super::op(uc::G_SPNOOP)
+    }
+
+    /// `gsSpecial3`.
+    #[must_use]
+    pub const fn gsSpecial3(hi: u32, lo: u32) -> Gfx {
+        Gfx::new(sh(uc::G_SPECIAL_3, 24, 8) | sh(hi, 0, 24), lo)
+    }
+
+    /// `gsSpecial2`.
+    #[must_use]
+    pub const fn gsSpecial2(hi: u32, lo: u32) -> Gfx {
+        Gfx::new(sh(uc::G_SPECIAL_2, 24, 8) | sh(hi, 0, 24), lo)
+    }
+
+    /// `gsSpecial1`.
+    #[must_use]
+    pub const fn gsSpecial1(hi: u32, lo: u32) -> Gfx {
+        Gfx::new(sh(uc::G_SPECIAL_1, 24, 8) | sh(hi, 0, 24), lo)
+    }
+}
+
+/// Concatenates the packets of many [`static_dl`](crate::static_dl)
+/// builders into a single `[Gfx; N]`, usable in a `static` or `const` item.
+///
+/// The first argument names the microcode family, one of `f3d`, `f3dex` and
+/// `f3dex2`, followed by the builder calls. Builders are named without a
+/// path.
+///
+/// ```
+/// use gfxd_sys::gbi;
+/// use gfxd_sys::gfx::Gfx;
+/// use gfxd_sys::gs_list;
+///
+/// static DL: [Gfx; 9] = gs_list!(f3d;
+///     gsDPPipeSync(),
+///     gsDPSetCombineMode(gbi::G_CC_SHADE, gbi::G_CC_SHADE),
+///     gsDPLoadTLUT_pal16(0, 0x0600_0000),
+///     gsSPEndDisplayList(),
+/// );
+/// # assert_eq!(0xB800_0000, DL[8].w0);
+/// ```
+#[macro_export]
+macro_rules! gs_list {
+    ($family:ident; $($name:ident($($arg:expr),* $(,)?)),* $(,)?) => {{
+        #[repr(C)]
+        #[derive(Copy, Clone)]
+        struct List($([$crate::gfx::Gfx; $crate::__gs_list!(@len $family $name)]),*);
+
+        #[repr(C)]
+        union Cast {
+            list: List,
+            gfx: [$crate::gfx::Gfx; 0 $(+ $crate::__gs_list!(@len $family $name))*],
+        }
+
+        // SAFETY: `List` is a `repr(C)` sequence of `Gfx` arrays, which have
+        // no padding between them, so it has the same layout as a single
+        // array of all of them.
+        unsafe {
+            Cast {
+                list: List($($crate::__gs_list!(
+                    @item $name $crate::static_dl::$family::$name($($arg),*)
+                )),*),
+            }
+            .gfx
+        }
+    }};
+}
+
+/// The number of packets of each builder, for `gs_list!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __gs_list {
+    (@len $family:ident gsDPLoadTLUT_pal16) => {
+        6
+    };
+    (@len $family:ident gsDPLoadTLUT_pal256) => {
+        6
+    };
+    (@len $family:ident gsDPLoadMultiBlockYuvS) => {
+        7
+    };
+    (@len $family:ident gsDPLoadMultiBlockYuv) => {
+        7
+    };
+    (@len $family:ident gsDPLoadMultiBlock_4bS) => {
+        7
+    };
+    (@len $family:ident gsDPLoadMultiBlock_4b) => {
+        7
+    };
+    (@len $family:ident gsDPLoadMultiBlockS) => {
+        7
+    };
+    (@len $family:ident gsDPLoadMultiBlock) => {
+        7
+    };
+    (@len $family:ident _gsDPLoadTextureBlockYuvS) => {
+        7
+    };
+    (@len $family:ident _gsDPLoadTextureBlockYuv) => {
+        7
+    };
+    (@len $family:ident _gsDPLoadTextureBlock_4bS) => {
+        7
+    };
+    (@len $family:ident _gsDPLoadTextureBlock_4b) => {
+        7
+    };
+    (@len $family:ident _gsDPLoadTextureBlockS) => {
+        7
+    };
+    (@len $family:ident _gsDPLoadTextureBlock) => {
+        7
+    };
+    (@len $family:ident gsDPLoadTextureBlockYuvS) => {
+        7
+    };
+    (@len $family:ident gsDPLoadTextureBlockYuv) => {
+        7
+    };
+    (@len $family:ident gsDPLoadTextureBlock_4bS) => {
+        7
+    };
+    (@len $family:ident gsDPLoadTextureBlock_4b) => {
+        7
+    };
+    (@len $family:ident gsDPLoadTextureBlockS) => {
+        7
+    };
+    (@len $family:ident gsDPLoadTextureBlock) => {
+        7
+    };
+    (@len $family:ident gsDPLoadMultiTileYuv) => {
+        7
+    };
+    (@len $family:ident gsDPLoadMultiTile_4b) => {
+        7
+    };
+    (@len $family:ident gsDPLoadMultiTile) => {
+        7
+    };
+    (@len $family:ident _gsDPLoadTextureTileYuv) => {
+        7
+    };
+    (@len $family:ident _gsDPLoadTextureTile_4b) => {
+        7
+    };
+    (@len $family:ident _gsDPLoadTextureTile) => {
+        7
+    };
+    (@len $family:ident gsDPLoadTextureTileYuv) => {
+        7
+    };
+    (@len $family:ident gsDPLoadTextureTile_4b) => {
+        7
+    };
+    (@len $family:ident gsDPLoadTextureTile) => {
+        7
+    };
+    (@len $family:ident gsSPBranchLessZraw) => {
+        2
+    };
+    (@len $family:ident gsSPClipRatio) => {
+        4
+    };
+    (@len f3dex2 gsSPForceMatrix) => {
+        2
+    };
+    (@len $family:ident gsSPForceMatrix) => {
+        4
+    };
+    (@len $family:ident gsSPLoadUcode) => {
+        2
+    };
+    (@len $family:ident gsSPLookAt) => {
+        2
+    };
+    (@len $family:ident gsSPSetLights1) => {
+        3
+    };
+    (@len $family:ident gsSPSetLights2) => {
+        4
+    };
+    (@len $family:ident gsSPSetLights3) => {
+        5
+    };
+    (@len $family:ident gsSPSetLights4) => {
+        6
+    };
+    (@len $family:ident gsSPSetLights5) => {
+        7
+    };
+    (@len $family:ident gsSPSetLights6) => {
+        8
+    };
+    (@len $family:ident gsSPSetLights7) => {
+        9
+    };
+    (@len $family:ident gsSPLightColor) => {
+        2
+    };
+    (@len $family:ident gsSPTextureRectangle) => {
+        3
+    };
+    (@len $family:ident gsSPTextureRectangleFlip) => {
+        3
+    };
+    (@len $family:ident gsDPLoadTLUT) => {
+        6
+    };
+    (@len $family:ident gsDPWord) => {
+        2
+    };
+    (@len $family:ident gsSPLoadUcodeEx) => {
+        2
+    };
+    (@len $family:ident $name:ident) => {
+        1
+    };
+    (@item gsDPLoadTLUT_pal16 $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTLUT_pal256 $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadMultiBlockYuvS $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadMultiBlockYuv $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadMultiBlock_4bS $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadMultiBlock_4b $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadMultiBlockS $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadMultiBlock $call:expr) => {
+        $call
+    };
+    (@item _gsDPLoadTextureBlockYuvS $call:expr) => {
+        $call
+    };
+    (@item _gsDPLoadTextureBlockYuv $call:expr) => {
+        $call
+    };
+    (@item _gsDPLoadTextureBlock_4bS $call:expr) => {
+        $call
+    };
+    (@item _gsDPLoadTextureBlock_4b $call:expr) => {
+        $call
+    };
+    (@item _gsDPLoadTextureBlockS $call:expr) => {
+        $call
+    };
+    (@item _gsDPLoadTextureBlock $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTextureBlockYuvS $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTextureBlockYuv $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTextureBlock_4bS $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTextureBlock_4b $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTextureBlockS $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTextureBlock $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadMultiTileYuv $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadMultiTile_4b $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadMultiTile $call:expr) => {
+        $call
+    };
+    (@item _gsDPLoadTextureTileYuv $call:expr) => {
+        $call
+    };
+    (@item _gsDPLoadTextureTile_4b $call:expr) => {
+        $call
+    };
+    (@item _gsDPLoadTextureTile $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTextureTileYuv $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTextureTile_4b $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTextureTile $call:expr) => {
+        $call
+    };
+    (@item gsSPBranchLessZraw $call:expr) => {
+        $call
+    };
+    (@item gsSPClipRatio $call:expr) => {
+        $call
+    };
+    (@item gsSPForceMatrix $call:expr) => {
+        $call
+    };
+    (@item gsSPLoadUcode $call:expr) => {
+        $call
+    };
+    (@item gsSPLookAt $call:expr) => {
+        $call
+    };
+    (@item gsSPSetLights1 $call:expr) => {
+        $call
+    };
+    (@item gsSPSetLights2 $call:expr) => {
+        $call
+    };
+    (@item gsSPSetLights3 $call:expr) => {
+        $call
+    };
+    (@item gsSPSetLights4 $call:expr) => {
+        $call
+    };
+    (@item gsSPSetLights5 $call:expr) => {
+        $call
+    };
+    (@item gsSPSetLights6 $call:expr) => {
+        $call
+    };
+    (@item gsSPSetLights7 $call:expr) => {
+        $call
+    };
+    (@item gsSPLightColor $call:expr) => {
+        $call
+    };
+    (@item gsSPTextureRectangle $call:expr) => {
+        $call
+    };
+    (@item gsSPTextureRectangleFlip $call:expr) => {
+        $call
+    };
+    (@item gsDPLoadTLUT $call:expr) => {
+        $call
+    };
+    (@item gsDPWord $call:expr) => {
+        $call
+    };
+    (@item gsSPLoadUcodeEx $call:expr) => {
+        $call
+    };
+    (@item $name:ident $call:expr) => {
+        [$call]
+    };
+}
@@ -0,0 +1,411 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Symbol names for addresses.
+//!
+//! A [`SymbolMap`] holds the names of known addresses, loaded from a
+//! `symbol_addrs.txt` file like the ones `splat` uses, from a GNU ld map
+//! file or from a simple CSV file. With the `std` feature, while a map is
+//! installed by `SymbolMap::with_installed`, every pointer argument that
+//! `libgfxd` prints is printed as the symbol it points to, like `name` or
+//! `name + 0x10`, instead of as a raw address.
+//!
+//! Addresses inside a symbol are only named when the size of the symbol is
+//! known, which GNU ld map files do not tell.
+
+use alloc::collections::BTreeMap;
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+#[cfg(feature = "std")]
+use core::cell::Cell;
+#[cfg(feature = "std")]
+use core::num::NonZeroU32;
+#[cfg(feature = "std")]
+use core::ptr::NonNull;
+
+#[cfg(feature = "std")]
+use crate::ffi;
+
+#[cfg(feature = "std")]
+use crate::argument_callbacks as callbacks;
+
+#[cfg(feature = "std")]
+use crate::ptr::NonNullConst;
+
+/// A named address.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct Symbol {
+    /// The name of the symbol, a C identifier.
+    pub name: String,
+    /// The address the symbol starts at.
+    pub addr: u32,
+    /// The size of the symbol in bytes, if known.
+    pub size: Option<u32>,
+}
+
+impl Symbol {
+    /// Whether `addr` is inside the symbol. When the size is not known,
+    /// only the address of the symbol is.
+    #[must_use]
+    pub fn contains(&self, addr: u32) -> bool {
+        match self.size {
+            Some(size) => addr.wrapping_sub(self.addr) < size.max(1),
+            None => addr == self.addr,
+        }
+    }
+}
+
+/// A set of symbols, looked up by address.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SymbolMap {
+    symbols: BTreeMap<u32, Symbol>,
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Parses a decimal number, or a hexadecimal one with a `0x` prefix. Wider
+/// addresses, like the 64-bit ones of GNU ld map files, are truncated.
+fn parse_number(s: &str) -> Option<u32> {
+    let value = if s.starts_with("0x") || s.starts_with("0X") {
+        u64::from_str_radix(&s[2..], 16).ok()?
+    } else {
+        s.parse::<u64>().ok()?
+    };
+    Some(value as u32)
+}
+
+impl SymbolMap {
+    /// An empty map, to be filled with [`SymbolMap::add`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a symbol, replacing any other symbol at the same address.
+    pub fn add(&mut self, name: &str, addr: u32, size: Option<u32>) {
+        self.symbols.insert(
+            addr,
+            Symbol {
+                name: name.into(),
+                addr,
+                size,
+            },
+        );
+    }
+
+    /// The number of symbols in the map.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.symbols.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.symbols.is_empty()
+    }
+
+    /// Every symbol, in address order.
+    pub fn iter(&self) -> impl Iterator<Item = &Symbol> {
+        self.symbols.values()
+    }
+
+    /// The symbol at exactly `addr`.
+    #[must_use]
+    pub fn get(&self, addr: u32) -> Option<&Symbol> {
+        self.symbols.get(&addr)
+    }
+
+    /// The symbol that contains `addr`, and the offset of `addr` in it.
+    #[must_use]
+    pub fn lookup(&self, addr: u32) -> Option<(&Symbol, u32)> {
+        let (_, symbol) = self.symbols.range(..=addr).next_back()?;
+        if symbol.contains(addr) {
+            Some((symbol, addr - symbol.addr))
+        } else {
+            None
+        }
+    }
+
+    /// The name of `addr` as C source, like `name` or `name + 0x10`.
+    #[must_use]
+    pub fn name(&self, addr: u32) -> Option<String> {
+        let (symbol, offset) = self.lookup(addr)?;
+        if offset == 0 {
+            Some(symbol.name.clone())
+        } else {
+            Some(format!("{} + 0x{:X}", symbol.name, offset))
+        }
+    }
+
+    /// Parses a `symbol_addrs.txt` file, made of lines like
+    /// `name = 0x80000400; // size:0x10`.
+    ///
+    /// Blank lines and comments are skipped. The `size` attribute is read
+    /// from the comment that follows a symbol, and other attributes are
+    /// ignored.
+    pub fn parse_symbol_addrs(text: &str) -> Result<Self, SymbolParseError> {
+        let mut map = Self::new();
+
+        for (i, line) in text.lines().enumerate() {
+            let error = SymbolParseError { line: i + 1 };
+            let (code, comment) = match line.find("//") {
+                Some(x) => (&line[..x], &line[x + 2..]),
+                None => (line, ""),
+            };
+            let code = code.trim();
+            if code.is_empty() {
+                continue;
+            }
+
+            let eq = code.find('=').ok_or(error)?;
+            let name = code[..eq].trim();
+            let value = code[eq + 1..].trim();
+            if !value.ends_with(';') || !is_identifier(name) {
+                return Err(error);
+            }
+            let addr = parse_number(value[..value.len() - 1].trim()).ok_or(error)?;
+
+            let size = comment
+                .split_whitespace()
+                .filter(|x| x.starts_with("size:"))
+                .find_map(|x| parse_number(&x[5..]));
+            map.add(name, addr, size);
+        }
+        Ok(map)
+    }
+
+    /// Reads the symbols of a GNU ld map file, from the lines that only hold
+    /// an address and a name.
+    ///
+    /// Every other line, like section headers, input files and assignments,
+    /// is skipped, so this never fails.
+    #[must_use]
+    pub fn parse_ld_map(text: &str) -> Self {
+        let mut map = Self::new();
+
+        for line in text.lines() {
+            let mut tokens = line.split_whitespace();
+            let (addr, name) = match (tokens.next(), tokens.next(), tokens.next()) {
+                (Some(addr), Some(name), None) => (addr, name),
+                _ => continue,
+            };
+            if !addr.starts_with("0x") || !is_identifier(name) {
+                continue;
+            }
+            if let Some(addr) = parse_number(addr) {
+                map.add(name, addr, None);
+            }
+        }
+        map
+    }
+
+    /// Parses a CSV file where each line holds a name and an address, in
+    /// either order, optionally followed by a size.
+    ///
+    /// A first line that does not hold a symbol is taken as a header and
+    /// skipped, as are blank lines and lines starting with `#`.
+    pub fn parse_csv(text: &str) -> Result<Self, SymbolParseError> {
+        let mut map = Self::new();
+        let mut first = true;
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let is_header = first;
+            first = false;
+
+            let mut fields = line.split(',').map(str::trim);
+            let a = fields.next().unwrap_or("");
+            let b = fields.next().unwrap_or("");
+            let size = fields.next();
+
+            let symbol = match (parse_number(a), parse_number(b)) {
+                (Some(addr), None) if is_identifier(b) => Some((b, addr)),
+                (None, Some(addr)) if is_identifier(a) => Some((a, addr)),
+                _ => None,
+            };
+            let size = match size {
+                Some(x) if !x.is_empty() => parse_number(x).map(Some),
+                _ => Some(None),
+            };
+
+            match (symbol, size) {
+                (Some((name, addr)), Some(size)) => map.add(name, addr, size),
+                _ if is_header => {}
+                _ => return Err(SymbolParseError { line: i + 1 }),
+            }
+        }
+        Ok(map)
+    }
+}
+
+/// An error produced while parsing a symbol file.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct SymbolParseError {
+    /// The number of the line that could not be parsed, starting at 1.
+    pub line: usize,
+}
+
+impl fmt::Display for SymbolParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid symbol on line {}", self.line)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SymbolParseError {}
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    static INSTALLED: Cell<Option<NonNull<SymbolMap>>> = Cell::new(None);
+}
+
+/// Installs the previous map again when dropped, even if the closure given
+/// to [`SymbolMap::with_installed`] panics.
+#[cfg(feature = "std")]
+struct Restore {
+    previous: Option<NonNull<SymbolMap>>,
+}
+
+#[cfg(feature = "std")]
+impl SymbolMap {
+    /// Calls `f` with the argument callbacks of every pointer type set on the
+    /// current config, so `libgfxd` prints the symbols of this map instead of
+    /// raw addresses. Addresses that no symbol contains are printed as usual.
+    ///
+    /// Only one map is used per thread at a time, so calling this inside the
+    /// closure of another map uses this map until `f` returns, and then the
+    /// previous one again. The callbacks are cleared once the outermost call
+    /// returns.
+    ///
+    /// Callbacks set without a map, through [`argument_callbacks`], cannot
+    /// be read back from `libgfxd`, so they are not restored.
+    ///
+    /// [`argument_callbacks`]: crate::argument_callbacks
+    pub fn with_installed<R>(&self, f: impl FnOnce() -> R) -> R {
+        let previous = INSTALLED.with(|x| x.replace(Some(NonNull::from(self))));
+        let _restore = Restore { previous };
+        // SAFETY: Plain setters on the current config.
+        unsafe {
+            callbacks::gfxd_tlut_callback(Some(tlut_fn));
+            callbacks::gfxd_timg_callback(Some(timg_fn));
+            callbacks::gfxd_cimg_callback(Some(cimg_fn));
+            callbacks::gfxd_zimg_callback(Some(addr_fn));
+            callbacks::gfxd_dl_callback(Some(addr_fn));
+            callbacks::gfxd_mtx_callback(Some(addr_fn));
+            callbacks::gfxd_lookat_callback(Some(addr_count_fn));
+            callbacks::gfxd_light_callback(Some(addr_fn));
+            callbacks::gfxd_lightsn_callback(Some(addr_count_fn));
+            callbacks::gfxd_seg_callback(Some(addr_count_fn));
+            callbacks::gfxd_vtx_callback(Some(addr_count_fn));
+            callbacks::gfxd_vp_callback(Some(addr_fn));
+            callbacks::gfxd_uctext_callback(Some(addr_size_fn));
+            callbacks::gfxd_ucdata_callback(Some(addr_size_fn));
+            callbacks::gfxd_dram_callback(Some(addr_size_fn));
+        }
+        f()
+    }
+}
+
+#[cfg(feature = "std")]
+impl Drop for Restore {
+    fn drop(&mut self) {
+        INSTALLED.with(|x| x.set(self.previous));
+        if self.previous.is_some() {
+            // The callbacks are the same for every map.
+            return;
+        }
+        // SAFETY: Plain setters on the current config.
+        unsafe {
+            callbacks::gfxd_tlut_callback(None);
+            callbacks::gfxd_timg_callback(None);
+            callbacks::gfxd_cimg_callback(None);
+            callbacks::gfxd_zimg_callback(None);
+            callbacks::gfxd_dl_callback(None);
+            callbacks::gfxd_mtx_callback(None);
+            callbacks::gfxd_lookat_callback(None);
+            callbacks::gfxd_light_callback(None);
+            callbacks::gfxd_lightsn_callback(None);
+            callbacks::gfxd_seg_callback(None);
+            callbacks::gfxd_vtx_callback(None);
+            callbacks::gfxd_vp_callback(None);
+            callbacks::gfxd_uctext_callback(None);
+            callbacks::gfxd_ucdata_callback(None);
+            callbacks::gfxd_dram_callback(None);
+        }
+    }
+}
+
+/// Prints the symbol of `addr`, returning whether there was one.
+#[cfg(feature = "std")]
+fn print_symbol(addr: u32) -> ffi::c_int {
+    let name = INSTALLED.with(|x| {
+        // SAFETY: `with_installed` borrows the map until it resets this.
+        x.get().and_then(|map| unsafe { map.as_ref() }.name(addr))
+    });
+    match name {
+        Some(name) => {
+            write_bytes(name.as_bytes());
+            1
+        }
+        None => 0,
+    }
+}
+
+/// Writes `bytes`, which must not be empty, to the output.
+#[cfg(feature = "std")]
+fn write_bytes(bytes: &[u8]) {
+    let buf = NonNullConst::from_ref(&bytes[0]).cast::<ffi::c_void>();
+    // SAFETY: `buf` points to `bytes.len()` readable bytes.
+    unsafe {
+        crate::custom_output::gfxd_write(buf, bytes.len() as ffi::c_int);
+    }
+}
+
+#[cfg(feature = "std")]
+unsafe extern "C" fn addr_fn(addr: u32) -> ffi::c_int {
+    print_symbol(addr)
+}
+
+#[cfg(feature = "std")]
+unsafe extern "C" fn addr_count_fn(addr: u32, _count: i32) -> ffi::c_int {
+    print_symbol(addr)
+}
+
+#[cfg(feature = "std")]
+unsafe extern "C" fn addr_size_fn(addr: u32, _size: NonZeroU32) -> ffi::c_int {
+    print_symbol(addr)
+}
+
+#[cfg(feature = "std")]
+unsafe extern "C" fn tlut_fn(tlut: u32, _idx: i32, _count: i32) -> ffi::c_int {
+    print_symbol(tlut)
+}
+
+#[cfg(feature = "std")]
+unsafe extern "C" fn timg_fn(
+    timg: u32,
+    _fmt: i32,
+    _siz: i32,
+    _width: i32,
+    _height: i32,
+    _pal: i32,
+) -> ffi::c_int {
+    print_symbol(timg)
+}
+
+#[cfg(feature = "std")]
+unsafe extern "C" fn cimg_fn(cimg: u32, _fmt: i32, _siz: i32, _width: i32) -> ffi::c_int {
+    print_symbol(cimg)
+}
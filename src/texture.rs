@@ -0,0 +1,665 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Texture decoding.
+//!
+//! Converts the texture images reported by [`gfxd_timg_callback`] into
+//! 8-bit RGBA pixels, four bytes per texel, row by row. Texture data is read
+//! as it is laid out in RDRAM, with 16-bit and 32-bit texels in big endian.
+//!
+//! Color indexed textures need the palette loaded by [`gfxd_tlut_callback`],
+//! along with the format of its colors, which is set in the `G_MDSFT_TEXTLUT`
+//! field of the othermode, see [`TlutFormat::from_texture_lut`].
+//!
+//! With the `alloc` feature, `collect_textures` finds the textures a display
+//! list references and their palettes, and with the `std` feature,
+//! `dump_textures` writes them all to PNG files.
+//!
+//! [`gfxd_timg_callback`]: crate::argument_callbacks::gfxd_timg_callback
+//! [`gfxd_tlut_callback`]: crate::argument_callbacks::gfxd_tlut_callback
+
+#[cfg(feature = "alloc")]
+use alloc::format;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::ffi;
+
+#[cfg(feature = "alloc")]
+use crate::arg_type::ArgType;
+#[cfg(feature = "alloc")]
+use crate::decode::DecodeSettings;
+use crate::gbi;
+#[cfg(feature = "alloc")]
+use crate::macro_info;
+#[cfg(feature = "alloc")]
+use crate::othermode;
+#[cfg(feature = "alloc")]
+use crate::traverse::Memory;
+
+#[cfg(feature = "alloc")]
+use crate::ptr::{NonNullConst, NonNullMut};
+
+/// The size in bytes of a decoded texel.
+pub const RGBA8_SIZE: usize = 4;
+
+/// The format and dimensions of a texture image, as given to
+/// [`gfxd_timg_callback`].
+///
+/// [`gfxd_timg_callback`]: crate::argument_callbacks::gfxd_timg_callback
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TextureInfo {
+    /// One of the `G_IM_FMT_*` constants.
+    pub fmt: u32,
+    /// One of the `G_IM_SIZ_*` constants.
+    pub siz: u32,
+    /// The width of the image, in texels.
+    pub width: u32,
+    /// The height of the image, in texels.
+    pub height: u32,
+    /// The palette of 4-bit color indexed images, each palette being 16
+    /// colors. This only tells which palette to pass to [`decode_into`].
+    pub pal: u32,
+}
+
+impl TextureInfo {
+    /// The number of texels in the image.
+    #[must_use]
+    pub fn texels(&self) -> usize {
+        self.width as usize * self.height as usize
+    }
+
+    /// The size in bits of a texel.
+    #[must_use]
+    pub fn bits_per_texel(&self) -> usize {
+        4 << (self.siz & 3)
+    }
+
+    /// The size in bytes of the image data.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        (self.texels() * self.bits_per_texel() + 7) / 8
+    }
+
+    /// The size in bytes of the decoded image.
+    #[must_use]
+    pub fn rgba8_size(&self) -> usize {
+        self.texels() * RGBA8_SIZE
+    }
+
+    /// Whether [`decode_into`] supports this format.
+    #[must_use]
+    pub fn is_supported(&self) -> bool {
+        format_name(self.fmt, self.siz).is_some()
+    }
+
+    /// Whether the image is color indexed.
+    #[must_use]
+    pub fn is_ci(&self) -> bool {
+        self.fmt == gbi::G_IM_FMT_CI
+    }
+}
+
+/// The format of the colors of a palette.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum TlutFormat {
+    /// `G_TT_RGBA16`.
+    Rgba16,
+    /// `G_TT_IA16`.
+    Ia16,
+}
+
+impl TlutFormat {
+    /// The palette format set by a `G_MDSFT_TEXTLUT` othermode field, like
+    /// [`OtherModeHi::texture_lut`], or `None` for `G_TT_NONE`.
+    ///
+    /// [`OtherModeHi::texture_lut`]: crate::othermode::OtherModeHi::texture_lut
+    #[must_use]
+    pub fn from_texture_lut(texture_lut: u32) -> Option<Self> {
+        match texture_lut & gbi::G_TT_IA16 {
+            gbi::G_TT_RGBA16 => Some(TlutFormat::Rgba16),
+            gbi::G_TT_IA16 => Some(TlutFormat::Ia16),
+            _ => None,
+        }
+    }
+
+    fn to_rgba8(self, color: u16) -> [u8; 4] {
+        match self {
+            TlutFormat::Rgba16 => rgba16(color),
+            TlutFormat::Ia16 => ia16(color),
+        }
+    }
+}
+
+/// A palette for color indexed images.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Tlut<'a> {
+    /// The colors, 16-bit each in big endian, like the data at the address
+    /// given to [`gfxd_tlut_callback`]. For 4-bit images, this starts at the
+    /// first color of the palette the image uses.
+    ///
+    /// [`gfxd_tlut_callback`]: crate::argument_callbacks::gfxd_tlut_callback
+    pub data: &'a [u8],
+    pub format: TlutFormat,
+}
+
+impl Tlut<'_> {
+    /// The color at `index`, or `None` if the palette is too short.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<[u8; 4]> {
+        let bytes = self.data.get(index * 2..index * 2 + 2)?;
+        Some(
+            self.format
+                .to_rgba8(u16::from_be_bytes([bytes[0], bytes[1]])),
+        )
+    }
+}
+
+/// Scales a value of `bits` bits to 8 bits, so that the largest value
+/// becomes `0xFF`.
+fn scale(x: u32, bits: u32) -> u8 {
+    let x = x << (8 - bits);
+    let mut out = x;
+    let mut shift = bits;
+    while shift < 8 {
+        out |= x >> shift;
+        shift += bits;
+    }
+    out as u8
+}
+
+fn rgba16(c: u16) -> [u8; 4] {
+    let c = u32::from(c);
+    [
+        scale((c >> 11) & 0x1F, 5),
+        scale((c >> 6) & 0x1F, 5),
+        scale((c >> 1) & 0x1F, 5),
+        if c & 1 != 0 { 0xFF } else { 0 },
+    ]
+}
+
+fn ia16(c: u16) -> [u8; 4] {
+    let [i, a] = c.to_be_bytes();
+    [i, i, i, a]
+}
+
+fn clamp(x: i32) -> u8 {
+    if x < 0 {
+        0
+    } else if x > 0xFF {
+        0xFF
+    } else {
+        x as u8
+    }
+}
+
+/// Converts a YUV texel to RGB, with the coefficients of ITU-R BT.601 in
+/// 8.8 fixed point.
+fn yuv(y: u8, u: u8, v: u8) -> [u8; 4] {
+    let y = i32::from(y);
+    let u = i32::from(u) - 0x80;
+    let v = i32::from(v) - 0x80;
+    [
+        clamp(y + ((359 * v) >> 8)),
+        clamp(y - ((88 * u + 183 * v) >> 8)),
+        clamp(y + ((454 * u) >> 8)),
+        0xFF,
+    ]
+}
+
+/// Decodes the image `data` described by `info` into `out`, which must hold
+/// [`TextureInfo::rgba8_size`] bytes.
+///
+/// `tlut` is only used by color indexed images, for which it is required.
+/// Extra bytes at the end of `data` are ignored.
+pub fn decode_into(
+    info: &TextureInfo,
+    data: &[u8],
+    tlut: Option<Tlut<'_>>,
+    out: &mut [u8],
+) -> Result<(), TextureError> {
+    if !info.is_supported() {
+        return Err(TextureError::Unsupported {
+            fmt: info.fmt,
+            siz: info.siz,
+        });
+    }
+    if data.len() < info.size() {
+        return Err(TextureError::DataTooShort {
+            expected: info.size(),
+            actual: data.len(),
+        });
+    }
+    if out.len() != info.rgba8_size() {
+        return Err(TextureError::OutputSize {
+            expected: info.rgba8_size(),
+            actual: out.len(),
+        });
+    }
+    if info.is_ci() && tlut.is_none() {
+        return Err(TextureError::MissingTlut);
+    }
+
+    let nibble = |i: usize| u32::from((data[i / 2] >> (4 - (i & 1) * 4)) & 0xF);
+    let half = |i: usize| u16::from_be_bytes([data[i * 2], data[i * 2 + 1]]);
+
+    for (i, texel) in out.chunks_exact_mut(RGBA8_SIZE).enumerate() {
+        let color = match (info.fmt, info.siz) {
+            (gbi::G_IM_FMT_RGBA, gbi::G_IM_SIZ_16b) => rgba16(half(i)),
+            (gbi::G_IM_FMT_RGBA, _) => [
+                data[i * 4],
+                data[i * 4 + 1],
+                data[i * 4 + 2],
+                data[i * 4 + 3],
+            ],
+            (gbi::G_IM_FMT_YUV, _) => {
+                // Each pair of texels is stored as U, Y0, V, Y1.
+                let pair = i & !1;
+                let y = data[i * 2 + 1];
+                let v = data.get(pair * 2 + 2).cloned().unwrap_or(0x80);
+                yuv(y, data[pair * 2], v)
+            }
+            (gbi::G_IM_FMT_CI, siz) => {
+                let index = if siz == gbi::G_IM_SIZ_4b {
+                    nibble(i) as usize
+                } else {
+                    data[i] as usize
+                };
+                let tlut = tlut.as_ref().ok_or(TextureError::MissingTlut)?;
+                tlut.get(index)
+                    .ok_or(TextureError::TlutTooShort { index })?
+            }
+            (gbi::G_IM_FMT_IA, gbi::G_IM_SIZ_4b) => {
+                let x = nibble(i);
+                let v = scale(x >> 1, 3);
+                [v, v, v, if x & 1 != 0 { 0xFF } else { 0 }]
+            }
+            (gbi::G_IM_FMT_IA, gbi::G_IM_SIZ_8b) => {
+                let v = scale(u32::from(data[i] >> 4), 4);
+                let a = scale(u32::from(data[i] & 0xF), 4);
+                [v, v, v, a]
+            }
+            (gbi::G_IM_FMT_IA, _) => ia16(half(i)),
+            // The intensity is used as alpha too.
+            (_, gbi::G_IM_SIZ_4b) => {
+                let v = scale(nibble(i), 4);
+                [v, v, v, v]
+            }
+            _ => {
+                let v = data[i];
+                [v, v, v, v]
+            }
+        };
+        texel.copy_from_slice(&color);
+    }
+    Ok(())
+}
+
+/// Decodes the image `data` described by `info` into 8-bit RGBA texels.
+///
+/// See [`decode_into`].
+#[cfg(feature = "alloc")]
+pub fn decode(
+    info: &TextureInfo,
+    data: &[u8],
+    tlut: Option<Tlut<'_>>,
+) -> Result<Vec<u8>, TextureError> {
+    let mut out = vec![0; info.rgba8_size()];
+    decode_into(info, data, tlut, &mut out)?;
+    Ok(out)
+}
+
+/// The reasons texture decoding can fail.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum TextureError {
+    /// The combination of `fmt` and `siz` is not a texture format.
+    Unsupported { fmt: u32, siz: u32 },
+    /// The image data is shorter than the image.
+    DataTooShort { expected: usize, actual: usize },
+    /// The output buffer does not have the size of the decoded image.
+    OutputSize { expected: usize, actual: usize },
+    /// A color indexed image was decoded without a palette.
+    MissingTlut,
+    /// A texel uses a color past the end of the palette.
+    TlutTooShort { index: usize },
+    /// The height of the image is not known.
+    UnknownHeight,
+}
+
+impl fmt::Display for TextureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            TextureError::Unsupported { fmt, siz } => write!(
+                f,
+                "unsupported texture format {} with texel size {}",
+                fmt, siz
+            ),
+            TextureError::DataTooShort { expected, actual } => write!(
+                f,
+                "texture data is {} bytes long, expected {}",
+                actual, expected
+            ),
+            TextureError::OutputSize { expected, actual } => write!(
+                f,
+                "output buffer is {} bytes long, expected {}",
+                actual, expected
+            ),
+            TextureError::MissingTlut => write!(f, "color indexed texture without a palette"),
+            TextureError::TlutTooShort { index } => {
+                write!(f, "palette has no color {}", index)
+            }
+            TextureError::UnknownHeight => write!(f, "texture height is unknown"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TextureError {}
+
+/// The short name of a texture format, like `rgba16` or `ci4`, or `None` if
+/// [`decode_into`] does not support it.
+#[must_use]
+pub fn format_name(fmt: u32, siz: u32) -> Option<&'static str> {
+    let name = match (fmt, siz) {
+        (gbi::G_IM_FMT_RGBA, gbi::G_IM_SIZ_16b) => "rgba16",
+        (gbi::G_IM_FMT_RGBA, gbi::G_IM_SIZ_32b) => "rgba32",
+        (gbi::G_IM_FMT_YUV, gbi::G_IM_SIZ_16b) => "yuv16",
+        (gbi::G_IM_FMT_CI, gbi::G_IM_SIZ_4b) => "ci4",
+        (gbi::G_IM_FMT_CI, gbi::G_IM_SIZ_8b) => "ci8",
+        (gbi::G_IM_FMT_IA, gbi::G_IM_SIZ_4b) => "ia4",
+        (gbi::G_IM_FMT_IA, gbi::G_IM_SIZ_8b) => "ia8",
+        (gbi::G_IM_FMT_IA, gbi::G_IM_SIZ_16b) => "ia16",
+        (gbi::G_IM_FMT_I, gbi::G_IM_SIZ_4b) => "i4",
+        (gbi::G_IM_FMT_I, gbi::G_IM_SIZ_8b) => "i8",
+        _ => return None,
+    };
+    Some(name)
+}
+
+/// A palette loaded by a display list, as reported to
+/// [`gfxd_tlut_callback`].
+///
+/// [`gfxd_tlut_callback`]: crate::argument_callbacks::gfxd_tlut_callback
+#[cfg(feature = "alloc")]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TlutRef {
+    /// The address of the first color.
+    pub addr: u32,
+    /// The palette the colors are loaded to, for 16 color palettes.
+    pub idx: Option<u32>,
+    /// The number of colors.
+    pub count: u32,
+    /// The format of the colors, as set in the othermode when the texture
+    /// that uses the palette was loaded, or when the palette was loaded if
+    /// it was not set by then. `G_TT_RGBA16` if neither is set.
+    pub format: TlutFormat,
+}
+
+/// A texture image referenced by a display list, as reported to
+/// [`gfxd_timg_callback`].
+///
+/// [`gfxd_timg_callback`]: crate::argument_callbacks::gfxd_timg_callback
+#[cfg(feature = "alloc")]
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct TextureRef {
+    /// The address of the image.
+    pub addr: u32,
+    /// One of the `G_IM_FMT_*` constants.
+    pub fmt: u32,
+    /// One of the `G_IM_SIZ_*` constants.
+    pub siz: u32,
+    /// The width of the image, in texels.
+    pub width: u32,
+    /// The height of the image, in texels, if known. A lone
+    /// `gsDPSetTextureImage` does not tell it, only the texture loading
+    /// macros do.
+    pub height: Option<u32>,
+    /// The palette of 4-bit color indexed images.
+    pub pal: u32,
+    /// The palette of color indexed images.
+    pub tlut: Option<TlutRef>,
+}
+
+#[cfg(feature = "alloc")]
+impl TextureRef {
+    /// The format and dimensions of the image, if its height is known.
+    #[must_use]
+    pub fn info(&self) -> Option<TextureInfo> {
+        Some(TextureInfo {
+            fmt: self.fmt,
+            siz: self.siz,
+            width: self.width,
+            height: self.height?,
+            pal: self.pal,
+        })
+    }
+
+    /// A file name for the texture, made of its address, format and size,
+    /// like `06001000.ci4.32x16.png`, or only its width, like
+    /// `06001000.ci4.32.png`, if its height is not known.
+    #[must_use]
+    pub fn file_name(&self) -> String {
+        let format = format_name(self.fmt, self.siz).unwrap_or("unknown");
+        match self.height {
+            Some(height) => format!("{:08X}.{}.{}x{}.png", self.addr, format, self.width, height),
+            None => format!("{:08X}.{}.{}.png", self.addr, format, self.width),
+        }
+    }
+
+    /// Decodes the texture into 8-bit RGBA texels, reading its data and its
+    /// palette from `memory`.
+    ///
+    /// Missing data is reported as [`TextureError::DataTooShort`], and an
+    /// unknown height as [`TextureError::UnknownHeight`].
+    pub fn decode(&self, memory: &Memory<'_>) -> Result<Vec<u8>, TextureError> {
+        let info = self.info().ok_or(TextureError::UnknownHeight)?;
+        let data = memory.read(self.addr).unwrap_or(&[]);
+        let tlut = self.tlut.map(|tlut| {
+            let data = memory.read(tlut.addr).unwrap_or(&[]);
+            let len = data.len().min(tlut.count as usize * 2);
+            // A 4-bit image whose palette was loaded along with others, as
+            // a 256 color palette, uses the 16 colors of its own palette.
+            let start = if tlut.idx.is_none() && info.siz == gbi::G_IM_SIZ_4b {
+                len.min(info.pal as usize * 16 * 2)
+            } else {
+                0
+            };
+            Tlut {
+                data: &data[start..len],
+                format: tlut.format,
+            }
+        });
+        decode(&info, data, tlut)
+    }
+}
+
+/// What the argument callbacks record while collecting textures.
+#[cfg(feature = "alloc")]
+struct CollectState {
+    textures: Vec<(TextureRef, Option<TlutFormat>, usize)>,
+    tluts: Vec<TlutRef>,
+    format: Option<TlutFormat>,
+}
+
+/// Finds every texture image that the display list in `input` references,
+/// along with the palette of color indexed images.
+///
+/// The palette of a color indexed image is the last one loaded before it,
+/// preferring the one loaded to its palette number for 4-bit images, or the
+/// first one loaded after it if there is none. Textures referenced several
+/// times with the same format, size and palette are only reported once, in
+/// the order they are first referenced. Images whose height is not known,
+/// like the one of a lone `gsDPSetTextureImage`, are reported without one.
+#[cfg(feature = "alloc")]
+pub fn collect_textures(input: &[u8], settings: &DecodeSettings) -> Vec<TextureRef> {
+    let mut state = CollectState {
+        textures: Vec::new(),
+        tluts: Vec::new(),
+        format: None,
+    };
+
+    crate::decode::with_config(|| {
+        settings.apply();
+        // SAFETY: `input` and `state` outlive the execution.
+        unsafe {
+            crate::io::gfxd_input_buffer(NonNullConst::new_void(input.as_ptr()), input.len() as _);
+            crate::io::gfxd_output_callback(Some(crate::decode::discard_output));
+            crate::handlers::gfxd_macro_fn(Some(track_tlut_format));
+            crate::argument_callbacks::gfxd_timg_callback(Some(record_timg));
+            crate::argument_callbacks::gfxd_tlut_callback(Some(record_tlut));
+            crate::settings::gfxd_udata_set(NonNullMut::new_void(&mut state));
+
+            crate::execution::gfxd_execute();
+        }
+    });
+
+    let mut textures: Vec<TextureRef> = Vec::new();
+    for &(texture, format, tluts_before) in state.textures.iter() {
+        let tlut = if texture.fmt == gbi::G_IM_FMT_CI {
+            let before = &state.tluts[..tluts_before];
+            let wanted = if texture.siz == gbi::G_IM_SIZ_4b {
+                Some(texture.pal)
+            } else {
+                None
+            };
+            before
+                .iter()
+                .rev()
+                .find(|x| wanted.is_some() && x.idx == wanted)
+                .or_else(|| before.last())
+                .or_else(|| state.tluts.get(tluts_before))
+                .map(|&tlut| TlutRef {
+                    format: format.unwrap_or(tlut.format),
+                    ..tlut
+                })
+        } else {
+            None
+        };
+
+        let texture = TextureRef { tlut, ..texture };
+        if !textures.contains(&texture) {
+            textures.push(texture);
+        }
+    }
+    textures
+}
+
+#[cfg(feature = "alloc")]
+unsafe fn collect_state<'a>() -> &'a mut CollectState {
+    unsafe { crate::decode::udata() }
+}
+
+/// The `G_MDSFT_TEXTLUT` field set by the current macro, if it sets all of
+/// it.
+#[cfg(feature = "alloc")]
+unsafe fn texture_lut() -> Option<u32> {
+    let value = |type_| {
+        unsafe { macro_info::gfxd_value_by_type(type_, 0) }.map(|x| unsafe { x.as_ref().u })
+    };
+    if let Some(tt) = value(ArgType::gfxd_Tt) {
+        return Some(tt);
+    }
+
+    let data = value(ArgType::gfxd_Othermodehi)?;
+    // `gsSPSetOtherModeHi` only sets the `len` bits at `sft`, while
+    // `gsDPSetOtherMode` sets the whole word.
+    let (sft, len) = match (value(ArgType::gfxd_Sfthi), value(ArgType::gfxd_Num)) {
+        (Some(sft), Some(len)) => (sft, len),
+        _ => (0, 32),
+    };
+    let field = gbi::G_TT_IA16;
+    if othermode::update(0, sft, len, !0) & field == field {
+        Some(data)
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+unsafe extern "C" fn track_tlut_format() -> ffi::c_int {
+    let state = unsafe { collect_state() };
+    if let Some(texture_lut) = unsafe { texture_lut() } {
+        state.format = TlutFormat::from_texture_lut(texture_lut);
+    }
+
+    // The argument callbacks are called while printing the arguments.
+    unsafe { crate::handlers::gfxd_macro_dflt() }
+}
+
+#[cfg(feature = "alloc")]
+unsafe extern "C" fn record_timg(
+    timg: u32,
+    fmt: i32,
+    siz: i32,
+    width: i32,
+    height: i32,
+    pal: i32,
+) -> ffi::c_int {
+    let state = unsafe { collect_state() };
+    if fmt >= 0 && siz >= 0 && width > 0 {
+        let texture = TextureRef {
+            addr: timg,
+            fmt: fmt as u32,
+            siz: siz as u32,
+            width: width as u32,
+            height: if height > 0 {
+                Some(height as u32)
+            } else {
+                None
+            },
+            pal: if pal >= 0 { pal as u32 } else { 0 },
+            tlut: None,
+        };
+        let tluts_before = state.tluts.len();
+        state.textures.push((texture, state.format, tluts_before));
+    }
+    0
+}
+
+#[cfg(feature = "alloc")]
+unsafe extern "C" fn record_tlut(tlut: u32, idx: i32, count: i32) -> ffi::c_int {
+    let state = unsafe { collect_state() };
+    if count > 0 {
+        state.tluts.push(TlutRef {
+            addr: tlut,
+            idx: if idx >= 0 { Some(idx as u32) } else { None },
+            count: count as u32,
+            format: state.format.unwrap_or(TlutFormat::Rgba16),
+        });
+    }
+    0
+}
+
+/// Writes every texture that the display list in `input` references to a
+/// PNG file in `dir`, named by [`TextureRef::file_name`], reading the
+/// textures from `memory`.
+///
+/// Textures that cannot be decoded, because their data is not in `memory`,
+/// their format is not supported or their height is not known, are skipped. Returns the textures that
+/// were written.
+#[cfg(feature = "std")]
+pub fn dump_textures(
+    input: &[u8],
+    settings: &DecodeSettings,
+    memory: &Memory<'_>,
+    dir: &std::path::Path,
+) -> std::io::Result<Vec<TextureRef>> {
+    let mut written = Vec::new();
+    for texture in collect_textures(input, settings) {
+        let (info, pixels) = match (texture.info(), texture.decode(memory)) {
+            (Some(info), Ok(pixels)) => (info, pixels),
+            _ => continue,
+        };
+        let png = crate::png::encode_rgba8(info.width, info.height, &pixels)
+            .expect("decoded textures have the size of the image");
+        std::fs::write(dir.join(texture.file_name()), png)?;
+        written.push(texture);
+    }
+    Ok(written)
+}
@@ -0,0 +1,254 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Recursive display list traversal.
+//!
+//! `libgfxd` decodes a single linear buffer, leaving the lists it calls to
+//! the caller. [`traverse`] starts from a root display list and follows
+//! every `gsSPDisplayList`, `gsSPBranchList`, `gsSPBranchLessZraw` and
+//! `gsBranchZ` it meets, decoding each list found in a [`Memory`] exactly
+//! once, however many times it is called and even when lists call each
+//! other in a cycle.
+//!
+//! Lists are returned in the order they are discovered, the root first,
+//! each with every call made to it.
+//!
+//! The `gsSPSegment` updates met along the way are recorded in an
+//! [`AddressResolver`], and applied to the address of each called list
+//! before looking it up, see [`traverse`].
+
+use alloc::collections::{BTreeMap, BTreeSet, VecDeque};
+use alloc::vec::Vec;
+
+use crate::address::AddressResolver;
+use crate::command::Command;
+use crate::decode::{DecodeSettings, Macro};
+use crate::differential::Backend;
+use crate::gbi;
+
+/// A set of buffers, each placed at an address.
+///
+/// Buffers can be placed at virtual addresses, like `0x80123000`, or at
+/// segmented addresses, see [`Memory::add_segment`]. [`Memory::read`] looks
+/// addresses up as given, [`traverse`] applies the segments set by the
+/// display lists first.
+#[derive(Debug, Default, Clone)]
+pub struct Memory<'a> {
+    regions: Vec<(u32, &'a [u8])>,
+}
+
+impl<'a> Memory<'a> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Places `data` at `addr`.
+    pub fn add_region(&mut self, addr: u32, data: &'a [u8]) {
+        self.regions.push((addr, data));
+    }
+
+    /// Places `data` at the start of segment `seg`, so that segmented
+    /// addresses like `0x06000010` point into it.
+    pub fn add_segment(&mut self, seg: u32, data: &'a [u8]) {
+        self.add_region((seg & 0xF) << 24, data);
+    }
+
+    /// The bytes from `addr` up to the end of the buffer that holds it, or
+    /// `None` if no buffer does.
+    ///
+    /// When buffers overlap, the one added first wins.
+    #[must_use]
+    pub fn read(&self, addr: u32) -> Option<&'a [u8]> {
+        self.regions.iter().find_map(|&(start, data)| {
+            let offset = addr.checked_sub(start)? as usize;
+            if offset < data.len() {
+                Some(&data[offset..])
+            } else {
+                None
+            }
+        })
+    }
+}
+
+/// How a display list was reached.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum CallKind {
+    /// `gsSPDisplayList`, which returns to the caller.
+    Call,
+    /// `gsSPBranchList`, which does not return.
+    Branch,
+    /// `gsSPBranchLessZraw` or `gsBranchZ`, which branch depending on the
+    /// depth of a vertex.
+    BranchZ,
+}
+
+/// A call from one display list to another.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Call {
+    /// The address of the calling list.
+    pub caller: u32,
+    /// The address of the calling macro.
+    pub addr: u32,
+    /// The address of the called list, once the segments set when the call
+    /// is made are applied, see [`traverse`].
+    pub target: u32,
+    pub kind: CallKind,
+}
+
+/// A display list found by [`traverse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DisplayList {
+    /// The address of the list.
+    pub addr: u32,
+    /// The macros of the list, up to the one that ends it. Offsets are
+    /// relative to `addr`.
+    pub macros: Vec<Macro>,
+    /// Every call made to this list, in the order they were found.
+    pub callers: Vec<Call>,
+}
+
+/// The outcome of [`traverse`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Traversal {
+    /// Every list found, in the order they were discovered.
+    pub lists: Vec<DisplayList>,
+    /// Calls to lists that are not in the [`Memory`].
+    pub unresolved: Vec<Call>,
+}
+
+impl Traversal {
+    /// The list at `addr`, if it was found.
+    #[must_use]
+    pub fn get(&self, addr: u32) -> Option<&DisplayList> {
+        self.lists.iter().find(|x| x.addr == addr)
+    }
+}
+
+/// The call made by `command`, and whether it ends the list.
+///
+/// `half_1` is the word of the `gsDPHalf1` right before `command`, if any,
+/// which holds the target of a `gsBranchZ`.
+pub(crate) fn call_of(command: &Command, half_1: Option<u32>) -> (Option<(u32, CallKind)>, bool) {
+    match *command {
+        Command::SPDisplayList { dl } => (Some((dl, CallKind::Call)), false),
+        Command::SPBranchList { dl } => (Some((dl, CallKind::Branch)), true),
+        Command::DisplayList { dl, flag } => {
+            if flag == gbi::G_DL_NOPUSH {
+                (Some((dl, CallKind::Branch)), true)
+            } else {
+                (Some((dl, CallKind::Call)), false)
+            }
+        }
+        Command::SPBranchLessZraw { dl, .. } => (Some((dl, CallKind::BranchZ)), false),
+        Command::BranchZ { .. } => (half_1.map(|dl| (dl, CallKind::BranchZ)), false),
+        Command::SPEndDisplayList => (None, true),
+        _ => (None, false),
+    }
+}
+
+/// The address `memory` holds the list at `addr` at, given the segments
+/// set in `resolver`.
+///
+/// Segment 0 is usually set to 0, which turns virtual addresses into
+/// physical ones, so an address is looked up as written when nothing is
+/// found where the segments point.
+fn locate(memory: &Memory<'_>, resolver: &AddressResolver, addr: u32) -> u32 {
+    match resolver.translate(addr) {
+        Ok(translated) if memory.read(translated).is_some() => translated,
+        _ => addr,
+    }
+}
+
+/// Decodes the display list at `root` and every list it calls, directly or
+/// not, using `backend`.
+///
+/// Each list is decoded from its address up to the end of its buffer, and
+/// its macros are cut after the first one that ends it, so decoding does
+/// not need to stop by itself.
+///
+/// `resolver` holds the segments set before `root` runs. Every
+/// `gsSPSegment` of a list is recorded in a copy of it, which applies to the
+/// lists it calls afterwards. A list is decoded once, with the segments set
+/// when it is first called, and is identified by its address with those
+/// segments applied. Only [`AddressResolver::segments`] is used, the buffers
+/// are placed by `memory`.
+pub fn traverse<B: Backend>(
+    memory: &Memory<'_>,
+    root: u32,
+    resolver: &AddressResolver,
+    settings: &DecodeSettings,
+    backend: &mut B,
+) -> Traversal {
+    let mut traversal = Traversal::default();
+    let mut seen = BTreeSet::new();
+    let mut queue = VecDeque::new();
+    let mut missing = Vec::new();
+    let mut callers: BTreeMap<u32, Vec<Call>> = BTreeMap::new();
+
+    let root = locate(memory, resolver, root);
+    seen.insert(root);
+    queue.push_back((root, *resolver));
+
+    while let Some((addr, mut resolver)) = queue.pop_front() {
+        let data = match memory.read(addr) {
+            Some(data) => data,
+            None => {
+                missing.push(addr);
+                continue;
+            }
+        };
+
+        let mut macros = backend.decode(data, settings);
+        let mut half_1 = None;
+        let mut end = macros.len();
+
+        for (i, m) in macros.iter().enumerate() {
+            let command = match Command::from_macro(m) {
+                Some(command) => command,
+                None => continue,
+            };
+            resolver.apply(&command);
+
+            let (target, ends) = call_of(&command, half_1);
+            if let Some((target, kind)) = target {
+                let target = locate(memory, &resolver, target);
+                callers.entry(target).or_default().push(Call {
+                    caller: addr,
+                    addr: addr.wrapping_add(m.offset),
+                    target,
+                    kind,
+                });
+                if seen.insert(target) {
+                    queue.push_back((target, resolver));
+                }
+            }
+            if ends {
+                end = i + 1;
+                break;
+            }
+
+            half_1 = match command {
+                Command::DPHalf1 { wordhi } => Some(wordhi),
+                _ => None,
+            };
+        }
+        macros.truncate(end);
+
+        traversal.lists.push(DisplayList {
+            addr,
+            macros,
+            callers: Vec::new(),
+        });
+    }
+
+    for list in traversal.lists.iter_mut() {
+        list.callers = callers.remove(&list.addr).unwrap_or_default();
+    }
+    for addr in missing {
+        traversal
+            .unresolved
+            .extend(callers.remove(&addr).unwrap_or_default());
+    }
+    traversal
+}
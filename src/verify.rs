@@ -0,0 +1,191 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Byte-exact round-trip verification.
+//!
+//! [`verify`] decodes a buffer, re-encodes every decoded [`Macro`] and
+//! reports each macro whose re-encoding differs from the input data, as
+//! returned by [`gfxd_macro_data`].
+//!
+//! Macros can be re-encoded in two ways, see [`Method`]. Re-assembling the
+//! printed text checks that the output of `libgfxd` rebuilds to the same
+//! bytes, which is what a decompilation needs. Encoding the argument values
+//! directly skips the printing step, which helps telling apart macros that
+//! can not be represented at all from macros that are only printed lossily.
+//!
+//! [`gfxd_macro_data`]: crate::macro_info::gfxd_macro_data
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::assembler::{AsmError, Assembler};
+use crate::command::Command;
+use crate::decode::{self, DecodeSettings, Macro};
+use crate::encode::{self, EncodeError};
+use crate::gfx::{Gfx, GfxView};
+use crate::macro_id::MacroId;
+use crate::settings::Ucode;
+
+/// How a decoded macro is turned back into packets.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub enum Method {
+    /// Re-assemble the text printed by [`gfxd_macro_dflt`], see
+    /// [`assembler`](crate::assembler).
+    ///
+    /// [`gfxd_macro_dflt`]: crate::handlers::gfxd_macro_dflt
+    Text,
+    /// Encode the argument values reported by [`gfxd_arg_value`], see
+    /// [`encode`].
+    ///
+    /// [`gfxd_arg_value`]: crate::macro_info::gfxd_arg_value
+    Args,
+}
+
+/// The reasons a macro could not be re-encoded at all.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReencodeError {
+    /// The text of the macro could not be assembled.
+    Asm(AsmError),
+    /// The arguments of the macro could not be encoded.
+    Encode(EncodeError),
+}
+
+impl fmt::Display for ReencodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReencodeError::Asm(x) => write!(f, "{}", x),
+            ReencodeError::Encode(x) => write!(f, "{}", x),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReencodeError {}
+
+/// A macro that does not rebuild to its input data.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mismatch {
+    /// The index of the macro in the decoded list.
+    pub index: usize,
+    /// The offset of the macro in the input, in bytes.
+    pub offset: u32,
+    /// The id of the macro.
+    pub id: MacroId,
+    /// The macro as printed by `libgfxd`.
+    pub text: String,
+    /// The packets in the input.
+    pub expected: Vec<Gfx>,
+    /// The packets the macro was re-encoded into, or why it could not be.
+    pub actual: Result<Vec<Gfx>, ReencodeError>,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "macro {} at offset 0x{:X} `{}`: expected ",
+            self.index, self.offset, self.text
+        )?;
+        write_packets(f, &self.expected)?;
+        match &self.actual {
+            Ok(actual) => {
+                write!(f, ", got ")?;
+                write_packets(f, actual)
+            }
+            Err(err) => write!(f, ", could not re-encode: {}", err),
+        }
+    }
+}
+
+fn write_packets(f: &mut fmt::Formatter<'_>, packets: &[Gfx]) -> fmt::Result {
+    for (i, gfx) in packets.iter().enumerate() {
+        if i != 0 {
+            write!(f, " ")?;
+        }
+        write!(f, "{{0x{:08X}, 0x{:08X}}}", gfx.w0, gfx.w1)?;
+    }
+    Ok(())
+}
+
+/// The outcome of [`verify`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// The number of macros checked.
+    pub checked: usize,
+    /// Every macro that did not rebuild to its input data, in order.
+    pub mismatches: Vec<Mismatch>,
+    /// The value returned by [`gfxd_execute`].
+    ///
+    /// [`gfxd_execute`]: crate::execution::gfxd_execute
+    pub status: i32,
+}
+
+impl Report {
+    /// Whether every macro rebuilt to its input data.
+    #[must_use]
+    pub fn is_match(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+/// Decodes `input` using `settings` and re-encodes every macro with
+/// `method`.
+#[must_use]
+pub fn verify(input: &[u8], settings: &DecodeSettings, method: Method) -> Report {
+    let decoded = decode::decode(input, settings);
+
+    Report {
+        checked: decoded.macros.len(),
+        mismatches: verify_macros(&decoded.macros, settings, method),
+        status: decoded.status,
+    }
+}
+
+/// Re-encodes every macro of an already decoded list, returning the ones
+/// that do not rebuild to their input data.
+///
+/// `settings` must be the ones `macros` were decoded with.
+#[must_use]
+pub fn verify_macros(macros: &[Macro], settings: &DecodeSettings, method: Method) -> Vec<Mismatch> {
+    macros
+        .iter()
+        .enumerate()
+        .filter_map(|(index, m)| {
+            let expected = GfxView::new(&m.data, settings.endian, settings.wordsize)
+                .iter()
+                .collect::<Vec<_>>();
+            let actual = reencode(m, settings.ucode, method);
+            if actual.as_ref() == Ok(&expected) {
+                return None;
+            }
+
+            Some(Mismatch {
+                index,
+                offset: m.offset,
+                id: m.id,
+                text: m.text.clone(),
+                expected,
+                actual,
+            })
+        })
+        .collect()
+}
+
+/// Turns a single decoded macro back into packets for `ucode`.
+pub fn reencode(m: &Macro, ucode: Ucode, method: Method) -> Result<Vec<Gfx>, ReencodeError> {
+    match method {
+        Method::Text => Assembler::new(ucode)
+            .assemble(&m.text)
+            .map_err(ReencodeError::Asm),
+        Method::Args => Command::from_macro(m)
+            .ok_or(EncodeError::ArgCount {
+                id: m.id,
+                expected: m.id.args().len(),
+                found: m.args.len(),
+            })
+            .and_then(|x| encode::encode_command(ucode, &x))
+            .map(|x| x.to_vec())
+            .map_err(ReencodeError::Encode),
+    }
+}
@@ -0,0 +1,119 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Viewports.
+//!
+//! A `Vp` describes the viewport as a scale and a translation, both in
+//! quarter pixels for the x and y axes. [`Vp`] reads and writes that layout,
+//! with the same endianness and word size rules as [`GfxView`], and
+//! [`Viewport`] is the rectangle, in pixels, it maps the screen to.
+//!
+//! [`GfxView`]: crate::gfx::GfxView
+
+use crate::gfx;
+use crate::settings::Endian;
+
+/// The largest depth value, `G_MAXZ`.
+pub const MAXZ: i16 = 0x03FF;
+
+/// A viewport, as `Vp_t` lays it out.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Vp {
+    /// The scale of the x, y and z axes, and padding.
+    pub vscale: [i16; 4],
+    /// The translation of the x, y and z axes, and padding.
+    pub vtrans: [i16; 4],
+}
+
+/// The rectangle a viewport maps the screen to, in pixels.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Viewport {
+    /// The left edge.
+    pub x: f32,
+    /// The top edge.
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl Vp {
+    /// The size in bytes of a viewport.
+    pub const SIZE: usize = 16;
+
+    /// The viewport that maps the screen to `rect`, with the full depth
+    /// range, like the `Vp` initializers of most games.
+    ///
+    /// The position is truncated to quarter pixels and the size to half
+    /// pixels.
+    #[must_use]
+    pub fn from_viewport(rect: &Viewport) -> Self {
+        let half_w = (rect.width * 2.0) as i16;
+        let half_h = (rect.height * 2.0) as i16;
+        Self {
+            vscale: [half_w, half_h, MAXZ / 2, 0],
+            vtrans: [
+                (rect.x * 4.0) as i16 + half_w,
+                (rect.y * 4.0) as i16 + half_h,
+                MAXZ / 2,
+                0,
+            ],
+        }
+    }
+
+    /// The rectangle this viewport maps the screen to.
+    ///
+    /// A negative scale, which flips the axis, gives a negative size.
+    #[must_use]
+    pub fn viewport(&self) -> Viewport {
+        let [sx, sy, _, _] = self.vscale;
+        let [tx, ty, _, _] = self.vtrans;
+        Viewport {
+            x: f32::from(tx - sx) / 4.0,
+            y: f32::from(ty - sy) / 4.0,
+            width: f32::from(sx) / 2.0,
+            height: f32::from(sy) / 2.0,
+        }
+    }
+
+    /// The depth range of this viewport, as its lowest and highest values.
+    #[must_use]
+    pub fn depth_range(&self) -> (i16, i16) {
+        (
+            self.vtrans[2].wrapping_sub(self.vscale[2]),
+            self.vtrans[2].wrapping_add(self.vscale[2]),
+        )
+    }
+
+    /// Converts the viewport to bytes, laid out as described by `endian`
+    /// and `wordsize`.
+    #[must_use]
+    pub fn to_bytes(&self, endian: Endian, wordsize: u32) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        for (i, x) in self.vscale.iter().chain(self.vtrans.iter()).enumerate() {
+            bytes[i * 2..i * 2 + 2].copy_from_slice(&x.to_be_bytes());
+        }
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        bytes
+    }
+
+    /// Reads a viewport stored with the given `endian` and `wordsize`, the
+    /// inverse of [`Vp::to_bytes`].
+    #[must_use]
+    pub fn from_bytes(mut bytes: [u8; Self::SIZE], endian: Endian, wordsize: u32) -> Self {
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        let half = |i: usize| i16::from_be_bytes([bytes[i * 2], bytes[i * 2 + 1]]);
+        Self {
+            vscale: [half(0), half(1), half(2), half(3)],
+            vtrans: [half(4), half(5), half(6), half(7)],
+        }
+    }
+
+    /// Reads the viewport at the start of `bytes`, stored with the given
+    /// `endian` and `wordsize`.
+    ///
+    /// Returns [`None`] if `bytes` is shorter than [`Vp::SIZE`].
+    #[must_use]
+    pub fn read(bytes: &[u8], endian: Endian, wordsize: u32) -> Option<Self> {
+        gfx::read_record(bytes, endian, wordsize, Self::from_bytes)
+    }
+}
@@ -0,0 +1,231 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+//! Vertex buffers.
+//!
+//! A [`Vtx`] is a single 16 byte vertex record, read with the same
+//! endianness and word size rules as [`GfxView`]. The last four bytes of a
+//! vertex are either a color, as in `Vtx_t`, or a normal and an alpha, as in
+//! `Vtx_tn`, depending on whether lighting is enabled when it is drawn, so
+//! both views are provided.
+//!
+//! With the `alloc` feature, `VtxRanges` collects the vertex arrays loaded
+//! by `gsSPVertex` while decoding, merging the loads that overlap, so each
+//! array can be extracted once.
+//!
+//! [`GfxView`]: crate::gfx::GfxView
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::arg_type::ArgType;
+#[cfg(feature = "alloc")]
+use crate::decode::Macro;
+use crate::gfx;
+use crate::settings::Endian;
+
+/// A single vertex, as `Vtx_t` and `Vtx_tn` lay it out.
+#[derive(Debug, Default, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct Vtx {
+    /// The position.
+    pub ob: [i16; 3],
+    pub flag: u16,
+    /// The texture coordinates, in S10.5 format.
+    pub tc: [i16; 2],
+    /// The color, or the normal and alpha.
+    pub cn: [u8; 4],
+}
+
+impl Vtx {
+    /// The size in bytes of a single vertex.
+    pub const SIZE: usize = 16;
+
+    /// The color of a `Vtx_t`, as red, green, blue and alpha.
+    #[must_use]
+    pub fn color(&self) -> [u8; 4] {
+        self.cn
+    }
+
+    /// The normal of a `Vtx_tn`.
+    #[must_use]
+    pub fn normal(&self) -> [i8; 3] {
+        [self.cn[0] as i8, self.cn[1] as i8, self.cn[2] as i8]
+    }
+
+    /// The alpha of a `Vtx_tn`.
+    #[must_use]
+    pub fn alpha(&self) -> u8 {
+        self.cn[3]
+    }
+
+    /// Converts the vertex to bytes, laid out as described by `endian` and
+    /// `wordsize`.
+    #[must_use]
+    pub fn to_bytes(&self, endian: Endian, wordsize: u32) -> [u8; Self::SIZE] {
+        let mut bytes = [0; Self::SIZE];
+        for (i, x) in self.ob.iter().enumerate() {
+            bytes[i * 2..i * 2 + 2].copy_from_slice(&x.to_be_bytes());
+        }
+        bytes[6..8].copy_from_slice(&self.flag.to_be_bytes());
+        bytes[8..10].copy_from_slice(&self.tc[0].to_be_bytes());
+        bytes[10..12].copy_from_slice(&self.tc[1].to_be_bytes());
+        bytes[12..].copy_from_slice(&self.cn);
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        bytes
+    }
+
+    /// Reads a vertex stored with the given `endian` and `wordsize`, the
+    /// inverse of [`Vtx::to_bytes`].
+    #[must_use]
+    pub fn from_bytes(mut bytes: [u8; Self::SIZE], endian: Endian, wordsize: u32) -> Self {
+        gfx::swap_words(&mut bytes, endian, wordsize);
+        let half = |i: usize| [bytes[i], bytes[i + 1]];
+        let mut cn = [0; 4];
+        cn.copy_from_slice(&bytes[12..]);
+        Self {
+            ob: [
+                i16::from_be_bytes(half(0)),
+                i16::from_be_bytes(half(2)),
+                i16::from_be_bytes(half(4)),
+            ],
+            flag: u16::from_be_bytes(half(6)),
+            tc: [i16::from_be_bytes(half(8)), i16::from_be_bytes(half(10))],
+            cn,
+        }
+    }
+
+    /// Reads the vertex at the start of `bytes`, stored with the given
+    /// `endian` and `wordsize`.
+    ///
+    /// Returns [`None`] if `bytes` is shorter than [`Vtx::SIZE`].
+    #[must_use]
+    pub fn read(bytes: &[u8], endian: Endian, wordsize: u32) -> Option<Self> {
+        gfx::read_record(bytes, endian, wordsize, Self::from_bytes)
+    }
+}
+
+/// Reads every whole vertex of `bytes`, stored with the given `endian` and
+/// `wordsize`. Trailing bytes that do not make a whole vertex are ignored.
+pub fn read_vertices(
+    bytes: &[u8],
+    endian: Endian,
+    wordsize: u32,
+) -> impl Iterator<Item = Vtx> + '_ {
+    bytes
+        .chunks_exact(Vtx::SIZE)
+        .filter_map(move |x| Vtx::read(x, endian, wordsize))
+}
+
+/// A run of consecutive vertices.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+pub struct VtxRange {
+    /// The address of the first vertex.
+    pub addr: u32,
+    /// The number of vertices.
+    pub count: u32,
+}
+
+impl VtxRange {
+    /// The address right after the last vertex, saturated at the end of the
+    /// address space.
+    #[must_use]
+    pub fn end(&self) -> u32 {
+        self.addr
+            .saturating_add(self.count.saturating_mul(Vtx::SIZE as u32))
+    }
+}
+
+/// The vertex arrays referenced by a display list, sorted by address.
+///
+/// Ranges that overlap are merged into one, while ranges that merely touch
+/// are kept apart, since they are usually different arrays placed one after
+/// the other.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VtxRanges {
+    ranges: Vec<VtxRange>,
+}
+
+#[cfg(feature = "alloc")]
+impl VtxRanges {
+    /// An empty set of ranges, to be filled with [`VtxRanges::add`].
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collects the vertex arrays referenced by `macros`.
+    #[must_use]
+    pub fn from_macros(macros: &[Macro]) -> Self {
+        let mut ranges = Self::new();
+        for m in macros.iter() {
+            ranges.add_macro(m);
+        }
+        ranges
+    }
+
+    /// Adds `count` vertices starting at `addr`. Vertices that would not
+    /// fit before the end of the address space are left out.
+    ///
+    /// This can be called directly from a [`gfxd_vtx_callback`].
+    ///
+    /// [`gfxd_vtx_callback`]: crate::argument_callbacks::gfxd_vtx_callback
+    pub fn add(&mut self, addr: u32, count: u32) {
+        let count = count.min((core::u32::MAX - addr) / Vtx::SIZE as u32);
+        if count == 0 {
+            return;
+        }
+        let mut new = VtxRange { addr, count };
+
+        // Every range that overlaps the new one is absorbed into it.
+        let mut i = 0;
+        while i < self.ranges.len() {
+            let x = self.ranges[i];
+            if x.addr < new.end() && new.addr < x.end() {
+                let start = x.addr.min(new.addr);
+                let end = x.end().max(new.end());
+                new = VtxRange {
+                    addr: start,
+                    count: (end - start + Vtx::SIZE as u32 - 1) / Vtx::SIZE as u32,
+                };
+                self.ranges.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+
+        let len = self.ranges.len();
+        let at = self
+            .ranges
+            .iter()
+            .position(|x| x.addr > new.addr)
+            .unwrap_or(len);
+        self.ranges.insert(at, new);
+    }
+
+    /// Adds the vertex arrays referenced by the `gfxd_Vtxptr` arguments of
+    /// `m`, each with as many vertices as the `gfxd_Num` argument of the same
+    /// order.
+    pub fn add_macro(&mut self, m: &Macro) {
+        let mut idx = 0;
+        while let Some(vtx) = m.arg_by_type(ArgType::gfxd_Vtxptr, idx) {
+            if let Some(num) = m.arg_by_type(ArgType::gfxd_Num, idx) {
+                self.add(vtx.value.as_u32(), num.value.as_u32());
+            }
+            idx += 1;
+        }
+    }
+
+    /// The collected ranges, sorted by address.
+    #[must_use]
+    pub fn ranges(&self) -> &[VtxRange] {
+        &self.ranges
+    }
+
+    /// Whether `addr` is in one of the collected ranges.
+    #[must_use]
+    pub fn contains(&self, addr: u32) -> bool {
+        self.ranges.iter().any(|x| x.addr <= addr && addr < x.end())
+    }
+}
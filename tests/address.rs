@@ -0,0 +1,65 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::address::{segmented, AddressResolver, ResolveError};
+use gfxd_sys::command::Command;
+
+#[test]
+fn test_address_resolve_map() {
+    let resolver = AddressResolver::new()
+        .with_segment(6, 0x1000)
+        .with_segment(4, 0x8_0000);
+
+    assert_eq!(Ok(0x1234), resolver.resolve(0x0600_0234));
+    assert_eq!(Ok(0x8_0010), resolver.resolve(segmented(4, 0x10)));
+    assert_eq!(
+        Err(ResolveError::Unmapped {
+            addr: 0x0800_0000,
+            segment: 8
+        }),
+        resolver.resolve(0x0800_0000)
+    );
+    // Only bits 24 to 27 select the segment, like on the RSP.
+    assert_eq!(Ok(0x1010), resolver.resolve(0xF600_0010));
+    assert!(!resolver.is_resolvable(0x8012_3456));
+}
+
+#[test]
+fn test_address_resolve_segment_updates() {
+    let mut resolver = AddressResolver::new()
+        .with_segment(0, 0)
+        .with_segment(6, 0x1000);
+
+    assert!(resolver.apply(&Command::SPSegment {
+        seg: 8,
+        base: 0x0600_0800,
+    }));
+    assert!(resolver.apply(&Command::SPSegment { seg: 0, base: 0 }));
+    assert!(!resolver.apply(&Command::DPPipeSync));
+
+    // Segment 8 points into segment 6, which is mapped.
+    assert_eq!(Ok(0x1810), resolver.resolve(0x0800_0010));
+    // Segment 0 points to itself, so physical addresses use its mapping.
+    assert_eq!(Ok(0x12_3456), resolver.resolve(0x8012_3456));
+
+    // A segment set by the display list overrides the caller's mapping.
+    resolver.set_segment(6, 0x0400_0000);
+    assert_eq!(
+        Err(ResolveError::Unmapped {
+            addr: 0x0600_0000,
+            segment: 4
+        }),
+        resolver.resolve(0x0600_0000)
+    );
+
+    resolver.set_segment(4, 0x0600_0000);
+    assert_eq!(
+        Err(ResolveError::TooDeep { addr: 0x0600_0000 }),
+        resolver.resolve(0x0600_0000)
+    );
+
+    resolver.reset();
+    assert_eq!(Ok(0x1000), resolver.resolve(0x0600_0000));
+}
@@ -0,0 +1,135 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::assembler::{self, AsmErrorKind, Assembler};
+use gfxd_sys::encode::EncodeError;
+use gfxd_sys::gfx::Gfx;
+use gfxd_sys::macro_id::MacroId;
+use gfxd_sys::settings::{Endian, Ucode};
+
+#[test]
+fn test_assemble_f3dex2() {
+    static TEXT: &str = "\
+{
+    gsSPVertex(0x42042069, 3, 0),
+    gsSP1Triangle(0, 1, 2, 0),
+    gsSPSetLights1(*(Lightsn *)0x09000000),
+    gsSPEndDisplayList(),
+}
+";
+    static EXPECTED: [u8; 0x30] = [
+        0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69, //
+        0x05, 0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x00, //
+        0xDB, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x18, //
+        0xDC, 0x08, 0x06, 0x0A, 0x09, 0x00, 0x00, 0x08, //
+        0xDC, 0x08, 0x09, 0x0A, 0x09, 0x00, 0x00, 0x00, //
+        0xDF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    ];
+
+    let bytes = Assembler::new(Ucode::gfxd_f3dex2)
+        .assemble_bytes(TEXT, Endian::gfxd_endian_big, 4)
+        .unwrap();
+
+    assert_eq!(&EXPECTED[..], &bytes[..]);
+}
+
+#[test]
+fn test_assemble_f3d() {
+    static TEXT: &str = "\
+gSPVertex(glistp++, 0x04000000, 16, 0);
+gSPNumLights(glistp++, NUMLIGHTS_1);
+gSP1Triangle(glistp++, 0, 1, 2, 0);
+gDPSetCycleType(glistp++, G_CYC_1CYCLE);
+";
+
+    let packets = assembler::assemble(TEXT, Ucode::gfxd_f3d).unwrap();
+
+    assert_eq!(
+        vec![
+            Gfx::new(0x04F0_0100, 0x0400_0000),
+            Gfx::new(0xBC00_0002, 0x8000_0040),
+            Gfx::new(0xBF00_0000, 0x0000_0A14),
+            Gfx::new(0xBA00_1402, 0x0000_0000),
+        ],
+        packets
+    );
+}
+
+#[test]
+fn test_assemble_rdp() {
+    static TEXT: &str = "\
+gsDPLoadTextureBlock(0x08000000, G_IM_FMT_RGBA, G_IM_SIZ_16b, 32, 32, 0,
+                     G_TX_NOMIRROR | G_TX_WRAP, G_TX_NOMIRROR | G_TX_WRAP, 5, 5,
+                     G_TX_NOLOD, G_TX_NOLOD),
+gsDPSetTileSize(G_TX_RENDERTILE, 0, 0, qu102(31), qu102(31)),
+gsDPSetCombineMode(G_CC_SHADE, G_CC_SHADE),
+gsDPSetCombineLERP(0, 0, 0, SHADE, 0, 0, 0, SHADE, 0, 0, 0, SHADE, 0, 0, 0, SHADE),
+gsDPSetRenderMode(G_RM_AA_ZB_OPA_SURF, G_RM_AA_ZB_OPA_SURF2),
+{0xE7000000, 0x00000000},
+";
+
+    let packets = assembler::assemble(TEXT, Ucode::gfxd_f3dex2).unwrap();
+
+    assert_eq!(
+        vec![
+            Gfx::new(0xFD10_0000, 0x0800_0000),
+            Gfx::new(0xF510_0000, 0x0701_4050),
+            Gfx::new(0xE600_0000, 0x0000_0000),
+            Gfx::new(0xF300_0000, 0x073F_F100),
+            Gfx::new(0xE700_0000, 0x0000_0000),
+            Gfx::new(0xF510_1000, 0x0001_4050),
+            Gfx::new(0xF200_0000, 0x0007_C07C),
+            Gfx::new(0xF200_0000, 0x0007_C07C),
+            Gfx::new(0xFCFF_FFFF, 0xFFFE_793C),
+            Gfx::new(0xFCFF_FFFF, 0xFFFE_793C),
+            Gfx::new(0xE200_001C, 0x0055_2078),
+            Gfx::new(0xE700_0000, 0x0000_0000),
+        ],
+        packets
+    );
+}
+
+#[test]
+fn test_assemble_errors() {
+    let err = assembler::assemble("gsSPFoo()", Ucode::gfxd_f3dex2).unwrap_err();
+    assert_eq!(AsmErrorKind::UnknownMacro("gsSPFoo".into()), err.kind);
+
+    let err = assembler::assemble(
+        "gsSPEndDisplayList(),\ngsSPDisplayList(my_dl)",
+        Ucode::gfxd_f3dex2,
+    )
+    .unwrap_err();
+    assert_eq!(AsmErrorKind::UnknownSymbol("my_dl".into()), err.kind);
+    assert_eq!((2, 17), (err.line, err.column));
+
+    let err = assembler::assemble("gSPEndDisplayList(glistp])", Ucode::gfxd_f3dex2).unwrap_err();
+    assert_eq!(AsmErrorKind::Expected("`,` or `)`"), err.kind);
+    assert_eq!((1, 25), (err.line, err.column));
+
+    let err =
+        assembler::assemble("gsSP2Triangles(0, 1, 2, 0, 2, 3, 0, 0)", Ucode::gfxd_f3d).unwrap_err();
+    assert_eq!(
+        AsmErrorKind::Encode(EncodeError::Unsupported {
+            id: MacroId::gfxd_SP2Triangles,
+            ucode: Ucode::gfxd_f3d,
+        }),
+        err.kind
+    );
+
+    let resolver = |name: &str| {
+        if name == "my_dl" {
+            Some(0x0600_1230)
+        } else {
+            None
+        }
+    };
+    let packets = Assembler::new(Ucode::gfxd_f3dex2)
+        .symbols(&resolver)
+        .assemble("gsSPDisplayList(my_dl)")
+        .unwrap();
+    assert_eq!(vec![Gfx::new(0xDE00_0000, 0x0600_1230)], packets);
+}
@@ -0,0 +1,112 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::c_data;
+use gfxd_sys::light::{Ambient, Light, Lights};
+use gfxd_sys::mtx::Mtx;
+use gfxd_sys::vp::Vp;
+use gfxd_sys::vtx::Vtx;
+
+#[test]
+fn c_data_vtx() {
+    let vertices = [
+        Vtx {
+            ob: [-10, 20, 256],
+            flag: 0,
+            tc: [1024, -1024],
+            cn: [0xFF, 0x00, 0x80, 0xFF],
+        },
+        Vtx {
+            ob: [1, 2, 3],
+            flag: 1,
+            tc: [0, 0],
+            cn: [0x00, 0x7F, 0x81, 0x40],
+        },
+    ];
+
+    assert_eq!(
+        c_data::vtx("sVtx", &vertices, false),
+        "Vtx sVtx[] = {\n\
+         \x20   VTX(-10, 20, 256, 1024, -1024, 0xFF, 0x00, 0x80, 0xFF),\n\
+         \x20   { { { 1, 2, 3 }, 1, { 0, 0 }, { 0x00, 0x7F, 0x81, 0x40 } } },\n\
+         };\n"
+    );
+    assert_eq!(
+        c_data::vtx("sVtx", &vertices[1..], true),
+        "Vtx sVtx[] = {\n\
+         \x20   { { { 1, 2, 3 }, 1, { 0, 0 }, { 0, 127, -127, 0x40 } } },\n\
+         };\n"
+    );
+}
+
+#[test]
+fn c_data_lights() {
+    let mut lights = Lights {
+        ambient: Ambient::new([0x20, 0x20, 0x20]),
+        lights: vec![Light {
+            col: [0xFF; 3],
+            colc: [0xFF; 3],
+            dir: [0x49, 0x49, 0x49],
+        }],
+    };
+
+    assert_eq!(
+        c_data::lights("sLights", &lights),
+        "Lights1 sLights = gdSPDefLights1(0x20, 0x20, 0x20, 0xFF, 0xFF, 0xFF, 73, 73, 73);\n"
+    );
+
+    lights.lights[0].colc = [0; 3];
+    assert_eq!(
+        c_data::lights("sLights", &lights),
+        "Lights1 sLights = {\n\
+         \x20   { { { 0x20, 0x20, 0x20 }, 0, { 0x20, 0x20, 0x20 }, 0 } },\n\
+         \x20   {\n\
+         \x20       { { { 0xFF, 0xFF, 0xFF }, 0, { 0x00, 0x00, 0x00 }, 0, { 73, 73, 73 }, 0 } },\n\
+         \x20   },\n\
+         };\n"
+    );
+
+    lights.lights.clear();
+    assert_eq!(
+        c_data::lights("sLights", &lights),
+        "Lights0 sLights = gdSPDefLights0(0x20, 0x20, 0x20);\n"
+    );
+}
+
+#[test]
+fn c_data_mtx_vp() {
+    let mut m = Mtx::identity();
+    m.m[3][0] = 0x0001_8000;
+
+    assert_eq!(
+        c_data::mtx("sMtx", &m),
+        "/*\n\
+         \x20* { 1, 0, 0, 0 }\n\
+         \x20* { 0, 1, 0, 0 }\n\
+         \x20* { 0, 0, 1, 0 }\n\
+         \x20* { 1.5, 0, 0, 1 }\n\
+         \x20*/\n\
+         Mtx sMtx = { {\n\
+         \x20   { 0x00010000, 0x00000000, 0x00000001, 0x00000000 },\n\
+         \x20   { 0x00000000, 0x00010000, 0x00010000, 0x00000001 },\n\
+         \x20   { 0x00000000, 0x00000000, 0x00000000, 0x00000000 },\n\
+         \x20   { 0x00000000, 0x00000000, 0x80000000, 0x00000000 },\n\
+         } };\n"
+    );
+
+    let vp = Vp {
+        vscale: [640, 480, 511, 0],
+        vtrans: [640, 480, 511, 0],
+    };
+    assert_eq!(
+        c_data::vp("sViewport", &vp),
+        "Vp sViewport = { {\n\
+         \x20   { 640, 480, 511, 0 },\n\
+         \x20   { 640, 480, 511, 0 },\n\
+         } };\n"
+    );
+}
@@ -0,0 +1,186 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::arg_type::ArgType;
+use gfxd_sys::c_file::CFile;
+use gfxd_sys::command::Command;
+use gfxd_sys::decode::{Arg, Macro, Value};
+use gfxd_sys::light::{Ambient, Light, Lights};
+use gfxd_sys::vp::Vp;
+use gfxd_sys::vtx::Vtx;
+
+/// Builds a macro the way `libgfxd` prints it, with pointers in hex.
+fn to_macro(command: Command) -> Macro {
+    let id = command.id();
+    let name = id.static_name().unwrap();
+    let args = id
+        .args()
+        .iter()
+        .zip(command.args().iter())
+        .map(|(info, &value)| {
+            let text = match info.type_ {
+                ArgType::gfxd_Dl | ArgType::gfxd_Vtxptr | ArgType::gfxd_Vpptr => {
+                    format!("0x{:08X}", value)
+                }
+                ArgType::gfxd_Lightsn => format!("*(Lightsn *)0x{:08X}", value),
+                _ => value.to_string(),
+            };
+            Arg {
+                name: info.name.into(),
+                type_: info.type_,
+                value: Value::U(value),
+                valid: true,
+                text,
+            }
+        })
+        .collect::<Vec<_>>();
+    let text = format!(
+        "{}({})",
+        name,
+        args.iter()
+            .map(|x| x.text.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    Macro {
+        offset: 0,
+        packets: 1,
+        data: vec![0; 8],
+        id,
+        name: Some(name.into()),
+        args,
+        text,
+    }
+}
+
+#[test]
+fn c_file_references() {
+    let mut file = CFile::new();
+    file.add_header("ultra64.h");
+    file.add_extern(0x0400_0000, "gSharedDL");
+
+    file.add_list(
+        "sDL",
+        0x0600_0100,
+        &[
+            to_macro(Command::SPVertex {
+                v: 0x0600_0010,
+                n: 2,
+                v0: 0,
+            }),
+            to_macro(Command::SPSetLights1 {
+                lights: 0x0600_0040,
+            }),
+            to_macro(Command::SPViewport { v: 0x0600_0080 }),
+            to_macro(Command::SPDisplayList { dl: 0x0400_0000 }),
+            to_macro(Command::SPDisplayList { dl: 0x0500_0000 }),
+            to_macro(Command::SPEndDisplayList),
+        ],
+    );
+    file.add_vtx("sVtx", 0x0600_0000, &[Vtx::default(); 3], false);
+    file.add_lights(
+        "sLights",
+        0x0600_0040,
+        &Lights {
+            ambient: Ambient::new([0x20; 3]),
+            lights: vec![Light::new([0xFF; 3], [0.0, 1.0, 0.0])],
+        },
+    );
+    file.add_vp(
+        "sViewport",
+        0x0600_0080,
+        &Vp {
+            vscale: [640, 480, 511, 0],
+            vtrans: [640, 480, 511, 0],
+        },
+    );
+
+    assert_eq!(
+        file.to_source(),
+        "\
+#include \"ultra64.h\"
+
+extern Gfx gSharedDL[];
+
+Vtx sVtx[] = {
+    VTX(0, 0, 0, 0, 0, 0x00, 0x00, 0x00, 0x00),
+    VTX(0, 0, 0, 0, 0, 0x00, 0x00, 0x00, 0x00),
+    VTX(0, 0, 0, 0, 0, 0x00, 0x00, 0x00, 0x00),
+};
+
+Lights1 sLights = gdSPDefLights1(0x20, 0x20, 0x20, 0xFF, 0xFF, 0xFF, 0, 127, 0);
+
+Vp sViewport = { {
+    { 640, 480, 511, 0 },
+    { 640, 480, 511, 0 },
+} };
+
+Gfx sDL[] = {
+    gsSPVertex(&sVtx[1], 2, 0),
+    gsSPSetLights1(sLights),
+    gsSPViewport(&sViewport),
+    gsSPDisplayList(gSharedDL),
+    gsSPDisplayList(0x05000000),
+    gsSPEndDisplayList(),
+};
+"
+    );
+}
+
+#[test]
+fn c_file_order() {
+    let mut file = CFile::new();
+    file.add_list(
+        "sA",
+        0x0600_0000,
+        &[
+            to_macro(Command::SPDisplayList { dl: 0x0600_0100 }),
+            to_macro(Command::SPEndDisplayList),
+        ],
+    );
+    file.add_list(
+        "sB",
+        0x0600_0100,
+        &[
+            to_macro(Command::SPDisplayList { dl: 0x0600_0200 }),
+            to_macro(Command::SPBranchList { dl: 0x0600_0000 }),
+        ],
+    );
+    file.add_list(
+        "sC",
+        0x0600_0200,
+        &[to_macro(Command::SPBranchList { dl: 0x0600_0200 })],
+    );
+    file.add_include("sTex", 0x0600_0300, 0x40, "tex.rgba16.inc.c");
+
+    // sC comes first as sB needs it, and sA is declared since sB needs it
+    // too while needing sB itself.
+    assert_eq!(
+        file.to_source(),
+        "\
+extern Gfx sA[];
+
+Gfx sC[] = {
+    gsSPBranchList(sC),
+};
+
+Gfx sB[] = {
+    gsSPDisplayList(sC),
+    gsSPBranchList(sA),
+};
+
+Gfx sA[] = {
+    gsSPDisplayList(sB),
+    gsSPEndDisplayList(),
+};
+
+u64 sTex[] = {
+#include \"tex.rgba16.inc.c\"
+};
+"
+    );
+}
@@ -0,0 +1,134 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::call_graph::{CallGraph, Edge};
+use gfxd_sys::command::Command;
+use gfxd_sys::decode::{Arg, Macro, Value};
+use gfxd_sys::traverse::CallKind;
+
+fn to_macros(commands: &[Command]) -> Vec<Macro> {
+    commands
+        .iter()
+        .enumerate()
+        .map(|(i, command)| {
+            let id = command.id();
+            Macro {
+                offset: i as u32 * 8,
+                packets: 1,
+                data: vec![0; 8],
+                id,
+                name: None,
+                args: id
+                    .args()
+                    .iter()
+                    .zip(command.args().iter())
+                    .map(|(info, &value)| Arg {
+                        name: info.name.into(),
+                        type_: info.type_,
+                        value: Value::U(value),
+                        valid: true,
+                        text: value.to_string(),
+                    })
+                    .collect(),
+                text: String::new(),
+            }
+        })
+        .collect()
+}
+
+fn graph() -> CallGraph {
+    let mut graph = CallGraph::new();
+    graph.record(
+        0x0600_0000,
+        &to_macros(&[
+            Command::SPDisplayList { dl: 0x0600_0100 },
+            Command::SPDisplayList { dl: 0x0600_0100 },
+            Command::DPHalf1 {
+                wordhi: 0x0600_0200,
+            },
+            Command::BranchZ { vtx: 1, zval: 2 },
+            Command::SPBranchList { dl: 0x0600_0100 },
+            Command::SPDisplayList { dl: 0x0600_0300 },
+        ]),
+    );
+    graph.record(
+        0x0600_0100,
+        &to_macros(&[
+            Command::SPCullDisplayList { vstart: 0, vend: 7 },
+            Command::SPEndDisplayList,
+        ]),
+    );
+    graph.set_name(0x0600_0100, "gMesh\"DL\"");
+    graph
+}
+
+#[test]
+fn test_call_graph_record() {
+    let graph = graph();
+
+    let addrs: Vec<u32> = graph.nodes.iter().map(|x| x.addr).collect();
+    assert_eq!(vec![0x0600_0000, 0x0600_0100, 0x0600_0200], addrs);
+    assert!(graph.node(0x0600_0100).unwrap().culls);
+    assert!(!graph.node(0x0600_0200).unwrap().decoded);
+    assert_eq!(None, graph.node(0x0600_0300));
+
+    assert_eq!(
+        vec![
+            Edge {
+                from: 0x0600_0000,
+                to: 0x0600_0100,
+                kind: CallKind::Call,
+                count: 2,
+            },
+            Edge {
+                from: 0x0600_0000,
+                to: 0x0600_0200,
+                kind: CallKind::BranchZ,
+                count: 1,
+            },
+            Edge {
+                from: 0x0600_0000,
+                to: 0x0600_0100,
+                kind: CallKind::Branch,
+                count: 1,
+            },
+        ],
+        graph.edges
+    );
+    assert_eq!(2, graph.callers(0x0600_0100).count());
+    assert_eq!(0, graph.callees(0x0600_0100).count());
+}
+
+#[test]
+fn test_call_graph_dot() {
+    assert_eq!(
+        "digraph {\n\
+         \x20   dl_06000000 [label=\"0x06000000\"];\n\
+         \x20   dl_06000100 [label=\"gMesh\\\"DL\\\"\", shape=box];\n\
+         \x20   dl_06000200 [label=\"0x06000200\", style=dashed];\n\
+         \x20   dl_06000000 -> dl_06000100 [label=\"call x2\"];\n\
+         \x20   dl_06000000 -> dl_06000200 [label=\"branch_z\"];\n\
+         \x20   dl_06000000 -> dl_06000100 [label=\"branch\"];\n\
+         }\n",
+        graph().to_dot()
+    );
+}
+
+#[test]
+fn test_call_graph_json() {
+    assert_eq!(
+        "{\"nodes\":[\
+         {\"addr\":100663296,\"name\":null,\"decoded\":true,\"culls\":false},\
+         {\"addr\":100663552,\"name\":\"gMesh\\\"DL\\\"\",\"decoded\":true,\"culls\":true},\
+         {\"addr\":100663808,\"name\":null,\"decoded\":false,\"culls\":false}],\
+         \"edges\":[\
+         {\"from\":100663296,\"to\":100663552,\"kind\":\"call\",\"count\":2},\
+         {\"from\":100663296,\"to\":100663808,\"kind\":\"branch_z\",\"count\":1},\
+         {\"from\":100663296,\"to\":100663552,\"kind\":\"branch\",\"count\":1}]}",
+        graph().to_json()
+    );
+}
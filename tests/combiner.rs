@@ -0,0 +1,83 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::combiner::Combiner;
+use gfxd_sys::command::Command;
+use gfxd_sys::gbi;
+use gfxd_sys::gfx::Gfx;
+use gfxd_sys::macro_id::MacroId;
+
+#[test]
+fn test_combiner_from_gfx() {
+    let gfx = Gfx::new(0xFCFF_FFFF, 0xFFFE_793C);
+    let combiner = Combiner::from_gfx(gfx).unwrap();
+
+    assert_eq!(
+        Combiner::from_presets(&gbi::G_CC_SHADE, &gbi::G_CC_SHADE),
+        combiner
+    );
+    assert_eq!(
+        Some(("G_CC_SHADE", "G_CC_SHADE")),
+        combiner.presets().map(|(x, y)| (x.name, y.name))
+    );
+    assert_eq!(gfx, combiner.to_gfx());
+    assert_eq!(None, Combiner::from_gfx(Gfx::new(0xE700_0000, 0)));
+}
+
+#[test]
+fn test_combiner_equations() {
+    let combiner = Combiner::from_presets(&gbi::G_CC_MODULATEI, &gbi::G_CC_PASS2);
+
+    assert_eq!(
+        "(TEXEL0 - 0) * SHADE + 0",
+        combiner.cycles[0].color.to_string()
+    );
+    assert_eq!("(0 - 0) * 0 + SHADE", combiner.cycles[0].alpha.to_string());
+    assert_eq!(
+        "cycle 1: color = (TEXEL0 - 0) * SHADE + 0, alpha = (0 - 0) * 0 + SHADE; \
+         cycle 2: color = (0 - 0) * 0 + COMBINED, alpha = (0 - 0) * 0 + COMBINED",
+        combiner.to_string()
+    );
+    assert_eq!(
+        Some(("G_CC_MODULATEI", "G_CC_PASS2")),
+        combiner.presets().map(|(x, y)| (x.name, y.name))
+    );
+    assert_eq!(Some(combiner), Combiner::from_gfx(combiner.to_gfx()));
+
+    // Inputs the hardware reads as zero but that have no name.
+    let mut lerp = combiner.to_lerp();
+    lerp[1] = 9;
+    let combiner = Combiner::from_lerp(lerp);
+    assert_eq!(
+        "(TEXEL0 - 9) * SHADE + 0",
+        combiner.cycles[0].color.to_string()
+    );
+    assert_eq!(None, combiner.presets());
+}
+
+#[test]
+fn test_combiner_from_command() {
+    let index = |name: &str| gbi::CC_PRESETS.iter().position(|x| x.name == name).unwrap() as u32;
+    let command = Command::DPSetCombineMode {
+        mode1: index("G_CC_MODULATEIDECALA"),
+        mode2: index("G_CC_PASS2"),
+    };
+    let combiner = Combiner::from_command(&command).unwrap();
+    assert_eq!(
+        Some(("G_CC_MODULATEIDECALA", "G_CC_PASS2")),
+        combiner.presets().map(|(x, y)| (x.name, y.name))
+    );
+
+    let lerp = combiner.to_lerp();
+    let command = Command::from_args(MacroId::gfxd_DPSetCombineLERP, &lerp).unwrap();
+    assert_eq!(Some(combiner), Combiner::from_command(&command));
+
+    let command = Command::DPSetCombineMode {
+        mode1: 10_000,
+        mode2: 0,
+    };
+    assert_eq!(None, Combiner::from_command(&command));
+    assert_eq!(None, Combiner::from_command(&Command::DPPipeSync));
+}
@@ -0,0 +1,138 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::command::{Command, MAX_ARGS};
+use gfxd_sys::macro_id::MacroId;
+
+#[test]
+fn test_command_round_trip() {
+    for raw in 0..=MacroId::MAX {
+        let id = MacroId::from_raw(raw).unwrap();
+        let len = if id == MacroId::gfxd_Invalid {
+            2
+        } else {
+            id.args().len()
+        };
+        assert!(len <= MAX_ARGS);
+
+        let values = (0..len as u32).map(|x| x * 3 + 1).collect::<Vec<_>>();
+        let command = Command::from_args(id, &values).unwrap();
+
+        assert_eq!(id, command.id());
+        assert_eq!(&values[..], &command.args()[..]);
+        assert_eq!(None, Command::from_args(id, &[0; MAX_ARGS + 1]));
+    }
+}
+
+#[test]
+fn test_command_fields() {
+    let command = Command::from_args(MacroId::gfxd_SPVertex, &[0x0600_0000, 3, 0]).unwrap();
+    assert_eq!(
+        Command::SPVertex {
+            v: 0x0600_0000,
+            n: 3,
+            v0: 0,
+        },
+        command
+    );
+
+    let command = Command::from_args(
+        MacroId::gfxd_DPSetTile,
+        &[0, 2, 8, 0, 7, 0, 0, 5, 0, 0, 5, 0],
+    )
+    .unwrap();
+    match command {
+        Command::DPSetTile {
+            siz,
+            line,
+            tile,
+            maskt,
+            masks,
+            ..
+        } => assert_eq!((2, 8, 7, 5, 5), (siz, line, tile, maskt, masks)),
+        x => panic!("unexpected command {:?}", x),
+    }
+
+    let raw = [
+        0x40,
+        0x40,
+        0x80,
+        0x80,
+        0,
+        (-32i32) as u32,
+        0,
+        (-0x400i32) as u32,
+        0x400,
+    ];
+    let command = Command::from_args(MacroId::gfxd_SPTextureRectangle, &raw).unwrap();
+    match command {
+        Command::SPTextureRectangle {
+            s, t, dsdx, dtdy, ..
+        } => {
+            assert_eq!((-32, 0, -0x400, 0x400), (s, t, dsdx, dtdy))
+        }
+        x => panic!("unexpected command {:?}", x),
+    }
+    assert_eq!(&raw[..], &command.args()[..]);
+
+    let command = Command::SPFogPosition {
+        min: -100,
+        max: 1000,
+    };
+    assert_eq!(&[0xFFFF_FF9C, 1000][..], &command.args()[..]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_command_from_macro() {
+    use gfxd_sys::arg_type::ArgType;
+    use gfxd_sys::decode::{Arg, Macro, Value};
+
+    let arg = |name: &str, type_, value| Arg {
+        name: name.into(),
+        type_,
+        value,
+        valid: true,
+        text: String::new(),
+    };
+    let m = Macro {
+        offset: 0,
+        packets: 1,
+        data: vec![0x06, 0x00, 0x00, 0x02, 0x00, 0x02, 0x04, 0x06],
+        id: MacroId::gfxd_SP2Triangles,
+        name: Some("gsSP2Triangles".into()),
+        args: vec![
+            arg("v00", ArgType::gfxd_Vtx, Value::I(0)),
+            arg("v01", ArgType::gfxd_Vtx, Value::I(1)),
+            arg("v02", ArgType::gfxd_Vtx, Value::I(2)),
+            arg("flag0", ArgType::gfxd_Vtxflag, Value::I(0)),
+            arg("v10", ArgType::gfxd_Vtx, Value::I(0)),
+            arg("v11", ArgType::gfxd_Vtx, Value::I(2)),
+            arg("v12", ArgType::gfxd_Vtx, Value::I(3)),
+            arg("flag1", ArgType::gfxd_Vtxflag, Value::I(0)),
+        ],
+        text: String::new(),
+    };
+
+    assert_eq!(
+        Some(Command::SP2Triangles {
+            v00: 0,
+            v01: 1,
+            v02: 2,
+            flag0: 0,
+            v10: 0,
+            v11: 2,
+            v12: 3,
+            flag1: 0,
+        }),
+        Command::from_macro(&m)
+    );
+
+    let m = Macro {
+        args: Vec::new(),
+        ..m
+    };
+    assert_eq!(None, Command::from_macro(&m));
+}
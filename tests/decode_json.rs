@@ -0,0 +1,141 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::arg_type::ArgType;
+use gfxd_sys::decode::{Arg, Decoded, Macro, Value};
+use gfxd_sys::json::JsonError;
+use gfxd_sys::macro_id::MacroId;
+
+fn arg(name: &str, type_: ArgType, value: Value, text: &str) -> Arg {
+    Arg {
+        name: name.into(),
+        type_,
+        value,
+        valid: true,
+        text: text.into(),
+    }
+}
+
+fn vertex() -> Macro {
+    Macro {
+        offset: 8,
+        packets: 1,
+        data: vec![0x01, 0x00, 0x40, 0x08, 0x06, 0x00, 0x00, 0x00],
+        id: MacroId::gfxd_SPVertex,
+        name: Some("gsSPVertex".into()),
+        args: vec![
+            arg(
+                "v",
+                ArgType::gfxd_Vtxptr,
+                Value::U(0x0600_0000),
+                "0x06000000",
+            ),
+            arg("n", ArgType::gfxd_Num, Value::I(4), "4"),
+            arg("v0", ArgType::gfxd_Vtx, Value::I(0), "0"),
+        ],
+        text: "gsSPVertex(0x06000000, 4, 0)".into(),
+    }
+}
+
+#[test]
+fn test_macro_to_json() {
+    assert_eq!(
+        vertex().to_json(),
+        "{\"offset\":8,\"packets\":1,\"words\":[16793608,100663296],\
+         \"id\":\"gfxd_SPVertex\",\"name\":\"gsSPVertex\",\"args\":[\
+         {\"name\":\"v\",\"type\":\"gfxd_Vtxptr\",\"fmt\":\"gfxd_argfmt_u\",\
+         \"value\":100663296,\"valid\":true,\"text\":\"0x06000000\"},\
+         {\"name\":\"n\",\"type\":\"gfxd_Num\",\"fmt\":\"gfxd_argfmt_i\",\
+         \"value\":4,\"valid\":true,\"text\":\"4\"},\
+         {\"name\":\"v0\",\"type\":\"gfxd_Vtx\",\"fmt\":\"gfxd_argfmt_i\",\
+         \"value\":0,\"valid\":true,\"text\":\"0\"}],\
+         \"text\":\"gsSPVertex(0x06000000, 4, 0)\"}"
+    );
+}
+
+#[test]
+fn test_round_trip() {
+    let invalid = Macro {
+        offset: 16,
+        packets: 1,
+        data: vec![0xFF; 8],
+        id: MacroId::gfxd_Invalid,
+        name: None,
+        args: vec![
+            arg("a", ArgType::gfxd_Word, Value::F(-1.5), "\"quoted\"\n"),
+            arg("b", ArgType::gfxd_Word, Value::F(core::f32::NAN), "NaN"),
+            arg(
+                "c",
+                ArgType::gfxd_Word,
+                Value::F(core::f32::NEG_INFINITY),
+                "-inf",
+            ),
+            arg("d", ArgType::gfxd_Word, Value::I(-2), "\u{e9}"),
+        ],
+        text: "".into(),
+    };
+    let decoded = Decoded {
+        macros: vec![vertex(), invalid],
+        status: -1,
+    };
+
+    let json = decoded.to_json();
+    assert_eq!(Decoded::from_json(&json), Ok(decoded));
+
+    let pretty = " {\n  \"status\": 0,\n  \"macros\": [ ]\n}\n";
+    assert_eq!(
+        Decoded::from_json(pretty),
+        Ok(Decoded {
+            macros: vec![],
+            status: 0
+        })
+    );
+}
+
+#[test]
+fn test_every_name() {
+    let macros = (0..=MacroId::MAX)
+        .filter_map(MacroId::from_raw)
+        .zip((0..=ArgType::MAX).filter_map(ArgType::from_raw).cycle())
+        .map(|(id, type_)| Macro {
+            id,
+            args: vec![arg("a", type_, Value::U(0), "0")],
+            ..vertex()
+        });
+
+    for m in macros {
+        let json = m.to_json();
+        assert!(json.contains(&format!("\"id\":\"{:?}\"", m.id)));
+        assert!(json.contains(&format!("\"type\":\"{:?}\"", m.args[0].type_)));
+        assert_eq!(Macro::from_json(&json), Ok(m));
+    }
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(
+        Macro::from_json("{\"offset\":8,}"),
+        Err(JsonError::Syntax {
+            offset: 12,
+            msg: "expected a member name"
+        })
+    );
+    let json = vertex().to_json().replace("gfxd_Vtxptr", "gfxd_Nope");
+    assert_eq!(Macro::from_json(&json), Err(JsonError::Invalid("type")));
+    assert_eq!(
+        Decoded::from_json("{\"status\":0}"),
+        Err(JsonError::Invalid("macros"))
+    );
+    let json = "[".repeat(100_000);
+    assert_eq!(
+        Decoded::from_json(&json),
+        Err(JsonError::Syntax {
+            offset: 128,
+            msg: "too deeply nested"
+        })
+    );
+}
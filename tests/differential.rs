@@ -0,0 +1,59 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::decode::DecodeSettings;
+use gfxd_sys::differential::{self, GoldenCorpus, Libgfxd};
+use gfxd_sys::settings::Ucode;
+
+static DLIST_DATA: [u8; 0x18] = [
+    0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69, //
+    0x05, 0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x00, //
+    0xDF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+];
+
+#[test]
+fn test_libgfxd_against_itself() {
+    let divergences = differential::compare_backends(&DLIST_DATA, &mut Libgfxd, &mut Libgfxd);
+
+    assert_eq!(Vec::<differential::Divergence>::new(), divergences);
+}
+
+#[test]
+fn test_golden_round_trip() {
+    let settings = Ucode::ALL.iter().map(|&x| DecodeSettings::new(x));
+    let corpus = GoldenCorpus::record_with(&DLIST_DATA, &mut Libgfxd, settings);
+
+    let parsed = GoldenCorpus::from_text(&corpus.to_text()).unwrap();
+    assert_eq!(corpus, parsed);
+
+    let divergences = differential::compare_golden(&DLIST_DATA, &parsed, &mut Libgfxd);
+    assert_eq!(Vec::<differential::Divergence>::new(), divergences);
+}
+
+#[test]
+fn test_golden_divergence() {
+    let settings = DecodeSettings::new(Ucode::gfxd_f3dex2);
+    let mut corpus =
+        GoldenCorpus::record_with(&DLIST_DATA, &mut Libgfxd, core::iter::once(settings));
+
+    let macros = corpus.entries.get_mut(&settings).unwrap();
+    macros[1].data[7] = 0x01;
+
+    let divergences = differential::compare_golden(&DLIST_DATA, &corpus, &mut Libgfxd);
+    assert_eq!(1, divergences.len());
+    assert_eq!(1, divergences[0].index);
+    assert_eq!(0x08, divergences[0].offset);
+}
+
+#[test]
+fn test_golden_parse_errors() {
+    let key = differential::settings_key(&DecodeSettings::new(Ucode::gfxd_f3dex2));
+    let text = format!("[{}]\n0x00000000\t1\t0\tD\u{e9}0\t\tgsDPNoOp()\n", key);
+
+    let err = GoldenCorpus::from_text(&text).unwrap_err();
+    assert_eq!((2, "bad macro"), (err.line, err.msg));
+}
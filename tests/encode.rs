@@ -0,0 +1,196 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::command::Command;
+use gfxd_sys::encode::{self, EncodeError};
+use gfxd_sys::gbi;
+use gfxd_sys::gfx::Gfx;
+use gfxd_sys::macro_id::MacroId;
+use gfxd_sys::settings::Ucode;
+
+#[test]
+fn test_encode_texture_rectangle() {
+    let command = Command::SPTextureRectangle {
+        ulx: 0x40,
+        uly: 0x40,
+        lrx: 0x80,
+        lry: 0x80,
+        tile: gbi::G_TX_RENDERTILE,
+        s: 0,
+        t: 0,
+        dsdx: 0x400,
+        dtdy: 0x400,
+    };
+
+    assert_eq!(
+        &[
+            Gfx::new(0xE408_0080, 0x0004_0040),
+            Gfx::new(0xE100_0000, 0x0000_0000),
+            Gfx::new(0xF100_0000, 0x0400_0400),
+        ][..],
+        &command.encode(Ucode::gfxd_f3dex2).unwrap()[..]
+    );
+    assert_eq!(
+        &[
+            Gfx::new(0xE408_0080, 0x0004_0040),
+            Gfx::new(0xB400_0000, 0x0000_0000),
+            Gfx::new(0xB300_0000, 0x0400_0400),
+        ][..],
+        &command.encode(Ucode::gfxd_f3d).unwrap()[..]
+    );
+}
+
+#[test]
+fn test_encode_cull_display_list() {
+    // `gbi.h` masks `vend + 1`, so the last vertex wraps around to 0.
+    let cases = [
+        (0, 14, Gfx::new(0xBE00_0000, 0x0000_0258)),
+        (1, 15, Gfx::new(0xBE00_0028, 0x0000_0000)),
+    ];
+    for &(vstart, vend, gfx) in cases.iter() {
+        let command = Command::SPCullDisplayList { vstart, vend };
+        assert_eq!(&[gfx][..], &command.encode(Ucode::gfxd_f3d).unwrap()[..]);
+    }
+}
+
+#[test]
+fn test_encode_num_lights_wraps() {
+    // `NUML` is computed in 32 bits, so an absurd count wraps around.
+    assert_eq!(
+        &[Gfx::new(0xBC00_0002, 0x8000_0000)][..],
+        &encode::encode(Ucode::gfxd_f3d, MacroId::gfxd_SPNumLights, &[0xFFFF_FFFF]).unwrap()[..]
+    );
+}
+
+#[test]
+fn test_encode_load_tlut() {
+    let command = Command::DPLoadTLUT_pal256 { dram: 0x0600_1000 };
+
+    let expected = [
+        Gfx::new(0xFD10_0000, 0x0600_1000),
+        Gfx::new(0xE800_0000, 0x0000_0000),
+        Gfx::new(0xF500_0100, 0x0700_0000),
+        Gfx::new(0xE600_0000, 0x0000_0000),
+        Gfx::new(0xF000_0000, 0x073F_C000),
+        Gfx::new(0xE700_0000, 0x0000_0000),
+    ];
+    for &ucode in Ucode::ALL.iter() {
+        assert_eq!(
+            &expected[..],
+            &encode::encode_command(ucode, &command).unwrap()[..]
+        );
+    }
+}
+
+#[test]
+fn test_encode_load_texture_block() {
+    let command = Command::DPLoadTextureBlock {
+        timg: 0x0800_0000,
+        fmt: gbi::G_IM_FMT_RGBA,
+        siz: gbi::G_IM_SIZ_16b,
+        width: 32,
+        height: 32,
+        pal: 0,
+        cms: gbi::G_TX_NOMIRROR | gbi::G_TX_WRAP,
+        cmt: gbi::G_TX_NOMIRROR | gbi::G_TX_WRAP,
+        masks: 5,
+        maskt: 5,
+        shifts: gbi::G_TX_NOLOD,
+        shiftt: gbi::G_TX_NOLOD,
+    };
+
+    assert_eq!(
+        &[
+            Gfx::new(0xFD10_0000, 0x0800_0000),
+            Gfx::new(0xF510_0000, 0x0701_4050),
+            Gfx::new(0xE600_0000, 0x0000_0000),
+            Gfx::new(0xF300_0000, 0x073F_F100),
+            Gfx::new(0xE700_0000, 0x0000_0000),
+            Gfx::new(0xF510_1000, 0x0001_4050),
+            Gfx::new(0xF200_0000, 0x0007_C07C),
+        ][..],
+        &command.encode(Ucode::gfxd_f3dex2).unwrap()[..]
+    );
+}
+
+#[test]
+fn test_encode_errors() {
+    let command = Command::SP2Triangles {
+        v00: 0,
+        v01: 1,
+        v02: 2,
+        flag0: 0,
+        v10: 0,
+        v11: 2,
+        v12: 3,
+        flag1: 0,
+    };
+    assert_eq!(
+        Err(EncodeError::Unsupported {
+            id: MacroId::gfxd_SP2Triangles,
+            ucode: Ucode::gfxd_f3d,
+        }),
+        command.encode(Ucode::gfxd_f3d)
+    );
+
+    assert_eq!(
+        Err(EncodeError::ArgCount {
+            id: MacroId::gfxd_SPVertex,
+            expected: 3,
+            found: 2,
+        }),
+        encode::encode(Ucode::gfxd_f3dex2, MacroId::gfxd_SPVertex, &[0, 0])
+    );
+
+    let command = Command::Invalid {
+        hi: 0x1234_5678,
+        lo: 0x9ABC_DEF0,
+    };
+    assert_eq!(
+        &[Gfx::new(0x1234_5678, 0x9ABC_DEF0)][..],
+        &command.encode(Ucode::gfxd_f3dex2).unwrap()[..]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_display_list_builder() {
+    use gfxd_sys::encode::DisplayListBuilder;
+    use gfxd_sys::settings::Endian;
+
+    let mut builder = DisplayListBuilder::new(Ucode::gfxd_f3dex2);
+    builder
+        .push(&Command::SPVertex {
+            v: 0x4204_2069,
+            n: 3,
+            v0: 0,
+        })
+        .unwrap()
+        .push(&Command::SP1Triangle {
+            v0: 0,
+            v1: 1,
+            v2: 2,
+            flag: 0,
+        })
+        .unwrap()
+        .push_raw(Gfx::new(0xDF00_0000, 0))
+        .push(&Command::SPPopMatrix {
+            param: gbi::f3dex2::G_MTX_MODELVIEW,
+        })
+        .unwrap();
+    assert_eq!(4, builder.packets().len());
+
+    static EXPECTED: [u8; 0x20] = [
+        0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69, //
+        0x05, 0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x00, //
+        0xDF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+        0xD8, 0x38, 0x00, 0x02, 0x00, 0x00, 0x00, 0x40, //
+    ];
+    assert_eq!(
+        &EXPECTED[..],
+        &builder.to_bytes(Endian::gfxd_endian_big, 4)[..]
+    );
+    assert_eq!(4, builder.build().len());
+}
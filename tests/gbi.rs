@@ -0,0 +1,71 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::arg_type::ArgType;
+use gfxd_sys::gbi;
+use gfxd_sys::settings::Ucode;
+
+#[test]
+fn test_gbi_symbolic_name() {
+    let cases: [(ArgType, u32, Option<&str>); 10] = [
+        (ArgType::gfxd_Fmt, gbi::G_IM_FMT_CI, Some("G_IM_FMT_CI")),
+        (ArgType::gfxd_Siz, gbi::G_IM_SIZ_16b, Some("G_IM_SIZ_16b")),
+        (ArgType::gfxd_Cm, gbi::G_TX_CLAMP, Some("G_TX_CLAMP")),
+        (ArgType::gfxd_Cm, gbi::G_TX_MIRROR | gbi::G_TX_CLAMP, None),
+        (ArgType::gfxd_Tf, gbi::G_TF_BILERP, Some("G_TF_BILERP")),
+        (ArgType::gfxd_Ccmuxa, gbi::G_CCMUX_TEXEL0, Some("TEXEL0")),
+        (ArgType::gfxd_Ccpre, 1, Some(gbi::CC_PRESETS[1].name)),
+        (ArgType::gfxd_Ccpre, 10_000, None),
+        (
+            ArgType::gfxd_Rm1,
+            gbi::G_RM_AA_ZB_OPA_SURF,
+            Some("G_RM_AA_ZB_OPA_SURF"),
+        ),
+        (ArgType::gfxd_Vtx, 0, None),
+    ];
+    for &(type_, value, name) in cases.iter() {
+        for &ucode in Ucode::ALL.iter() {
+            assert_eq!(
+                name,
+                gbi::symbolic_name(ucode, type_, value),
+                "{:?} {}",
+                type_,
+                value
+            );
+        }
+    }
+}
+
+#[test]
+fn test_gbi_symbolic_name_ucode() {
+    assert_eq!(
+        Some("G_MW_SEGMENT"),
+        gbi::symbolic_name(Ucode::gfxd_f3d, ArgType::gfxd_Mw, gbi::f3d::G_MW_SEGMENT)
+    );
+    assert_eq!(
+        Some("G_MV_LIGHT"),
+        gbi::symbolic_name(
+            Ucode::gfxd_f3dex2,
+            ArgType::gfxd_Mv,
+            gbi::f3dex2::G_MV_LIGHT
+        )
+    );
+    assert_eq!(
+        None,
+        gbi::symbolic_name(Ucode::gfxd_f3dex, ArgType::gfxd_Mvo, 0)
+    );
+    assert_eq!(
+        Some("G_MVO_LOOKATY"),
+        gbi::symbolic_name(
+            Ucode::gfxd_f3dex2,
+            ArgType::gfxd_Mvo,
+            gbi::f3dex2::G_MVO_LOOKATY
+        )
+    );
+    assert_eq!(
+        None,
+        gbi::arg_constants(Ucode::gfxd_f3dex2, ArgType::gfxd_Ccpre)
+    );
+}
@@ -0,0 +1,128 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::command::Command;
+use gfxd_sys::gbi;
+use gfxd_sys::geometry_mode::{GeometryMode, GeometryModeChange, GeometryModeParseError};
+use gfxd_sys::settings::Ucode;
+use gfxd_sys::static_dl;
+
+#[test]
+fn test_geometry_mode_format() {
+    let bits = gbi::f3dex2::G_ZBUFFER | gbi::f3dex2::G_SHADE | gbi::f3dex2::G_CULL_BACK;
+    let mode = GeometryMode::new(Ucode::gfxd_f3dex2, bits);
+    assert_eq!("G_ZBUFFER | G_SHADE | G_CULL_BACK", mode.to_string());
+
+    // The same bits mean something else on F3D.
+    let mode = GeometryMode::new(Ucode::gfxd_f3d, bits);
+    assert_eq!("G_ZBUFFER | G_SHADE | 0x400", mode.to_string());
+    assert_eq!(0x400, mode.unnamed_bits());
+
+    let bits = gbi::f3d::G_CULL_BOTH | gbi::f3d::G_LIGHTING;
+    let mode = GeometryMode::new(Ucode::gfxd_f3d, bits);
+    assert_eq!("G_CULL_BOTH | G_LIGHTING", mode.to_string());
+
+    assert_eq!("0", GeometryMode::new(Ucode::gfxd_f3dex, 0).to_string());
+}
+
+#[test]
+fn test_geometry_mode_parse() {
+    for &ucode in Ucode::ALL.iter() {
+        let mode = GeometryMode::parse(ucode, "G_ZBUFFER | G_SHADE | G_CULL_BACK").unwrap();
+        assert_eq!("G_ZBUFFER | G_SHADE | G_CULL_BACK", mode.to_string());
+        assert_eq!(mode, GeometryMode::parse(ucode, &mode.to_string()).unwrap());
+    }
+
+    assert_eq!(
+        GeometryMode::new(Ucode::gfxd_f3dex2, gbi::f3dex2::G_FOG | 0x10),
+        GeometryMode::parse(Ucode::gfxd_f3dex2, "G_FOG|0x10").unwrap()
+    );
+    assert_eq!(
+        Err(GeometryModeParseError { offset: 12 }),
+        GeometryMode::parse(Ucode::gfxd_f3d, "G_ZBUFFER | G_NOPE")
+    );
+}
+
+#[test]
+fn test_geometry_mode_to_ucode() {
+    let mode = GeometryMode::parse(
+        Ucode::gfxd_f3d,
+        "G_ZBUFFER | G_SHADE | G_SHADING_SMOOTH | G_CULL_BOTH | G_TEXTURE_ENABLE",
+    )
+    .unwrap();
+    let converted = mode.to_ucode(Ucode::gfxd_f3dex2);
+
+    assert_eq!(
+        gbi::f3dex2::G_ZBUFFER
+            | gbi::f3dex2::G_SHADE
+            | gbi::f3dex2::G_SHADING_SMOOTH
+            | gbi::f3dex2::G_CULL_BOTH,
+        converted.bits
+    );
+    assert_eq!(
+        mode.bits & !gbi::f3d::G_TEXTURE_ENABLE,
+        converted.to_ucode(Ucode::gfxd_f3d).bits
+    );
+}
+
+#[test]
+fn test_geometry_mode_change() {
+    let ucode = Ucode::gfxd_f3dex2;
+    let gfx = static_dl::f3dex2::gsSPGeometryMode(gbi::f3dex2::G_CULL_BOTH, gbi::f3dex2::G_FOG);
+    let change = GeometryModeChange::from_gfx(ucode, gfx).unwrap();
+
+    assert_eq!(
+        GeometryModeChange::new(ucode, gbi::f3dex2::G_CULL_BOTH, gbi::f3dex2::G_FOG),
+        change
+    );
+    assert_eq!("clear G_CULL_BOTH, set G_FOG", change.to_string());
+    assert_eq!(
+        Some(change),
+        GeometryModeChange::from_command(
+            ucode,
+            &Command::SPGeometryMode {
+                clearbits: gbi::f3dex2::G_CULL_BOTH,
+                setbits: gbi::f3dex2::G_FOG,
+            }
+        )
+    );
+    assert_eq!(
+        gbi::f3dex2::G_ZBUFFER | gbi::f3dex2::G_FOG,
+        change.apply(gbi::f3dex2::G_ZBUFFER | gbi::f3dex2::G_CULL_BACK)
+    );
+
+    let gfx = static_dl::f3dex2::gsSPLoadGeometryMode(gbi::f3dex2::G_SHADE);
+    let change = GeometryModeChange::from_gfx(ucode, gfx).unwrap();
+    assert_eq!(
+        GeometryModeChange::from_command(
+            ucode,
+            &Command::SPLoadGeometryMode {
+                mode: gbi::f3dex2::G_SHADE
+            }
+        ),
+        Some(change)
+    );
+    assert_eq!(gbi::f3dex2::G_SHADE, change.apply(0x00FF_FFFF));
+
+    let ucode = Ucode::gfxd_f3d;
+    let gfx = static_dl::f3d::gsSPClearGeometryMode(gbi::f3d::G_LIGHTING);
+    assert_eq!(
+        Some(GeometryModeChange::new(ucode, gbi::f3d::G_LIGHTING, 0)),
+        GeometryModeChange::from_gfx(ucode, gfx)
+    );
+    let gfx = static_dl::f3d::gsSPSetGeometryMode(gbi::f3d::G_LIGHTING);
+    assert_eq!(
+        Some(GeometryModeChange::new(ucode, 0, gbi::f3d::G_LIGHTING)),
+        GeometryModeChange::from_gfx(ucode, gfx)
+    );
+    assert_eq!(
+        None,
+        GeometryModeChange::from_gfx(ucode, static_dl::gsDPPipeSync())
+    );
+    assert_eq!(
+        None,
+        GeometryModeChange::from_command(ucode, &Command::DPPipeSync)
+    );
+}
@@ -0,0 +1,134 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::gfx::{Gfx, GfxView};
+use gfxd_sys::settings::{Endian, Ucode};
+
+static BIG: [u8; 0x18] = [
+    0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69, //
+    0x05, 0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x00, //
+    0xDF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+];
+
+static PACKETS: [Gfx; 3] = [
+    Gfx::new(0x0100_3006, 0x4204_2069),
+    Gfx::new(0x0500_0204, 0x0000_0000),
+    Gfx::new(0xDF00_0000, 0x0000_0000),
+];
+
+#[test]
+fn test_gfx_layouts() {
+    let gfx = PACKETS[0];
+
+    let layouts: [(Endian, u32, [u8; 8]); 6] = [
+        (
+            Endian::gfxd_endian_big,
+            4,
+            [0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69],
+        ),
+        (
+            Endian::gfxd_endian_little,
+            0,
+            [0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69],
+        ),
+        (
+            Endian::gfxd_endian_little,
+            1,
+            [0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69],
+        ),
+        (
+            Endian::gfxd_endian_little,
+            2,
+            [0x00, 0x01, 0x06, 0x30, 0x04, 0x42, 0x69, 0x20],
+        ),
+        (
+            Endian::gfxd_endian_little,
+            4,
+            [0x06, 0x30, 0x00, 0x01, 0x69, 0x20, 0x04, 0x42],
+        ),
+        (
+            Endian::gfxd_endian_little,
+            8,
+            [0x69, 0x20, 0x04, 0x42, 0x06, 0x30, 0x00, 0x01],
+        ),
+    ];
+
+    for &(endian, wordsize, bytes) in layouts.iter() {
+        assert_eq!(bytes, gfx.to_bytes(endian, wordsize));
+        assert_eq!(gfx, Gfx::from_bytes(bytes, endian, wordsize));
+        assert_eq!(Some(gfx), Gfx::read(&bytes, endian, wordsize));
+    }
+
+    assert_eq!(None, Gfx::read(&BIG[..7], Endian::gfxd_endian_big, 4));
+
+    let view = GfxView::new(&BIG, Endian::gfxd_endian_little, 0);
+    assert_eq!(&PACKETS[..], &view.iter().collect::<Vec<_>>()[..]);
+}
+
+#[test]
+fn test_gfx_view() {
+    let view = GfxView::new(&BIG, Endian::gfxd_endian_big, 4);
+
+    assert_eq!(3, view.len());
+    assert_eq!(&PACKETS[..], &view.iter().collect::<Vec<_>>()[..]);
+    assert_eq!(Some(PACKETS[1]), view.get(1));
+    assert_eq!(None, view.get(3));
+    assert_eq!(Some(PACKETS[2]), view.at_offset(0x10));
+    assert_eq!(None, view.at_offset(0x0C));
+    assert_eq!(Some(PACKETS[2]), view.iter().next_back());
+
+    let little = PACKETS
+        .iter()
+        .flat_map(|x| x.to_bytes(Endian::gfxd_endian_little, 2).to_vec())
+        .chain(core::iter::once(0xFF))
+        .collect::<Vec<u8>>();
+    let view = GfxView::new(&little, Endian::gfxd_endian_little, 2);
+    assert_eq!(3, view.len());
+    assert_eq!(0x18, view.as_bytes().len());
+    assert_eq!(&PACKETS[..], &view.into_iter().collect::<Vec<_>>()[..]);
+}
+
+#[test]
+fn test_gfx_cast_slice() {
+    let words = [0u32; 7];
+    // SAFETY: Any initialized memory can be viewed as bytes.
+    let bytes = unsafe { core::slice::from_raw_parts(words.as_ptr() as *const u8, 28) };
+
+    assert_eq!(None, Gfx::cast_slice(bytes));
+    assert_eq!(None, Gfx::cast_slice(&bytes[2..26]));
+    assert_eq!(Some(3), Gfx::cast_slice(&bytes[4..28]).map(|x| x.len()));
+
+    let words = [
+        PACKETS[0].w0,
+        PACKETS[0].w1,
+        PACKETS[1].w0,
+        PACKETS[1].w1,
+        PACKETS[2].w0,
+        PACKETS[2].w1,
+    ];
+    // SAFETY: Any initialized memory can be viewed as bytes.
+    let host = unsafe { core::slice::from_raw_parts(words.as_ptr() as *const u8, 0x18) };
+    assert_eq!(Some(&PACKETS[..]), Gfx::cast_slice(host));
+
+    let view = GfxView::new(host, Endian::gfxd_endian_host, 4);
+    assert_eq!(&PACKETS[..], &view.iter().collect::<Vec<_>>()[..]);
+}
+
+#[test]
+fn test_gfx_opcode() {
+    let vtx = Gfx::new(0x0100_3006, 0x4204_2069);
+    assert_eq!(0x01, vtx.opcode());
+    assert_eq!(Some("G_VTX"), vtx.opcode_name(Ucode::gfxd_f3dex2));
+    assert_eq!(Some("G_MTX"), vtx.opcode_name(Ucode::gfxd_f3d));
+
+    let tri = Gfx::new(0xBF00_0000, 0x0000_0A14);
+    assert_eq!(Some("G_TRI1"), tri.opcode_name(Ucode::gfxd_f3dex));
+    assert_eq!(None, tri.opcode_name(Ucode::gfxd_f3dex2));
+
+    let combine = Gfx::new(0xFCFF_FFFF, 0xFFFE_793C);
+    for &ucode in Ucode::ALL.iter() {
+        assert_eq!(Some("G_SETCOMBINE"), combine.opcode_name(ucode));
+    }
+}
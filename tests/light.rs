@@ -0,0 +1,72 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::light::{Ambient, Light, Lights, LookAt};
+use gfxd_sys::settings::Endian;
+
+fn assert_close(a: [f32; 3], b: [f32; 3]) {
+    for (x, y) in a.iter().zip(b.iter()) {
+        assert!((x - y).abs() < 1e-4, "{:?} != {:?}", a, b);
+    }
+}
+
+#[test]
+fn light_direction() {
+    // gdSPDefLights1(0x20, 0x20, 0x20, 0xFF, 0xFF, 0xFF, 0x49, 0x49, 0x49)
+    let light = Light {
+        col: [0xFF; 3],
+        colc: [0xFF; 3],
+        dir: [0x49, 0x49, 0x49],
+    };
+    let d = 1.0 / 3f32.sqrt();
+    assert_close(light.direction(), [d, d, d]);
+
+    assert_eq!(Light::new([0xFF; 3], [1.0, 1.0, 1.0]), light);
+    assert_eq!(Light::new([0; 3], [0.0, -5.0, 0.0]).dir, [0, -127, 0]);
+    assert_close(Light::default().direction(), [0.0; 3]);
+
+    let look_at = LookAt::new([1.0, 0.0, 0.0], [0.0, 0.0, 2.0]);
+    assert_eq!(look_at.l[0].dir, [127, 0, 0]);
+    assert_eq!(look_at.l[1].dir, [0, 0, 127]);
+    let bytes = look_at.to_bytes(Endian::gfxd_endian_little, 4);
+    assert_eq!(
+        LookAt::read(&bytes, Endian::gfxd_endian_little, 4),
+        Some(look_at)
+    );
+}
+
+#[test]
+fn light_lights() {
+    let lights = Lights {
+        ambient: Ambient::new([0x10, 0x20, 0x30]),
+        lights: vec![
+            Light::new([0xFF, 0x80, 0x00], [0.0, 1.0, 0.0]),
+            Light::new([0x00, 0x80, 0xFF], [0.0, 0.0, -1.0]),
+        ],
+    };
+    let bytes = lights.to_bytes(Endian::gfxd_endian_big, 4);
+
+    assert_eq!(bytes.len(), Lights::size(2));
+    assert_eq!(
+        &bytes[..24],
+        &[
+            0x10, 0x20, 0x30, 0, 0x10, 0x20, 0x30, 0, 0xFF, 0x80, 0x00, 0, 0xFF, 0x80, 0x00, 0, 0,
+            0x7F, 0, 0, 0, 0, 0, 0
+        ][..]
+    );
+    assert_eq!(
+        Lights::read(&bytes, 2, Endian::gfxd_endian_big, 4),
+        Some(lights.clone())
+    );
+    assert_eq!(
+        Lights::read(&bytes, 1, Endian::gfxd_endian_big, 4)
+            .unwrap()
+            .lights,
+        &lights.lights[..1]
+    );
+    assert_eq!(Lights::read(&bytes, 3, Endian::gfxd_endian_big, 4), None);
+}
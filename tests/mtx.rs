@@ -0,0 +1,61 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::mtx::Mtx;
+use gfxd_sys::settings::Endian;
+
+#[test]
+fn mtx_layout() {
+    let identity = Mtx::identity();
+    let bytes = identity.to_bytes(Endian::gfxd_endian_big, 4);
+
+    // The integer parts, then the fractional parts.
+    let mut expected = [0; 64];
+    for i in 0..4 {
+        expected[i * 10 + 1] = 1;
+    }
+    assert_eq!(&bytes[..], &expected[..]);
+    assert_eq!(
+        Mtx::read(&bytes, Endian::gfxd_endian_big, 4),
+        Some(identity)
+    );
+    assert_eq!(Mtx::read(&bytes[1..], Endian::gfxd_endian_big, 4), None);
+
+    let mut m = Mtx::default();
+    m.m[3][0] = -0x0001_8000;
+    m.m[1][2] = 0x1234_5678;
+    let bytes = m.to_bytes(Endian::gfxd_endian_big, 4);
+    assert_eq!(&bytes[24..26], &[0xFF, 0xFE]);
+    assert_eq!(&bytes[56..58], &[0x80, 0x00]);
+    assert_eq!(&bytes[12..14], &[0x12, 0x34]);
+    assert_eq!(&bytes[44..46], &[0x56, 0x78]);
+
+    for &wordsize in [1, 2, 4, 8].iter() {
+        for &endian in [Endian::gfxd_endian_big, Endian::gfxd_endian_little].iter() {
+            let raw = m.to_bytes(endian, wordsize);
+            assert_eq!(Mtx::from_bytes(raw, endian, wordsize), m);
+        }
+    }
+}
+
+#[test]
+fn mtx_float() {
+    let mf = [
+        [2.0, 0.0, 0.0, 0.0],
+        [0.0, 0.5, 0.0, 0.0],
+        [0.0, 0.0, -1.0, 0.0],
+        [10.0, -20.25, 30.125, 1.0],
+    ];
+    let m = Mtx::from_f32(&mf);
+
+    assert_eq!(m.m[0][0], 0x0002_0000);
+    assert_eq!(m.m[1][1], 0x0000_8000);
+    assert_eq!(m.m[3][1], -0x0014_4000);
+    assert_eq!(m.to_f32(), mf);
+
+    let huge = Mtx::from_f32(&[[1e9; 4]; 4]);
+    assert_eq!(huge.m[0][0], i32::max_value());
+    assert_eq!(Mtx::from_f32(&Mtx::identity().to_f32()), Mtx::identity());
+}
@@ -0,0 +1,131 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::gbi;
+use gfxd_sys::gfx::Gfx;
+use gfxd_sys::othermode::{Blender, OtherMode, OtherModeHi, RenderMode};
+
+#[test]
+fn test_othermode_hi() {
+    let bits = gbi::G_AD_NOISE
+        | gbi::G_CD_MAGICSQ
+        | gbi::G_TC_FILT
+        | gbi::G_TF_BILERP
+        | gbi::G_TT_RGBA16
+        | gbi::G_TP_PERSP
+        | gbi::G_CYC_2CYCLE
+        | gbi::G_PM_NPRIMITIVE;
+    let hi = OtherModeHi::from_bits(bits);
+
+    assert_eq!(gbi::G_TF_BILERP, hi.texture_filter);
+    assert_eq!(gbi::G_TT_RGBA16, hi.texture_lut);
+    assert_eq!(gbi::G_CYC_2CYCLE, hi.cycle_type);
+    assert_eq!(bits, hi.to_bits());
+    assert_eq!(
+        "G_AD_NOISE | G_CD_MAGICSQ | G_CK_NONE | G_TC_FILT | G_TF_BILERP | G_TT_RGBA16 | \
+         G_TL_TILE | G_TD_CLAMP | G_TP_PERSP | G_CYC_2CYCLE | G_PM_NPRIMITIVE",
+        hi.to_string()
+    );
+}
+
+#[test]
+fn test_othermode_render_mode() {
+    let bits = gbi::G_RM_AA_ZB_OPA_SURF | gbi::G_RM_AA_ZB_OPA_SURF2;
+    let rm = RenderMode::from_bits(bits);
+
+    assert_eq!(
+        gbi::AA_EN | gbi::Z_CMP | gbi::Z_UPD | gbi::IM_RD | gbi::ALPHA_CVG_SEL,
+        rm.flags
+    );
+    assert_eq!(
+        Blender {
+            p: gbi::G_BL_CLR_IN,
+            a: gbi::G_BL_A_IN,
+            m: gbi::G_BL_CLR_MEM,
+            b: gbi::G_BL_A_MEM,
+        },
+        rm.cycles[0]
+    );
+    assert_eq!(rm.cycles[0], rm.cycles[1]);
+    assert_eq!(bits, rm.to_bits());
+    assert_eq!(
+        Some(("G_RM_AA_ZB_OPA_SURF", "G_RM_AA_ZB_OPA_SURF2")),
+        rm.presets()
+    );
+    assert_eq!("G_RM_AA_ZB_OPA_SURF | G_RM_AA_ZB_OPA_SURF2", rm.to_string());
+
+    let rm = RenderMode {
+        flags: gbi::Z_CMP | gbi::CVG_DST_FULL | gbi::ZMODE_XLU | gbi::FORCE_BL,
+        cycles: [
+            Blender {
+                p: gbi::G_BL_CLR_FOG,
+                a: gbi::G_BL_A_SHADE,
+                m: gbi::G_BL_CLR_IN,
+                b: gbi::G_BL_1MA,
+            },
+            Blender {
+                p: gbi::G_BL_CLR_BL,
+                a: gbi::G_BL_0,
+                m: gbi::G_BL_CLR_BL,
+                b: gbi::G_BL_1,
+            },
+        ],
+    };
+    assert_eq!(None, rm.presets());
+    assert_eq!(
+        "Z_CMP | CVG_DST_FULL | ZMODE_XLU | FORCE_BL | \
+         GBL_c1(G_BL_CLR_FOG, G_BL_A_SHADE, G_BL_CLR_IN, G_BL_1MA) | \
+         GBL_c2(G_BL_CLR_BL, G_BL_0, G_BL_CLR_BL, G_BL_1)",
+        rm.to_string()
+    );
+    assert_eq!(rm, RenderMode::from_bits(rm.to_bits()));
+}
+
+#[test]
+fn test_othermode_gfx() {
+    let hi = gbi::G_CYC_1CYCLE | gbi::G_TF_POINT | gbi::G_PM_1PRIMITIVE;
+    let lo =
+        gbi::G_AC_THRESHOLD | gbi::G_ZS_PRIM | gbi::G_RM_AA_ZB_XLU_SURF | gbi::G_RM_AA_ZB_XLU_SURF2;
+    let gfx = Gfx::new(0xEF00_0000 | hi, lo);
+    let mode = OtherMode::from_gfx(gfx).unwrap();
+
+    assert_eq!(gbi::G_AC_THRESHOLD, mode.lo.alpha_compare);
+    assert_eq!(gbi::G_ZS_PRIM, mode.lo.z_source);
+    assert_eq!(gbi::G_TF_POINT, mode.hi.texture_filter);
+    assert_eq!(
+        "G_AC_THRESHOLD | G_ZS_PRIM | G_RM_AA_ZB_XLU_SURF | G_RM_AA_ZB_XLU_SURF2",
+        mode.lo.to_string()
+    );
+    assert_eq!(gfx, mode.to_gfx());
+    assert_eq!(None, OtherMode::from_gfx(Gfx::new(0xE700_0000, 0)));
+}
+
+#[test]
+fn test_othermode_set() {
+    let mut mode = OtherMode::default();
+
+    mode.set(true, gbi::G_MDSFT_CYCLETYPE, 2, gbi::G_CYC_COPY);
+    mode.set(true, gbi::G_MDSFT_TEXTFILT, 2, gbi::G_TF_AVERAGE);
+    assert_eq!(gbi::G_CYC_COPY, mode.hi.cycle_type);
+    assert_eq!(gbi::G_TF_AVERAGE, mode.hi.texture_filter);
+
+    // Bits outside of the field are left alone.
+    mode.set(true, gbi::G_MDSFT_CYCLETYPE, 2, !0);
+    assert_eq!(gbi::G_CYC_FILL, mode.hi.cycle_type);
+    assert_eq!(gbi::G_TF_AVERAGE, mode.hi.texture_filter);
+
+    mode.set(
+        false,
+        gbi::G_MDSFT_RENDERMODE,
+        29,
+        gbi::G_RM_OPA_SURF | gbi::G_RM_OPA_SURF2,
+    );
+    mode.set(false, gbi::G_MDSFT_ALPHACOMPARE, 2, gbi::G_AC_DITHER);
+    assert_eq!(gbi::G_AC_DITHER, mode.lo.alpha_compare);
+    assert_eq!(
+        Some(("G_RM_OPA_SURF", "G_RM_OPA_SURF2")),
+        mode.lo.render_mode.presets()
+    );
+}
@@ -0,0 +1,109 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::gbi;
+use gfxd_sys::png;
+use gfxd_sys::texture::{TextureRef, TlutFormat, TlutRef};
+use gfxd_sys::traverse::Memory;
+
+fn be32(x: &[u8]) -> u32 {
+    u32::from_be_bytes([x[0], x[1], x[2], x[3]])
+}
+
+/// Splits a PNG file into its chunks, checking the signature and lengths.
+fn chunks(file: &[u8]) -> Vec<(String, Vec<u8>)> {
+    assert_eq!(&file[..8], b"\x89PNG\r\n\x1a\n");
+    let mut out = Vec::new();
+    let mut rest = &file[8..];
+    while !rest.is_empty() {
+        let len = be32(rest) as usize;
+        let kind = String::from_utf8(rest[4..8].to_vec()).unwrap();
+        out.push((kind, rest[8..8 + len].to_vec()));
+        rest = &rest[12 + len..];
+    }
+    out
+}
+
+/// Reads back the data of a zlib stream made of stored blocks.
+fn inflate_stored(stream: &[u8]) -> Vec<u8> {
+    assert_eq!(&stream[..2], &[0x78, 0x01]);
+    let mut out = Vec::new();
+    let mut rest = &stream[2..];
+    loop {
+        let last = rest[0] & 1 != 0;
+        assert_eq!(rest[0] & 6, 0);
+        let len = u16::from_le_bytes([rest[1], rest[2]]) as usize;
+        let nlen = u16::from_le_bytes([rest[3], rest[4]]) as usize;
+        assert_eq!(len, !nlen & 0xFFFF);
+        out.extend_from_slice(&rest[5..5 + len]);
+        rest = &rest[5 + len..];
+        if last {
+            break;
+        }
+    }
+    assert_eq!(rest.len(), 4);
+    out
+}
+
+#[test]
+fn png_encode() {
+    let pixels = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16];
+    let file = png::encode_rgba8(2, 2, &pixels).unwrap();
+    let chunks = chunks(&file);
+
+    assert_eq!(
+        chunks.iter().map(|x| x.0.as_str()).collect::<Vec<_>>(),
+        ["IHDR", "IDAT", "IEND"]
+    );
+    assert_eq!(chunks[0].1, [0, 0, 0, 2, 0, 0, 0, 2, 8, 6, 0, 0, 0]);
+    assert_eq!(
+        inflate_stored(&chunks[1].1),
+        [0, 1, 2, 3, 4, 5, 6, 7, 8, 0, 9, 10, 11, 12, 13, 14, 15, 16]
+    );
+    // The CRC of an empty IEND chunk is always the same.
+    assert_eq!(&file[file.len() - 4..], &[0xAE, 0x42, 0x60, 0x82]);
+
+    assert_eq!(png::encode_rgba8(2, 2, &pixels[1..]), None);
+
+    // Images larger than a stored block are split.
+    let big = vec![0x55; 128 * 256 * 4];
+    let file = png::encode_rgba8(128, 256, &big).unwrap();
+    let chunks = self::chunks(&file);
+    assert_eq!(inflate_stored(&chunks[1].1).len(), (128 * 4 + 1) * 256);
+}
+
+#[test]
+fn png_texture_ref() {
+    let image = [0x01, 0x23];
+    let palette = [0x00, 0x01, 0xF8, 0x01, 0x07, 0xC1, 0x00, 0x3F];
+    let mut memory = Memory::new();
+    memory.add_segment(6, &image);
+    memory.add_region(0x0700_0000, &palette);
+
+    let texture = TextureRef {
+        addr: 0x0600_0000,
+        fmt: gbi::G_IM_FMT_CI,
+        siz: gbi::G_IM_SIZ_4b,
+        width: 2,
+        height: Some(2),
+        pal: 0,
+        tlut: Some(TlutRef {
+            addr: 0x0700_0000,
+            idx: Some(0),
+            count: 4,
+            format: TlutFormat::Rgba16,
+        }),
+    };
+    assert_eq!(texture.file_name(), "06000000.ci4.2x2.png");
+
+    let pixels = texture.decode(&memory).unwrap();
+    assert_eq!(
+        pixels,
+        [0, 0, 0, 0xFF, 0xFF, 0, 0, 0xFF, 0, 0xFF, 0, 0xFF, 0, 0, 0xFF, 0xFF]
+    );
+    assert!(png::encode_rgba8(2, 2, &pixels).is_some());
+}
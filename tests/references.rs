@@ -0,0 +1,202 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::decode::DecodeSettings;
+use gfxd_sys::gbi;
+use gfxd_sys::gfx::Gfx;
+use gfxd_sys::references::{
+    self, ColorImageUse, LightsUse, PaletteUse, Range, References, SegmentUse, TextureUse,
+};
+use gfxd_sys::settings::{Endian, Ucode};
+use gfxd_sys::static_dl::f3dex2::*;
+use gfxd_sys::texture::TextureInfo;
+
+#[test]
+fn test_to_json() {
+    let mut refs = References::new();
+    refs.textures.push(TextureUse {
+        addr: 0x0600_0000,
+        fmt: gbi::G_IM_FMT_CI,
+        siz: gbi::G_IM_SIZ_4b,
+        width: 32,
+        height: Some(16),
+        pal: 1,
+    });
+    refs.textures.push(TextureUse {
+        addr: 0x0600_0800,
+        fmt: gbi::G_IM_FMT_RGBA,
+        siz: gbi::G_IM_SIZ_16b,
+        width: 32,
+        height: None,
+        pal: 0,
+    });
+    refs.palettes.push(PaletteUse {
+        addr: 0x0600_0100,
+        idx: Some(1),
+        count: 16,
+    });
+    refs.vertices.add(0x0600_0200, 4);
+    refs.vertices.add(0x0600_0220, 4);
+    refs.lights.push(LightsUse {
+        addr: 0x0600_0400,
+        num: Some(1),
+    });
+    refs.display_lists.push(0x0600_0800);
+    refs.ucode_text.push(Range {
+        addr: 0x8010_0000,
+        size: 0x1000,
+    });
+
+    assert_eq!(
+        refs.to_json(),
+        "{\"textures\":[{\"addr\":100663296,\"fmt\":2,\"siz\":0,\"format\":\"ci4\",\
+         \"width\":32,\"height\":16,\"pal\":1},\
+         {\"addr\":100665344,\"fmt\":0,\"siz\":2,\"format\":\"rgba16\",\
+         \"width\":32,\"height\":null,\"pal\":0}],\
+         \"palettes\":[{\"addr\":100663552,\"idx\":1,\"count\":16}],\
+         \"vertices\":[{\"addr\":100663808,\"count\":6}],\
+         \"matrices\":[],\
+         \"lights\":[{\"addr\":100664320,\"num\":1}],\
+         \"lookats\":[],\"viewports\":[],\
+         \"display_lists\":[100665344],\
+         \"color_images\":[],\"depth_images\":[],\"segments\":[],\
+         \"ucode_text\":[{\"addr\":2148532224,\"size\":4096}],\
+         \"ucode_data\":[],\"dram\":[]}"
+    );
+}
+
+#[test]
+fn test_texture_info() {
+    let mut texture = TextureUse {
+        addr: 0x0600_0000,
+        fmt: gbi::G_IM_FMT_RGBA,
+        siz: gbi::G_IM_SIZ_16b,
+        width: 32,
+        height: None,
+        pal: 0,
+    };
+    assert_eq!(texture.info(), None);
+
+    texture.height = Some(16);
+    assert_eq!(
+        texture.info(),
+        Some(TextureInfo {
+            fmt: gbi::G_IM_FMT_RGBA,
+            siz: gbi::G_IM_SIZ_16b,
+            width: 32,
+            height: 16,
+            pal: 0,
+        })
+    );
+}
+
+#[test]
+fn test_collect() {
+    let load_block = gsDPLoadTextureBlock(
+        0x0600_1000,
+        gbi::G_IM_FMT_RGBA,
+        gbi::G_IM_SIZ_16b,
+        4,
+        2,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    );
+
+    let mut list = Vec::new();
+    list.push(gsSPSegment(6, 0x8030_0000));
+    list.extend_from_slice(&gsSPLoadUcodeEx(0x8010_0000, 0x8011_0000, 0x800));
+    list.push(gsDPSetColorImage(
+        gbi::G_IM_FMT_RGBA,
+        gbi::G_IM_SIZ_16b,
+        320,
+        0x8040_0000,
+    ));
+    list.push(gsDPSetDepthImage(0x8050_0000));
+    list.push(gsSPViewport(0x0600_5000));
+    list.push(gsSPMatrix(0x0600_4000, 0));
+    list.extend_from_slice(&gsSPSetLights1(0x0600_7000));
+    list.extend_from_slice(&gsSPLookAt(0x0600_8000));
+    // The height of a lone texture image is not known.
+    list.push(gsDPSetTextureImage(
+        gbi::G_IM_FMT_RGBA,
+        gbi::G_IM_SIZ_16b,
+        32,
+        0x0600_0000,
+    ));
+    list.extend_from_slice(&gsDPLoadTLUT_pal16(1, 0x0600_2000));
+    list.extend_from_slice(&load_block);
+    list.extend_from_slice(&load_block);
+    list.push(gsSPVertex(0x0600_3000, 4, 0));
+    list.push(gsSPVertex(0x0600_3020, 4, 0));
+    list.push(gsSPDisplayList(0x0600_6000));
+    list.push(gsSPDisplayList(0x0600_6000));
+    list.push(gsSPEndDisplayList());
+    let input: Vec<u8> = list
+        .iter()
+        .flat_map(|x: &Gfx| x.to_bytes(Endian::gfxd_endian_big, 4).to_vec())
+        .collect();
+
+    let refs = references::collect(&input, &DecodeSettings::new(Ucode::gfxd_f3dex2));
+
+    let mut expected = References::new();
+    expected.segments.push(SegmentUse {
+        addr: 0x8030_0000,
+        seg: 6,
+    });
+    expected.ucode_text.push(Range {
+        addr: 0x8010_0000,
+        size: 0x1000,
+    });
+    expected.ucode_data.push(Range {
+        addr: 0x8011_0000,
+        size: 0x800,
+    });
+    expected.color_images.push(ColorImageUse {
+        addr: 0x8040_0000,
+        fmt: gbi::G_IM_FMT_RGBA,
+        siz: gbi::G_IM_SIZ_16b,
+        width: 320,
+    });
+    expected.depth_images.push(0x8050_0000);
+    expected.viewports.push(0x0600_5000);
+    expected.matrices.push(0x0600_4000);
+    expected.lights.push(LightsUse {
+        addr: 0x0600_7000,
+        num: Some(1),
+    });
+    expected.lookats.push(0x0600_8000);
+    expected.textures.push(TextureUse {
+        addr: 0x0600_0000,
+        fmt: gbi::G_IM_FMT_RGBA,
+        siz: gbi::G_IM_SIZ_16b,
+        width: 32,
+        height: None,
+        pal: 0,
+    });
+    expected.palettes.push(PaletteUse {
+        addr: 0x0600_2000,
+        idx: Some(1),
+        count: 16,
+    });
+    expected.textures.push(TextureUse {
+        addr: 0x0600_1000,
+        fmt: gbi::G_IM_FMT_RGBA,
+        siz: gbi::G_IM_SIZ_16b,
+        width: 4,
+        height: Some(2),
+        pal: 0,
+    });
+    expected.vertices.add(0x0600_3000, 6);
+    expected.display_lists.push(0x0600_6000);
+
+    assert_eq!(refs, expected);
+}
@@ -0,0 +1,202 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "serde")]
+
+use core::fmt;
+
+use pretty_assertions::assert_eq;
+use serde::de::value::{Error as DeError, SeqDeserializer};
+use serde::de::{Deserialize, IntoDeserializer};
+use serde::ser::{Impossible, Serialize, Serializer};
+
+use gfxd_sys::arg_type::ArgType;
+use gfxd_sys::macro_id::MacroId;
+use gfxd_sys::macro_info::ArgFmt;
+use gfxd_sys::settings::{Endian, FeatureOption};
+
+/// The error of [`NameSerializer`], for anything that is not a string.
+#[derive(Debug)]
+struct NotAString;
+
+impl fmt::Display for NotAString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("not a string")
+    }
+}
+
+impl serde::ser::StdError for NotAString {}
+
+impl serde::ser::Error for NotAString {
+    fn custom<T: fmt::Display>(_msg: T) -> Self {
+        NotAString
+    }
+}
+
+/// A serializer that only accepts strings, which is all the enums of the
+/// crate serialize to.
+struct NameSerializer;
+
+macro_rules! not_a_string {
+    ($($method:ident($($arg:ty),*);)*) => {
+        $(
+            fn $method(self, $(_: $arg),*) -> Result<String, NotAString> {
+                Err(NotAString)
+            }
+        )*
+    };
+}
+
+impl Serializer for NameSerializer {
+    type Ok = String;
+    type Error = NotAString;
+    type SerializeSeq = Impossible<String, NotAString>;
+    type SerializeTuple = Impossible<String, NotAString>;
+    type SerializeTupleStruct = Impossible<String, NotAString>;
+    type SerializeTupleVariant = Impossible<String, NotAString>;
+    type SerializeMap = Impossible<String, NotAString>;
+    type SerializeStruct = Impossible<String, NotAString>;
+    type SerializeStructVariant = Impossible<String, NotAString>;
+
+    fn serialize_str(self, v: &str) -> Result<String, NotAString> {
+        Ok(v.into())
+    }
+
+    fn collect_str<T: ?Sized + fmt::Display>(self, value: &T) -> Result<String, NotAString> {
+        Ok(value.to_string())
+    }
+
+    not_a_string! {
+        serialize_bool(bool);
+        serialize_i8(i8);
+        serialize_i16(i16);
+        serialize_i32(i32);
+        serialize_i64(i64);
+        serialize_u8(u8);
+        serialize_u16(u16);
+        serialize_u32(u32);
+        serialize_u64(u64);
+        serialize_f32(f32);
+        serialize_f64(f64);
+        serialize_char(char);
+        serialize_bytes(&[u8]);
+        serialize_none();
+        serialize_unit();
+        serialize_unit_struct(&'static str);
+        serialize_unit_variant(&'static str, u32, &'static str);
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<String, NotAString> {
+        Err(NotAString)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: &T,
+    ) -> Result<String, NotAString> {
+        Err(NotAString)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<String, NotAString> {
+        Err(NotAString)
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, NotAString> {
+        Err(NotAString)
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, NotAString> {
+        Err(NotAString)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, NotAString> {
+        Err(NotAString)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, NotAString> {
+        Err(NotAString)
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, NotAString> {
+        Err(NotAString)
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, NotAString> {
+        Err(NotAString)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, NotAString> {
+        Err(NotAString)
+    }
+}
+
+fn to_name<T: Serialize>(value: &T) -> String {
+    value.serialize(NameSerializer).unwrap()
+}
+
+fn from_name<'de, T: Deserialize<'de>>(name: &'de str) -> Result<T, DeError> {
+    T::deserialize(name.into_deserializer())
+}
+
+#[test]
+fn test_names() {
+    assert_eq!(to_name(&MacroId::gfxd_SPVertex), "gfxd_SPVertex");
+    assert_eq!(to_name(&ArgType::gfxd_Vtxptr), "gfxd_Vtxptr");
+    assert_eq!(to_name(&ArgFmt::gfxd_argfmt_f), "gfxd_argfmt_f");
+    assert_eq!(
+        from_name::<Endian>("gfxd_endian_little").unwrap(),
+        Endian::gfxd_endian_little
+    );
+
+    let seq = SeqDeserializer::<_, DeError>::new(vec!["gfxd_emit_q_macro"].into_iter());
+    assert_eq!(
+        <[FeatureOption; 1]>::deserialize(seq).unwrap(),
+        [FeatureOption::gfxd_emit_q_macro]
+    );
+
+    assert!(from_name::<MacroId>("gsSPVertex").is_err());
+    assert!(Endian::deserialize(IntoDeserializer::<DeError>::into_deserializer(0u32)).is_err());
+}
+
+#[test]
+fn test_round_trip() {
+    for id in (0..=MacroId::MAX).filter_map(MacroId::from_raw) {
+        assert_eq!(from_name::<MacroId>(&to_name(&id)).unwrap(), id);
+    }
+    for type_ in (0..=ArgType::MAX).filter_map(ArgType::from_raw) {
+        assert_eq!(from_name::<ArgType>(&to_name(&type_)).unwrap(), type_);
+    }
+    for &option in FeatureOption::ALL.iter() {
+        assert_eq!(
+            from_name::<FeatureOption>(&to_name(&option)).unwrap(),
+            option
+        );
+    }
+}
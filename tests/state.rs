@@ -0,0 +1,289 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::combiner::Combiner;
+use gfxd_sys::command::Command;
+use gfxd_sys::gbi;
+use gfxd_sys::settings::Ucode;
+use gfxd_sys::state::{Image, LoadKind, MatrixRef, StateTracker, Vertex};
+
+#[test]
+fn test_state_rdp() {
+    let preset = |name: &str| gbi::CC_PRESETS.iter().position(|x| x.name == name).unwrap() as u32;
+    for &ucode in Ucode::ALL.iter() {
+        let mut tracker = StateTracker::new(ucode);
+        let commands = [
+            Command::DPSetCycleType {
+                mode: gbi::G_CYC_2CYCLE,
+            },
+            Command::DPSetRenderMode {
+                mode1: gbi::G_RM_FOG_SHADE_A,
+                mode2: gbi::G_RM_AA_ZB_OPA_SURF2,
+            },
+            Command::DPSetCombineMode {
+                mode1: preset("G_CC_MODULATEI"),
+                mode2: preset("G_CC_PASS2"),
+            },
+            Command::DPSetPrimColor {
+                m: 1,
+                l: 0x80,
+                r: 0x11,
+                g: 0x22,
+                b: 0x33,
+                a: 0x44,
+            },
+            Command::DPSetEnvColor {
+                r: 0xFF,
+                g: 0,
+                b: 0,
+                a: 0xFF,
+            },
+        ];
+        for command in commands.iter() {
+            tracker.apply(command).unwrap();
+        }
+
+        let state = tracker.state();
+        assert_eq!(gbi::G_CYC_2CYCLE, state.othermode.hi.cycle_type);
+        assert_eq!(
+            Some(("G_RM_FOG_SHADE_A", "G_RM_AA_ZB_OPA_SURF2")),
+            state.othermode.lo.render_mode.presets()
+        );
+        assert_eq!(
+            Some(("G_CC_MODULATEI", "G_CC_PASS2")),
+            state.combiner.presets().map(|(x, y)| (x.name, y.name))
+        );
+        assert_eq!(0x1122_3344, state.colors.prim);
+        assert_eq!(1, state.colors.prim_min_level);
+        assert_eq!(0x80, state.colors.prim_lod_frac);
+        assert_eq!(0xFF00_00FF, state.colors.env);
+    }
+}
+
+#[test]
+fn test_state_texture_load() {
+    let mut tracker = StateTracker::new(Ucode::gfxd_f3dex2);
+    let command = Command::DPLoadTextureBlock {
+        timg: 0x0600_1000,
+        fmt: gbi::G_IM_FMT_RGBA,
+        siz: gbi::G_IM_SIZ_16b,
+        width: 32,
+        height: 32,
+        pal: 0,
+        cms: gbi::G_TX_WRAP,
+        cmt: gbi::G_TX_MIRROR,
+        masks: 5,
+        maskt: 5,
+        shifts: 0,
+        shiftt: 0,
+    };
+    tracker.apply(&command).unwrap();
+
+    let state = tracker.state();
+    assert_eq!(
+        Image {
+            fmt: gbi::G_IM_FMT_RGBA,
+            siz: gbi::G_IM_SIZ_16b,
+            width: 1,
+            addr: 0x0600_1000,
+        },
+        state.texture_image
+    );
+
+    let tile = state.tiles[gbi::G_TX_RENDERTILE as usize];
+    assert_eq!(gbi::G_IM_SIZ_16b, tile.siz);
+    assert_eq!(8, tile.line);
+    assert_eq!(gbi::G_TX_MIRROR, tile.cmt);
+    assert_eq!(
+        (0, 0, 31 << 2, 31 << 2),
+        (tile.uls, tile.ult, tile.lrs, tile.lrt)
+    );
+
+    let load = state.load_at(0).unwrap();
+    assert_eq!(LoadKind::Block, load.kind);
+    assert_eq!(0x0600_1000, load.image.addr);
+    assert_eq!(32 * 32 - 1, load.lrs);
+    assert_eq!(None, state.load_at(0x100));
+
+    // A second load to the same address replaces the first one.
+    tracker
+        .apply(&Command::DPLoadTLUT_pal256 { dram: 0x0600_2000 })
+        .unwrap();
+    let state = tracker.state();
+    assert_eq!(LoadKind::Tlut, state.load_at(0x100).unwrap().kind);
+    assert_eq!(0x0600_2000, state.loads[0].unwrap().image.addr);
+    assert_eq!(0x0600_1000, state.loads[1].unwrap().image.addr);
+    assert_eq!(None, state.loads[2]);
+}
+
+#[test]
+fn test_state_rsp() {
+    for &ucode in Ucode::ALL.iter() {
+        let (projection, load, push) = match ucode {
+            Ucode::gfxd_f3dex2 => (
+                gbi::f3dex2::G_MTX_PROJECTION,
+                gbi::f3dex2::G_MTX_LOAD,
+                gbi::f3dex2::G_MTX_PUSH,
+            ),
+            _ => (
+                gbi::f3d::G_MTX_PROJECTION,
+                gbi::f3d::G_MTX_LOAD,
+                gbi::f3d::G_MTX_PUSH,
+            ),
+        };
+        let mut tracker = StateTracker::new(ucode);
+        let commands = [
+            Command::SPSegment {
+                seg: 6,
+                base: 0x8012_0000,
+            },
+            Command::SPMatrix {
+                mtx: 0x0100_0000,
+                param: projection | load,
+            },
+            Command::SPMatrix {
+                mtx: 0x0100_0040,
+                param: load,
+            },
+            Command::SPMatrix {
+                mtx: 0x0100_0080,
+                param: push,
+            },
+            Command::SPVertex {
+                v: 0x0600_0100,
+                n: 4,
+                v0: 2,
+            },
+            Command::SPTexture {
+                sc: 0x8000,
+                tc: 0x4000,
+                level: 0,
+                tile: 1,
+                on: 1,
+            },
+        ];
+        for command in commands.iter() {
+            tracker.apply(command).unwrap();
+        }
+
+        let state = tracker.state();
+        assert_eq!(Some(0x8012_0000), state.segments[6], "{:?}", ucode);
+        assert_eq!(Ok(0x8012_0140), state.resolve(0x0600_0140));
+        assert_eq!(Ok(0x0700_0140), state.resolve(0x0700_0140));
+
+        let stack = &state.matrices;
+        assert_eq!(
+            Some(MatrixRef {
+                addr: 0x0100_0000,
+                load: true
+            }),
+            stack.projection,
+            "{:?}",
+            ucode
+        );
+        assert_eq!(1, stack.depth, "{:?}", ucode);
+        assert_eq!(Some(0x0100_0040), stack.modelview[0].map(|x| x.addr));
+        assert_eq!(
+            Some(MatrixRef {
+                addr: 0x0100_0080,
+                load: false
+            }),
+            stack.current()
+        );
+
+        assert_eq!(None, state.vertices[1]);
+        assert_eq!(
+            Some(Vertex {
+                addr: 0x0600_0130,
+                modified: false
+            }),
+            state.vertices[5],
+            "{:?}",
+            ucode
+        );
+        assert_eq!(None, state.vertices[6]);
+
+        assert!(state.texture.on);
+        assert_eq!(
+            (0x8000, 0x4000, 1),
+            (state.texture.s, state.texture.t, state.texture.tile)
+        );
+
+        let pop = match ucode {
+            Ucode::gfxd_f3dex2 => Command::SPPopMatrixN {
+                param: gbi::f3dex2::G_MTX_MODELVIEW,
+                num: 1,
+            },
+            _ => Command::SPPopMatrix {
+                param: gbi::f3d::G_MTX_MODELVIEW,
+            },
+        };
+        tracker.apply(&pop).unwrap();
+        assert_eq!(0, tracker.state().matrices.depth);
+        assert_eq!(
+            Some(0x0100_0040),
+            tracker.state().matrices.current().map(|x| x.addr)
+        );
+    }
+}
+
+#[test]
+fn test_state_othermode_and_geometry_mode() {
+    let ucode = Ucode::gfxd_f3dex2;
+    let mut tracker = StateTracker::new(ucode);
+    let commands = [
+        Command::SPLoadGeometryMode {
+            mode: gbi::f3dex2::G_ZBUFFER | gbi::f3dex2::G_SHADE,
+        },
+        Command::SPSetGeometryMode {
+            mode: gbi::f3dex2::G_CULL_BACK,
+        },
+        Command::SPClearGeometryMode {
+            mode: gbi::f3dex2::G_SHADE,
+        },
+        Command::DPSetTextureFilter {
+            mode: gbi::G_TF_BILERP,
+        },
+        Command::DPSetAlphaCompare {
+            mode: gbi::G_AC_THRESHOLD,
+        },
+    ];
+    for command in commands.iter() {
+        tracker.apply(command).unwrap();
+    }
+
+    let snapshot = tracker.state().clone();
+    assert_eq!(
+        "G_ZBUFFER | G_CULL_BACK",
+        snapshot.geometry_mode.to_string()
+    );
+    assert_eq!(gbi::G_TF_BILERP, snapshot.othermode.hi.texture_filter);
+    assert_eq!(gbi::G_AC_THRESHOLD, snapshot.othermode.lo.alpha_compare);
+    assert_eq!(Combiner::default(), snapshot.combiner);
+
+    // Snapshots are unaffected by later macros.
+    tracker
+        .apply(&Command::DPSetTextureFilter {
+            mode: gbi::G_TF_POINT,
+        })
+        .unwrap();
+    assert_eq!(gbi::G_TF_BILERP, snapshot.othermode.hi.texture_filter);
+    assert_eq!(gbi::G_TF_POINT, tracker.state().othermode.hi.texture_filter);
+
+    let mut resumed = StateTracker::with_state(snapshot.clone());
+    resumed
+        .apply(&Command::DPSetTextureFilter {
+            mode: gbi::G_TF_AVERAGE,
+        })
+        .unwrap();
+    assert_eq!(
+        gbi::G_AC_THRESHOLD,
+        resumed.state().othermode.lo.alpha_compare
+    );
+    assert_eq!(
+        gbi::G_TF_AVERAGE,
+        resumed.state().othermode.hi.texture_filter
+    );
+}
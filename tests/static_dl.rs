@@ -0,0 +1,726 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::encode;
+use gfxd_sys::gbi;
+use gfxd_sys::gfx::Gfx;
+use gfxd_sys::gs_list;
+use gfxd_sys::macro_id::MacroId;
+use gfxd_sys::settings::Ucode;
+use gfxd_sys::static_dl::{self, f3d, f3dex, f3dex2};
+
+fn check(ucode: Ucode, id: MacroId, args: &[u32], packets: &[Gfx]) {
+    assert_eq!(
+        &encode::encode(ucode, id, args).unwrap()[..],
+        packets,
+        "{:?} for {}",
+        id,
+        ucode.name()
+    );
+}
+
+#[test]
+fn test_static_dl_rdp() {
+    let texture_block = [
+        0x0800_0000,
+        gbi::G_IM_FMT_RGBA,
+        gbi::G_IM_SIZ_16b,
+        32,
+        32,
+        0,
+        gbi::G_TX_NOMIRROR | gbi::G_TX_WRAP,
+        gbi::G_TX_NOMIRROR | gbi::G_TX_WRAP,
+        5,
+        5,
+        gbi::G_TX_NOLOD,
+        gbi::G_TX_NOLOD,
+    ];
+    let texture_tile = [
+        0x0800_0000,
+        gbi::G_IM_FMT_CI,
+        gbi::G_IM_SIZ_8b,
+        64,
+        32,
+        8,
+        4,
+        39,
+        19,
+        0,
+        gbi::G_TX_CLAMP,
+        gbi::G_TX_MIRROR,
+        5,
+        4,
+        gbi::G_TX_NOLOD,
+        gbi::G_TX_NOLOD,
+    ];
+
+    for &ucode in Ucode::ALL.iter() {
+        check(
+            ucode,
+            MacroId::gfxd_DPFillRectangle,
+            &[10, 20, 300, 220],
+            &[static_dl::gsDPFillRectangle(10, 20, 300, 220)],
+        );
+        check(
+            ucode,
+            MacroId::gfxd_DPLoadTLUT_pal16,
+            &[3, 0x0600_1000],
+            &static_dl::gsDPLoadTLUT_pal16(3, 0x0600_1000),
+        );
+        check(
+            ucode,
+            MacroId::gfxd_DPSetPrimColor,
+            &[0, 0x80, 0xFF, 0x20, 0x40, 0xFF],
+            &[static_dl::gsDPSetPrimColor(0, 0x80, 0xFF, 0x20, 0x40, 0xFF)],
+        );
+        check(
+            ucode,
+            MacroId::gfxd_DPSetCombineMode,
+            &[1, 2],
+            &[static_dl::gsDPSetCombineMode(
+                gbi::CC_PRESETS[1],
+                gbi::CC_PRESETS[2],
+            )],
+        );
+        check(
+            ucode,
+            MacroId::gfxd_DPSetScissor,
+            &[gbi::G_SC_NON_INTERLACE, 0, 0, 320, 240],
+            &[static_dl::gsDPSetScissor(
+                gbi::G_SC_NON_INTERLACE,
+                0,
+                0,
+                320,
+                240,
+            )],
+        );
+        check(
+            ucode,
+            MacroId::gfxd_DPSetConvert,
+            &[
+                175,
+                0x1FF & -43i32 as u32,
+                0x1FF & -89i32 as u32,
+                222,
+                114,
+                42,
+            ],
+            &[static_dl::gsDPSetConvert(
+                175,
+                0x1FF & -43i32 as u32,
+                0x1FF & -89i32 as u32,
+                222,
+                114,
+                42,
+            )],
+        );
+        check(
+            ucode,
+            MacroId::gfxd_TexRect,
+            &[0x40, 0x40, 0x80, 0x80, 0],
+            &[static_dl::gsTexRect(0x40, 0x40, 0x80, 0x80, 0)],
+        );
+        check(
+            ucode,
+            MacroId::gfxd_DPLoadTextureTile,
+            &texture_tile,
+            &static_dl::gsDPLoadTextureTile(
+                0x0800_0000,
+                gbi::G_IM_FMT_CI,
+                gbi::G_IM_SIZ_8b,
+                64,
+                32,
+                8,
+                4,
+                39,
+                19,
+                0,
+                gbi::G_TX_CLAMP,
+                gbi::G_TX_MIRROR,
+                5,
+                4,
+                gbi::G_TX_NOLOD,
+                gbi::G_TX_NOLOD,
+            ),
+        );
+    }
+
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_DPLoadTextureBlock,
+        &texture_block,
+        &f3dex2::gsDPLoadTextureBlock(
+            0x0800_0000,
+            gbi::G_IM_FMT_RGBA,
+            gbi::G_IM_SIZ_16b,
+            32,
+            32,
+            0,
+            gbi::G_TX_NOMIRROR | gbi::G_TX_WRAP,
+            gbi::G_TX_NOMIRROR | gbi::G_TX_WRAP,
+            5,
+            5,
+            gbi::G_TX_NOLOD,
+            gbi::G_TX_NOLOD,
+        ),
+    );
+    check(
+        Ucode::gfxd_f3d,
+        MacroId::gfxd_DPLoadTextureBlock_4b,
+        &[0x0800_0000, gbi::G_IM_FMT_I, 64, 64, 0, 0, 0, 6, 6, 0, 0],
+        &f3d::gsDPLoadTextureBlock_4b(0x0800_0000, gbi::G_IM_FMT_I, 64, 64, 0, 0, 0, 6, 6, 0, 0),
+    );
+}
+
+#[test]
+fn test_static_dl_rsp() {
+    check(
+        Ucode::gfxd_f3d,
+        MacroId::gfxd_SPVertex,
+        &[0x0600_0000, 8, 2],
+        &[f3d::gsSPVertex(0x0600_0000, 8, 2)],
+    );
+    check(
+        Ucode::gfxd_f3dex,
+        MacroId::gfxd_SPVertex,
+        &[0x0600_0000, 8, 2],
+        &[f3dex::gsSPVertex(0x0600_0000, 8, 2)],
+    );
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_SPVertex,
+        &[0x0600_0000, 8, 2],
+        &[f3dex2::gsSPVertex(0x0600_0000, 8, 2)],
+    );
+
+    for flag in 0..4 {
+        check(
+            Ucode::gfxd_f3dex,
+            MacroId::gfxd_SP1Quadrangle,
+            &[1, 2, 3, 4, flag],
+            &[f3dex::gsSP1Quadrangle(1, 2, 3, 4, flag)],
+        );
+        check(
+            Ucode::gfxd_f3dex2,
+            MacroId::gfxd_SP1Triangle,
+            &[5, 6, 7, flag],
+            &[f3dex2::gsSP1Triangle(5, 6, 7, flag)],
+        );
+        check(
+            Ucode::gfxd_f3dex,
+            MacroId::gfxd_SPLine3D,
+            &[5, 6, flag],
+            &[f3dex::gsSPLine3D(5, 6, flag)],
+        );
+    }
+
+    check(
+        Ucode::gfxd_f3d,
+        MacroId::gfxd_SPSetLights2,
+        &[0x0600_0100],
+        &f3d::gsSPSetLights2(0x0600_0100),
+    );
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_SPSetLights2,
+        &[0x0600_0100],
+        &f3dex2::gsSPSetLights2(0x0600_0100),
+    );
+    check(
+        Ucode::gfxd_f3dex,
+        MacroId::gfxd_SPForceMatrix,
+        &[0x0600_0200],
+        &f3dex::gsSPForceMatrix(0x0600_0200),
+    );
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_SPForceMatrix,
+        &[0x0600_0200],
+        &f3dex2::gsSPForceMatrix(0x0600_0200),
+    );
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_SPLookAt,
+        &[0x0600_0300],
+        &f3dex2::gsSPLookAt(0x0600_0300),
+    );
+    check(
+        Ucode::gfxd_f3d,
+        MacroId::gfxd_SPLookAt,
+        &[0x0600_0300],
+        &f3d::gsSPLookAt(0x0600_0300),
+    );
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_SPMatrix,
+        &[
+            0x0600_0400,
+            gbi::f3dex2::G_MTX_PUSH | gbi::f3dex2::G_MTX_LOAD,
+        ],
+        &[f3dex2::gsSPMatrix(
+            0x0600_0400,
+            gbi::f3dex2::G_MTX_PUSH | gbi::f3dex2::G_MTX_LOAD,
+        )],
+    );
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_SPSetOtherMode,
+        &[gbi::f3dex2::G_SETOTHERMODE_H, gbi::G_MDSFT_CYCLETYPE, 2, 0],
+        &[f3dex2::gsSPSetOtherMode(
+            gbi::f3dex2::G_SETOTHERMODE_H,
+            gbi::G_MDSFT_CYCLETYPE,
+            2,
+            0,
+        )],
+    );
+    check(
+        Ucode::gfxd_f3dex,
+        MacroId::gfxd_DPSetCycleType,
+        &[gbi::G_CYC_2CYCLE],
+        &[f3dex::gsDPSetCycleType(gbi::G_CYC_2CYCLE)],
+    );
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_SPGeometryMode,
+        &[0x0002_0000, 0x0000_0004],
+        &[f3dex2::gsSPGeometryMode(0x0002_0000, 0x0000_0004)],
+    );
+    check(
+        Ucode::gfxd_f3d,
+        MacroId::gfxd_SPFogPosition,
+        &[900, 1000],
+        &[f3d::gsSPFogPosition(900, 1000)],
+    );
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_SPClipRatio,
+        &[2],
+        &f3dex2::gsSPClipRatio(2),
+    );
+    check(
+        Ucode::gfxd_f3dex,
+        MacroId::gfxd_SPModifyVertex,
+        &[3, gbi::G_MWO_POINT_ST, 0x0100_0200],
+        &[f3dex::gsSPModifyVertex(3, gbi::G_MWO_POINT_ST, 0x0100_0200)],
+    );
+    check(
+        Ucode::gfxd_f3dex2,
+        MacroId::gfxd_SPTextureRectangle,
+        &[0x40, 0x40, 0x80, 0x80, 0, 0, 0, 0x400, 0x400],
+        &f3dex2::gsSPTextureRectangle(0x40, 0x40, 0x80, 0x80, 0, 0, 0, 0x400, 0x400),
+    );
+    check(
+        Ucode::gfxd_f3dex,
+        MacroId::gfxd_SPLoadUcodeEx,
+        &[0x8010_0000, 0x8020_0000, 0x800],
+        &f3dex::gsSPLoadUcodeEx(0x8010_0000, 0x8020_0000, 0x800),
+    );
+}
+
+/// Checks every listed builder of `$family` against `encode::encode` with the
+/// same arguments, for each microcode in `$ucodes`.
+macro_rules! check_builders {
+    ($family:ident, $ucodes:expr; $($name:ident($($arg:expr),* $(,)?);)*) => {
+        for &ucode in $ucodes.iter() {
+            $(
+                check(
+                    ucode,
+                    MacroId::from_name(stringify!($name)).unwrap(),
+                    &[$($arg),*],
+                    &gs_list!($family; $name($($arg),*)),
+                );
+            )*
+        }
+    };
+}
+
+/// Checks the RDP builders, which every family re-exports.
+macro_rules! check_rdp_builders {
+    ($family:ident, $ucodes:expr) => {
+        check_builders!($family, $ucodes;
+            gsDPFillRectangle(10, 20, 300, 220);
+            gsDPFullSync();
+            gsDPLoadSync();
+            gsDPTileSync();
+            gsDPPipeSync();
+            gsDPLoadTLUT_pal16(3, 0x0600_0000);
+            gsDPLoadTLUT_pal256(0x0600_0100);
+            gsDPLoadMultiTileYuv(
+                0x0600_0200, 0x100, 1, 2, 1, 32, 16, 0, 0, 31, 15, 3, 2, 2, 5, 4, 1, 1,
+            );
+            gsDPLoadMultiTile_4b(
+                0x0600_0300, 0x100, 1, 2, 32, 16, 0, 0, 31, 15, 3, 2, 2, 5, 4, 1, 1,
+            );
+            gsDPLoadMultiTile(
+                0x0600_0400, 0x100, 1, 2, 1, 32, 16, 0, 0, 31, 15, 3, 2, 2, 5, 4, 1, 1,
+            );
+            _gsDPLoadTextureTileYuv(
+                0x0600_0500, 0x100, 2, 1, 32, 16, 0, 0, 31, 15, 3, 2, 2, 5, 4, 1, 1,
+            );
+            _gsDPLoadTextureTile_4b(
+                0x0600_0600, 0x100, 2, 32, 16, 0, 0, 31, 15, 3, 2, 2, 5, 4, 1, 1,
+            );
+            _gsDPLoadTextureTile(
+                0x0600_0700, 0x100, 2, 1, 32, 16, 0, 0, 31, 15, 3, 2, 2, 5, 4, 1, 1,
+            );
+            gsDPLoadTextureTileYuv(0x0600_0800, 2, 1, 32, 16, 0, 0, 31, 15, 3, 2, 2, 5, 4, 1, 1);
+            gsDPLoadTextureTile_4b(0x0600_0900, 2, 32, 16, 0, 0, 31, 15, 3, 2, 2, 5, 4, 1, 1);
+            gsDPLoadTextureTile(0x0600_0A00, 2, 1, 32, 16, 0, 0, 31, 15, 3, 2, 2, 5, 4, 1, 1);
+            gsDPLoadBlock(2, 0, 0, 31, 0x800);
+            gsDPSetBlendColor(0x80, 0x80, 0x80, 0x80);
+            gsDPSetEnvColor(0x80, 0x80, 0x80, 0x80);
+            gsDPSetFillColor(0x1234_5678);
+            gsDPSetFogColor(0x80, 0x80, 0x80, 0x80);
+            gsDPSetPrimColor(0x80, 0x80, 0x80, 0x80, 0x80, 0x80);
+            gsDPSetColorImage(2, 1, 32, 0x0600_0B00);
+            gsDPSetDepthImage(0x0600_0C00);
+            gsDPSetTextureImage(2, 1, 32, 0x0600_0D00);
+            gsDPSetCombineLERP(1, 4, 3, 5, 3, 3, 4, 3, 1, 4, 3, 5, 3, 3, 4, 3);
+            gsDPSetConvert(100, 100, 100, 100, 100, 100);
+            gsDPSetKeyGB(0x80, 0x10, 0x123, 0x80, 0x10, 0x123);
+            gsDPSetKeyR(0x80, 0x10, 0x123);
+            gsDPSetPrimDepth(0x100, 0x10);
+            gsDPSetScissor(0, 10, 20, 300, 220);
+            gsDPSetScissorFrac(0, 0x40, 0x40, 0x80, 0x80);
+            gsDPSetTile(2, 1, 8, 0x100, 2, 3, 2, 4, 1, 2, 5, 1);
+            gsDPSetTileSize(2, 0, 0, 0x7C, 0x3C);
+            gsDPLoadTLUTCmd(2, 16);
+            gsDPLoadTLUT(16, 0x100, 0x0600_0E00);
+            gsDPLoadTile(2, 0, 0, 0x7C, 0x3C);
+            gsDPSetOtherMode(0x0010_0000, 0x0000_2078);
+            gsTexRect(0x40, 0x40, 0x80, 0x80, 2);
+            gsTexRectFlip(0x40, 0x40, 0x80, 0x80, 2);
+        );
+    };
+}
+
+#[test]
+fn test_static_dl_every_builder() {
+    // `gsDPSetCombineMode` takes the presets themselves instead of their
+    // index, it is checked in `test_static_dl_rdp`.
+    let f3d_ucodes = [Ucode::gfxd_f3d, Ucode::gfxd_f3db];
+    let f3dex_ucodes = [Ucode::gfxd_f3dex, Ucode::gfxd_f3dexb];
+    let f3dex2_ucodes = [Ucode::gfxd_f3dex2];
+
+    check_rdp_builders!(f3d, f3d_ucodes);
+    check_rdp_builders!(f3dex, f3dex_ucodes);
+    check_rdp_builders!(f3dex2, f3dex2_ucodes);
+
+    check_builders!(f3d, f3d_ucodes;
+        gsDPLoadMultiBlockYuvS(0x0600_0F00, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlockYuv(0x0600_1000, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlock_4bS(0x0600_1100, 0x100, 1, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlock_4b(0x0600_1200, 0x100, 1, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlockS(0x0600_1300, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlock(0x0600_1400, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlockYuvS(0x0600_1500, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlockYuv(0x0600_1600, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlock_4bS(0x0600_1700, 0x100, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlock_4b(0x0600_1800, 0x100, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlockS(0x0600_1900, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlock(0x0600_1A00, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlockYuvS(0x0600_1B00, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlockYuv(0x0600_1C00, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlock_4bS(0x0600_1D00, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlock_4b(0x0600_1E00, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlockS(0x0600_1F00, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlock(0x0600_2000, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPNoOp();
+        gsDPNoOpTag(0x12);
+        gsDPPipelineMode(0x0080_0000);
+        gsDPSetAlphaCompare(1);
+        gsDPSetAlphaDither(0x10);
+        gsDPSetColorDither(0x40);
+        gsDPSetTextureConvert(0x0000_0C00);
+        gsDPSetCycleType(0x0010_0000);
+        gsDPSetDepthSource(4);
+        gsDPSetCombineKey(0x100);
+        gsDPSetRenderMode(0x0044_2078, 0x0011_2078);
+        gsDPSetTextureDetail(0x0000_0040);
+        gsDPSetTextureFilter(0x0000_2000);
+        gsDPSetTextureLOD(0x0001_0000);
+        gsDPSetTextureLUT(0x0000_8000);
+        gsDPSetTexturePersp(0x0008_0000);
+        gsSP1Triangle(1, 2, 3, 1);
+        gsSPBranchList(0x0600_2100);
+        gsSPClipRatio(0x20);
+        gsSPCullDisplayList(1, 15);
+        gsSPDisplayList(0x0600_2200);
+        gsSPEndDisplayList();
+        gsSPFogFactor(0x100, 0x200);
+        gsSPFogPosition(900, 1000);
+        gsSPForceMatrix(0x0600_2300);
+        gsSPSetGeometryMode(0x0002_0005);
+        gsSPClearGeometryMode(0x0002_0005);
+        gsSPInsertMatrix(0x18, 0x1234_5678);
+        gsSPLine3D(1, 2, 1);
+        gsSPLineW3D(1, 2, 3, 1);
+        gsSPLookAtX(0x0600_2400);
+        gsSPLookAtY(0x0600_2500);
+        gsSPLookAt(0x0600_2600);
+        gsSPMatrix(0x0600_2700, 1);
+        gsSPModifyVertex(1, 0x14, 0x1234_5678);
+        gsSPPerspNormalize(0x100);
+        gsSPPopMatrix(0);
+        gsSPSegment(6, 0x0600_2800);
+        gsSPSetLights1(0x0600_2900);
+        gsSPSetLights2(0x0600_2A00);
+        gsSPSetLights3(0x0600_2B00);
+        gsSPSetLights4(0x0600_2C00);
+        gsSPSetLights5(0x0600_2D00);
+        gsSPSetLights6(0x0600_2E00);
+        gsSPSetLights7(0x0600_2F00);
+        gsSPNumLights(4);
+        gsSPLight(0x0600_3000, 4);
+        gsSPLightColor(4, 0x1234_5678);
+        gsSPTexture(0x8000, 0x4000, 1, 2, 1);
+        gsSPTextureRectangle(0x40, 0x40, 0x80, 0x80, 2, 0x20, 0x40, 0x400, 0x200);
+        gsSPTextureRectangleFlip(0x40, 0x40, 0x80, 0x80, 2, 0x20, 0x40, 0x400, 0x200);
+        gsSPVertex(0x0600_3100, 4, 3);
+        gsSPViewport(0x0600_3200);
+        gsDisplayList(0x0600_3300, 1);
+        gsDPHalf1(0x1234_5678);
+        gsDPHalf2(0x1234_5678);
+        gsDPWord(0x1234_5678, 0x1234_5678);
+        gsSPSetOtherMode(0xE7, 3, 2, 0x0000_2078);
+        gsSPSetOtherModeLo(3, 2, 0x0000_2078);
+        gsSPSetOtherModeHi(20, 2, 0x0010_0000);
+        gsMoveWd(6, 0x18, 0x1234_5678);
+        gsMoveMem(0x800, 0x86, 0x18, 0x0600_3400);
+        gsSPNoOp();
+    );
+    check_builders!(f3dex, f3dex_ucodes;
+        gsDPLoadMultiBlockYuvS(0x0600_3500, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlockYuv(0x0600_3600, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlock_4bS(0x0600_3700, 0x100, 1, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlock_4b(0x0600_3800, 0x100, 1, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlockS(0x0600_3900, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlock(0x0600_3A00, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlockYuvS(0x0600_3B00, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlockYuv(0x0600_3C00, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlock_4bS(0x0600_3D00, 0x100, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlock_4b(0x0600_3E00, 0x100, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlockS(0x0600_3F00, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlock(0x0600_4000, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlockYuvS(0x0600_4100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlockYuv(0x0600_4200, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlock_4bS(0x0600_4300, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlock_4b(0x0600_4400, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlockS(0x0600_4500, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlock(0x0600_4600, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPNoOp();
+        gsDPNoOpTag(0x12);
+        gsDPPipelineMode(0x0080_0000);
+        gsDPSetAlphaCompare(1);
+        gsDPSetAlphaDither(0x10);
+        gsDPSetColorDither(0x40);
+        gsDPSetTextureConvert(0x0000_0C00);
+        gsDPSetCycleType(0x0010_0000);
+        gsDPSetDepthSource(4);
+        gsDPSetCombineKey(0x100);
+        gsDPSetRenderMode(0x0044_2078, 0x0011_2078);
+        gsDPSetTextureDetail(0x0000_0040);
+        gsDPSetTextureFilter(0x0000_2000);
+        gsDPSetTextureLOD(0x0001_0000);
+        gsDPSetTextureLUT(0x0000_8000);
+        gsDPSetTexturePersp(0x0008_0000);
+        gsSP1Triangle(1, 2, 3, 1);
+        gsSP2Triangles(1, 2, 3, 1, 5, 6, 7, 1);
+        gsSP1Quadrangle(1, 2, 3, 4, 1);
+        gsSPBranchLessZraw(0x0600_4700, 2, 0x1234);
+        gsSPBranchList(0x0600_4800);
+        gsSPClipRatio(0x20);
+        gsSPCullDisplayList(1, 15);
+        gsSPDisplayList(0x0600_4900);
+        gsSPEndDisplayList();
+        gsSPFogFactor(0x100, 0x200);
+        gsSPFogPosition(900, 1000);
+        gsSPForceMatrix(0x0600_4A00);
+        gsSPSetGeometryMode(0x0002_0005);
+        gsSPClearGeometryMode(0x0002_0005);
+        gsSPInsertMatrix(0x18, 0x1234_5678);
+        gsSPLine3D(1, 2, 1);
+        gsSPLineW3D(1, 2, 3, 1);
+        gsSPLoadUcode(0x0600_4B00, 0x0600_4C00);
+        gsSPLookAtX(0x0600_4D00);
+        gsSPLookAtY(0x0600_4E00);
+        gsSPLookAt(0x0600_4F00);
+        gsSPMatrix(0x0600_5000, 1);
+        gsSPModifyVertex(1, 0x14, 0x1234_5678);
+        gsSPPerspNormalize(0x100);
+        gsSPPopMatrix(0);
+        gsSPSegment(6, 0x0600_5100);
+        gsSPSetLights1(0x0600_5200);
+        gsSPSetLights2(0x0600_5300);
+        gsSPSetLights3(0x0600_5400);
+        gsSPSetLights4(0x0600_5500);
+        gsSPSetLights5(0x0600_5600);
+        gsSPSetLights6(0x0600_5700);
+        gsSPSetLights7(0x0600_5800);
+        gsSPNumLights(4);
+        gsSPLight(0x0600_5900, 4);
+        gsSPLightColor(4, 0x1234_5678);
+        gsSPTexture(0x8000, 0x4000, 1, 2, 1);
+        gsSPTextureRectangle(0x40, 0x40, 0x80, 0x80, 2, 0x20, 0x40, 0x400, 0x200);
+        gsSPTextureRectangleFlip(0x40, 0x40, 0x80, 0x80, 2, 0x20, 0x40, 0x400, 0x200);
+        gsSPVertex(0x0600_5A00, 4, 3);
+        gsSPViewport(0x0600_5B00);
+        gsBranchZ(1, 0x1234);
+        gsDisplayList(0x0600_5C00, 1);
+        gsDPHalf1(0x1234_5678);
+        gsDPHalf2(0x1234_5678);
+        gsDPWord(0x1234_5678, 0x1234_5678);
+        gsSPSetOtherMode(0xE7, 3, 2, 0x0000_2078);
+        gsSPSetOtherModeLo(3, 2, 0x0000_2078);
+        gsSPSetOtherModeHi(20, 2, 0x0010_0000);
+        gsMoveWd(6, 0x18, 0x1234_5678);
+        gsMoveMem(0x800, 0x86, 0x18, 0x0600_5D00);
+        gsLoadUcode(0x0600_5E00, 0x800);
+        gsSPLoadUcodeEx(0x0600_5F00, 0x0600_6000, 0x800);
+        gsSPNoOp();
+    );
+    check_builders!(f3dex2, f3dex2_ucodes;
+        gsDPLoadMultiBlockYuvS(0x0600_6100, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlockYuv(0x0600_6200, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlock_4bS(0x0600_6300, 0x100, 1, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlock_4b(0x0600_6400, 0x100, 1, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlockS(0x0600_6500, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadMultiBlock(0x0600_6600, 0x100, 1, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlockYuvS(0x0600_6700, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlockYuv(0x0600_6800, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlock_4bS(0x0600_6900, 0x100, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlock_4b(0x0600_6A00, 0x100, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlockS(0x0600_6B00, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        _gsDPLoadTextureBlock(0x0600_6C00, 0x100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlockYuvS(0x0600_6D00, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlockYuv(0x0600_6E00, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlock_4bS(0x0600_6F00, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlock_4b(0x0600_7000, 2, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlockS(0x0600_7100, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPLoadTextureBlock(0x0600_7200, 2, 1, 32, 16, 3, 2, 2, 5, 4, 1, 1);
+        gsDPNoOp();
+        gsDPNoOpTag(0x12);
+        gsDPPipelineMode(0x0080_0000);
+        gsDPSetAlphaCompare(1);
+        gsDPSetAlphaDither(0x10);
+        gsDPSetColorDither(0x40);
+        gsDPSetTextureConvert(0x0000_0C00);
+        gsDPSetCycleType(0x0010_0000);
+        gsDPSetDepthSource(4);
+        gsDPSetCombineKey(0x100);
+        gsDPSetRenderMode(0x0044_2078, 0x0011_2078);
+        gsDPSetTextureDetail(0x0000_0040);
+        gsDPSetTextureFilter(0x0000_2000);
+        gsDPSetTextureLOD(0x0001_0000);
+        gsDPSetTextureLUT(0x0000_8000);
+        gsDPSetTexturePersp(0x0008_0000);
+        gsSP1Triangle(1, 2, 3, 1);
+        gsSP2Triangles(1, 2, 3, 1, 5, 6, 7, 1);
+        gsSP1Quadrangle(1, 2, 3, 4, 1);
+        gsSPBranchLessZraw(0x0600_7300, 2, 0x1234);
+        gsSPBranchList(0x0600_7400);
+        gsSPClipRatio(0x20);
+        gsSPCullDisplayList(1, 15);
+        gsSPDisplayList(0x0600_7500);
+        gsSPEndDisplayList();
+        gsSPFogFactor(0x100, 0x200);
+        gsSPFogPosition(900, 1000);
+        gsSPForceMatrix(0x0600_7600);
+        gsSPSetGeometryMode(0x0002_0005);
+        gsSPClearGeometryMode(0x0002_0005);
+        gsSPLoadGeometryMode(0x0002_0005);
+        gsSPInsertMatrix(0x18, 0x1234_5678);
+        gsSPLine3D(1, 2, 1);
+        gsSPLineW3D(1, 2, 3, 1);
+        gsSPLoadUcode(0x0600_7700, 0x0600_7800);
+        gsSPLookAtX(0x0600_7900);
+        gsSPLookAtY(0x0600_7A00);
+        gsSPLookAt(0x0600_7B00);
+        gsSPMatrix(0x0600_7C00, 1);
+        gsSPModifyVertex(1, 0x14, 0x1234_5678);
+        gsSPPerspNormalize(0x100);
+        gsSPPopMatrix(0);
+        gsSPPopMatrixN(0, 2);
+        gsSPSegment(6, 0x0600_7D00);
+        gsSPSetLights1(0x0600_7E00);
+        gsSPSetLights2(0x0600_7F00);
+        gsSPSetLights3(0x0600_8000);
+        gsSPSetLights4(0x0600_8100);
+        gsSPSetLights5(0x0600_8200);
+        gsSPSetLights6(0x0600_8300);
+        gsSPSetLights7(0x0600_8400);
+        gsSPNumLights(4);
+        gsSPLight(0x0600_8500, 4);
+        gsSPLightColor(4, 0x1234_5678);
+        gsSPTexture(0x8000, 0x4000, 1, 2, 1);
+        gsSPTextureRectangle(0x40, 0x40, 0x80, 0x80, 2, 0x20, 0x40, 0x400, 0x200);
+        gsSPTextureRectangleFlip(0x40, 0x40, 0x80, 0x80, 2, 0x20, 0x40, 0x400, 0x200);
+        gsSPVertex(0x0600_8600, 4, 3);
+        gsSPViewport(0x0600_8700);
+        gsBranchZ(1, 0x1234);
+        gsDisplayList(0x0600_8800, 1);
+        gsDPHalf1(0x1234_5678);
+        gsDPHalf2(0x1234_5678);
+        gsDPWord(0x1234_5678, 0x1234_5678);
+        gsSPGeometryMode(0x0002_0005, 0x0002_0005);
+        gsSPSetOtherMode(0xE7, 3, 2, 0x0000_2078);
+        gsSPSetOtherModeLo(3, 2, 0x0000_2078);
+        gsSPSetOtherModeHi(20, 2, 0x0010_0000);
+        gsMoveWd(6, 0x18, 0x1234_5678);
+        gsMoveMem(0x800, 0x86, 0x18, 0x0600_8900);
+        gsSPDma_io(0, 0x100, 0x0600_8A00, 0x800);
+        gsSPDmaRead(0x100, 0x0600_8B00, 0x800);
+        gsSPDmaWrite(0x100, 0x0600_8C00, 0x800);
+        gsLoadUcode(0x0600_8D00, 0x800);
+        gsSPLoadUcodeEx(0x0600_8E00, 0x0600_8F00, 0x800);
+        gsSPNoOp();
+        gsSpecial3(0x1234_5678, 0x1234_5678);
+        gsSpecial2(0x1234_5678, 0x1234_5678);
+        gsSpecial1(0x1234_5678, 0x1234_5678);
+    );
+}
+
+static DL: [Gfx; 16] = gs_list!(f3dex2;
+    gsDPPipeSync(),
+    gsDPSetCombineMode(gbi::G_CC_MODULATEIDECALA, gbi::G_CC_PASS2),
+    gsDPLoadTLUT_pal256(0x0600_1000),
+    gsSPVertex(0x0600_0000, 3, 0),
+    gsSPForceMatrix(0x0600_0200),
+    gsSP1Triangle(0, 1, 2, 0),
+    gsSPTextureRectangle(0x40, 0x40, 0x80, 0x80, 0, 0, 0, 0x400, 0x400),
+    gsSPEndDisplayList(),
+);
+
+#[test]
+fn test_static_dl_gs_list() {
+    let mut expected = Vec::new();
+    let mut push = |id, args: &[u32]| {
+        expected.extend_from_slice(&encode::encode(Ucode::gfxd_f3dex2, id, args).unwrap());
+    };
+    let preset = |name: &str| gbi::CC_PRESETS.iter().position(|x| x.name == name).unwrap() as u32;
+
+    push(MacroId::gfxd_DPPipeSync, &[]);
+    push(
+        MacroId::gfxd_DPSetCombineMode,
+        &[preset("G_CC_MODULATEIDECALA"), preset("G_CC_PASS2")],
+    );
+    push(MacroId::gfxd_DPLoadTLUT_pal256, &[0x0600_1000]);
+    push(MacroId::gfxd_SPVertex, &[0x0600_0000, 3, 0]);
+    push(MacroId::gfxd_SPForceMatrix, &[0x0600_0200]);
+    push(MacroId::gfxd_SP1Triangle, &[0, 1, 2, 0]);
+    push(
+        MacroId::gfxd_SPTextureRectangle,
+        &[0x40, 0x40, 0x80, 0x80, 0, 0, 0, 0x400, 0x400],
+    );
+    push(MacroId::gfxd_SPEndDisplayList, &[]);
+
+    assert_eq!(&expected[..], &DL[..]);
+
+    const EMPTY: [Gfx; 0] = gs_list!(f3d;);
+    assert_eq!(0, EMPTY.len());
+
+    const FORCE: [Gfx; 5] = gs_list!(f3d; gsSPForceMatrix(0x0600_0200), gsSPNoOp());
+    assert_eq!(&f3d::gsSPForceMatrix(0x0600_0200)[..], &FORCE[..4]);
+}
@@ -0,0 +1,148 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use gfxd_sys::symbols::{SymbolMap, SymbolParseError};
+#[cfg(feature = "std")]
+use gfxd_sys::{
+    ffi,
+    gfx::Gfx,
+    ptr::{NonNullConst, NonNullMut},
+    settings::Endian,
+    static_dl::f3dex2::*,
+};
+
+#[test]
+fn test_parse_formats() {
+    let map = SymbolMap::parse_symbol_addrs(
+        "// Textures\n\
+         gTex = 0x06000000; // size:0x800 type:u16\n\
+         \n\
+         gDl = 0x06000800;\n",
+    )
+    .unwrap();
+    assert_eq!(map.len(), 2);
+    assert_eq!(map.get(0x0600_0000).unwrap().size, Some(0x800));
+    assert_eq!(map.get(0x0600_0800).unwrap().name, "gDl");
+    assert_eq!(
+        SymbolMap::parse_symbol_addrs("gDl = 0x06000800;\ngTex 0x06000000;\n"),
+        Err(SymbolParseError { line: 2 })
+    );
+
+    let map = SymbolMap::parse_ld_map(
+        " .text          0x0000000080000400     0x1230 build/src/main.o\n\
+         \x20               0x0000000080000400                main\n\
+         \x20               0x0000000080000460                . = ALIGN (0x10)\n\
+         \x20               0x0000000080000480                gfx_init\n",
+    );
+    let names: Vec<_> = map.iter().map(|x| (x.addr, x.name.as_str())).collect();
+    assert_eq!(names, [(0x8000_0400, "main"), (0x8000_0480, "gfx_init")]);
+
+    let map =
+        SymbolMap::parse_csv("name,addr,size\ngTex,0x06000000,0x800\n0x06000800,gDl\n").unwrap();
+    assert_eq!(map.get(0x0600_0000).unwrap().size, Some(0x800));
+    assert_eq!(map.get(0x0600_0800).unwrap().name, "gDl");
+    assert_eq!(
+        SymbolMap::parse_csv("gTex,0x06000000\ngDl,x\n"),
+        Err(SymbolParseError { line: 2 })
+    );
+}
+
+#[test]
+fn test_name() {
+    let mut map = SymbolMap::new();
+    map.add("gTex", 0x0600_0000, Some(0x800));
+    map.add("gDl", 0x0600_0800, None);
+
+    assert_eq!(map.name(0x0600_0000).unwrap(), "gTex");
+    assert_eq!(map.name(0x0600_0010).unwrap(), "gTex + 0x10");
+    assert_eq!(map.name(0x0600_0800).unwrap(), "gDl");
+    assert_eq!(map.name(0x0600_0808), None);
+    assert_eq!(map.name(0x05FF_FFFF), None);
+}
+
+/// Prints every macro of `list` with `f3dex2`, one per line, on the
+/// current config.
+#[cfg(feature = "std")]
+fn print_list(list: &[Gfx]) -> String {
+    unsafe extern "C" fn output(buf: NonNullConst<ffi::c_char>, count: ffi::c_int) -> ffi::c_int {
+        let out = unsafe { gfxd_sys::settings::gfxd_udata_get() }.unwrap();
+        let out = unsafe { out.cast::<Vec<u8>>().as_mut() };
+        let bytes =
+            unsafe { std::slice::from_raw_parts(buf.as_ptr() as *const u8, count as usize) };
+        out.extend_from_slice(bytes);
+        count
+    }
+
+    unsafe extern "C" fn macro_fn() -> ffi::c_int {
+        unsafe {
+            gfxd_sys::handlers::gfxd_macro_dflt();
+            gfxd_sys::custom_output::gfxd_write(NonNullConst::new_void(b"\n".as_ptr()).unwrap(), 1);
+        }
+        0
+    }
+
+    let input: Vec<u8> = list
+        .iter()
+        .flat_map(|x| x.to_bytes(Endian::gfxd_endian_big, 4).to_vec())
+        .collect();
+    let mut out = Vec::new();
+    unsafe {
+        gfxd_sys::io::gfxd_input_buffer(NonNullConst::new_void(input.as_ptr()), input.len() as _);
+        gfxd_sys::io::gfxd_output_callback(Some(output));
+        gfxd_sys::handlers::gfxd_macro_fn(Some(macro_fn));
+        gfxd_sys::settings::gfxd_udata_set(NonNullMut::new_void(&mut out));
+        gfxd_sys::settings::gfxd_target(Some(gfxd_sys::settings::gfxd_f3dex2));
+        gfxd_sys::execution::gfxd_execute();
+    }
+    String::from_utf8(out).unwrap()
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_with_installed() {
+    let list = [
+        gsSPDisplayList(0x0600_0000),
+        gsSPDisplayList(0x0600_0010),
+        gsSPEndDisplayList(),
+    ];
+
+    let mut outer = SymbolMap::new();
+    outer.add("gTex", 0x0600_0000, Some(0x800));
+    let mut inner = SymbolMap::new();
+    inner.add("gDl", 0x0600_0000, None);
+
+    outer.with_installed(|| {
+        assert_eq!(
+            print_list(&list),
+            "gsSPDisplayList(gTex)\n\
+             gsSPDisplayList(gTex + 0x10)\n\
+             gsSPEndDisplayList()\n"
+        );
+
+        inner.with_installed(|| {
+            assert_eq!(
+                print_list(&list),
+                "gsSPDisplayList(gDl)\n\
+                 gsSPDisplayList(0x06000010)\n\
+                 gsSPEndDisplayList()\n"
+            );
+        });
+
+        // Returning from the inner map installs the outer one again.
+        assert_eq!(
+            print_list(&list),
+            "gsSPDisplayList(gTex)\n\
+             gsSPDisplayList(gTex + 0x10)\n\
+             gsSPEndDisplayList()\n"
+        );
+    });
+
+    assert_eq!(
+        print_list(&list),
+        "gsSPDisplayList(0x06000000)\n\
+         gsSPDisplayList(0x06000010)\n\
+         gsSPEndDisplayList()\n"
+    );
+}
@@ -0,0 +1,327 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::decode::DecodeSettings;
+use gfxd_sys::gbi;
+use gfxd_sys::gfx::Gfx;
+use gfxd_sys::othermode::OtherModeHi;
+use gfxd_sys::settings::{Endian, Ucode};
+use gfxd_sys::static_dl::f3dex2::*;
+use gfxd_sys::texture::{self, TextureError, TextureInfo, TextureRef, Tlut, TlutFormat, TlutRef};
+use gfxd_sys::traverse::Memory;
+
+fn info(fmt: u32, siz: u32, width: u32, height: u32) -> TextureInfo {
+    TextureInfo {
+        fmt,
+        siz,
+        width,
+        height,
+        pal: 0,
+    }
+}
+
+#[test]
+fn texture_direct_formats() {
+    let rgba16 = info(gbi::G_IM_FMT_RGBA, gbi::G_IM_SIZ_16b, 2, 1);
+    assert_eq!(
+        texture::decode(&rgba16, &[0xF8, 0x01, 0x07, 0xC0], None).unwrap(),
+        vec![0xFF, 0, 0, 0xFF, 0, 0xFF, 0, 0]
+    );
+
+    let rgba32 = info(gbi::G_IM_FMT_RGBA, gbi::G_IM_SIZ_32b, 1, 1);
+    assert_eq!(
+        texture::decode(&rgba32, &[1, 2, 3, 4], None).unwrap(),
+        vec![1, 2, 3, 4]
+    );
+
+    let ia4 = info(gbi::G_IM_FMT_IA, gbi::G_IM_SIZ_4b, 2, 1);
+    assert_eq!(
+        texture::decode(&ia4, &[0xF4], None).unwrap(),
+        vec![0xFF, 0xFF, 0xFF, 0xFF, 0x49, 0x49, 0x49, 0]
+    );
+
+    let ia8 = info(gbi::G_IM_FMT_IA, gbi::G_IM_SIZ_8b, 1, 1);
+    assert_eq!(
+        texture::decode(&ia8, &[0x3C], None).unwrap(),
+        vec![0x33, 0x33, 0x33, 0xCC]
+    );
+
+    let ia16 = info(gbi::G_IM_FMT_IA, gbi::G_IM_SIZ_16b, 1, 1);
+    assert_eq!(
+        texture::decode(&ia16, &[0x12, 0x34], None).unwrap(),
+        vec![0x12, 0x12, 0x12, 0x34]
+    );
+
+    let i4 = info(gbi::G_IM_FMT_I, gbi::G_IM_SIZ_4b, 3, 1);
+    assert_eq!(
+        texture::decode(&i4, &[0x1F, 0x80], None).unwrap(),
+        vec![0x11, 0x11, 0x11, 0x11, 0xFF, 0xFF, 0xFF, 0xFF, 0x88, 0x88, 0x88, 0x88]
+    );
+
+    let i8 = info(gbi::G_IM_FMT_I, gbi::G_IM_SIZ_8b, 1, 1);
+    assert_eq!(
+        texture::decode(&i8, &[0x42], None).unwrap(),
+        vec![0x42, 0x42, 0x42, 0x42]
+    );
+
+    // Gray has no chroma, so it stays gray.
+    let yuv = info(gbi::G_IM_FMT_YUV, gbi::G_IM_SIZ_16b, 2, 1);
+    assert_eq!(
+        texture::decode(&yuv, &[0x80, 0x10, 0x80, 0xEB], None).unwrap(),
+        vec![0x10, 0x10, 0x10, 0xFF, 0xEB, 0xEB, 0xEB, 0xFF]
+    );
+}
+
+#[test]
+fn texture_color_indexed() {
+    let palette = [0xF8, 0x01, 0x07, 0xC1, 0x00, 0x3F];
+    let rgba = Tlut {
+        data: &palette,
+        format: TlutFormat::Rgba16,
+    };
+    let ia = Tlut {
+        data: &palette,
+        format: TlutFormat::Ia16,
+    };
+
+    let ci4 = info(gbi::G_IM_FMT_CI, gbi::G_IM_SIZ_4b, 2, 1);
+    assert_eq!(
+        texture::decode(&ci4, &[0x10], Some(rgba)).unwrap(),
+        vec![0, 0xFF, 0, 0xFF, 0xFF, 0, 0, 0xFF]
+    );
+
+    let ci8 = info(gbi::G_IM_FMT_CI, gbi::G_IM_SIZ_8b, 2, 1);
+    assert_eq!(
+        texture::decode(&ci8, &[2, 0], Some(rgba)).unwrap(),
+        vec![0, 0, 0xFF, 0xFF, 0xFF, 0, 0, 0xFF]
+    );
+    assert_eq!(
+        texture::decode(&ci8, &[1, 2], Some(ia)).unwrap(),
+        vec![0x07, 0x07, 0x07, 0xC1, 0, 0, 0, 0x3F]
+    );
+
+    assert_eq!(
+        texture::decode(&ci8, &[3, 0], Some(rgba)),
+        Err(TextureError::TlutTooShort { index: 3 })
+    );
+    assert_eq!(
+        texture::decode(&ci8, &[0, 0], None),
+        Err(TextureError::MissingTlut)
+    );
+
+    let hi = OtherModeHi::from_bits(gbi::G_TT_IA16);
+    assert_eq!(
+        TlutFormat::from_texture_lut(hi.texture_lut),
+        Some(TlutFormat::Ia16)
+    );
+    assert_eq!(
+        TlutFormat::from_texture_lut(gbi::G_TT_RGBA16),
+        Some(TlutFormat::Rgba16)
+    );
+    assert_eq!(TlutFormat::from_texture_lut(gbi::G_TT_NONE), None);
+}
+
+#[test]
+fn texture_errors() {
+    let ia32 = info(gbi::G_IM_FMT_IA, gbi::G_IM_SIZ_32b, 1, 1);
+    assert_eq!(
+        texture::decode(&ia32, &[0; 4], None),
+        Err(TextureError::Unsupported {
+            fmt: gbi::G_IM_FMT_IA,
+            siz: gbi::G_IM_SIZ_32b,
+        })
+    );
+
+    let rgba16 = info(gbi::G_IM_FMT_RGBA, gbi::G_IM_SIZ_16b, 4, 4);
+    assert_eq!(rgba16.size(), 32);
+    assert_eq!(
+        texture::decode(&rgba16, &[0; 31], None),
+        Err(TextureError::DataTooShort {
+            expected: 32,
+            actual: 31,
+        })
+    );
+
+    let mut out = [0; 4];
+    assert_eq!(
+        texture::decode_into(&rgba16, &[0; 32], None, &mut out),
+        Err(TextureError::OutputSize {
+            expected: 64,
+            actual: 4,
+        })
+    );
+}
+
+#[test]
+fn texture_ref_decode() {
+    // Palettes 0 and 1 of a 256 color palette, black and then red and
+    // green, and two CI4 texels.
+    let black = [[0, 0x01]; 16].concat();
+    let palette = [&black[..], &[0xF8, 0x01, 0x07, 0xC1], &[0; 14 * 2]].concat();
+    let image = [0x01];
+    let mut memory = Memory::new();
+    memory.add_segment(6, &image);
+    memory.add_segment(7, &palette);
+
+    let mut texture = TextureRef {
+        addr: 0x0600_0000,
+        fmt: gbi::G_IM_FMT_CI,
+        siz: gbi::G_IM_SIZ_4b,
+        width: 2,
+        height: Some(1),
+        pal: 1,
+        tlut: Some(TlutRef {
+            addr: 0x0700_0000,
+            idx: None,
+            count: 256,
+            format: TlutFormat::Rgba16,
+        }),
+    };
+    assert_eq!(
+        texture.decode(&memory),
+        Ok(vec![0xFF, 0, 0, 0xFF, 0, 0xFF, 0, 0xFF])
+    );
+
+    texture.height = None;
+    assert_eq!(texture.decode(&memory), Err(TextureError::UnknownHeight));
+}
+
+/// A CI4 texture with an IA16 palette, then an RGBA16 texture, then an
+/// image of unknown height, all in segment 6, along with the data they use.
+fn textures_dl() -> (Vec<u8>, Vec<u8>) {
+    let mut list = Vec::new();
+    list.push(gsDPSetTextureLUT(gbi::G_TT_IA16));
+    // Sets the bits right above `G_MDSFT_TEXTLUT`, which must not reset
+    // the palette format.
+    list.push(gsSPSetOtherModeHi(gbi::G_MDSFT_TEXTLOD, 2, 0));
+    list.extend_from_slice(&gsDPLoadTLUT_pal16(0, 0x0600_0000));
+    list.extend_from_slice(&gsDPLoadTextureBlock_4b(
+        0x0600_0020,
+        gbi::G_IM_FMT_CI,
+        16,
+        2,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ));
+    list.push(gsDPSetTextureLUT(gbi::G_TT_NONE));
+    list.extend_from_slice(&gsDPLoadTextureBlock(
+        0x0600_0030,
+        gbi::G_IM_FMT_RGBA,
+        gbi::G_IM_SIZ_16b,
+        4,
+        2,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ));
+    list.push(gsDPSetTextureImage(
+        gbi::G_IM_FMT_RGBA,
+        gbi::G_IM_SIZ_16b,
+        8,
+        0x0600_0040,
+    ));
+    list.push(gsSPEndDisplayList());
+    let input = list
+        .iter()
+        .flat_map(|x: &Gfx| x.to_bytes(Endian::gfxd_endian_big, 4).to_vec())
+        .collect();
+
+    let mut data = Vec::new();
+    for _ in 0..16 {
+        data.extend_from_slice(&[0xFF, 0x80]);
+    }
+    data.extend_from_slice(&[0; 16]);
+    for _ in 0..8 {
+        data.extend_from_slice(&[0xF8, 0x01]);
+    }
+    (input, data)
+}
+
+fn expected_textures() -> Vec<TextureRef> {
+    vec![
+        TextureRef {
+            addr: 0x0600_0020,
+            fmt: gbi::G_IM_FMT_CI,
+            siz: gbi::G_IM_SIZ_4b,
+            width: 16,
+            height: Some(2),
+            pal: 0,
+            tlut: Some(TlutRef {
+                addr: 0x0600_0000,
+                idx: Some(0),
+                count: 16,
+                format: TlutFormat::Ia16,
+            }),
+        },
+        TextureRef {
+            addr: 0x0600_0030,
+            fmt: gbi::G_IM_FMT_RGBA,
+            siz: gbi::G_IM_SIZ_16b,
+            width: 4,
+            height: Some(2),
+            pal: 0,
+            tlut: None,
+        },
+        TextureRef {
+            addr: 0x0600_0040,
+            fmt: gbi::G_IM_FMT_RGBA,
+            siz: gbi::G_IM_SIZ_16b,
+            width: 8,
+            height: None,
+            pal: 0,
+            tlut: None,
+        },
+    ]
+}
+
+#[test]
+fn texture_collect() {
+    let (input, _) = textures_dl();
+    let settings = DecodeSettings::new(Ucode::gfxd_f3dex2);
+
+    let textures = texture::collect_textures(&input, &settings);
+    assert_eq!(textures, expected_textures());
+    assert_eq!(textures[0].file_name(), "06000020.ci4.16x2.png");
+    assert_eq!(textures[1].file_name(), "06000030.rgba16.4x2.png");
+    assert_eq!(textures[2].file_name(), "06000040.rgba16.8.png");
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn texture_dump() {
+    let (input, data) = textures_dl();
+    let settings = DecodeSettings::new(Ucode::gfxd_f3dex2);
+    let mut memory = Memory::new();
+    memory.add_segment(6, &data);
+
+    let dir = std::env::temp_dir().join("gfxd-sys-texture-dump");
+    std::fs::create_dir_all(&dir).unwrap();
+
+    // The image of unknown height cannot be decoded.
+    let written = texture::dump_textures(&input, &settings, &memory, &dir).unwrap();
+    assert_eq!(written, expected_textures()[..2]);
+
+    let ci4 = [[0xFF, 0xFF, 0xFF, 0x80]; 16 * 2].concat();
+    let rgba16 = [[0xFF, 0, 0, 0xFF]; 4 * 2].concat();
+    for (texture, pixels) in written.iter().zip([ci4, rgba16].iter()) {
+        let info = texture.info().unwrap();
+        let png = gfxd_sys::png::encode_rgba8(info.width, info.height, pixels);
+        let file = std::fs::read(dir.join(texture.file_name())).unwrap();
+        assert_eq!(Some(file), png);
+    }
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,283 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::address::AddressResolver;
+use gfxd_sys::call_graph::CallGraph;
+use gfxd_sys::command::Command;
+use gfxd_sys::decode::{Arg, DecodeSettings, Macro, Value};
+use gfxd_sys::differential::Backend;
+use gfxd_sys::gbi;
+use gfxd_sys::gfx::Gfx;
+use gfxd_sys::settings::Ucode;
+use gfxd_sys::static_dl::f3dex2::*;
+use gfxd_sys::traverse::{self, Call, CallKind, Memory};
+
+/// Decodes the few `f3dex2` commands the tests use, one macro per packet.
+struct TestBackend;
+
+impl Backend for TestBackend {
+    fn name(&self) -> &str {
+        "test"
+    }
+
+    fn decode(&mut self, input: &[u8], _settings: &DecodeSettings) -> Vec<Macro> {
+        input
+            .chunks_exact(8)
+            .enumerate()
+            .map(|(i, data)| {
+                let word = |x: &[u8]| u32::from_be_bytes([x[0], x[1], x[2], x[3]]);
+                let (w0, w1) = (word(&data[..4]), word(&data[4..]));
+                let command = match w0 >> 24 {
+                    0xDE if (w0 >> 16) & 0xFF == gbi::G_DL_PUSH => {
+                        Command::SPDisplayList { dl: w1 }
+                    }
+                    0xDE => Command::SPBranchList { dl: w1 },
+                    0xDF => Command::SPEndDisplayList,
+                    0xDB if (w0 >> 16) & 0xFF == gbi::f3d::G_MW_SEGMENT => Command::SPSegment {
+                        seg: (w0 & 0xFFFF) / 4,
+                        base: w1,
+                    },
+                    0xE1 => Command::DPHalf1 { wordhi: w1 },
+                    0x04 => Command::BranchZ {
+                        vtx: (w0 & 0xFFF) / 2,
+                        zval: w1,
+                    },
+                    0xE7 => Command::DPPipeSync,
+                    _ => Command::Invalid { hi: w0, lo: w1 },
+                };
+                let id = command.id();
+                Macro {
+                    offset: i as u32 * 8,
+                    packets: 1,
+                    data: data.to_vec(),
+                    id,
+                    name: None,
+                    args: id
+                        .args()
+                        .iter()
+                        .zip(command.args().iter())
+                        .map(|(info, &value)| Arg {
+                            name: info.name.into(),
+                            type_: info.type_,
+                            value: Value::U(value),
+                            valid: true,
+                            text: value.to_string(),
+                        })
+                        .collect(),
+                    text: String::new(),
+                }
+            })
+            .collect()
+    }
+}
+
+fn to_bytes(list: &[Gfx]) -> Vec<u8> {
+    list.iter()
+        .flat_map(|x| {
+            let mut bytes = x.w0.to_be_bytes().to_vec();
+            bytes.extend_from_slice(&x.w1.to_be_bytes());
+            bytes
+        })
+        .collect()
+}
+
+#[test]
+fn test_traverse_calls_and_cycles() {
+    // 0x06000000: root, calls A twice and branches to B.
+    // 0x06000030: A, calls B.
+    // 0x06000048: B, branches back to A.
+    let segment = to_bytes(&[
+        gsSPDisplayList(0x0600_0030),
+        gsDPPipeSync(),
+        gsSPDisplayList(0x0600_0030),
+        gsSPBranchList(0x0600_0048),
+        // Never reached.
+        gsSPDisplayList(0x0600_0100),
+        gsSPEndDisplayList(),
+        gsSPDisplayList(0x0600_0048),
+        gsSPDisplayList(0x0700_0000),
+        gsSPEndDisplayList(),
+        gsSPBranchList(0x0600_0030),
+    ]);
+    let mut memory = Memory::new();
+    memory.add_segment(6, &segment);
+
+    let settings = DecodeSettings::new(Ucode::gfxd_f3dex2);
+    let traversal = traverse::traverse(
+        &memory,
+        0x0600_0000,
+        &AddressResolver::new(),
+        &settings,
+        &mut TestBackend,
+    );
+
+    let addrs: Vec<u32> = traversal.lists.iter().map(|x| x.addr).collect();
+    assert_eq!(vec![0x0600_0000, 0x0600_0030, 0x0600_0048], addrs);
+
+    let root = traversal.get(0x0600_0000).unwrap();
+    assert_eq!(4, root.macros.len());
+    assert_eq!(Vec::<Call>::new(), root.callers);
+
+    let a = traversal.get(0x0600_0030).unwrap();
+    assert_eq!(3, a.macros.len());
+    assert_eq!(
+        vec![
+            Call {
+                caller: 0x0600_0000,
+                addr: 0x0600_0000,
+                target: 0x0600_0030,
+                kind: CallKind::Call,
+            },
+            Call {
+                caller: 0x0600_0000,
+                addr: 0x0600_0010,
+                target: 0x0600_0030,
+                kind: CallKind::Call,
+            },
+            Call {
+                caller: 0x0600_0048,
+                addr: 0x0600_0048,
+                target: 0x0600_0030,
+                kind: CallKind::Branch,
+            },
+        ],
+        a.callers
+    );
+
+    let b = traversal.get(0x0600_0048).unwrap();
+    assert_eq!(1, b.macros.len());
+    let callers: Vec<u32> = b.callers.iter().map(|x| x.addr).collect();
+    assert_eq!(vec![0x0600_0018, 0x0600_0030], callers);
+
+    assert_eq!(
+        vec![Call {
+            caller: 0x0600_0030,
+            addr: 0x0600_0038,
+            target: 0x0700_0000,
+            kind: CallKind::Call,
+        }],
+        traversal.unresolved
+    );
+}
+
+#[test]
+fn test_traverse_branch_z() {
+    let root = to_bytes(&[
+        gsDPHalf1(0x8010_0020),
+        gsBranchZ(3, 0x1234),
+        gsSPEndDisplayList(),
+    ]);
+    let child = to_bytes(&[gsSPEndDisplayList()]);
+    let mut memory = Memory::new();
+    memory.add_region(0x8010_0000, &root);
+    memory.add_region(0x8010_0020, &child);
+
+    let settings = DecodeSettings::new(Ucode::gfxd_f3dex2);
+    let traversal = traverse::traverse(
+        &memory,
+        0x8010_0000,
+        &AddressResolver::new(),
+        &settings,
+        &mut TestBackend,
+    );
+
+    assert_eq!(2, traversal.lists.len());
+    assert_eq!(
+        vec![Call {
+            caller: 0x8010_0000,
+            addr: 0x8010_0008,
+            target: 0x8010_0020,
+            kind: CallKind::BranchZ,
+        }],
+        traversal.lists[1].callers
+    );
+    assert!(traversal.unresolved.is_empty());
+
+    let traversal = traverse::traverse(
+        &memory,
+        0x0600_0000,
+        &AddressResolver::new(),
+        &settings,
+        &mut TestBackend,
+    );
+    assert!(traversal.lists.is_empty());
+}
+
+#[test]
+fn test_traverse_segments() {
+    // 0x06000000: root, calls A and branches to B, both through segment 8.
+    // 0x06000020: A.
+    // 0x06000028: B, calls A through segment 9, set before the root runs.
+    let segment = to_bytes(&[
+        gsSPSegment(8, 0x0600_0020),
+        gsSPDisplayList(0x0800_0000),
+        gsSPSegment(8, 0x0600_0028),
+        gsSPBranchList(0x0800_0000),
+        gsSPEndDisplayList(),
+        gsSPDisplayList(0x0900_0000),
+        gsSPEndDisplayList(),
+    ]);
+    let mut memory = Memory::new();
+    memory.add_segment(6, &segment);
+
+    let mut resolver = AddressResolver::new();
+    resolver.set_segment(9, 0x0600_0020);
+    // The root itself goes through the segments too.
+    resolver.set_segment(5, 0x0600_0000);
+
+    let settings = DecodeSettings::new(Ucode::gfxd_f3dex2);
+    let traversal =
+        traverse::traverse(&memory, 0x0500_0000, &resolver, &settings, &mut TestBackend);
+
+    let addrs: Vec<u32> = traversal.lists.iter().map(|x| x.addr).collect();
+    assert_eq!(vec![0x0600_0000, 0x0600_0020, 0x0600_0028], addrs);
+
+    let a = traversal.get(0x0600_0020).unwrap();
+    assert_eq!(
+        vec![
+            Call {
+                caller: 0x0600_0000,
+                addr: 0x0600_0008,
+                target: 0x0600_0020,
+                kind: CallKind::Call,
+            },
+            Call {
+                caller: 0x0600_0028,
+                addr: 0x0600_0028,
+                target: 0x0600_0020,
+                kind: CallKind::Call,
+            },
+        ],
+        a.callers
+    );
+
+    let b = traversal.get(0x0600_0028).unwrap();
+    assert_eq!(2, b.macros.len());
+    assert_eq!(
+        vec![Call {
+            caller: 0x0600_0000,
+            addr: 0x0600_0018,
+            target: 0x0600_0028,
+            kind: CallKind::Branch,
+        }],
+        b.callers
+    );
+    assert!(traversal.unresolved.is_empty());
+
+    let graph = CallGraph::from_traversal(&traversal);
+    let edges: Vec<(u32, u32, CallKind)> =
+        graph.edges.iter().map(|x| (x.from, x.to, x.kind)).collect();
+    assert_eq!(
+        vec![
+            (0x0600_0000, 0x0600_0020, CallKind::Call),
+            (0x0600_0000, 0x0600_0028, CallKind::Branch),
+            (0x0600_0028, 0x0600_0020, CallKind::Call),
+        ],
+        edges
+    );
+    assert!(graph.nodes.iter().all(|x| x.decoded));
+}
@@ -0,0 +1,158 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::arg_type::ArgType;
+use gfxd_sys::decode::{Arg, DecodeSettings, Macro, Value};
+use gfxd_sys::gfx::Gfx;
+use gfxd_sys::macro_id::MacroId;
+use gfxd_sys::settings::{Endian, Ucode};
+use gfxd_sys::verify::{self, Method, ReencodeError};
+
+fn arg(name: &str, type_: ArgType, value: u32) -> Arg {
+    Arg {
+        name: name.into(),
+        type_,
+        value: Value::U(value),
+        valid: true,
+        text: format!("{}", value),
+    }
+}
+
+fn vertex_macro(data: Vec<u8>, text: &str) -> Macro {
+    Macro {
+        offset: 0x10,
+        packets: 1,
+        data,
+        id: MacroId::gfxd_SPVertex,
+        name: Some("gsSPVertex".into()),
+        args: vec![
+            arg("v", ArgType::gfxd_Vtxptr, 0x4204_2069),
+            arg("n", ArgType::gfxd_Num, 3),
+            arg("v0", ArgType::gfxd_Vtx, 0),
+        ],
+        text: text.into(),
+    }
+}
+
+#[test]
+fn test_verify_match() {
+    let settings = DecodeSettings::new(Ucode::gfxd_f3dex2);
+    let macros = [vertex_macro(
+        vec![0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69],
+        "gsSPVertex(0x42042069, 3, 0)",
+    )];
+
+    for &method in [Method::Text, Method::Args].iter() {
+        let mismatches = verify::verify_macros(&macros, &settings, method);
+        assert_eq!(Vec::<verify::Mismatch>::new(), mismatches);
+    }
+}
+
+#[test]
+fn test_verify_cull_display_list() {
+    let settings = DecodeSettings::new(Ucode::gfxd_f3d);
+    // `vend + 1` wraps around to 0 in the low word.
+    let macros = [Macro {
+        offset: 0,
+        packets: 1,
+        data: vec![0xBE, 0x00, 0x00, 0x28, 0x00, 0x00, 0x00, 0x00],
+        id: MacroId::gfxd_SPCullDisplayList,
+        name: Some("gsSPCullDisplayList".into()),
+        args: vec![
+            arg("vstart", ArgType::gfxd_Vtx, 1),
+            arg("vend", ArgType::gfxd_Vtx, 15),
+        ],
+        text: "gsSPCullDisplayList(1, 15)".into(),
+    }];
+
+    for &method in [Method::Text, Method::Args].iter() {
+        let mismatches = verify::verify_macros(&macros, &settings, method);
+        assert_eq!(Vec::<verify::Mismatch>::new(), mismatches);
+    }
+}
+
+#[test]
+fn test_verify_little_endian() {
+    let settings = DecodeSettings {
+        endian: Endian::gfxd_endian_little,
+        ..DecodeSettings::new(Ucode::gfxd_f3dex2)
+    };
+    let macros = [vertex_macro(
+        vec![0x06, 0x30, 0x00, 0x01, 0x69, 0x20, 0x04, 0x42],
+        "gsSPVertex(0x42042069, 3, 0)",
+    )];
+
+    let mismatches = verify::verify_macros(&macros, &settings, Method::Text);
+    assert_eq!(Vec::<verify::Mismatch>::new(), mismatches);
+}
+
+#[test]
+fn test_verify_mismatch() {
+    let settings = DecodeSettings::new(Ucode::gfxd_f3dex2);
+    // The printed text lost the low bit of the vertex count.
+    let macros = [vertex_macro(
+        vec![0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69],
+        "gsSPVertex(0x42042069, 2, 0)",
+    )];
+
+    let mismatches = verify::verify_macros(&macros, &settings, Method::Text);
+    assert_eq!(1, mismatches.len());
+    assert_eq!(0, mismatches[0].index);
+    assert_eq!(0x10, mismatches[0].offset);
+    assert_eq!(
+        vec![Gfx::new(0x0100_3006, 0x4204_2069)],
+        mismatches[0].expected
+    );
+    assert_eq!(
+        Ok(vec![Gfx::new(0x0100_2004, 0x4204_2069)]),
+        mismatches[0].actual
+    );
+    assert_eq!(
+        "macro 0 at offset 0x10 `gsSPVertex(0x42042069, 2, 0)`: expected \
+         {0x01003006, 0x42042069}, got {0x01002004, 0x42042069}",
+        mismatches[0].to_string()
+    );
+
+    // The arguments themselves are still fine.
+    let mismatches = verify::verify_macros(&macros, &settings, Method::Args);
+    assert_eq!(Vec::<verify::Mismatch>::new(), mismatches);
+}
+
+#[test]
+fn test_verify_unsupported() {
+    let settings = DecodeSettings::new(Ucode::gfxd_f3dex2);
+    let macros = [vertex_macro(
+        vec![0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69],
+        "gsSPVertex(my_vtx, 3, 0)",
+    )];
+
+    let mismatches = verify::verify_macros(&macros, &settings, Method::Text);
+    assert_eq!(1, mismatches.len());
+    match &mismatches[0].actual {
+        Err(ReencodeError::Asm(_)) => {}
+        x => panic!("unexpected result {:?}", x),
+    }
+}
+
+#[test]
+fn test_verify_buffer() {
+    static DLIST_DATA: [u8; 0x18] = [
+        0x01, 0x00, 0x30, 0x06, 0x42, 0x04, 0x20, 0x69, //
+        0x05, 0x00, 0x02, 0x04, 0x00, 0x00, 0x00, 0x00, //
+        0xDF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, //
+    ];
+
+    let report = verify::verify(
+        &DLIST_DATA,
+        &DecodeSettings::new(Ucode::gfxd_f3dex2),
+        Method::Text,
+    );
+
+    assert_eq!(3, report.checked);
+    assert_eq!(Vec::<verify::Mismatch>::new(), report.mismatches);
+    assert!(report.is_match());
+}
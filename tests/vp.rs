@@ -0,0 +1,57 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::settings::Endian;
+use gfxd_sys::vp::{Viewport, Vp, MAXZ};
+
+#[test]
+fn vp_viewport() {
+    // The usual full screen viewport of a 320x240 game.
+    let vp = Vp {
+        vscale: [640, 480, 511, 0],
+        vtrans: [640, 480, 511, 0],
+    };
+    let rect = Viewport {
+        x: 0.0,
+        y: 0.0,
+        width: 320.0,
+        height: 240.0,
+    };
+
+    assert_eq!(vp.viewport(), rect);
+    assert_eq!(Vp::from_viewport(&rect), vp);
+    assert_eq!(vp.depth_range(), (0, 1022));
+    assert_eq!(MAXZ / 2, 511);
+
+    let inset = Viewport {
+        x: 10.5,
+        y: 20.0,
+        width: 100.0,
+        height: 50.5,
+    };
+    assert_eq!(Vp::from_viewport(&inset).viewport(), inset);
+}
+
+#[test]
+fn vp_bytes() {
+    let vp = Vp {
+        vscale: [640, -480, 511, 0],
+        vtrans: [642, 480, 511, 0],
+    };
+    let bytes = vp.to_bytes(Endian::gfxd_endian_big, 4);
+
+    assert_eq!(
+        &bytes[..],
+        &[
+            0x02, 0x80, 0xFE, 0x20, 0x01, 0xFF, 0x00, 0x00, 0x02, 0x82, 0x01, 0xE0, 0x01, 0xFF,
+            0x00, 0x00
+        ][..]
+    );
+    assert_eq!(Vp::read(&bytes, Endian::gfxd_endian_big, 4), Some(vp));
+
+    let little = vp.to_bytes(Endian::gfxd_endian_little, 4);
+    assert_eq!(&little[..4], &[0x20, 0xFE, 0x80, 0x02]);
+    assert_eq!(Vp::read(&little, Endian::gfxd_endian_little, 4), Some(vp));
+}
@@ -0,0 +1,143 @@
+/* SPDX-FileCopyrightText: © 2025 Decompollaborate */
+/* SPDX-License-Identifier: MIT */
+
+#![cfg(feature = "alloc")]
+
+use pretty_assertions::assert_eq;
+
+use gfxd_sys::command::Command;
+use gfxd_sys::decode::{Arg, Macro, Value};
+use gfxd_sys::settings::Endian;
+use gfxd_sys::vtx::{self, Vtx, VtxRange, VtxRanges};
+
+fn vertex_macro(v: u32, n: u32, v0: u32) -> Macro {
+    let command = Command::SPVertex { v, n, v0 };
+    let id = command.id();
+    Macro {
+        offset: 0,
+        packets: 1,
+        data: vec![0; 8],
+        id,
+        name: None,
+        args: id
+            .args()
+            .iter()
+            .zip(command.args().iter())
+            .map(|(info, &value)| Arg {
+                name: info.name.into(),
+                type_: info.type_,
+                value: Value::U(value),
+                valid: true,
+                text: value.to_string(),
+            })
+            .collect(),
+        text: String::new(),
+    }
+}
+
+#[test]
+fn vtx_read() {
+    let bytes = [
+        0xFF, 0xF6, 0x00, 0x14, 0x01, 0x00, 0x00, 0x00, 0x04, 0x00, 0xFC, 0x00, 0x7F, 0x81, 0x00,
+        0xC8,
+    ];
+    let v = Vtx::read(&bytes, Endian::gfxd_endian_big, 4).unwrap();
+
+    assert_eq!(
+        v,
+        Vtx {
+            ob: [-10, 20, 256],
+            flag: 0,
+            tc: [1024, -1024],
+            cn: [0x7F, 0x81, 0x00, 0xC8],
+        }
+    );
+    assert_eq!(v.color(), [0x7F, 0x81, 0x00, 0xC8]);
+    assert_eq!(v.normal(), [127, -127, 0]);
+    assert_eq!(v.alpha(), 0xC8);
+
+    for &wordsize in [1, 2, 4, 8].iter() {
+        for &endian in [Endian::gfxd_endian_big, Endian::gfxd_endian_little].iter() {
+            let raw = v.to_bytes(endian, wordsize);
+            assert_eq!(Vtx::from_bytes(raw, endian, wordsize), v);
+        }
+    }
+
+    let little = v.to_bytes(Endian::gfxd_endian_little, 4);
+    assert_eq!(&little[..4], &[0x14, 0x00, 0xF6, 0xFF]);
+
+    let mut two = bytes.to_vec();
+    two.extend_from_slice(&bytes);
+    two.push(0);
+    assert_eq!(
+        vtx::read_vertices(&two, Endian::gfxd_endian_big, 4).count(),
+        2
+    );
+    assert_eq!(Vtx::read(&bytes[1..], Endian::gfxd_endian_big, 4), None);
+}
+
+#[test]
+fn vtx_ranges_merge() {
+    let macros = [
+        vertex_macro(0x0600_0100, 32, 0),
+        vertex_macro(0x0600_0000, 8, 0),
+        // Overlaps the first load.
+        vertex_macro(0x0600_0200, 32, 0),
+        // Touches the second load without overlapping it.
+        vertex_macro(0x0600_0080, 4, 4),
+    ];
+    let mut ranges = VtxRanges::from_macros(&macros);
+
+    assert_eq!(
+        ranges.ranges(),
+        &[
+            VtxRange {
+                addr: 0x0600_0000,
+                count: 8,
+            },
+            VtxRange {
+                addr: 0x0600_0080,
+                count: 4,
+            },
+            VtxRange {
+                addr: 0x0600_0100,
+                count: 48,
+            },
+        ]
+    );
+
+    // Bridges the last two ranges.
+    ranges.add(0x0600_00A0, 8);
+    assert_eq!(
+        ranges.ranges()[1],
+        VtxRange {
+            addr: 0x0600_0080,
+            count: 56,
+        }
+    );
+    assert!(ranges.contains(0x0600_03F0));
+    assert!(!ranges.contains(0x0600_0400));
+
+    // Vertices past the end of the address space are left out.
+    let mut ranges = VtxRanges::new();
+    ranges.add(0xFFFF_FFE0, 4);
+    ranges.add(0xFFFF_FFC0, 4);
+    ranges.add(0xFFFF_FFF8, 1);
+    assert_eq!(
+        ranges.ranges(),
+        &[VtxRange {
+            addr: 0xFFFF_FFC0,
+            count: 3,
+        }]
+    );
+    assert!(ranges.contains(0xFFFF_FFE0));
+    assert!(!ranges.contains(0x0000_0000));
+    assert_eq!(
+        VtxRange {
+            addr: 0xFFFF_FFF0,
+            count: 2,
+        }
+        .end(),
+        0xFFFF_FFFF
+    );
+}